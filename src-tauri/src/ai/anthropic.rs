@@ -33,7 +33,7 @@ impl AnthropicProvider {
     }
 
     fn create_http_client() -> Result<Client, AIError> {
-        Client::builder()
+        crate::utils::proxy::client_builder()
             .timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECS))
             .build()
             .map_err(|e| AIError::NetworkError(format!("Failed to create HTTP client: {}", e)))
@@ -29,12 +29,15 @@ impl GeminiProvider {
         model: String,
         options: HashMap<String, serde_json::Value>,
     ) -> Result<Self, AIError> {
-        // Validate model
+        // Validate model; other Gemini models listed by the API are allowed with a warning
         if !SUPPORTED_MODELS.contains(&model.as_str()) {
-            return Err(AIError::ValidationError(format!(
-                "Unsupported model: {}",
-                model
-            )));
+            if !model.starts_with("gemini-") {
+                return Err(AIError::ValidationError(format!(
+                    "Unsupported model: {}",
+                    model
+                )));
+            }
+            log::warn!("Using uncurated Gemini model: {}", model);
         }
 
         // Validate API key format (basic check)
@@ -53,7 +56,7 @@ impl GeminiProvider {
     }
 
     fn create_http_client() -> Result<Client, AIError> {
-        Client::builder()
+        crate::utils::proxy::client_builder()
             .timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECS))
             .build()
             .map_err(|e| AIError::NetworkError(format!("Failed to create HTTP client: {}", e)))
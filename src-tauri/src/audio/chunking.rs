@@ -0,0 +1,175 @@
+// Chunked transcription for long audio. The normalized WAV is split into
+// overlapping windows that are read and written one at a time, so memory use
+// is bounded by the chunk size rather than the file length. Each window's
+// text is stitched onto the previous one, dropping the words the overlap
+// transcribed twice.
+
+use hound::{WavReader, WavWriter};
+use std::ops::Range;
+use std::path::Path;
+
+pub const DEFAULT_CHUNK_SECONDS: u32 = 300;
+pub const DEFAULT_OVERLAP_SECONDS: u32 = 5;
+
+/// Shortest chunk accepted; whisper works on 30 s windows anyway
+pub const MIN_CHUNK_SECONDS: u32 = 30;
+
+/// Chunking settings; a `chunk_seconds` of 0 transcribes files in one pass
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkingConfig {
+    pub chunk_seconds: u32,
+    pub overlap_seconds: u32,
+}
+
+impl Default for ChunkingConfig {
+    fn default() -> Self {
+        Self {
+            chunk_seconds: DEFAULT_CHUNK_SECONDS,
+            overlap_seconds: DEFAULT_OVERLAP_SECONDS,
+        }
+    }
+}
+
+impl ChunkingConfig {
+    /// Whether audio of `total_samples` should be split
+    pub fn applies_to(&self, total_samples: usize, sample_rate: u32) -> bool {
+        self.chunk_seconds > 0
+            && total_samples
+                > self.chunk_seconds.max(MIN_CHUNK_SECONDS) as usize * sample_rate as usize
+    }
+}
+
+/// Longest run of words compared when stitching two chunks
+const MAX_STITCH_WORDS: usize = 40;
+
+/// Sample ranges covering `total_samples` in windows of `chunk_seconds`, each
+/// starting `overlap_seconds` before the previous one ended
+pub fn plan_chunks(
+    total_samples: usize,
+    sample_rate: u32,
+    chunk_seconds: u32,
+    overlap_seconds: u32,
+) -> Vec<Range<usize>> {
+    let chunk = chunk_seconds.max(MIN_CHUNK_SECONDS) as usize * sample_rate as usize;
+    // Overlap must leave the window advancing
+    let overlap = (overlap_seconds as usize * sample_rate as usize).min(chunk / 2);
+    if chunk == 0 || total_samples <= chunk {
+        return vec![0..total_samples];
+    }
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    loop {
+        let end = (start + chunk).min(total_samples);
+        chunks.push(start..end);
+        if end == total_samples {
+            break;
+        }
+        start = end - overlap;
+    }
+    chunks
+}
+
+/// Copy `range` of a mono WAV into a new file with the same format
+pub fn extract_chunk_wav(source: &Path, range: Range<usize>, dest: &Path) -> Result<(), String> {
+    let mut reader =
+        WavReader::open(source).map_err(|e| format!("Failed to open audio for chunking: {}", e))?;
+    let spec = reader.spec();
+    reader
+        .seek(range.start as u32)
+        .map_err(|e| format!("Failed to seek audio chunk: {}", e))?;
+
+    let mut writer =
+        WavWriter::create(dest, spec).map_err(|e| format!("Failed to write audio chunk: {}", e))?;
+    for sample in reader.samples::<i16>().take(range.len()) {
+        let sample = sample.map_err(|e| format!("Failed to read audio chunk: {}", e))?;
+        writer
+            .write_sample(sample)
+            .map_err(|e| format!("Failed to write audio chunk: {}", e))?;
+    }
+    writer
+        .finalize()
+        .map_err(|e| format!("Failed to finalize audio chunk: {}", e))
+}
+
+fn normalize_word(word: &str) -> String {
+    word.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Append `next` to `text`, removing the longest run of words at the start of
+/// `next` that repeats the end of `text` (ignoring case and punctuation)
+pub fn stitch_transcripts(text: &str, next: &str) -> String {
+    let next = next.trim();
+    if text.trim().is_empty() {
+        return next.to_string();
+    }
+    if next.is_empty() {
+        return text.trim_end().to_string();
+    }
+
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let tail: Vec<String> = words[words.len().saturating_sub(MAX_STITCH_WORDS)..]
+        .iter()
+        .map(|w| normalize_word(w))
+        .collect();
+    let head_words: Vec<&str> = next.split_whitespace().collect();
+    let head: Vec<String> = head_words
+        .iter()
+        .take(MAX_STITCH_WORDS)
+        .map(|w| normalize_word(w))
+        .collect();
+
+    let max = tail.len().min(head.len());
+    let repeated = (1..=max)
+        .rev()
+        .find(|&n| tail[tail.len() - n..] == head[..n])
+        .unwrap_or(0);
+
+    let rest = head_words[repeated..].join(" ");
+    if rest.is_empty() {
+        text.trim_end().to_string()
+    } else {
+        format!("{} {}", text.trim_end(), rest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_chunks_overlaps_and_covers_audio() {
+        let rate = 16000;
+        let chunks = plan_chunks(rate as usize * 150, rate, 60, 5);
+        assert_eq!(
+            chunks,
+            vec![
+                0..rate as usize * 60,
+                rate as usize * 55..rate as usize * 115,
+                rate as usize * 110..rate as usize * 150,
+            ]
+        );
+
+        // Short audio is a single chunk
+        assert_eq!(plan_chunks(1000, rate, 60, 5), vec![0..1000]);
+    }
+
+    #[test]
+    fn test_stitch_transcripts_drops_repeated_overlap() {
+        assert_eq!(
+            stitch_transcripts(
+                "we should meet on Tuesday at noon",
+                "Tuesday, at noon. Then lunch."
+            ),
+            "we should meet on Tuesday at noon Then lunch."
+        );
+        assert_eq!(
+            stitch_transcripts("first part", "second part"),
+            "first part second part"
+        );
+        assert_eq!(stitch_transcripts("", " hello "), "hello");
+    }
+}
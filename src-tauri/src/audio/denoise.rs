@@ -0,0 +1,228 @@
+// Spectral-subtraction noise suppression for captured speech.
+// Runs on the normalized 16 kHz mono audio before transcription.
+
+use hound::{SampleFormat, WavReader, WavWriter};
+use realfft::num_complex::Complex;
+use realfft::RealFftPlanner;
+use std::path::Path;
+
+const FRAME_SIZE: usize = 512; // 32 ms at 16 kHz
+const HOP_SIZE: usize = FRAME_SIZE / 2;
+/// Share of the quietest frames used to estimate the noise floor
+const NOISE_FRAME_FRACTION: f32 = 0.1;
+/// Lowest gain applied to a bin; keeps some residual noise to avoid "musical" artifacts
+const MIN_GAIN: f32 = 0.1;
+/// Strength used by `denoise` when the caller doesn't pick one
+pub const DEFAULT_STRENGTH: f32 = 0.5;
+
+/// Suppress stationary background noise with the default strength
+pub fn denoise(samples: &[f32]) -> Vec<f32> {
+    denoise_with_strength(samples, DEFAULT_STRENGTH)
+}
+
+/// Suppress stationary background noise. `strength` ranges from 0.0 (off)
+/// to 1.0 (aggressive); the noise profile is estimated from the quietest frames.
+pub fn denoise_with_strength(samples: &[f32], strength: f32) -> Vec<f32> {
+    let strength = strength.clamp(0.0, 1.0);
+    if strength == 0.0 || samples.len() < FRAME_SIZE {
+        return samples.to_vec();
+    }
+
+    // Zero-pad to a whole number of hops so the tail after the last full
+    // frame is processed too; the padding is cut off again at the end
+    let full_frames = (samples.len() - FRAME_SIZE) / HOP_SIZE + 1;
+    let frame_count = (samples.len() - FRAME_SIZE).div_ceil(HOP_SIZE) + 1;
+    let mut padded = samples.to_vec();
+    padded.resize((frame_count - 1) * HOP_SIZE + FRAME_SIZE, 0.0);
+
+    let window: Vec<f32> = (0..FRAME_SIZE)
+        .map(|i| 0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / FRAME_SIZE as f32).cos())
+        .collect();
+
+    let mut planner = RealFftPlanner::<f32>::new();
+    let forward = planner.plan_fft_forward(FRAME_SIZE);
+    let inverse = planner.plan_fft_inverse(FRAME_SIZE);
+
+    // Analysis: windowed spectra for every hop
+    let mut spectra: Vec<Vec<Complex<f32>>> = Vec::with_capacity(frame_count);
+    let mut frame = forward.make_input_vec();
+    for f in 0..frame_count {
+        let start = f * HOP_SIZE;
+        for (i, value) in frame.iter_mut().enumerate() {
+            *value = padded[start + i] * window[i];
+        }
+        let mut spectrum = forward.make_output_vec();
+        if forward.process(&mut frame, &mut spectrum).is_err() {
+            return samples.to_vec();
+        }
+        spectra.push(spectrum);
+    }
+
+    // The padded last frame would read as near-silent and skew the estimate
+    let noise_profile = estimate_noise_profile(&spectra[..full_frames]);
+
+    // Synthesis: subtract the noise magnitude per bin, keep the phase, overlap-add
+    let mut output = vec![0.0f32; padded.len()];
+    let mut time_frame = inverse.make_output_vec();
+    for (f, spectrum) in spectra.iter_mut().enumerate() {
+        for (bin, value) in spectrum.iter_mut().enumerate() {
+            let magnitude = value.norm();
+            let gain = if magnitude > 0.0 {
+                (1.0 - strength * 2.0 * noise_profile[bin] / magnitude).max(MIN_GAIN)
+            } else {
+                MIN_GAIN
+            };
+            *value *= gain;
+        }
+        // DC and Nyquist bins must be purely real for the inverse transform
+        spectrum[0].im = 0.0;
+        if let Some(last) = spectrum.last_mut() {
+            last.im = 0.0;
+        }
+
+        if inverse.process(spectrum, &mut time_frame).is_err() {
+            return samples.to_vec();
+        }
+        let start = f * HOP_SIZE;
+        for (i, value) in time_frame.iter().enumerate() {
+            // realfft's inverse is unnormalized
+            output[start + i] += value / FRAME_SIZE as f32;
+        }
+    }
+
+    // Hann windows at 50% overlap sum to one, except in the first and last half frame
+    let covered = padded.len();
+    output[..HOP_SIZE].copy_from_slice(&padded[..HOP_SIZE]);
+    output[covered - HOP_SIZE..].copy_from_slice(&padded[covered - HOP_SIZE..]);
+
+    output.truncate(samples.len());
+    output
+}
+
+/// Average magnitude per bin over the quietest frames
+fn estimate_noise_profile(spectra: &[Vec<Complex<f32>>]) -> Vec<f32> {
+    let bins = spectra.first().map(|s| s.len()).unwrap_or(0);
+    let mut energies: Vec<(usize, f32)> = spectra
+        .iter()
+        .enumerate()
+        .map(|(i, s)| (i, s.iter().map(|c| c.norm_sqr()).sum()))
+        .collect();
+    energies.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+
+    let count = ((spectra.len() as f32 * NOISE_FRAME_FRACTION).ceil() as usize).max(1);
+    let mut profile = vec![0.0f32; bins];
+    for &(index, _) in energies.iter().take(count) {
+        for (bin, value) in spectra[index].iter().enumerate() {
+            profile[bin] += value.norm();
+        }
+    }
+    for value in profile.iter_mut() {
+        *value /= count as f32;
+    }
+    profile
+}
+
+/// Denoise a 16-bit mono WAV file in place
+pub fn denoise_wav_file(path: &Path, strength: f32) -> Result<(), String> {
+    let mut reader =
+        WavReader::open(path).map_err(|e| format!("Failed to open audio for denoise: {}", e))?;
+    let spec = reader.spec();
+    if spec.channels != 1 || spec.bits_per_sample != 16 || spec.sample_format != SampleFormat::Int {
+        return Err("Noise suppression expects 16-bit mono audio".to_string());
+    }
+
+    let samples: Vec<f32> = reader
+        .samples::<i16>()
+        .map(|s| s.map(|v| v as f32 / i16::MAX as f32))
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("Failed to read audio for denoise: {}", e))?;
+    drop(reader);
+
+    let cleaned = denoise_with_strength(&samples, strength);
+
+    // Write next to the original and swap, so a failure leaves the input intact
+    let tmp_path = path.with_extension("denoised.wav");
+    let result = (|| {
+        let mut writer = WavWriter::create(&tmp_path, spec)
+            .map_err(|e| format!("Failed to write denoised audio: {}", e))?;
+        for sample in cleaned {
+            writer
+                .write_sample((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)
+                .map_err(|e| format!("Failed to write denoised audio: {}", e))?;
+        }
+        writer
+            .finalize()
+            .map_err(|e| format!("Failed to finalize denoised audio: {}", e))?;
+        crate::utils::secure_delete::replace_file(&tmp_path, path)
+            .map_err(|e| format!("Failed to replace audio with denoised version: {}", e))
+    })();
+
+    if result.is_err() {
+        let _ = crate::utils::secure_delete::remove_file(&tmp_path);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rms(samples: &[f32]) -> f32 {
+        (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+    }
+
+    fn noise(len: usize, amplitude: f32) -> Vec<f32> {
+        // Deterministic pseudo-random noise
+        let mut state: u32 = 12345;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                ((state >> 16) as f32 / 32768.0 - 1.0) * amplitude
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_denoise_disabled_is_passthrough() {
+        let samples = noise(4000, 0.1);
+        assert_eq!(denoise_with_strength(&samples, 0.0), samples);
+        // Too short to process
+        let short = noise(100, 0.1);
+        assert_eq!(denoise(&short), short);
+    }
+
+    #[test]
+    fn test_denoise_reduces_background_noise() {
+        let samples = noise(16000, 0.05);
+        let cleaned = denoise_with_strength(&samples, 1.0);
+        assert_eq!(cleaned.len(), samples.len());
+        assert!(rms(&cleaned) < rms(&samples) * 0.7);
+    }
+
+    #[test]
+    fn test_denoise_keeps_tail_after_last_full_frame() {
+        // Not a whole number of frames or hops
+        let len = 16000 + HOP_SIZE / 2 + 37;
+        let mut samples = noise(len, 0.01);
+        for (i, s) in samples.iter_mut().enumerate().skip(4000) {
+            *s += 0.5 * (2.0 * std::f32::consts::PI * 440.0 * i as f32 / 16000.0).sin();
+        }
+        let cleaned = denoise(&samples);
+        assert_eq!(cleaned.len(), len);
+        let tail = len - FRAME_SIZE..;
+        assert!(rms(&cleaned[tail.clone()]) > rms(&samples[tail]) * 0.8);
+    }
+
+    #[test]
+    fn test_denoise_keeps_speech_like_tone() {
+        // Quiet noise-only lead-in followed by a loud tone over the same noise
+        let mut samples = noise(16000, 0.01);
+        for (i, s) in samples.iter_mut().enumerate().skip(4000) {
+            *s += 0.5 * (2.0 * std::f32::consts::PI * 440.0 * i as f32 / 16000.0).sin();
+        }
+        let cleaned = denoise(&samples);
+        let tone_before = rms(&samples[8000..12000]);
+        let tone_after = rms(&cleaned[8000..12000]);
+        assert!(tone_after > tone_before * 0.8);
+    }
+}
@@ -45,6 +45,9 @@ pub async fn try_start_device_watcher_if_ready(app: &AppHandle) {
     log::info!("Device watcher: both conditions met, starting watcher");
     let device_watcher = app.state::<DeviceWatcher>();
     device_watcher.start();
+
+    // Pre-roll capture needs the same conditions as the watcher
+    crate::commands::audio::apply_preroll_settings(app);
 }
 
 /// Background watcher that monitors OS microphone devices and emits updates.
@@ -0,0 +1,118 @@
+// Encoding of stored recordings for export. Recordings are kept as 16 kHz
+// mono WAV; WAV is written here and MP3 by the bundled ffmpeg. Only formats
+// with an encoder are offered, so asking for anything else fails when the
+// format is deserialized.
+
+use hound::{SampleFormat, WavReader, WavSpec, WavWriter};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tauri::AppHandle;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AudioFormat {
+    /// 16-bit PCM WAV at the recording's sample rate
+    Wav,
+    /// MP3 at `MP3_BITRATE_KBPS`
+    Mp3,
+}
+
+/// Plenty for 16 kHz mono speech
+pub const MP3_BITRATE_KBPS: u32 = 64;
+
+impl AudioFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            AudioFormat::Wav => "wav",
+            AudioFormat::Mp3 => "mp3",
+        }
+    }
+}
+
+/// Re-encode a WAV file as 16-bit PCM, converting float or other bit depths
+pub fn encode_wav(source: &Path, dest: &Path) -> Result<(), String> {
+    let mut reader =
+        WavReader::open(source).map_err(|e| format!("Failed to open recording: {}", e))?;
+    let spec = reader.spec();
+    let out_spec = WavSpec {
+        bits_per_sample: 16,
+        sample_format: SampleFormat::Int,
+        ..spec
+    };
+
+    let samples: Vec<i16> = match (spec.sample_format, spec.bits_per_sample) {
+        (SampleFormat::Int, 16) => reader.samples::<i16>().collect::<Result<_, _>>(),
+        (SampleFormat::Int, bits) => {
+            let shift = bits.saturating_sub(16) as u32;
+            reader
+                .samples::<i32>()
+                .map(|s| s.map(|v| (v >> shift) as i16))
+                .collect::<Result<_, _>>()
+        }
+        (SampleFormat::Float, _) => reader
+            .samples::<f32>()
+            .map(|s| s.map(|v| (v.clamp(-1.0, 1.0) * i16::MAX as f32) as i16))
+            .collect::<Result<_, _>>(),
+    }
+    .map_err(|e| format!("Failed to read recording: {}", e))?;
+
+    let mut writer = WavWriter::create(dest, out_spec)
+        .map_err(|e| format!("Failed to create export file: {}", e))?;
+    for sample in samples {
+        writer
+            .write_sample(sample)
+            .map_err(|e| format!("Failed to write export file: {}", e))?;
+    }
+    writer
+        .finalize()
+        .map_err(|e| format!("Failed to finalize export file: {}", e))
+}
+
+/// Encode the recording at `source` into `dest` in `format`
+pub async fn export_audio(
+    app: &AppHandle,
+    source: &Path,
+    format: AudioFormat,
+    dest: &Path,
+) -> Result<(), String> {
+    match format {
+        AudioFormat::Wav => {
+            let (source, dest) = (source.to_path_buf(), dest.to_path_buf());
+            tauri::async_runtime::spawn_blocking(move || encode_wav(&source, &dest))
+                .await
+                .map_err(|e| format!("Export task failed: {}", e))?
+        }
+        AudioFormat::Mp3 => crate::ffmpeg::to_mp3(app, source, dest, MP3_BITRATE_KBPS).await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_wav_converts_float_to_pcm16() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("recording_1.wav");
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: 16000,
+            bits_per_sample: 32,
+            sample_format: SampleFormat::Float,
+        };
+        let mut writer = WavWriter::create(&source, spec).unwrap();
+        for sample in [0.0f32, 0.5, -1.0, 2.0] {
+            writer.write_sample(sample).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let dest = dir.path().join("export.wav");
+        encode_wav(&source, &dest).unwrap();
+
+        let mut reader = WavReader::open(&dest).unwrap();
+        assert_eq!(reader.spec().bits_per_sample, 16);
+        assert_eq!(reader.spec().sample_rate, 16000);
+        let samples: Vec<i16> = reader.samples::<i16>().map(|s| s.unwrap()).collect();
+        assert_eq!(samples, vec![0, i16::MAX / 2, -i16::MAX, i16::MAX]);
+    }
+}
@@ -0,0 +1,139 @@
+// Input gain and peak normalization for captured speech.
+// Gain is applied live in the recorder (so the mic-level meter previews it);
+// peak normalization runs on the finished 16 kHz mono recording.
+
+use hound::{SampleFormat, WavReader, WavWriter};
+use std::path::Path;
+
+/// Range accepted for `recording_gain_db`
+pub const MIN_GAIN_DB: f32 = -20.0;
+pub const MAX_GAIN_DB: f32 = 20.0;
+
+/// Default peak targeted by normalization (about -1 dBFS)
+pub const DEFAULT_TARGET_PEAK: f32 = 0.9;
+
+/// Recordings quieter than this are silence; amplifying them only raises noise
+const MIN_NORMALIZE_PEAK: f32 = 0.001;
+
+pub fn clamp_gain_db(db: f32) -> f32 {
+    if db.is_finite() {
+        db.clamp(MIN_GAIN_DB, MAX_GAIN_DB)
+    } else {
+        0.0
+    }
+}
+
+pub fn db_to_linear(db: f32) -> f32 {
+    10f32.powf(clamp_gain_db(db) / 20.0)
+}
+
+/// Scale samples by `db` decibels, clamping to [-1.0, 1.0] so loud input
+/// saturates instead of wrapping
+pub fn apply_gain(samples: &mut [f32], db: f32) {
+    let factor = db_to_linear(db);
+    if factor == 1.0 {
+        return;
+    }
+    for sample in samples.iter_mut() {
+        *sample = (*sample * factor).clamp(-1.0, 1.0);
+    }
+}
+
+pub fn peak(samples: &[f32]) -> f32 {
+    samples.iter().fold(0.0f32, |max, s| max.max(s.abs()))
+}
+
+/// Scale samples so the loudest one reaches `target_peak`. Returns the factor
+/// applied (1.0 when the audio is silent or already at the target).
+pub fn normalize_peak(samples: &mut [f32], target_peak: f32) -> f32 {
+    let target = target_peak.clamp(0.0, 1.0);
+    let current = peak(samples);
+    if current < MIN_NORMALIZE_PEAK || target == 0.0 {
+        return 1.0;
+    }
+    let factor = target / current;
+    for sample in samples.iter_mut() {
+        *sample = (*sample * factor).clamp(-1.0, 1.0);
+    }
+    factor
+}
+
+/// Peak-normalize a 16-bit mono WAV file in place
+pub fn normalize_wav_file(path: &Path, target_peak: f32) -> Result<f32, String> {
+    let mut reader =
+        WavReader::open(path).map_err(|e| format!("Failed to open audio for normalize: {}", e))?;
+    let spec = reader.spec();
+    if spec.channels != 1 || spec.bits_per_sample != 16 || spec.sample_format != SampleFormat::Int {
+        return Err("Peak normalization expects 16-bit mono audio".to_string());
+    }
+
+    let mut samples: Vec<f32> = reader
+        .samples::<i16>()
+        .map(|s| s.map(|v| v as f32 / i16::MAX as f32))
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("Failed to read audio for normalize: {}", e))?;
+    drop(reader);
+
+    let factor = normalize_peak(&mut samples, target_peak);
+    if factor == 1.0 {
+        return Ok(factor);
+    }
+
+    // Write next to the original and swap, so a failure leaves the input intact
+    let tmp_path = path.with_extension("gain.wav");
+    let result = (|| {
+        let mut writer = WavWriter::create(&tmp_path, spec)
+            .map_err(|e| format!("Failed to write normalized audio: {}", e))?;
+        for sample in samples {
+            writer
+                .write_sample((sample * i16::MAX as f32) as i16)
+                .map_err(|e| format!("Failed to write normalized audio: {}", e))?;
+        }
+        writer
+            .finalize()
+            .map_err(|e| format!("Failed to finalize normalized audio: {}", e))?;
+        crate::utils::secure_delete::replace_file(&tmp_path, path)
+            .map_err(|e| format!("Failed to replace audio with normalized version: {}", e))
+    })();
+
+    if result.is_err() {
+        let _ = crate::utils::secure_delete::remove_file(&tmp_path);
+    }
+    result.map(|_| factor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_gain_scales_and_clamps() {
+        let mut samples = vec![0.1, -0.1, 0.8];
+        apply_gain(&mut samples, 6.0);
+        assert!((samples[0] - 0.1995).abs() < 1e-3);
+        assert!((samples[1] + 0.1995).abs() < 1e-3);
+        // Would be ~1.6, saturates instead of clipping around
+        assert_eq!(samples[2], 1.0);
+
+        let mut unchanged = vec![0.5];
+        apply_gain(&mut unchanged, 0.0);
+        assert_eq!(unchanged, vec![0.5]);
+
+        // Out-of-range and non-finite settings are clamped
+        assert_eq!(clamp_gain_db(60.0), MAX_GAIN_DB);
+        assert_eq!(clamp_gain_db(f32::NAN), 0.0);
+    }
+
+    #[test]
+    fn test_normalize_peak_targets_loudest_sample() {
+        let mut samples = vec![0.2, -0.45, 0.1];
+        let factor = normalize_peak(&mut samples, 0.9);
+        assert!((factor - 2.0).abs() < 1e-6);
+        assert!((peak(&samples) - 0.9).abs() < 1e-6);
+
+        // Silence is left alone
+        let mut silence = vec![0.0; 16];
+        assert_eq!(normalize_peak(&mut silence, 0.9), 1.0);
+        assert!(silence.iter().all(|s| *s == 0.0));
+    }
+}
@@ -1,10 +1,17 @@
+pub mod chunking;
 pub mod converter;
+pub mod denoise;
 pub mod device_watcher;
+pub mod encode;
+pub mod gain;
 pub mod level_meter;
 pub mod normalizer;
+pub mod preroll;
 pub mod recorder;
 pub mod resampler;
 pub mod silence_detector;
+pub mod sound_cues;
+pub mod trim;
 
 #[cfg(test)]
 mod converter_tests;
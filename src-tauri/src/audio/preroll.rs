@@ -0,0 +1,231 @@
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use std::collections::VecDeque;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Upper bound for the pre-roll window
+pub const MAX_PREROLL_MS: u32 = 2000;
+
+/// Fixed-size ring of the most recent interleaved i16 samples
+pub struct PrerollBuffer {
+    samples: VecDeque<i16>,
+    capacity: usize,
+    sample_rate: u32,
+    channels: u16,
+}
+
+impl PrerollBuffer {
+    pub fn new(preroll_ms: u32, sample_rate: u32, channels: u16) -> Self {
+        let capacity = Self::capacity_for(preroll_ms, sample_rate, channels);
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+            sample_rate,
+            channels,
+        }
+    }
+
+    /// Number of interleaved samples needed to hold `preroll_ms` of audio
+    pub fn capacity_for(preroll_ms: u32, sample_rate: u32, channels: u16) -> usize {
+        let frames = sample_rate as u64 * preroll_ms.min(MAX_PREROLL_MS) as u64 / 1000;
+        (frames * channels.max(1) as u64) as usize
+    }
+
+    /// Append samples, discarding the oldest once the window is full
+    pub fn push(&mut self, samples: &[i16]) {
+        if self.capacity == 0 {
+            return;
+        }
+        let incoming = if samples.len() > self.capacity {
+            &samples[samples.len() - self.capacity..]
+        } else {
+            samples
+        };
+        let overflow = (self.samples.len() + incoming.len()).saturating_sub(self.capacity);
+        self.samples.drain(..overflow);
+        self.samples.extend(incoming.iter().copied());
+    }
+
+    pub fn snapshot(&self) -> Vec<i16> {
+        self.samples.iter().copied().collect()
+    }
+
+    pub fn format(&self) -> (u32, u16) {
+        (self.sample_rate, self.channels)
+    }
+}
+
+/// Background input stream that keeps the last few hundred milliseconds of
+/// audio so a recording can start slightly before the hotkey was pressed.
+pub struct PrerollCapture {
+    buffer: Arc<Mutex<Option<PrerollBuffer>>>,
+    stop_tx: mpsc::Sender<()>,
+    preroll_ms: u32,
+    device_name: Option<String>,
+}
+
+impl PrerollCapture {
+    pub fn start(preroll_ms: u32, device_name: Option<String>) -> Result<Self, String> {
+        let buffer: Arc<Mutex<Option<PrerollBuffer>>> = Arc::new(Mutex::new(None));
+        let (stop_tx, stop_rx) = mpsc::channel::<()>();
+        let (ready_tx, ready_rx) = mpsc::channel::<Result<(), String>>();
+
+        let buffer_for_thread = buffer.clone();
+        let device_for_thread = device_name.clone();
+        thread::spawn(move || {
+            let stream =
+                match build_preroll_stream(preroll_ms, device_for_thread, buffer_for_thread) {
+                    Ok(stream) => {
+                        let _ = ready_tx.send(Ok(()));
+                        stream
+                    }
+                    Err(e) => {
+                        let _ = ready_tx.send(Err(e));
+                        return;
+                    }
+                };
+
+            // Keep the stream alive until asked to stop (or the owner is dropped)
+            let _ = stop_rx.recv();
+
+            // Pause first so capture (and the OS mic indicator) stops even if
+            // the driver is slow to release the device. Unlike the recorder,
+            // the stream is dropped on its own thread on every platform: a
+            // Windows driver that hangs in drop() only parks this thread.
+            if let Err(e) = stream.pause() {
+                log::warn!("Failed to pause pre-roll stream: {}", e);
+            }
+            drop(stream);
+            log::info!("Pre-roll capture stopped");
+        });
+
+        ready_rx
+            .recv()
+            .map_err(|_| "Pre-roll capture thread exited unexpectedly".to_string())??;
+
+        log::info!("Pre-roll capture started ({} ms)", preroll_ms);
+        Ok(Self {
+            buffer,
+            stop_tx,
+            preroll_ms,
+            device_name,
+        })
+    }
+
+    /// Whether this capture already matches the requested configuration
+    pub fn matches(&self, preroll_ms: u32, device_name: &Option<String>) -> bool {
+        self.preroll_ms == preroll_ms && &self.device_name == device_name
+    }
+
+    /// Shared handle to the ring buffer, read when a recording starts
+    pub fn buffer(&self) -> Arc<Mutex<Option<PrerollBuffer>>> {
+        self.buffer.clone()
+    }
+}
+
+impl Drop for PrerollCapture {
+    fn drop(&mut self) {
+        let _ = self.stop_tx.send(());
+    }
+}
+
+fn build_preroll_stream(
+    preroll_ms: u32,
+    device_name: Option<String>,
+    buffer: Arc<Mutex<Option<PrerollBuffer>>>,
+) -> Result<cpal::Stream, String> {
+    let host = cpal::default_host();
+    let device = device_name
+        .and_then(|name| {
+            host.input_devices()
+                .ok()?
+                .find(|d| d.name().map(|n| n == name).unwrap_or(false))
+        })
+        .or_else(|| host.default_input_device())
+        .ok_or("No input device available")?;
+
+    let config = device.default_input_config().map_err(|e| e.to_string())?;
+    if let Ok(mut guard) = buffer.lock() {
+        *guard = Some(PrerollBuffer::new(
+            preroll_ms,
+            config.sample_rate().0,
+            config.channels(),
+        ));
+    }
+
+    let err_fn = |err: cpal::StreamError| log::warn!("Pre-roll stream error: {}", err);
+    let stream = match config.sample_format() {
+        cpal::SampleFormat::F32 => device.build_input_stream(
+            &config.config(),
+            move |data: &[f32], _: &_| {
+                let samples: Vec<i16> = data
+                    .iter()
+                    .map(|&s| (s.clamp(-1.0, 1.0) * 32767.0) as i16)
+                    .collect();
+                if let Ok(mut guard) = buffer.try_lock() {
+                    if let Some(ring) = guard.as_mut() {
+                        ring.push(&samples);
+                    }
+                }
+            },
+            err_fn,
+            None,
+        ),
+        cpal::SampleFormat::I16 => device.build_input_stream(
+            &config.config(),
+            move |data: &[i16], _: &_| {
+                if let Ok(mut guard) = buffer.try_lock() {
+                    if let Some(ring) = guard.as_mut() {
+                        ring.push(data);
+                    }
+                }
+            },
+            err_fn,
+            None,
+        ),
+        cpal::SampleFormat::U16 => device.build_input_stream(
+            &config.config(),
+            move |data: &[u16], _: &_| {
+                let samples: Vec<i16> = data.iter().map(|&x| (x as i32 - 32768) as i16).collect();
+                if let Ok(mut guard) = buffer.try_lock() {
+                    if let Some(ring) = guard.as_mut() {
+                        ring.push(&samples);
+                    }
+                }
+            },
+            err_fn,
+            None,
+        ),
+        other => return Err(format!("Unsupported sample format: {:?}", other)),
+    }
+    .map_err(|e| e.to_string())?;
+
+    stream.play().map_err(|e| e.to_string())?;
+    Ok(stream)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preroll_capacity() {
+        assert_eq!(PrerollBuffer::capacity_for(300, 48000, 2), 28800);
+        assert_eq!(PrerollBuffer::capacity_for(0, 48000, 1), 0);
+        // Clamped to MAX_PREROLL_MS
+        assert_eq!(PrerollBuffer::capacity_for(60_000, 1000, 1), 2000);
+    }
+
+    #[test]
+    fn test_preroll_buffer_keeps_latest_samples() {
+        // 10 ms at 1 kHz mono = 10 samples
+        let mut ring = PrerollBuffer::new(10, 1000, 1);
+        ring.push(&[1, 2, 3, 4, 5, 6]);
+        ring.push(&[7, 8, 9, 10, 11, 12]);
+        assert_eq!(ring.snapshot(), vec![3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+
+        ring.push(&(100..120).collect::<Vec<i16>>());
+        assert_eq!(ring.snapshot(), (110..120).collect::<Vec<i16>>());
+    }
+}
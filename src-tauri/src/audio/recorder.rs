@@ -1,11 +1,14 @@
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::mpsc;
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::Duration;
 
+use super::gain;
 use super::level_meter::AudioLevelMeter;
+use super::preroll::PrerollCapture;
 use super::silence_detector::SilenceDetector;
 
 // Type-safe recording size limits
@@ -26,9 +29,59 @@ impl RecordingSize {
     }
 }
 
+/// Tracks captured frames against an optional maximum recording length
+pub struct RecordingLimit {
+    max_frames: Option<u64>,
+    frames: u64,
+}
+
+impl RecordingLimit {
+    /// `max_seconds` of zero or `None` means no limit
+    pub fn new(max_seconds: Option<u32>, sample_rate: u32) -> Self {
+        Self {
+            max_frames: max_seconds
+                .filter(|&secs| secs > 0)
+                .map(|secs| secs as u64 * sample_rate as u64),
+            frames: 0,
+        }
+    }
+
+    /// Record newly captured frames; returns true once the limit is reached
+    pub fn advance(&mut self, frames: u64) -> bool {
+        self.frames = self.frames.saturating_add(frames);
+        self.max_frames
+            .map(|max| self.frames >= max)
+            .unwrap_or(false)
+    }
+}
+
+/// Why the recorder stopped itself without a user request
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AutoStopReason {
+    MaxLength,
+}
+
+// Cap on buffered samples for partial transcription if the consumer falls behind
+const MAX_PENDING_SECONDS: usize = 60;
+
 pub struct AudioRecorder {
     recording_handle: Arc<Mutex<Option<RecordingHandle>>>,
     audio_level_receiver: Arc<Mutex<Option<mpsc::Receiver<f64>>>>,
+    // Copy of captured samples for interim (partial) transcription
+    capture_partials: Arc<AtomicBool>,
+    pending_samples: Arc<Mutex<Vec<i16>>>,
+    input_format: Arc<Mutex<Option<(u32, u16)>>>,
+    // Optional maximum recording length in seconds (0/None = unlimited)
+    max_recording_seconds: Option<u32>,
+    auto_stop_receiver: Arc<Mutex<Option<mpsc::Receiver<AutoStopReason>>>>,
+    // Background capture of the audio just before recording starts
+    preroll: Option<PrerollCapture>,
+    // While set, captured audio is dropped instead of written
+    paused: Arc<AtomicBool>,
+    // Input gain in dB (f32 bits), read live by the capture callback
+    gain_db: Arc<AtomicU32>,
+    // RMS of each captured chunk, for the recording's noise floor
+    chunk_levels: Arc<Mutex<Vec<f32>>>,
 }
 
 impl Drop for AudioRecorder {
@@ -64,6 +117,7 @@ struct RecordingHandle {
 enum RecorderCommand {
     Stop,
     StopSilence,
+    StopMaxLength,
 }
 
 impl AudioRecorder {
@@ -71,6 +125,15 @@ impl AudioRecorder {
         Self {
             recording_handle: Arc::new(Mutex::new(None)),
             audio_level_receiver: Arc::new(Mutex::new(None)),
+            capture_partials: Arc::new(AtomicBool::new(false)),
+            pending_samples: Arc::new(Mutex::new(Vec::new())),
+            input_format: Arc::new(Mutex::new(None)),
+            max_recording_seconds: None,
+            auto_stop_receiver: Arc::new(Mutex::new(None)),
+            preroll: None,
+            paused: Arc::new(AtomicBool::new(false)),
+            gain_db: Arc::new(AtomicU32::new(0f32.to_bits())),
+            chunk_levels: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
@@ -104,6 +167,29 @@ impl AudioRecorder {
         let (stop_tx, stop_rx) = mpsc::channel();
         let stop_tx_clone = stop_tx.clone();
 
+        // Reset partial capture state from any previous recording
+        if let Ok(mut pending) = self.pending_samples.lock() {
+            pending.clear();
+        }
+        if let Ok(mut format) = self.input_format.lock() {
+            *format = None;
+        }
+        if let Ok(mut levels) = self.chunk_levels.lock() {
+            levels.clear();
+        }
+        let chunk_levels = self.chunk_levels.clone();
+        let capture_partials = self.capture_partials.clone();
+        let pending_samples = self.pending_samples.clone();
+        let input_format = self.input_format.clone();
+
+        // Channel used to notify the app when the recorder stops on its own
+        let (auto_stop_tx, auto_stop_rx) = mpsc::channel::<AutoStopReason>();
+        let max_recording_seconds = self.max_recording_seconds;
+        let preroll_buffer = self.preroll.as_ref().map(|p| p.buffer());
+        self.paused.store(false, Ordering::SeqCst);
+        let paused = self.paused.clone();
+        let gain_db = self.gain_db.clone();
+
         // Create audio level channel (f64 for EBU R128 loudness values)
         let (audio_level_tx, audio_level_rx) = mpsc::channel::<f64>();
 
@@ -180,9 +266,37 @@ impl AudioRecorder {
                 sample_format: hound::SampleFormat::Int,
             };
 
+            if let Ok(mut format) = input_format.lock() {
+                *format = Some((spec.sample_rate, spec.channels));
+            }
+            let max_pending =
+                MAX_PENDING_SECONDS * spec.sample_rate as usize * spec.channels as usize;
+
             let writer = Arc::new(Mutex::new(Some(
                 hound::WavWriter::create(&output_path, spec).map_err(|e| e.to_string())?,
             )));
+
+            // Prepend the pre-roll window so the first word isn't clipped
+            if let Some(preroll_buffer) = &preroll_buffer {
+                let preroll = preroll_buffer.lock().ok().and_then(|guard| {
+                    guard
+                        .as_ref()
+                        .filter(|ring| ring.format() == (spec.sample_rate, spec.channels))
+                        .map(|ring| ring.snapshot())
+                });
+                if let (Some(samples), Ok(mut guard)) = (preroll, writer.lock()) {
+                    if let Some(w) = guard.as_mut() {
+                        let db = f32::from_bits(gain_db.load(Ordering::Relaxed));
+                        for &sample in &samples {
+                            let mut value = [sample as f32 / i16::MAX as f32];
+                            gain::apply_gain(&mut value, db);
+                            w.write_sample((value[0] * i16::MAX as f32) as i16)
+                                .map_err(|e| e.to_string())?;
+                        }
+                        log::debug!("Prepended {} pre-roll samples", samples.len());
+                    }
+                }
+            }
             // Error callback that triggers stop on device errors (e.g., disconnection)
             let stop_tx_for_error = stop_tx_clone.clone();
             let error_occurred = Arc::new(Mutex::new(None::<String>));
@@ -207,6 +321,11 @@ impl AudioRecorder {
 
             // Shared state for size tracking
             let bytes_written = Arc::new(Mutex::new(0u64));
+            let recording_limit = Arc::new(Mutex::new(RecordingLimit::new(
+                max_recording_seconds,
+                spec.sample_rate,
+            )));
+            let channel_count = spec.channels.max(1) as u64;
 
             // Common audio processing closure
             let process_audio = {
@@ -215,13 +334,48 @@ impl AudioRecorder {
                 let bytes_clone = bytes_written.clone();
                 let stop_tx_for_size = stop_tx_clone.clone();
                 let stop_tx_for_silence = stop_tx_clone.clone();
+                let stop_tx_for_limit = stop_tx_clone.clone();
+                let recording_limit_clone = recording_limit.clone();
                 let silence_detector_clone = silence_detector.clone();
                 let level_meter_clone = level_meter.clone();
+                let capture_partials = capture_partials.clone();
+                let pending_samples = pending_samples.clone();
+                let paused = paused.clone();
+                let gain_db = gain_db.clone();
+                let chunk_levels = chunk_levels.clone();
 
                 move |f32_samples: &[f32], i16_samples: &[i16]| {
+                    // While paused, drop the audio and hold the silence timer so the
+                    // gap is neither recorded nor counted toward the length/silence limits
+                    if paused.load(Ordering::Relaxed) {
+                        if let Ok(mut detector) = silence_detector_clone.try_lock() {
+                            detector.reset();
+                        }
+                        return;
+                    }
+
+                    // Apply input gain before metering so the level meter previews it
+                    let db = f32::from_bits(gain_db.load(Ordering::Relaxed));
+                    let gained = (db != 0.0).then(|| {
+                        let mut samples = f32_samples.to_vec();
+                        gain::apply_gain(&mut samples, db);
+                        let ints: Vec<i16> = samples
+                            .iter()
+                            .map(|&s| (s * i16::MAX as f32) as i16)
+                            .collect();
+                        (samples, ints)
+                    });
+                    let (f32_samples, i16_samples) = match &gained {
+                        Some((floats, ints)) => (floats.as_slice(), ints.as_slice()),
+                        None => (f32_samples, i16_samples),
+                    };
+
                     // Calculate RMS for both level meter and silence detection
                     let sum: f32 = f32_samples.iter().map(|x| x * x).sum();
                     let rms = (sum / f32_samples.len() as f32).sqrt();
+                    if let Ok(mut levels) = chunk_levels.try_lock() {
+                        levels.push(rms);
+                    }
 
                     // Process with level meter
                     if let Ok(mut meter) = level_meter_clone.try_lock() {
@@ -236,6 +390,13 @@ impl AudioRecorder {
                         }
                     }
 
+                    // Enforce maximum recording length
+                    if let Ok(mut limit) = recording_limit_clone.try_lock() {
+                        if limit.advance(i16_samples.len() as u64 / channel_count) {
+                            let _ = stop_tx_for_limit.send(RecorderCommand::StopMaxLength);
+                        }
+                    }
+
                     // Check size before writing
                     let sample_bytes = i16_samples.len() * 2; // 2 bytes per i16 sample
                     if let Ok(mut bytes_guard) = bytes_clone.lock() {
@@ -247,6 +408,17 @@ impl AudioRecorder {
                         *bytes_guard = new_total;
                     }
 
+                    // Keep a copy for partial transcription, dropping the oldest audio if unread
+                    if capture_partials.load(Ordering::Relaxed) {
+                        if let Ok(mut pending) = pending_samples.try_lock() {
+                            pending.extend_from_slice(i16_samples);
+                            if pending.len() > max_pending {
+                                let excess = pending.len() - max_pending;
+                                pending.drain(..excess);
+                            }
+                        }
+                    }
+
                     // Write audio data (i16 format)
                     if let Ok(mut guard) = writer_clone.try_lock() {
                         if let Some(writer) = guard.as_mut() {
@@ -393,6 +565,10 @@ impl AudioRecorder {
                 Some(RecorderCommand::StopSilence) => {
                     Ok("Recording stopped due to silence".to_string())
                 }
+                Some(RecorderCommand::StopMaxLength) => {
+                    let _ = auto_stop_tx.send(AutoStopReason::MaxLength);
+                    Ok("Recording stopped: maximum length reached".to_string())
+                }
                 Some(RecorderCommand::Stop) => Ok("Recording stopped by user".to_string()),
                 None => Ok("Recording stopped".to_string()),
             }
@@ -404,6 +580,10 @@ impl AudioRecorder {
             thread_handle,
         });
 
+        if let Ok(mut guard) = self.auto_stop_receiver.lock() {
+            *guard = Some(auto_stop_rx);
+        }
+
         // Store the audio level receiver
         *self
             .audio_level_receiver
@@ -414,6 +594,7 @@ impl AudioRecorder {
     }
 
     pub fn stop_recording(&mut self) -> Result<String, String> {
+        self.paused.store(false, Ordering::SeqCst);
         let handle = self
             .recording_handle
             .lock()
@@ -460,6 +641,111 @@ impl AudioRecorder {
             .unwrap_or(false)
     }
 
+    /// Stop writing captured audio while keeping the input stream open
+    pub fn pause(&self) -> Result<(), String> {
+        if !self.is_recording() {
+            return Err("Not recording".to_string());
+        }
+        if self.paused.swap(true, Ordering::SeqCst) {
+            return Err("Recording is already paused".to_string());
+        }
+        Ok(())
+    }
+
+    pub fn resume(&self) -> Result<(), String> {
+        if !self.is_recording() {
+            return Err("Not recording".to_string());
+        }
+        if !self.paused.swap(false, Ordering::SeqCst) {
+            return Err("Recording is not paused".to_string());
+        }
+        Ok(())
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// Set the input gain in dB. Takes effect immediately, including mid-recording.
+    pub fn set_gain_db(&self, db: f32) {
+        self.gain_db
+            .store(gain::clamp_gain_db(db).to_bits(), Ordering::Relaxed);
+    }
+
+    pub fn gain_db(&self) -> f32 {
+        f32::from_bits(self.gain_db.load(Ordering::Relaxed))
+    }
+
+    /// Background noise of the last recording in dBFS, measured while capturing
+    /// so the finished file doesn't have to be decoded again
+    pub fn take_noise_floor_dbfs(&self) -> Option<f32> {
+        let levels = self
+            .chunk_levels
+            .lock()
+            .map(|mut levels| std::mem::take(&mut *levels))
+            .unwrap_or_default();
+        (!levels.is_empty())
+            .then(|| crate::utils::model_suggestions::noise_floor_from_levels(levels))
+    }
+
+    /// Set the maximum recording length applied to the next recording
+    pub fn set_max_recording_seconds(&mut self, max_seconds: Option<u32>) {
+        self.max_recording_seconds = max_seconds.filter(|&secs| secs > 0);
+    }
+
+    /// Start, restart or stop the background pre-roll capture.
+    /// `preroll_ms` of zero disables it.
+    pub fn configure_preroll(&mut self, preroll_ms: u32, device_name: Option<String>) {
+        if preroll_ms == 0 {
+            self.preroll = None;
+            return;
+        }
+        if let Some(existing) = &self.preroll {
+            if existing.matches(preroll_ms, &device_name) {
+                return;
+            }
+        }
+
+        // Drop the old stream before opening a new one on the device
+        self.preroll = None;
+        match PrerollCapture::start(preroll_ms, device_name) {
+            Ok(capture) => self.preroll = Some(capture),
+            Err(e) => log::warn!("Failed to start pre-roll capture: {}", e),
+        }
+    }
+
+    /// Receiver notified when the recorder stops itself (e.g. max length reached)
+    pub fn take_auto_stop_receiver(&mut self) -> Option<mpsc::Receiver<AutoStopReason>> {
+        self.auto_stop_receiver
+            .lock()
+            .ok()
+            .and_then(|mut guard| guard.take())
+    }
+
+    /// Enable or disable buffering of captured samples for partial transcription.
+    /// Takes effect immediately, including for a recording already in progress.
+    pub fn set_partial_capture(&self, enabled: bool) {
+        self.capture_partials.store(enabled, Ordering::SeqCst);
+        if !enabled {
+            if let Ok(mut pending) = self.pending_samples.lock() {
+                pending.clear();
+            }
+        }
+    }
+
+    /// Drain samples captured since the last call (interleaved i16)
+    pub fn take_pending_samples(&self) -> Vec<i16> {
+        self.pending_samples
+            .lock()
+            .map(|mut pending| std::mem::take(&mut *pending))
+            .unwrap_or_default()
+    }
+
+    /// Sample rate and channel count of the active input stream
+    pub fn input_format(&self) -> Option<(u32, u16)> {
+        self.input_format.lock().ok().and_then(|format| *format)
+    }
+
     pub fn take_audio_level_receiver(&mut self) -> Option<mpsc::Receiver<f64>> {
         self.audio_level_receiver
             .lock()
@@ -19,6 +19,11 @@ impl SilenceDetector {
         }
     }
 
+    /// Restart the silence timer, e.g. while recording is paused
+    pub fn reset(&mut self) {
+        self.last_voice_time = Instant::now();
+    }
+
     /// Update with current RMS level and check if should stop
     pub fn update(&mut self, rms: f32) -> bool {
         if rms > self.voice_threshold {
@@ -0,0 +1,308 @@
+// Audio cues for recording start, stop and transcription complete, so the
+// recording state can be followed without seeing the overlay. Each cue is a
+// short built-in tone or a user-chosen WAV file, played on the default output
+// device at the configured volume. Cues stay silent while the system is in
+// Do Not Disturb / Focus mode unless the user opts out.
+//
+// When `play_sound_cues` is off, the older `play_sound_on_recording(_end)`
+// system sounds are used instead.
+
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use std::path::Path;
+use std::time::Duration;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoundCue {
+    RecordingStart,
+    RecordingStop,
+    TranscriptionComplete,
+}
+
+impl SoundCue {
+    fn file_setting(self) -> &'static str {
+        match self {
+            SoundCue::RecordingStart => "sound_cue_start_file",
+            SoundCue::RecordingStop => "sound_cue_stop_file",
+            SoundCue::TranscriptionComplete => "sound_cue_complete_file",
+        }
+    }
+
+    /// Built-in tone as (frequency Hz, duration ms) notes. Start rises, stop
+    /// falls and completion is a two-note chime, so they're told apart by ear.
+    fn notes(self) -> &'static [(f32, u32)] {
+        match self {
+            SoundCue::RecordingStart => &[(660.0, 70), (880.0, 90)],
+            SoundCue::RecordingStop => &[(880.0, 70), (660.0, 90)],
+            SoundCue::TranscriptionComplete => &[(784.0, 80), (1047.0, 140)],
+        }
+    }
+}
+
+/// Fade applied to both ends of each note to avoid clicks
+const FADE_MS: u32 = 8;
+
+/// Mono samples of the built-in tone for `cue`
+pub fn tone_samples(cue: SoundCue, sample_rate: u32) -> Vec<f32> {
+    let fade = (sample_rate * FADE_MS / 1000).max(1) as usize;
+    let mut samples = Vec::new();
+    for &(frequency, duration_ms) in cue.notes() {
+        let len = (sample_rate as u64 * duration_ms as u64 / 1000) as usize;
+        samples.extend((0..len).map(|i| {
+            let envelope = (i.min(len - 1 - i) as f32 / fade as f32).min(1.0);
+            let t = i as f32 / sample_rate as f32;
+            (2.0 * std::f32::consts::PI * frequency * t).sin() * envelope * 0.5
+        }));
+    }
+    samples
+}
+
+/// Mono samples and sample rate of a WAV file
+fn load_wav(path: &Path) -> Result<(Vec<f32>, u32), String> {
+    let mut reader =
+        hound::WavReader::open(path).map_err(|e| format!("Failed to open sound file: {}", e))?;
+    let spec = reader.spec();
+    let samples: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Float => reader
+            .samples::<f32>()
+            .collect::<Result<_, _>>()
+            .map_err(|e| format!("Failed to read sound file: {}", e))?,
+        hound::SampleFormat::Int => {
+            let scale = (1i64 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .samples::<i32>()
+                .map(|s| s.map(|s| s as f32 / scale))
+                .collect::<Result<_, _>>()
+                .map_err(|e| format!("Failed to read sound file: {}", e))?
+        }
+    };
+    let channels = spec.channels.max(1) as usize;
+    let mono = samples
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+        .collect();
+    Ok((mono, spec.sample_rate))
+}
+
+/// Linear resampling; good enough for short cues
+pub fn resample_linear(samples: &[f32], from_rate: u32, to_rate: u32) -> Vec<f32> {
+    if from_rate == to_rate || samples.is_empty() {
+        return samples.to_vec();
+    }
+    let len = (samples.len() as u64 * to_rate as u64 / from_rate as u64) as usize;
+    let step = from_rate as f64 / to_rate as f64;
+    (0..len)
+        .map(|i| {
+            let pos = i as f64 * step;
+            let index = pos as usize;
+            let frac = (pos - index as f64) as f32;
+            let a = samples[index.min(samples.len() - 1)];
+            let b = samples[(index + 1).min(samples.len() - 1)];
+            a + (b - a) * frac
+        })
+        .collect()
+}
+
+/// Whether Do Not Disturb / Focus is on. Best effort: `false` when it can't
+/// be determined.
+#[cfg(target_os = "macos")]
+pub fn do_not_disturb_active() -> bool {
+    // Focus writes active assertions here (macOS 12+); scheduled Focus
+    // periods aren't listed, so those aren't detected
+    let Some(path) =
+        dirs::home_dir().map(|home| home.join("Library/DoNotDisturb/DB/Assertions.json"))
+    else {
+        return false;
+    };
+    std::fs::read(path)
+        .ok()
+        .and_then(|contents| serde_json::from_slice::<serde_json::Value>(&contents).ok())
+        .and_then(|json| {
+            json.get("data")?.as_array().map(|data| {
+                data.iter().any(|entry| {
+                    entry
+                        .get("storeAssertionRecords")
+                        .and_then(|records| records.as_array())
+                        .is_some_and(|records| !records.is_empty())
+                })
+            })
+        })
+        .unwrap_or(false)
+}
+
+/// Whether Do Not Disturb / Focus is on. Best effort: `false` when it can't
+/// be determined.
+#[cfg(target_os = "windows")]
+pub fn do_not_disturb_active() -> bool {
+    use windows::Win32::UI::Shell::{
+        SHQueryUserNotificationState, QUNS_BUSY, QUNS_PRESENTATION_MODE, QUNS_QUIET_TIME,
+        QUNS_RUNNING_D3D_FULL_SCREEN,
+    };
+    match unsafe { SHQueryUserNotificationState() } {
+        Ok(state) => [
+            QUNS_BUSY,
+            QUNS_RUNNING_D3D_FULL_SCREEN,
+            QUNS_PRESENTATION_MODE,
+            QUNS_QUIET_TIME,
+        ]
+        .contains(&state),
+        Err(_) => false,
+    }
+}
+
+/// Whether Do Not Disturb / Focus is on. Best effort: `false` when it can't
+/// be determined.
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+pub fn do_not_disturb_active() -> bool {
+    // GNOME hides notification banners while Do Not Disturb is on
+    std::process::Command::new("gsettings")
+        .args(["get", "org.gnome.desktop.notifications", "show-banners"])
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim() == "false")
+        .unwrap_or(false)
+}
+
+/// Whether sound cues replace the legacy start/end sounds
+pub fn enabled(app: &AppHandle) -> bool {
+    app.store("settings")
+        .ok()
+        .and_then(|store| store.get("play_sound_cues"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+/// Play `cue` in the background if sound cues are on
+pub fn play_cue(app: &AppHandle, cue: SoundCue) {
+    let Ok(store) = app.store("settings") else {
+        return;
+    };
+    if !store
+        .get("play_sound_cues")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+    {
+        return;
+    }
+    let volume = store
+        .get("sound_cue_volume")
+        .and_then(|v| v.as_f64())
+        .map(|v| (v as f32).clamp(0.0, 1.0))
+        .unwrap_or(0.6);
+    let respect_dnd = store
+        .get("sound_cues_respect_dnd")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(true);
+    let file = store
+        .get(cue.file_setting())
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .filter(|s| !s.trim().is_empty());
+    if volume <= 0.0 {
+        return;
+    }
+
+    std::thread::spawn(move || {
+        if respect_dnd && do_not_disturb_active() {
+            log::debug!("Skipping {:?} sound cue: Do Not Disturb is on", cue);
+            return;
+        }
+        let custom = file.and_then(|file| match load_wav(Path::new(&file)) {
+            Ok(sound) => Some(sound),
+            Err(e) => {
+                log::warn!("{}; using the built-in {:?} tone", e, cue);
+                None
+            }
+        });
+        if let Err(e) = play_samples(cue, custom, volume) {
+            log::warn!("Failed to play {:?} sound cue: {}", cue, e);
+        }
+    });
+}
+
+/// Play mono samples (or the built-in tone) on the default output device,
+/// blocking until done
+fn play_samples(cue: SoundCue, custom: Option<(Vec<f32>, u32)>, volume: f32) -> Result<(), String> {
+    let device = cpal::default_host()
+        .default_output_device()
+        .ok_or("No output device available")?;
+    let config = device.default_output_config().map_err(|e| e.to_string())?;
+    let sample_rate = config.sample_rate().0;
+    let channels = config.channels().max(1) as usize;
+
+    let samples: Vec<f32> = match custom {
+        Some((samples, rate)) => resample_linear(&samples, rate, sample_rate),
+        None => tone_samples(cue, sample_rate),
+    }
+    .into_iter()
+    .map(|s| (s * volume).clamp(-1.0, 1.0))
+    .collect();
+    let duration = Duration::from_millis(samples.len() as u64 * 1000 / sample_rate as u64);
+
+    let mut position = 0;
+    let mut next_frame = move || {
+        let sample = samples.get(position).copied().unwrap_or(0.0);
+        position += 1;
+        sample
+    };
+    let err_fn = |err: cpal::StreamError| log::warn!("Sound cue stream error: {}", err);
+    let stream = match config.sample_format() {
+        cpal::SampleFormat::F32 => device.build_output_stream(
+            &config.config(),
+            move |data: &mut [f32], _: &_| {
+                for frame in data.chunks_mut(channels) {
+                    frame.fill(next_frame());
+                }
+            },
+            err_fn,
+            None,
+        ),
+        cpal::SampleFormat::I16 => device.build_output_stream(
+            &config.config(),
+            move |data: &mut [i16], _: &_| {
+                for frame in data.chunks_mut(channels) {
+                    frame.fill((next_frame() * 32767.0) as i16);
+                }
+            },
+            err_fn,
+            None,
+        ),
+        cpal::SampleFormat::U16 => device.build_output_stream(
+            &config.config(),
+            move |data: &mut [u16], _: &_| {
+                for frame in data.chunks_mut(channels) {
+                    frame.fill((next_frame() * 32767.0 + 32768.0) as u16);
+                }
+            },
+            err_fn,
+            None,
+        ),
+        other => return Err(format!("Unsupported sample format: {:?}", other)),
+    }
+    .map_err(|e| e.to_string())?;
+
+    stream.play().map_err(|e| e.to_string())?;
+    // Let the device drain its buffer before the stream is dropped
+    std::thread::sleep(duration + Duration::from_millis(100));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cue_tones_and_resampling() {
+        let start = tone_samples(SoundCue::RecordingStart, 48000);
+        assert_eq!(start.len(), 48000 * 160 / 1000);
+        // Faded in and out, and never clipping
+        assert_eq!(start[0], 0.0);
+        assert!(start.last().unwrap().abs() < 0.01);
+        assert!(start.iter().all(|s| s.abs() <= 0.5));
+        assert_ne!(start, tone_samples(SoundCue::RecordingStop, 48000));
+
+        let resampled = resample_linear(&[0.0, 1.0, 0.0, -1.0], 16000, 32000);
+        assert_eq!(resampled.len(), 8);
+        assert_eq!(resampled[1], 0.5);
+        assert_eq!(resample_linear(&[0.25], 44100, 44100), vec![0.25]);
+    }
+}
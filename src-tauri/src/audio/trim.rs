@@ -0,0 +1,161 @@
+// Leading/trailing silence trimming for finished recordings. Silence after
+// the last word wastes transcription time and is where Whisper tends to
+// hallucinate text, so it is cut before transcription. A margin is kept on
+// both sides so soft word onsets and endings aren't clipped.
+
+use hound::{SampleFormat, WavReader, WavWriter};
+use std::path::Path;
+
+/// Frame RMS below this level is treated as silence
+pub const DEFAULT_TRIM_THRESHOLD_DB: f32 = -45.0;
+
+/// Audio kept around the detected speech, in milliseconds
+pub const TRIM_MARGIN_MS: u32 = 250;
+
+/// Frame length used to measure loudness, in milliseconds
+const FRAME_MS: u32 = 20;
+
+const SAMPLE_RATE: u32 = 16000;
+
+fn frame_rms(frame: &[f32]) -> f32 {
+    if frame.is_empty() {
+        return 0.0;
+    }
+    (frame.iter().map(|s| s * s).sum::<f32>() / frame.len() as f32).sqrt()
+}
+
+/// Drop leading and trailing 16 kHz audio quieter than `threshold_db`
+/// (dBFS), keeping `TRIM_MARGIN_MS` around the speech. All-silent input is
+/// returned unchanged so the empty-recording checks still see it.
+pub fn trim_silence(samples: &[f32], threshold_db: f32) -> Vec<f32> {
+    let threshold = 10f32.powf(threshold_db.min(0.0) / 20.0);
+    let frame_len = (SAMPLE_RATE * FRAME_MS / 1000) as usize;
+    let margin = (SAMPLE_RATE * TRIM_MARGIN_MS / 1000) as usize;
+
+    let loud: Vec<bool> = samples
+        .chunks(frame_len)
+        .map(|frame| frame_rms(frame) >= threshold)
+        .collect();
+    let Some(first) = loud.iter().position(|l| *l) else {
+        return samples.to_vec();
+    };
+    let last = loud.iter().rposition(|l| *l).unwrap_or(first);
+
+    let start = (first * frame_len).saturating_sub(margin);
+    let end = ((last + 1) * frame_len + margin).min(samples.len());
+    samples[start..end].to_vec()
+}
+
+/// Loud audio a clip needs in total to count as containing speech, so a
+/// single click or bump doesn't
+pub const MIN_SPEECH_MS: u32 = 100;
+
+/// Whether 16 kHz audio has at least `MIN_SPEECH_MS` of frames louder than
+/// `threshold_db` (dBFS)
+pub fn contains_speech(samples: &[f32], threshold_db: f32) -> bool {
+    let threshold = 10f32.powf(threshold_db.min(0.0) / 20.0);
+    let frame_len = (SAMPLE_RATE * FRAME_MS / 1000) as usize;
+    let needed = (MIN_SPEECH_MS / FRAME_MS).max(1) as usize;
+    samples
+        .chunks(frame_len)
+        .filter(|frame| frame_rms(frame) >= threshold)
+        .nth(needed - 1)
+        .is_some()
+}
+
+fn read_mono_16bit(path: &Path) -> Result<(hound::WavSpec, Vec<f32>), String> {
+    let mut reader = WavReader::open(path).map_err(|e| format!("Failed to open audio: {}", e))?;
+    let spec = reader.spec();
+    if spec.channels != 1 || spec.bits_per_sample != 16 || spec.sample_format != SampleFormat::Int {
+        return Err("Expected 16-bit mono audio".to_string());
+    }
+
+    let samples: Vec<f32> = reader
+        .samples::<i16>()
+        .map(|s| s.map(|v| v as f32 / i16::MAX as f32))
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("Failed to read audio: {}", e))?;
+    Ok((spec, samples))
+}
+
+/// `contains_speech` for a 16-bit mono WAV file
+pub fn wav_contains_speech(path: &Path, threshold_db: f32) -> Result<bool, String> {
+    let (_, samples) = read_mono_16bit(path)?;
+    Ok(contains_speech(&samples, threshold_db))
+}
+
+/// Trim silence from a 16-bit mono WAV file in place. Returns the number of
+/// samples removed.
+pub fn trim_wav_file(path: &Path, threshold_db: f32) -> Result<usize, String> {
+    let (spec, samples) =
+        read_mono_16bit(path).map_err(|e| format!("Silence trimming failed: {}", e))?;
+
+    let trimmed = trim_silence(&samples, threshold_db);
+    let removed = samples.len() - trimmed.len();
+    if removed == 0 {
+        return Ok(0);
+    }
+
+    // Write next to the original and swap, so a failure leaves the input intact
+    let tmp_path = path.with_extension("trim.wav");
+    let result = (|| {
+        let mut writer = WavWriter::create(&tmp_path, spec)
+            .map_err(|e| format!("Failed to write trimmed audio: {}", e))?;
+        for sample in trimmed {
+            writer
+                .write_sample((sample * i16::MAX as f32) as i16)
+                .map_err(|e| format!("Failed to write trimmed audio: {}", e))?;
+        }
+        writer
+            .finalize()
+            .map_err(|e| format!("Failed to finalize trimmed audio: {}", e))?;
+        crate::utils::secure_delete::replace_file(&tmp_path, path)
+            .map_err(|e| format!("Failed to replace audio with trimmed version: {}", e))
+    })();
+
+    if result.is_err() {
+        let _ = crate::utils::secure_delete::remove_file(&tmp_path);
+    }
+    result.map(|_| removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trim_silence_keeps_margin_around_speech() {
+        let second = SAMPLE_RATE as usize;
+        let mut samples = vec![0.0f32; second];
+        samples.extend(vec![0.3f32; second / 2]);
+        samples.extend(vec![0.0001f32; 2 * second]);
+
+        let trimmed = trim_silence(&samples, DEFAULT_TRIM_THRESHOLD_DB);
+        let margin = (SAMPLE_RATE * TRIM_MARGIN_MS / 1000) as usize;
+        assert_eq!(trimmed.len(), second / 2 + 2 * margin);
+        assert_eq!(trimmed[0], 0.0);
+        assert_eq!(trimmed[margin], 0.3);
+        assert_eq!(trimmed[margin + second / 2 - 1], 0.3);
+
+        // Silence is left for the empty-recording checks
+        let silence = vec![0.0f32; second];
+        assert_eq!(
+            trim_silence(&silence, DEFAULT_TRIM_THRESHOLD_DB).len(),
+            second
+        );
+    }
+
+    #[test]
+    fn test_contains_speech_ignores_silence_and_clicks() {
+        let second = SAMPLE_RATE as usize;
+        let mut samples = vec![0.0001f32; second];
+        assert!(!contains_speech(&samples, DEFAULT_TRIM_THRESHOLD_DB));
+
+        // A 20 ms click isn't speech
+        samples[..320].fill(0.5);
+        assert!(!contains_speech(&samples, DEFAULT_TRIM_THRESHOLD_DB));
+
+        samples[second / 2..second / 2 + 1600].fill(0.1);
+        assert!(contains_speech(&samples, DEFAULT_TRIM_THRESHOLD_DB));
+    }
+}
@@ -3,6 +3,7 @@ use crate::ai::openai::{
 };
 use crate::ai::{AIEnhancementRequest, AIProviderConfig, AIProviderFactory, EnhancementOptions};
 use crate::commands::audio::pill_toast;
+use chrono::{DateTime, Utc};
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -12,21 +13,138 @@ use tauri_plugin_store::StoreExt;
 
 // In-memory cache for API keys to avoid system password prompts
 // Keys are stored in Stronghold by frontend and cached here for backend use
-static API_KEY_CACHE: Lazy<Mutex<HashMap<String, String>>> =
-    Lazy::new(|| Mutex::new(HashMap::new()));
+static API_KEY_CACHE: Lazy<Mutex<ApiKeyCache>> = Lazy::new(|| Mutex::new(ApiKeyCache::default()));
+
+const API_KEY_CACHE_PREFIX: &str = "ai_api_key_";
+
+struct CachedApiKey {
+    value: String,
+    last_used: DateTime<Utc>,
+}
+
+/// Decrypted API keys with the time each was last cached or used, so idle
+/// keys can be evicted from memory
+#[derive(Default)]
+pub(crate) struct ApiKeyCache {
+    entries: HashMap<String, CachedApiKey>,
+}
+
+impl ApiKeyCache {
+    fn insert(&mut self, key: String, value: String) {
+        self.entries.insert(
+            key,
+            CachedApiKey {
+                value,
+                last_used: Utc::now(),
+            },
+        );
+    }
+
+    fn contains_key(&self, key: &str) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// Return a key for use, refreshing its last-used time
+    fn use_key(&mut self, key: &str) -> Option<String> {
+        self.entries.get_mut(key).map(|entry| {
+            entry.last_used = Utc::now();
+            entry.value.clone()
+        })
+    }
+
+    fn remove(&mut self, key: &str) {
+        self.entries.remove(key);
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    fn keys(&self) -> impl Iterator<Item = &String> {
+        self.entries.keys()
+    }
+
+    /// Evict keys idle for longer than `max_idle`; returns the evicted cache keys
+    fn prune_idle(&mut self, max_idle: chrono::Duration, now: DateTime<Utc>) -> Vec<String> {
+        let expired: Vec<String> = self
+            .entries
+            .iter()
+            .filter(|(_, entry)| now - entry.last_used > max_idle)
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in &expired {
+            self.entries.remove(key);
+        }
+        expired
+    }
+}
+
+/// Provider with a cached key; the key value itself is never exposed
+#[derive(Serialize, Debug, Clone)]
+pub struct CachedKeyProvider {
+    pub provider: String,
+    pub last_used: String,
+    pub idle_secs: u64,
+}
 
 const DEFAULT_OPENAI_BASE_URL: &str = "https://api.openai.com/v1";
 const CUSTOM_BASE_URL_KEY: &str = "ai_custom_base_url";
 const CUSTOM_NO_AUTH_KEY: &str = "ai_custom_no_auth";
 const LEGACY_OPENAI_BASE_URL_KEY: &str = "ai_openai_base_url";
 const LEGACY_OPENAI_NO_AUTH_KEY: &str = "ai_openai_no_auth";
+const PROVIDER_MODELS_KEY: &str = "ai_provider_models";
+const GEMINI_MODELS_URL: &str = "https://generativelanguage.googleapis.com/v1beta/models";
+
+// Model ids last listed by each provider's API, used to validate selections offline
+static FETCHED_MODELS: Lazy<Mutex<HashMap<String, Vec<String>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+// Helper: model chosen for a provider. Falls back to the shared `ai_model` key
+// (used before models were stored per provider) for the active provider.
+fn provider_model<R: tauri::Runtime>(
+    store: &tauri_plugin_store::Store<R>,
+    provider: &str,
+) -> String {
+    store
+        .get(PROVIDER_MODELS_KEY)
+        .and_then(|models| {
+            models
+                .get(provider)
+                .and_then(|m| m.as_str())
+                .map(|m| m.to_string())
+        })
+        .filter(|model| !model.is_empty())
+        .or_else(|| {
+            let active = store
+                .get("ai_provider")
+                .is_some_and(|v| v.as_str() == Some(provider));
+            active
+                .then(|| store.get("ai_model"))
+                .flatten()
+                .and_then(|v| v.as_str().map(|s| s.to_string()))
+        })
+        .unwrap_or_default()
+}
+
+fn set_provider_model<R: tauri::Runtime>(
+    store: &tauri_plugin_store::Store<R>,
+    provider: &str,
+    model: &str,
+) {
+    let mut models = store
+        .get(PROVIDER_MODELS_KEY)
+        .and_then(|v| v.as_object().cloned())
+        .unwrap_or_default();
+    models.insert(provider.to_string(), json!(model));
+    store.set(PROVIDER_MODELS_KEY, serde_json::Value::Object(models));
+}
 
 // Helper: determine if we should consider that the app "has an API key" for a provider
 // For OpenAI-compatible providers, a configured no_auth=true also counts as "has key"
 fn check_has_api_key<R: tauri::Runtime>(
     provider: &str,
     store: &tauri_plugin_store::Store<R>,
-    cache: &HashMap<String, String>,
+    cache: &ApiKeyCache,
 ) -> bool {
     if provider == "openai" {
         cache.contains_key("ai_api_key_openai") || store.get(LEGACY_OPENAI_BASE_URL_KEY).is_some()
@@ -251,10 +369,7 @@ pub async fn get_ai_settings(app: tauri::AppHandle) -> Result<AISettings, String
         .and_then(|v| v.as_str().map(|s| s.to_string()))
         .unwrap_or_default(); // Empty by default, user must select
 
-    let model = store
-        .get("ai_model")
-        .and_then(|v| v.as_str().map(|s| s.to_string()))
-        .unwrap_or_else(|| "".to_string()); // Empty by default
+    let model = provider_model(&store, &provider); // Empty by default
 
     // For OpenAI-compatible providers, treat no_auth as having a usable config
     let has_api_key = {
@@ -286,10 +401,7 @@ pub async fn get_ai_settings_for_provider(
         .and_then(|v| v.as_bool())
         .unwrap_or(false);
 
-    let model = store
-        .get("ai_model")
-        .and_then(|v| v.as_str().map(|s| s.to_string()))
-        .unwrap_or_else(|| "".to_string()); // Empty by default
+    let model = provider_model(&store, &provider); // Empty by default
 
     // For OpenAI-compatible providers, treat no_auth as having a usable config
     let has_api_key = {
@@ -409,6 +521,7 @@ pub async fn validate_and_cache_api_key(
             serde_json::Value::Bool(inferred_no_auth),
         );
         if let Some(m) = model.clone() {
+            set_provider_model(&store, &provider, &m);
             store.set("ai_model", serde_json::Value::String(m));
         }
         store
@@ -441,7 +554,9 @@ pub async fn validate_and_cache_api_key(
         let validate_model = model.clone().unwrap_or_else(|| "gpt-5-nano".to_string());
         let allow_chat_probe_fallback = provider == "custom";
 
-        let client = reqwest::Client::new();
+        let client = crate::utils::proxy::client_builder()
+            .build()
+            .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
         let auth_header = if inferred_no_auth {
             None
         } else {
@@ -500,7 +615,9 @@ pub async fn test_openai_endpoint(
             .map(|s| s.trim().is_empty())
             .unwrap_or(true);
 
-    let client = reqwest::Client::new();
+    let client = crate::utils::proxy::client_builder()
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
     let auth_header = if no_auth {
         None
     } else {
@@ -558,6 +675,140 @@ pub fn clear_all_api_key_cache() -> Result<(), String> {
     Ok(())
 }
 
+/// List providers with a cached API key and when each was last used (never the values)
+#[tauri::command]
+pub async fn list_cached_key_providers() -> Result<Vec<CachedKeyProvider>, String> {
+    let cache = API_KEY_CACHE
+        .lock()
+        .map_err(|_| "Failed to access cache".to_string())?;
+
+    let now = Utc::now();
+    let mut providers: Vec<CachedKeyProvider> = cache
+        .entries
+        .iter()
+        .map(|(key, entry)| CachedKeyProvider {
+            provider: key
+                .strip_prefix(API_KEY_CACHE_PREFIX)
+                .unwrap_or(key)
+                .to_string(),
+            last_used: entry.last_used.to_rfc3339(),
+            idle_secs: (now - entry.last_used).num_seconds().max(0) as u64,
+        })
+        .collect();
+    providers.sort_by(|a, b| a.provider.cmp(&b.provider));
+
+    Ok(providers)
+}
+
+/// Evict cached API keys not used within `max_idle_secs`; returns the evicted providers.
+/// Evicted keys are re-cached by the frontend the next time they're needed.
+#[tauri::command]
+pub async fn prune_api_key_cache(max_idle_secs: u64) -> Result<Vec<String>, String> {
+    let mut cache = API_KEY_CACHE
+        .lock()
+        .map_err(|_| "Failed to access cache".to_string())?;
+
+    let max_idle = chrono::Duration::seconds(max_idle_secs.min(i64::MAX as u64) as i64);
+    let evicted: Vec<String> = cache
+        .prune_idle(max_idle, Utc::now())
+        .into_iter()
+        .map(|key| {
+            key.strip_prefix(API_KEY_CACHE_PREFIX)
+                .unwrap_or(&key)
+                .to_string()
+        })
+        .collect();
+
+    if !evicted.is_empty() {
+        log::info!("Pruned idle API keys for providers: {:?}", evicted);
+    }
+
+    Ok(evicted)
+}
+
+/// Settings keys that belong only to `provider` (e.g. `ai_custom_base_url`).
+/// The legacy `ai_openai_*` keys still hold the custom endpoint's config, so
+/// they aren't OpenAI's to remove.
+fn provider_setting_keys(keys: Vec<String>, provider: &str) -> Vec<String> {
+    let prefix = format!("ai_{}_", provider);
+    keys.into_iter()
+        .filter(|key| {
+            key.starts_with(&prefix)
+                && !key.starts_with(API_KEY_CACHE_PREFIX)
+                && key != LEGACY_OPENAI_BASE_URL_KEY
+                && key != LEGACY_OPENAI_NO_AUTH_KEY
+        })
+        .collect()
+}
+
+/// Forget everything about `provider`: its saved API key, the cached key and
+/// fetched models, and its settings. Deselects it if it was the active
+/// provider. Returns what was removed.
+#[tauri::command]
+pub async fn remove_ai_provider(
+    app: tauri::AppHandle,
+    provider: String,
+) -> Result<Vec<String>, String> {
+    validate_provider_name(&provider)?;
+    let mut removed = Vec::new();
+
+    let key_name = format!("{}{}", API_KEY_CACHE_PREFIX, provider);
+    if crate::secure_store::secure_has(&app, &key_name).unwrap_or(false) {
+        crate::secure_store::secure_delete(&app, &key_name)?;
+        removed.push("Saved API key".to_string());
+    }
+
+    {
+        let mut cache = API_KEY_CACHE
+            .lock()
+            .map_err(|_| "Failed to access cache".to_string())?;
+        if cache.contains_key(&key_name) {
+            cache.remove(&key_name);
+            removed.push("Cached API key".to_string());
+        }
+    }
+    if let Ok(mut fetched) = FETCHED_MODELS.lock() {
+        if fetched.remove(&provider).is_some() {
+            removed.push("Fetched model list".to_string());
+        }
+    }
+
+    let store = app
+        .store("settings")
+        .map_err(|e| format!("Failed to access store: {}", e))?;
+    for key in provider_setting_keys(store.keys(), &provider) {
+        store.delete(&key);
+        removed.push(format!("Setting {}", key));
+    }
+    if let Some(mut models) = store
+        .get(PROVIDER_MODELS_KEY)
+        .and_then(|v| v.as_object().cloned())
+    {
+        if models.remove(&provider).is_some() {
+            store.set(PROVIDER_MODELS_KEY, serde_json::Value::Object(models));
+            removed.push("Selected model".to_string());
+        }
+    }
+    let was_active = store
+        .get("ai_provider")
+        .is_some_and(|v| v.as_str() == Some(provider.as_str()));
+    if was_active {
+        store.set("ai_enabled", json!(false));
+        store.set("ai_provider", json!(""));
+        store.set("ai_model", json!(""));
+        removed.push("Active provider selection".to_string());
+    }
+    store
+        .save()
+        .map_err(|e| format!("Failed to save settings: {}", e))?;
+
+    // Invalidate recording config cache when AI settings change
+    crate::commands::audio::invalidate_recording_config_cache(&app).await;
+
+    log::info!("Removed AI provider {}: {:?}", provider, removed);
+    Ok(removed)
+}
+
 #[tauri::command]
 pub async fn update_ai_settings(
     enabled: bool,
@@ -630,11 +881,19 @@ pub async fn update_ai_settings(
         }
     }
 
+    // Reject models the provider doesn't offer
+    if !provider.is_empty() && !model.is_empty() {
+        validate_provider_model(&app, &provider, &model).await?;
+    }
+
     let store = app.store("settings").map_err(|e| e.to_string())?;
 
     store.set("ai_enabled", json!(enabled));
     store.set("ai_provider", json!(provider));
     store.set("ai_model", json!(model));
+    if !provider.is_empty() {
+        set_provider_model(&store, &provider, &model);
+    }
 
     store
         .save()
@@ -731,10 +990,7 @@ pub async fn enhance_transcription(text: String, app: tauri::AppHandle) -> Resul
         .and_then(|v| v.as_str().map(|s| s.to_string()))
         .unwrap_or_default(); // Empty by default
 
-    let model = store
-        .get("ai_model")
-        .and_then(|v| v.as_str().map(|s| s.to_string()))
-        .unwrap_or_else(|| "".to_string()); // Empty by default
+    let model = provider_model(&store, &provider); // Empty by default
 
     // Don't enhance if no model or provider selected
     if model.is_empty() || provider.is_empty() {
@@ -747,13 +1003,17 @@ pub async fn enhance_transcription(text: String, app: tauri::AppHandle) -> Resul
 
     // Determine provider-specific config
     let (factory_provider, api_key, options) = if provider == "openai" {
-        let cache = API_KEY_CACHE.lock().map_err(|e| {
+        let mut cache = API_KEY_CACHE.lock().map_err(|e| {
             log::error!("Failed to access API key cache: {}", e);
             "Failed to access cache".to_string()
         })?;
 
-        let openai_cached = cache.get("ai_api_key_openai").cloned();
-        let custom_cached = cache.get("ai_api_key_custom").cloned();
+        let openai_cached = cache.use_key("ai_api_key_openai");
+        let custom_cached = if openai_cached.is_none() {
+            cache.use_key("ai_api_key_custom")
+        } else {
+            None
+        };
         drop(cache);
 
         if let Some(cached) = openai_cached {
@@ -802,12 +1062,12 @@ pub async fn enhance_transcription(text: String, app: tauri::AppHandle) -> Resul
             })
             .unwrap_or_else(|| DEFAULT_OPENAI_BASE_URL.to_string());
 
-        let cache = API_KEY_CACHE.lock().map_err(|e| {
+        let mut cache = API_KEY_CACHE.lock().map_err(|e| {
             log::error!("Failed to access API key cache: {}", e);
             "Failed to access cache".to_string()
         })?;
 
-        let cached = cache.get("ai_api_key_custom").cloned();
+        let cached = cache.use_key("ai_api_key_custom");
 
         if cached.is_some() {
             log::info!("Using cached API key for custom provider");
@@ -827,11 +1087,11 @@ pub async fn enhance_transcription(text: String, app: tauri::AppHandle) -> Resul
         ("openai".to_string(), cached.unwrap_or_default(), opts)
     } else if provider == "gemini" {
         // Require API key from in-memory cache
-        let cache = API_KEY_CACHE
+        let mut cache = API_KEY_CACHE
             .lock()
             .map_err(|_| "Failed to access cache".to_string())?;
         let key_name = format!("ai_api_key_{}", provider);
-        let api_key = cache.get(&key_name).cloned().ok_or_else(|| {
+        let api_key = cache.use_key(&key_name).ok_or_else(|| {
             log::error!(
                 "API key not found in cache for provider: {}. Cache keys: {:?}",
                 provider,
@@ -966,7 +1226,7 @@ pub async fn get_openai_config(app: tauri::AppHandle) -> Result<OpenAIConfig, St
 }
 
 // ============================================================================
-// Curated Model List (Static - No API Fetching)
+// Provider Model Lists (curated, refreshed from the provider when possible)
 // ============================================================================
 
 /// A model available from a provider
@@ -1010,29 +1270,212 @@ fn get_curated_models(provider: &str) -> Vec<ProviderModel> {
         .collect()
 }
 
-/// List available models for a provider
-/// Returns curated static list - no API call needed
+/// Text-generation models from an OpenAI-compatible `/models` response
+fn parse_openai_models(body: &serde_json::Value) -> Vec<String> {
+    const NON_TEXT: &[&str] = &[
+        "embedding",
+        "whisper",
+        "tts",
+        "dall-e",
+        "moderation",
+        "audio",
+        "realtime",
+        "transcribe",
+        "image",
+    ];
+    body.get("data")
+        .and_then(|d| d.as_array())
+        .map(|data| {
+            data.iter()
+                .filter_map(|entry| entry.get("id").and_then(|id| id.as_str()))
+                .filter(|id| !NON_TEXT.iter().any(|kind| id.contains(kind)))
+                .map(|id| id.to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Gemini models that support `generateContent`, without the `models/` prefix
+fn parse_gemini_models(body: &serde_json::Value) -> Vec<String> {
+    body.get("models")
+        .and_then(|m| m.as_array())
+        .map(|models| {
+            models
+                .iter()
+                .filter(|model| {
+                    model
+                        .get("supportedGenerationMethods")
+                        .and_then(|m| m.as_array())
+                        .is_some_and(|methods| {
+                            methods
+                                .iter()
+                                .any(|m| m.as_str() == Some("generateContent"))
+                        })
+                })
+                .filter_map(|model| model.get("name").and_then(|n| n.as_str()))
+                .map(|name| name.trim_start_matches("models/").to_string())
+                .filter(|id| id.starts_with("gemini-"))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Combine listed model ids with the curated list: curated models come first
+/// and keep their display names and recommendation
+fn merge_with_curated(provider: &str, ids: &[String]) -> Vec<ProviderModel> {
+    let curated = get_curated_models(provider);
+    let mut models: Vec<ProviderModel> = curated
+        .iter()
+        .filter(|model| ids.contains(&model.id))
+        .cloned()
+        .collect();
+    let mut others: Vec<&String> = ids
+        .iter()
+        .filter(|id| !curated.iter().any(|model| &model.id == *id))
+        .collect();
+    others.sort();
+    others.dedup();
+    models.extend(others.into_iter().map(|id| ProviderModel {
+        id: id.clone(),
+        name: id.clone(),
+        recommended: false,
+    }));
+    models
+}
+
+/// Query the provider's models endpoint with the cached key
+pub(crate) async fn fetch_provider_models(
+    app: &tauri::AppHandle,
+    provider: &str,
+) -> Result<Vec<String>, String> {
+    let store = app.store("settings").map_err(|e| e.to_string())?;
+    let api_key = {
+        let mut cache = API_KEY_CACHE
+            .lock()
+            .map_err(|_| "Failed to access cache".to_string())?;
+        cache.use_key(&format!("{}{}", API_KEY_CACHE_PREFIX, provider))
+    };
+
+    let client = crate::utils::proxy::client_builder()
+        .timeout(std::time::Duration::from_secs(10))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let request = match provider {
+        "gemini" => {
+            let key = api_key.ok_or_else(|| "API key not found in cache".to_string())?;
+            client.get(GEMINI_MODELS_URL).header("x-goog-api-key", key)
+        }
+        "openai" | "custom" => {
+            let base = if provider == "custom" {
+                store
+                    .get(CUSTOM_BASE_URL_KEY)
+                    .or_else(|| store.get(LEGACY_OPENAI_BASE_URL_KEY))
+                    .and_then(|v| v.as_str().map(|s| s.to_string()))
+                    .ok_or_else(|| "No base URL configured".to_string())?
+            } else {
+                DEFAULT_OPENAI_BASE_URL.to_string()
+            };
+            let request = client.get(normalize_models_url(&base));
+            match api_key {
+                Some(key) => request.header("Authorization", format!("Bearer {}", key)),
+                None if provider == "custom" => request,
+                None => return Err("API key not found in cache".to_string()),
+            }
+        }
+        _ => return Err(format!("Unsupported provider: {}", provider)),
+    };
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("Network error: {}", e))?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("HTTP {} from models endpoint", status));
+    }
+    let body: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse models response: {}", e))?;
+
+    let ids = if provider == "gemini" {
+        parse_gemini_models(&body)
+    } else {
+        parse_openai_models(&body)
+    };
+    if let Ok(mut fetched) = FETCHED_MODELS.lock() {
+        fetched.insert(provider.to_string(), ids.clone());
+    }
+    Ok(ids)
+}
+
+/// Check `model` against the models the provider lists. When the provider
+/// can't be reached and never was, the model is accepted as is.
+async fn validate_provider_model(
+    app: &tauri::AppHandle,
+    provider: &str,
+    model: &str,
+) -> Result<(), String> {
+    let known = match fetch_provider_models(app, provider).await {
+        Ok(ids) => Some(ids),
+        Err(e) => {
+            log::debug!("Could not list models for {}: {}", provider, e);
+            FETCHED_MODELS
+                .lock()
+                .ok()
+                .and_then(|fetched| fetched.get(provider).cloned())
+        }
+    };
+
+    match known {
+        Some(ids) if !ids.is_empty() && !ids.iter().any(|id| id == model) => Err(format!(
+            "Model '{}' is not available for provider {}",
+            model, provider
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// List available models for a provider from its models endpoint, using the
+/// cached API key. Falls back to the curated list when the provider can't be
+/// queried.
 #[tauri::command]
 pub async fn list_provider_models(
     provider: String,
-    _app: tauri::AppHandle,
+    app: tauri::AppHandle,
 ) -> Result<Vec<ProviderModel>, String> {
     // Validate provider
-    if !["openai", "gemini"].contains(&provider.as_str()) {
+    if !["openai", "gemini", "custom"].contains(&provider.as_str()) {
         return Err(format!(
             "Unsupported provider for model listing: {}",
             provider
         ));
     }
 
-    let models = get_curated_models(&provider);
-    log::info!(
-        "Returning {} curated models for provider {}",
-        models.len(),
-        provider
-    );
-
-    Ok(models)
+    match fetch_provider_models(&app, &provider).await {
+        Ok(ids) if !ids.is_empty() => {
+            let models = merge_with_curated(&provider, &ids);
+            log::info!(
+                "Returning {} models listed by provider {}",
+                models.len(),
+                provider
+            );
+            Ok(models)
+        }
+        result => {
+            if let Err(e) = result {
+                log::warn!("Failed to list models for {}: {}", provider, e);
+            }
+            let models = get_curated_models(&provider);
+            log::info!(
+                "Returning {} curated models for provider {}",
+                models.len(),
+                provider
+            );
+            Ok(models)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1057,6 +1500,28 @@ mod tests {
         assert!(validate_provider_name("").is_err());
     }
 
+    #[test]
+    fn test_provider_setting_keys() {
+        let keys = vec![
+            "ai_custom_base_url".to_string(),
+            "ai_custom_no_auth".to_string(),
+            "ai_openai_base_url".to_string(),
+            "ai_openai_no_auth".to_string(),
+            "ai_gemini_region".to_string(),
+            "ai_provider".to_string(),
+        ];
+        assert_eq!(
+            provider_setting_keys(keys.clone(), "custom"),
+            vec!["ai_custom_base_url", "ai_custom_no_auth"]
+        );
+        // Legacy custom endpoint keys survive removing OpenAI
+        assert!(provider_setting_keys(keys.clone(), "openai").is_empty());
+        assert_eq!(
+            provider_setting_keys(keys, "gemini"),
+            vec!["ai_gemini_region"]
+        );
+    }
+
     #[test]
     fn test_curated_models() {
         // OpenAI models
@@ -1080,4 +1545,50 @@ mod tests {
         let unknown_models = get_curated_models("unknown");
         assert!(unknown_models.is_empty());
     }
+
+    #[test]
+    fn test_api_key_cache_prunes_idle_entries() {
+        let mut cache = ApiKeyCache::default();
+        cache.insert("ai_api_key_openai".to_string(), "sk-old".to_string());
+        cache.insert("ai_api_key_gemini".to_string(), "g-new".to_string());
+
+        let later = Utc::now() + chrono::Duration::seconds(120);
+        // Touch gemini "later" so only openai is idle
+        if let Some(entry) = cache.entries.get_mut("ai_api_key_gemini") {
+            entry.last_used = later;
+        }
+
+        let evicted = cache.prune_idle(chrono::Duration::seconds(60), later);
+        assert_eq!(evicted, vec!["ai_api_key_openai".to_string()]);
+        assert!(!cache.contains_key("ai_api_key_openai"));
+        assert_eq!(cache.use_key("ai_api_key_gemini").as_deref(), Some("g-new"));
+    }
+
+    #[test]
+    fn test_listed_models_merge_with_curated() {
+        let body = json!({
+            "data": [
+                {"id": "gpt-4o-mini"},
+                {"id": "gpt-5-mini"},
+                {"id": "text-embedding-3-small"},
+                {"id": "whisper-1"}
+            ]
+        });
+        let ids = parse_openai_models(&body);
+        assert_eq!(ids, vec!["gpt-4o-mini", "gpt-5-mini"]);
+
+        let models = merge_with_curated("openai", &ids);
+        assert_eq!(models[0].id, "gpt-5-mini");
+        assert!(models[0].recommended);
+        assert_eq!(models[1].id, "gpt-4o-mini");
+        assert!(!models[1].recommended);
+
+        let body = json!({
+            "models": [
+                {"name": "models/gemini-2.5-flash", "supportedGenerationMethods": ["generateContent"]},
+                {"name": "models/text-embedding-004", "supportedGenerationMethods": ["embedContent"]}
+            ]
+        });
+        assert_eq!(parse_gemini_models(&body), vec!["gemini-2.5-flash"]);
+    }
 }
@@ -1,7 +1,7 @@
-use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering as AtomicOrdering};
 use tauri::{AppHandle, Emitter, Manager, State};
 
-use crate::audio::recorder::AudioRecorder;
+use crate::audio::recorder::{AudioRecorder, AutoStopReason};
 use crate::commands::license::check_license_status_internal;
 use crate::commands::settings::{get_settings, resolve_pill_indicator_mode, Settings};
 use crate::license::LicenseState;
@@ -14,7 +14,9 @@ use crate::utils::system_monitor;
 use crate::whisper::cache::TranscriberCache;
 use crate::whisper::languages::validate_language;
 use crate::whisper::manager::WhisperManager;
-use crate::{emit_to_window, update_recording_state, AppState, RecordingMode, RecordingState};
+use crate::{
+    emit_to_all, emit_to_window, update_recording_state, AppState, RecordingMode, RecordingState,
+};
 use cpal::traits::{DeviceTrait, HostTrait};
 use once_cell::sync::Lazy;
 use serde_json;
@@ -32,6 +34,16 @@ static TOAST_ID_COUNTER: AtomicU64 = AtomicU64::new(0);
 /// Global media pause controller for pausing/resuming system media during recording
 static MEDIA_CONTROLLER: Lazy<MediaPauseController> = Lazy::new(MediaPauseController::new);
 
+/// Set once `prepare_shutdown` has finished, so the exit handler only runs it once
+static SHUTDOWN_PREPARED: AtomicBool = AtomicBool::new(false);
+
+// Background re-transcription started by `retranscribe_low_confidence`
+static RETRANSCRIBE_ACTIVE: AtomicBool = AtomicBool::new(false);
+static RETRANSCRIBE_CANCEL: AtomicBool = AtomicBool::new(false);
+
+/// How long quitting waits for an in-flight transcription to be pasted and saved
+const SHUTDOWN_TRANSCRIPTION_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
 /// Payload for pill toast messages
 #[derive(serde::Serialize, Clone)]
 pub struct PillToastPayload {
@@ -209,6 +221,20 @@ fn play_recording_end_sound() {
     // No-op on other platforms
 }
 
+/// What to do with a recording that contains no speech
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum OnEmptyAudio {
+    /// Don't transcribe; go back to idle without feedback
+    Skip,
+    /// Transcribe as usual, then discard the result: nothing is pasted or
+    /// saved, so silence can't paste or store hallucinated text
+    PasteNothing,
+    /// Don't transcribe; show "No speech detected"
+    #[default]
+    Notify,
+}
+
 /// Cached recording configuration to avoid repeated store access during transcription flow
 /// Cache is invalidated when settings change via update hooks
 #[derive(Clone, Debug)]
@@ -223,6 +249,19 @@ pub struct RecordingConfig {
     pub language: String,
     pub translate_to_english: bool,
     pub show_recording_status: bool,
+    pub noise_suppression: bool,
+    pub noise_suppression_strength: f32,
+    pub max_memory_mb: u64,
+    pub min_confidence: f32,
+    pub keep_recordings: bool,
+    pub normalize_peak: bool,
+    pub normalize_target_peak: f32,
+    pub trim_silence: bool,
+    pub incognito_mode: bool,
+    pub overlay_during_transcription: bool,
+    pub overlay_timeout_ms: u32,
+    pub diarization: bool,
+    pub on_empty_audio: OnEmptyAudio,
     // Internal cache metadata
     loaded_at: Instant,
 }
@@ -291,6 +330,62 @@ impl RecordingConfig {
                 .get("show_recording_status")
                 .and_then(|v| v.as_bool())
                 .unwrap_or(true),
+            noise_suppression: store
+                .get("noise_suppression")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            noise_suppression_strength: store
+                .get("noise_suppression_strength")
+                .and_then(|v| v.as_f64())
+                .map(|v| (v as f32).clamp(0.0, 1.0))
+                .unwrap_or(crate::audio::denoise::DEFAULT_STRENGTH),
+            max_memory_mb: store
+                .get("max_memory_mb")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(0),
+            min_confidence: store
+                .get("min_confidence")
+                .and_then(|v| v.as_f64())
+                .map(|v| (v as f32).clamp(0.0, 1.0))
+                .unwrap_or(0.0),
+            keep_recordings: store
+                .get("keep_recordings")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            normalize_peak: store
+                .get("normalize_peak")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            normalize_target_peak: store
+                .get("normalize_target_peak")
+                .and_then(|v| v.as_f64())
+                .map(|v| (v as f32).clamp(0.0, 1.0))
+                .unwrap_or(crate::audio::gain::DEFAULT_TARGET_PEAK),
+            trim_silence: store
+                .get("trim_silence")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            incognito_mode: store
+                .get("incognito_mode")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            overlay_during_transcription: store
+                .get("overlay_during_transcription")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(true),
+            overlay_timeout_ms: store
+                .get("overlay_timeout_ms")
+                .and_then(|v| v.as_u64())
+                .map(|v| v.min(crate::commands::settings::MAX_OVERLAY_TIMEOUT_MS as u64) as u32)
+                .unwrap_or(0),
+            diarization: store
+                .get("diarization")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            on_empty_audio: store
+                .get("on_empty_audio")
+                .and_then(|v| serde_json::from_value(v).ok())
+                .unwrap_or_default(),
             loaded_at: Instant::now(),
         })
     }
@@ -337,6 +432,26 @@ impl ActiveEngineSelection {
     }
 }
 
+/// Release the model used for the last transcription so its memory is returned to the OS.
+/// The next recording reloads it on demand.
+async fn unload_active_model(app: &AppHandle, selection: &ActiveEngineSelection) {
+    match selection {
+        ActiveEngineSelection::Whisper { .. } => {
+            let cache_state = app.state::<AsyncMutex<TranscriberCache>>();
+            cache_state.lock().await.clear();
+            log::info!("Unloaded cached Whisper models");
+        }
+        ActiveEngineSelection::Parakeet { .. } => {
+            let parakeet_manager = app.state::<ParakeetManager>();
+            if let Err(e) = parakeet_manager.unload_model(app).await {
+                log::warn!("Failed to unload Parakeet model: {:?}", e);
+            }
+        }
+        // Cloud transcription keeps no local model
+        ActiveEngineSelection::Soniox { .. } => {}
+    }
+}
+
 async fn abort_due_to_missing_model(
     app: &AppHandle,
     audio_path: &Path,
@@ -346,7 +461,7 @@ async fn abort_due_to_missing_model(
     log::error!("{}", log_message);
     update_recording_state(app, RecordingState::Error, Some(user_message.to_string()));
 
-    if let Err(e) = std::fs::remove_file(audio_path) {
+    if let Err(e) = crate::utils::secure_delete::remove_file(audio_path) {
         log::warn!("Failed to remove audio file: {}", e);
     }
 
@@ -516,6 +631,33 @@ pub async fn get_recording_config(app: &AppHandle) -> Result<RecordingConfig, St
 // Global audio recorder state
 pub struct RecorderState(pub Mutex<AudioRecorder>);
 
+/// Start or stop the background pre-roll capture to match the saved settings.
+/// Stays off until onboarding is complete so the mic isn't opened early.
+pub fn apply_preroll_settings(app: &AppHandle) {
+    let Ok(store) = app.store("settings") else {
+        return;
+    };
+
+    let onboarding_done = store
+        .get("onboarding_completed")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    let preroll_ms = store
+        .get("preroll_ms")
+        .and_then(|v| v.as_u64())
+        .map(|v| v.min(crate::audio::preroll::MAX_PREROLL_MS as u64) as u32)
+        .filter(|_| onboarding_done)
+        .unwrap_or(0);
+    let device_name = store
+        .get("selected_microphone")
+        .and_then(|v| v.as_str().map(|s| s.to_string()));
+
+    match app.state::<RecorderState>().0.lock() {
+        Ok(mut recorder) => recorder.configure_preroll(preroll_ms, device_name),
+        Err(e) => log::warn!("Failed to acquire recorder lock for pre-roll: {}", e),
+    }
+}
+
 /// Select the best fallback model based on available models
 /// Prioritizes models by size (smaller to larger for better performance)
 fn select_best_fallback_model(
@@ -650,6 +792,198 @@ async fn validate_recording_requirements(app: &AppHandle) -> Result<(), String>
     Ok(())
 }
 
+/// Why recording can't start (or may not behave as expected)
+#[derive(serde::Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadinessIssueKind {
+    OnboardingIncomplete,
+    Busy,
+    MicrophonePermission,
+    NoInputDevice,
+    SelectedDeviceMissing,
+    NoModel,
+    ModelNotDownloaded,
+    SonioxKeyMissing,
+    AccessibilityPermission,
+}
+
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ReadinessIssue {
+    pub kind: ReadinessIssueKind,
+    pub message: String,
+    /// Blocking issues make `start_recording` fail; the rest are warnings
+    pub blocking: bool,
+}
+
+/// Result of the pre-flight checks run by `can_start_recording`
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ReadinessReport {
+    pub ready: bool,
+    pub input_device: Option<String>,
+    pub model: Option<String>,
+    pub issues: Vec<ReadinessIssue>,
+}
+
+impl ReadinessReport {
+    pub fn new(
+        input_device: Option<String>,
+        model: Option<String>,
+        issues: Vec<ReadinessIssue>,
+    ) -> Self {
+        Self {
+            ready: !issues.iter().any(|issue| issue.blocking),
+            input_device,
+            model,
+            issues,
+        }
+    }
+}
+
+/// Which device the recorder will open: the selected one if it is plugged in,
+/// otherwise the default input (mirrors the fallback in `AudioRecorder`).
+pub fn resolve_input_device(
+    selected: Option<&str>,
+    available: &[String],
+    default_device: Option<String>,
+) -> (Option<String>, Option<ReadinessIssue>) {
+    match selected {
+        Some(name) if available.iter().any(|d| d == name) => (Some(name.to_string()), None),
+        Some(name) => match default_device {
+            Some(default_device) => (
+                Some(default_device.clone()),
+                Some(ReadinessIssue {
+                    kind: ReadinessIssueKind::SelectedDeviceMissing,
+                    message: format!(
+                        "Microphone '{}' is not connected; '{}' will be used instead",
+                        name, default_device
+                    ),
+                    blocking: false,
+                }),
+            ),
+            None => (
+                None,
+                Some(ReadinessIssue {
+                    kind: ReadinessIssueKind::NoInputDevice,
+                    message: format!(
+                        "Microphone '{}' is not connected and no other input device is available",
+                        name
+                    ),
+                    blocking: true,
+                }),
+            ),
+        },
+        None => match default_device {
+            Some(default_device) => (Some(default_device), None),
+            None => (
+                None,
+                Some(ReadinessIssue {
+                    kind: ReadinessIssueKind::NoInputDevice,
+                    message: "No input device available".to_string(),
+                    blocking: true,
+                }),
+            ),
+        },
+    }
+}
+
+/// Check the input device, model and permissions without starting anything,
+/// so the UI can disable the record button and say why.
+#[tauri::command]
+pub async fn can_start_recording(app: AppHandle) -> Result<ReadinessReport, String> {
+    let settings = get_settings(app.clone()).await?;
+    let mut issues = Vec::new();
+
+    let recording_state = crate::get_recording_state(&app);
+    if !matches!(
+        recording_state,
+        RecordingState::Idle | RecordingState::Error
+    ) {
+        issues.push(ReadinessIssue {
+            kind: ReadinessIssueKind::Busy,
+            message: format!("A recording is already in progress ({:?})", recording_state),
+            blocking: true,
+        });
+    }
+
+    // Device enumeration triggers the mic prompt, so only probe after onboarding
+    let mut input_device = None;
+    if !settings.onboarding_completed {
+        issues.push(ReadinessIssue {
+            kind: ReadinessIssueKind::OnboardingIncomplete,
+            message: "Finish onboarding before recording".to_string(),
+            blocking: true,
+        });
+    } else if !crate::commands::permissions::check_microphone_permission().await? {
+        issues.push(ReadinessIssue {
+            kind: ReadinessIssueKind::MicrophonePermission,
+            message: "Microphone access has not been granted".to_string(),
+            blocking: true,
+        });
+    } else {
+        let selected = settings.selected_microphone.clone();
+        let (device, issue) = tauri::async_runtime::spawn_blocking(move || {
+            let available = AudioRecorder::get_devices();
+            let default_device = cpal::default_host()
+                .default_input_device()
+                .and_then(|device| device.name().ok());
+            resolve_input_device(selected.as_deref(), &available, default_device)
+        })
+        .await
+        .map_err(|e| format!("Failed to check input devices: {}", e))?;
+        input_device = device;
+        issues.extend(issue);
+    }
+
+    let availability = crate::recognition_availability_snapshot(&app).await;
+    let model = (!settings.current_model.is_empty()).then(|| settings.current_model.clone());
+    if availability.soniox_selected {
+        if !availability.soniox_ready {
+            issues.push(ReadinessIssue {
+                kind: ReadinessIssueKind::SonioxKeyMissing,
+                message: "Add your Soniox API key in Models".to_string(),
+                blocking: true,
+            });
+        }
+    } else if !availability.any_available() {
+        issues.push(ReadinessIssue {
+            kind: ReadinessIssueKind::NoModel,
+            message: "Download a model from Models before recording".to_string(),
+            blocking: true,
+        });
+    } else if let Some(model) = &model {
+        let downloaded = if settings.current_model_engine == "parakeet" {
+            app.state::<ParakeetManager>()
+                .list_models()
+                .iter()
+                .any(|m| m.downloaded && &m.name == model)
+        } else {
+            app.state::<AsyncRwLock<WhisperManager>>()
+                .read()
+                .await
+                .get_downloaded_model_names()
+                .contains(model)
+        };
+        if !downloaded {
+            issues.push(ReadinessIssue {
+                kind: ReadinessIssueKind::ModelNotDownloaded,
+                message: format!("The selected model '{}' is not downloaded", model),
+                blocking: true,
+            });
+        }
+    }
+
+    // Only needed to paste the result, so recording itself still works
+    if !crate::commands::permissions::check_accessibility_permission().await? {
+        issues.push(ReadinessIssue {
+            kind: ReadinessIssueKind::AccessibilityPermission,
+            message: "Accessibility access is needed to paste transcriptions".to_string(),
+            blocking: false,
+        });
+    }
+
+    Ok(ReadinessReport::new(input_device, model, issues))
+}
+
 #[tauri::command]
 pub async fn start_recording(
     app: AppHandle,
@@ -716,7 +1050,14 @@ pub async fn start_recording(
     }
 
     // Play sound on recording start if enabled
-    if let Ok(store) = app.store("settings") {
+    if crate::audio::sound_cues::enabled(&app) {
+        crate::audio::sound_cues::play_cue(
+            &app,
+            crate::audio::sound_cues::SoundCue::RecordingStart,
+        );
+        // Same delay as below, for Bluetooth headsets
+        tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+    } else if let Ok(store) = app.store("settings") {
         let play_sound = store
             .get("play_sound_on_recording")
             .and_then(|v| v.as_bool())
@@ -769,7 +1110,7 @@ pub async fn start_recording(
         config.current_model
     );
     // Get app data directory for recordings
-    let recordings_dir = match app.path().app_data_dir() {
+    let recordings_dir = match crate::utils::data_dir::data_dir(&app) {
         Ok(dir) => dir.join("recordings"),
         Err(e) => {
             resume_media_if_needed();
@@ -810,8 +1151,12 @@ pub async fn start_recording(
     }
 
     // Get selected microphone from settings (before acquiring recorder lock)
+    let mut max_recording_seconds = 0;
+    let mut recording_gain_db = 0.0;
     let selected_microphone = match get_settings(app.clone()).await {
         Ok(settings) => {
+            max_recording_seconds = settings.max_recording_seconds;
+            recording_gain_db = settings.recording_gain_db;
             if let Some(mic) = settings.selected_microphone {
                 log::info!("Using selected microphone: {}", mic);
                 Some(mic)
@@ -881,6 +1226,10 @@ pub async fn start_recording(
             }
         }
 
+        // Guard against runaway recordings (e.g. a stuck hotkey)
+        recorder.set_max_recording_seconds(Some(max_recording_seconds));
+        recorder.set_gain_db(recording_gain_db);
+
         // Try to start recording with graceful error handling
         let recorder_init_start = Instant::now();
         let audio_path_str = match audio_path.to_str() {
@@ -989,9 +1338,43 @@ pub async fn start_recording(
                 }
             };
 
+        let auto_stop_rx = recorder.take_auto_stop_receiver();
+
         // Release the recorder lock after successful start
         drop(recorder);
 
+        // Mark the recording in progress so a crash can be recovered on next launch
+        if let Err(e) =
+            crate::utils::crash_recovery::acquire_recording_lock(&recordings_dir, &audio_path)
+        {
+            log::warn!("{}", e);
+        }
+
+        // Stop and transcribe when the recorder hits the maximum length
+        if let Some(auto_stop_rx) = auto_stop_rx {
+            let app_for_limit = app.clone();
+            std::thread::spawn(move || {
+                // recv() fails once the recording thread exits for any other reason
+                if let Ok(AutoStopReason::MaxLength) = auto_stop_rx.recv() {
+                    log::warn!(
+                        "Maximum recording length of {}s reached, stopping",
+                        max_recording_seconds
+                    );
+                    let _ =
+                        emit_to_all(&app_for_limit, "max-length-reached", max_recording_seconds);
+                    pill_toast(&app_for_limit, "Maximum recording length reached", 1500);
+
+                    let app_handle = app_for_limit.clone();
+                    tauri::async_runtime::spawn(async move {
+                        let recorder_state = app_handle.state::<RecorderState>();
+                        if let Err(e) = stop_recording(app_handle.clone(), recorder_state).await {
+                            log::error!("Auto-stop at maximum length failed: {}", e);
+                        }
+                    });
+                }
+            });
+        }
+
         // Start audio level monitoring
         if let Some(audio_level_rx) = audio_level_rx {
             let app_for_levels = app.clone();
@@ -1025,6 +1408,63 @@ pub async fn start_recording(
     // Update state to recording
     update_recording_state(&app, RecordingState::Recording, None);
 
+    // Note the app being dictated into; detection can shell out, so keep it
+    // off the start path
+    if let Ok(mut recording_app) = app_state.recording_app.lock() {
+        *recording_app = None;
+    }
+    let app_for_context = app.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        let active_app = crate::utils::active_app::frontmost_app();
+        if let Ok(mut recording_app) = app_for_context.state::<AppState>().recording_app.lock() {
+            *recording_app = active_app;
+        }
+    });
+
+    // Stream interim text (and type it live) for local Whisper models when enabled
+    let setting_enabled = |key: &str| {
+        app.store("settings")
+            .ok()
+            .and_then(|store| store.get(key))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false)
+    };
+    let partials_enabled = setting_enabled("partial_transcripts_enabled");
+    // Push-to-talk holds the hotkey's modifiers down while typing, which would
+    // turn typed characters into shortcuts, so live typing is toggle-only
+    let push_to_talk = app
+        .state::<AppState>()
+        .recording_mode
+        .lock()
+        .map(|mode| *mode == RecordingMode::PushToTalk)
+        .unwrap_or(false);
+    let live_typing =
+        setting_enabled("live_typing") && config.current_engine == "whisper" && !push_to_talk;
+    crate::recording::live_typing::begin(live_typing);
+    if (partials_enabled || live_typing) && config.current_engine == "whisper" {
+        let model_path = app
+            .state::<AsyncRwLock<WhisperManager>>()
+            .read()
+            .await
+            .get_model_path(&config.current_model);
+        if let Some(model_path) = model_path {
+            let language = if config.language.is_empty() {
+                None
+            } else {
+                Some(config.language.clone())
+            };
+            crate::recording::partial::spawn_partial_transcription(
+                app.clone(),
+                model_path,
+                language,
+                config.translate_to_english,
+                partials_enabled,
+            );
+        } else {
+            log::debug!("Partial transcripts skipped: current Whisper model not available");
+        }
+    }
+
     // If a toggle-stop was requested while starting, honor it immediately after entering Recording
     if app_state
         .pending_stop_after_start
@@ -1144,6 +1584,7 @@ pub async fn stop_recording(
 
     // Stop recording (lock only within this scope to stay Send)
     log::info!("🛑 Stopping recording...");
+    let noise_floor_dbfs;
     {
         let mut recorder = state
             .inner()
@@ -1164,9 +1605,16 @@ pub async fn stop_recording(
             .stop_recording()
             .map_err(|e| format!("Failed to stop recording: {}", e))?;
         log::info!("{}", stop_message);
+        noise_floor_dbfs = recorder.take_noise_floor_dbfs();
+        crate::utils::crash_recovery::release_app_recording_lock(&app);
 
         // Play sound on recording end if enabled
-        if let Ok(store) = app.store("settings") {
+        if crate::audio::sound_cues::enabled(&app) {
+            crate::audio::sound_cues::play_cue(
+                &app,
+                crate::audio::sound_cues::SoundCue::RecordingStop,
+            );
+        } else if let Ok(store) = app.store("settings") {
             let play_sound = store
                 .get("play_sound_on_recording_end")
                 .and_then(|v| v.as_bool())
@@ -1232,7 +1680,7 @@ pub async fn stop_recording(
         if let Ok(path_guard) = app_state.current_recording_path.lock() {
             if let Some(audio_path) = path_guard.as_ref() {
                 log::info!("Removing cancelled recording file");
-                if let Err(e) = std::fs::remove_file(audio_path) {
+                if let Err(e) = crate::utils::secure_delete::remove_file(audio_path) {
                     log::warn!("Failed to remove cancelled recording: {}", e);
                 }
             }
@@ -1282,7 +1730,7 @@ pub async fn stop_recording(
         // A valid WAV header is typically 44 bytes; <= 44 implies no audio samples were written
         if meta.len() <= 44 {
             pill_toast(&app, "No audio captured", 1000);
-            if let Err(e) = std::fs::remove_file(&audio_path) {
+            if let Err(e) = crate::utils::secure_delete::remove_file(&audio_path) {
                 log::debug!("Failed to remove empty audio file: {}", e);
             }
             // Frontend will hide pill after showing feedback
@@ -1469,6 +1917,9 @@ pub async fn stop_recording(
         }
     };
 
+    // Set when the recording has no speech and on_empty_audio is paste_nothing
+    let mut no_speech_detected = false;
+
     // For Whisper/Parakeet: normalize and duration gate; for Soniox: skip both
     let audio_path = match &engine_selection {
         ActiveEngineSelection::Soniox { .. } => {
@@ -1494,17 +1945,109 @@ pub async fn stop_recording(
                         RecordingState::Error,
                         Some("Audio normalization failed".to_string()),
                     );
-                    let _ = std::fs::remove_file(&audio_path);
+                    let _ = crate::utils::secure_delete::remove_file(&audio_path);
                     return Err("Audio normalization failed".to_string());
                 }
                 out_path
             };
 
             // Remove raw capture after successful normalization
-            if let Err(e) = std::fs::remove_file(&audio_path) {
+            if let Err(e) = crate::utils::secure_delete::remove_file(&audio_path) {
                 log::debug!("Failed to remove raw audio: {}", e);
             }
 
+            // Optional noise suppression on the normalized 16 kHz mono audio
+            if config.noise_suppression {
+                let path = normalized_path.clone();
+                let strength = config.noise_suppression_strength;
+                let denoise_start = Instant::now();
+                match tokio::task::spawn_blocking(move || {
+                    crate::audio::denoise::denoise_wav_file(&path, strength)
+                })
+                .await
+                {
+                    Ok(Ok(())) => log::info!(
+                        "Noise suppression applied (strength {:.2}) in {}ms",
+                        strength,
+                        denoise_start.elapsed().as_millis()
+                    ),
+                    // Fall back to the unprocessed audio rather than failing the recording
+                    Ok(Err(e)) => log::warn!("Noise suppression skipped: {}", e),
+                    Err(e) => log::warn!("Noise suppression task failed: {}", e),
+                }
+            }
+
+            // Optional silence trimming, so trailing silence isn't transcribed
+            if config.trim_silence {
+                let path = normalized_path.clone();
+                match tokio::task::spawn_blocking(move || {
+                    crate::audio::trim::trim_wav_file(
+                        &path,
+                        crate::audio::trim::DEFAULT_TRIM_THRESHOLD_DB,
+                    )
+                })
+                .await
+                {
+                    Ok(Ok(removed)) => {
+                        log::info!("Silence trimming removed {:.2}s", removed as f32 / 16000.0)
+                    }
+                    Ok(Err(e)) => log::warn!("Silence trimming skipped: {}", e),
+                    Err(e) => log::warn!("Silence trimming task failed: {}", e),
+                }
+            }
+
+            // Look for speech before peak normalization can amplify background noise
+            let path = normalized_path.clone();
+            match tokio::task::spawn_blocking(move || {
+                crate::audio::trim::wav_contains_speech(
+                    &path,
+                    crate::audio::trim::DEFAULT_TRIM_THRESHOLD_DB,
+                )
+            })
+            .await
+            {
+                Ok(Ok(true)) => {}
+                Ok(Ok(false)) if config.on_empty_audio == OnEmptyAudio::PasteNothing => {
+                    log::info!("No speech detected; result will be discarded");
+                    no_speech_detected = true;
+                }
+                Ok(Ok(false)) => {
+                    log::info!("No speech detected; skipping transcription");
+                    if let Err(e) = crate::utils::secure_delete::remove_file(&normalized_path) {
+                        log::debug!("Failed to remove silent normalized audio: {}", e);
+                    }
+                    if config.on_empty_audio == OnEmptyAudio::Notify {
+                        pill_toast(&app, "No speech detected", 1500);
+                        // Leave the pill up long enough to show the toast
+                        tokio::time::sleep(std::time::Duration::from_millis(1500)).await;
+                    }
+                    if should_hide_pill(&app).await {
+                        let _ = crate::commands::window::hide_pill_widget(app.clone()).await;
+                    }
+                    update_recording_state(&app, RecordingState::Idle, None);
+                    return Ok("".to_string());
+                }
+                Ok(Err(e)) => log::warn!("Speech check skipped: {}", e),
+                Err(e) => log::warn!("Speech check task failed: {}", e),
+            }
+
+            // Optional peak normalization, after denoising so removed noise doesn't set the peak
+            if config.normalize_peak {
+                let path = normalized_path.clone();
+                let target = config.normalize_target_peak;
+                match tokio::task::spawn_blocking(move || {
+                    crate::audio::gain::normalize_wav_file(&path, target)
+                })
+                .await
+                {
+                    Ok(Ok(factor)) => {
+                        log::info!("Peak normalization applied (x{:.2})", factor)
+                    }
+                    Ok(Err(e)) => log::warn!("Peak normalization skipped: {}", e),
+                    Err(e) => log::warn!("Peak normalization task failed: {}", e),
+                }
+            }
+
             // Determine min duration based on recording mode (PTT vs Toggle) once
             let (min_duration_s_f32, min_duration_label) = {
                 let app_state = app.state::<AppState>();
@@ -1549,7 +2092,7 @@ pub async fn stop_recording(
                     "recording-too-short",
                     format!("Recording shorter than {} seconds", min_duration_label),
                 );
-                if let Err(e) = std::fs::remove_file(&normalized_path) {
+                if let Err(e) = crate::utils::secure_delete::remove_file(&normalized_path) {
                     log::debug!("Failed to remove short normalized audio: {}", e);
                 }
                 // Frontend will hide pill after showing feedback
@@ -1586,6 +2129,13 @@ pub async fn stop_recording(
 
     let engine_label = engine_selection.engine_name().to_string();
     let selected_model_name = engine_selection.model_name().to_string();
+    // Recorded with the result so it can be reproduced with the same file
+    let whisper_quantization = match &engine_selection {
+        ActiveEngineSelection::Whisper { model_path, .. } => {
+            Some(crate::whisper::manager::quantization_of(model_path))
+        }
+        _ => None,
+    };
 
     log::info!(
         "🤖 Using {} model for transcription: {}",
@@ -1598,6 +2148,11 @@ pub async fn stop_recording(
         translate_to_english
     );
 
+    // Describe the recording before transcribing so a crash leaves a detectable orphan
+    if let Err(e) = crate::utils::recordings::write_pending_sidecar(&audio_path) {
+        log::warn!("{}", e);
+    }
+
     let audio_path_clone = audio_path.clone();
     let engine_selection_for_task = engine_selection;
     let language_for_task = language.clone();
@@ -1610,6 +2165,16 @@ pub async fn stop_recording(
 
         // Update state to transcribing
         update_recording_state(&app_for_task, RecordingState::Transcribing, None);
+        // Keep the overlay up until the result is in, even if something hid it
+        // when recording stopped
+        if config.overlay_during_transcription
+            && config.show_pill_widget
+            && config.pill_indicator_mode != "never"
+        {
+            if let Err(e) = crate::commands::window::show_pill_widget(app_for_task.clone()).await {
+                log::warn!("Failed to show overlay while transcribing: {}", e);
+            }
+        }
         // Also emit legacy event to pill window
         let _ = emit_to_window(&app_for_task, "pill", "transcription-started", ());
         // Give UI a moment to render the loader before heavy CPU work
@@ -1633,8 +2198,33 @@ pub async fn stop_recording(
             return;
         }
 
+        // Read for ETA calibration and opt-in latency telemetry; never includes the transcript
+        let audio_duration_secs = crate::utils::telemetry::wav_duration_secs(&audio_path_clone);
+        // Duration and noise bucket, used to learn which model suits the user's audio
+        let audio_conditions = audio_duration_secs
+            .zip(noise_floor_dbfs)
+            .map(|(secs, dbfs)| crate::utils::model_suggestions::AudioConditions::new(secs, dbfs));
+        if let Some(conditions) = audio_conditions {
+            crate::utils::model_suggestions::record_conditions(&app_for_task, conditions);
+        }
+        let transcription_started = Instant::now();
+        let memory_sampler = crate::utils::memory_sampler::MemorySampler::start(
+            crate::utils::memory_sampler::DEFAULT_SAMPLE_INTERVAL,
+        );
+
+        // Timed segments with confidence (and speaker labels with a -tdrz
+        // model), when the engine provides them (Whisper)
+        let mut transcription_segments: Vec<crate::whisper::confidence::TranscribedSegment> =
+            Vec::new();
+        // Language the engine reports it transcribed, when it reports one
+        let mut detected_language: Option<String> = None;
         let transcription_result: Result<String, String> = match &engine_selection_for_task {
-            ActiveEngineSelection::Whisper { model_path, .. } => {
+            ActiveEngineSelection::Whisper {
+                model_name,
+                model_path,
+            } => {
+                let diarize = config.diarization
+                    && crate::whisper::diarization::model_can_diarize("whisper", model_name);
                 let transcriber = {
                     let cache_state = app_for_task.state::<AsyncMutex<TranscriberCache>>();
                     let mut cache = cache_state.lock().await;
@@ -1660,7 +2250,8 @@ pub async fn stop_recording(
                 const MAX_RETRIES: u32 = 3;
                 const RETRY_DELAY_MS: u64 = 500;
 
-                let mut result = Err("No attempt made".to_string());
+                let mut result: Result<crate::whisper::confidence::WhisperTranscription, String> =
+                    Err("No attempt made".to_string());
 
                 for attempt in 1..=MAX_RETRIES {
                     if app_state.is_cancellation_requested() {
@@ -1669,11 +2260,13 @@ pub async fn stop_recording(
                         break;
                     }
 
-                    result = transcriber.transcribe_with_cancellation(
+                    let cancel_flag = app_state.should_cancel_recording.clone();
+                    result = transcriber.transcribe_segments(
                         &audio_path_clone,
                         language_for_task.as_deref(),
                         translate_to_english,
-                        || app_state.is_cancellation_requested(),
+                        diarize,
+                        move || cancel_flag.load(AtomicOrdering::SeqCst),
                     );
 
                     match &result {
@@ -1706,7 +2299,11 @@ pub async fn stop_recording(
                     }
                 }
 
-                result
+                result.map(|transcription| {
+                    transcription_segments = transcription.segments;
+                    detected_language = transcription.language;
+                    transcription.text
+                })
             }
             ActiveEngineSelection::Parakeet { model_name } => {
                 let parakeet_manager = app_for_task.state::<ParakeetManager>();
@@ -1731,7 +2328,10 @@ pub async fn stop_recording(
                     )
                     .await
                 {
-                    Ok(ParakeetResponse::Transcription { text, .. }) => Ok(text),
+                    Ok(ParakeetResponse::Transcription { text, language, .. }) => {
+                        detected_language = language;
+                        Ok(text)
+                    }
                     Ok(other) => {
                         let message = format!("Unexpected Parakeet response: {:?}", other);
                         Err(message)
@@ -1753,16 +2353,57 @@ pub async fn stop_recording(
             }
         };
 
-        // Clean up temp file regardless of outcome
-        if let Err(e) = std::fs::remove_file(&audio_path_clone) {
-            log::warn!("Failed to remove temporary audio file: {}", e);
+        let peak_memory_mb = memory_sampler.finish();
+        if let Some(peak_mb) = peak_memory_mb {
+            log::debug!("Peak memory during transcription: {} MB", peak_mb);
+            if crate::utils::memory_sampler::exceeds_memory_cap(peak_mb, config.max_memory_mb) {
+                log::warn!(
+                    "Peak memory {} MB exceeded the {} MB cap, unloading model",
+                    peak_mb,
+                    config.max_memory_mb
+                );
+                unload_active_model(&app_for_task, &engine_selection_for_task).await;
+            }
+        }
+
+        if let (Ok(_), Some(duration)) = (&transcription_result, audio_duration_secs) {
+            crate::utils::transcription_eta::record_transcription_time(
+                &app_for_task,
+                engine_selection_for_task.engine_name(),
+                &selected_model_name_for_task,
+                duration,
+                transcription_started.elapsed(),
+            );
+            crate::utils::telemetry::record_transcription_metric(
+                &app_for_task,
+                crate::utils::telemetry::TranscriptionMetric::new(
+                    &selected_model_name_for_task,
+                    engine_selection_for_task.engine_name(),
+                    duration,
+                    transcription_started.elapsed(),
+                )
+                .with_peak_memory(peak_memory_mb),
+            );
         }
 
+        // Keep the recording for history when enabled, otherwise clean up regardless of outcome.
+        // Incognito transcriptions are never stored, so their audio goes right away.
+        let kept_recording =
+            if config.keep_recordings && !config.incognito_mode && transcription_result.is_ok() {
+                Some(audio_path_clone.clone())
+            } else {
+                crate::utils::recordings::remove_recording(&audio_path_clone);
+                None
+            };
+
         match transcription_result {
             Ok(text) => {
                 // Final cancellation check before processing result
                 if app_state.is_cancellation_requested() {
                     log::info!("Transcription completed but was cancelled, discarding result");
+                    if let Some(recording) = &kept_recording {
+                        crate::utils::recordings::remove_recording(recording);
+                    }
 
                     // Hide pill window since we're cancelling (only if show_pill_indicator is false)
                     if should_hide_pill(&app_for_task).await {
@@ -1782,6 +2423,9 @@ pub async fn stop_recording(
                 // Check if transcription is empty or just noise
                 if text.is_empty() || text.trim().is_empty() || text == "[BLANK_AUDIO]" {
                     log::info!("Whisper returned empty transcription - no speech detected");
+                    if let Some(recording) = &kept_recording {
+                        crate::utils::recordings::remove_recording(recording);
+                    }
 
                     // Emit graceful feedback to user via pill toast
                     pill_toast(
@@ -1822,9 +2466,83 @@ pub async fn stop_recording(
 
                 // Backend handles the complete flow
                 let app_for_process = app_for_task.clone();
-                let text_for_process = text.clone();
+                // Dictionary corrections run before AI enhancement so it sees the fixed terms
+                let text_for_process =
+                    crate::post_processing::apply_post_processing(&app_for_task, &text);
                 let model_for_process = selected_model_name_for_task.clone();
+                let mut history_metadata = serde_json::Map::new();
+                if let Some(peak_mb) = peak_memory_mb {
+                    history_metadata.insert("peak_memory_mb".to_string(), peak_mb.into());
+                }
+                if let Some(quantization) = &whisper_quantization {
+                    history_metadata
+                        .insert("quantization".to_string(), quantization.clone().into());
+                }
+                if let Some(secs) = audio_duration_secs {
+                    history_metadata.insert("audio_seconds".to_string(), secs.into());
+                }
+                // Engines that don't report a language fall back to the configured one,
+                // unless that is "auto" and says nothing about what was spoken
+                let history_language = detected_language.or_else(|| {
+                    (!config.language.is_empty() && config.language != "auto")
+                        .then(|| config.language.clone())
+                });
+                if let Some(language) = history_language {
+                    history_metadata.insert("language".to_string(), language.into());
+                }
+                let confidence =
+                    crate::whisper::confidence::overall_confidence(&transcription_segments);
+                if let Some(confidence) = confidence {
+                    history_metadata.insert("confidence".to_string(), confidence.into());
+                    if let Some(conditions) = audio_conditions {
+                        crate::utils::model_suggestions::record_result(
+                            &app_for_task,
+                            conditions,
+                            &selected_model_name_for_task,
+                            confidence,
+                        );
+                    }
+                    if crate::whisper::confidence::is_low_confidence(
+                        Some(confidence),
+                        config.min_confidence,
+                    ) {
+                        log::info!(
+                            "Transcription confidence {:.2} is below the {:.2} threshold",
+                            confidence,
+                            config.min_confidence
+                        );
+                        history_metadata.insert("low_confidence".to_string(), true.into());
+                    }
+                }
+                if !transcription_segments.is_empty() {
+                    history_metadata.insert(
+                        "segments".to_string(),
+                        serde_json::json!(transcription_segments),
+                    );
+                }
+                if let Some(file_name) = kept_recording
+                    .as_ref()
+                    .and_then(|path| path.file_name())
+                    .map(|name| name.to_string_lossy().to_string())
+                {
+                    history_metadata.insert("recording_file".to_string(), file_name.into());
+                }
+                let recording_app = app_for_task
+                    .state::<AppState>()
+                    .recording_app
+                    .lock()
+                    .ok()
+                    .and_then(|mut recording_app| recording_app.take());
+                history_metadata.insert(
+                    "app".to_string(),
+                    match recording_app {
+                        Some(active_app) => serde_json::json!(active_app),
+                        None => serde_json::json!({ "name": UNKNOWN_APP, "id": null }),
+                    },
+                );
                 let ai_enabled_for_task = ai_enabled; // Capture from cached config
+                let incognito_for_task = config.incognito_mode;
+                let overlay_timeout_for_task = config.overlay_timeout_ms;
 
                 tokio::spawn(async move {
                     // 1. Process the transcription and enhancement
@@ -1898,12 +2616,32 @@ pub async fn stop_recording(
                             text_for_process.clone()
                         }
                     };
+                    let final_text = crate::post_processing::apply_output_formatting(
+                        &app_for_process,
+                        &final_text,
+                    );
 
                     // 2. Hide pill window first, then insert text with reduced delay
                     let app_state = app_for_process.state::<AppState>();
 
-                    // Hide pill window first (only if show_pill_indicator is false)
-                    if should_hide_pill(&app_for_process).await {
+                    // Cancelled while enhancing: drop the result before it is pasted or saved
+                    if app_state.is_cancellation_requested() {
+                        log::info!("Transcription cancelled before output, discarding result");
+                        if let Some(recording) = &kept_recording {
+                            crate::utils::recordings::remove_recording(recording);
+                        }
+                        if should_hide_pill(&app_for_process).await {
+                            let _ =
+                                crate::commands::window::hide_pill_widget(app_for_process.clone())
+                                    .await;
+                        }
+                        update_recording_state(&app_for_process, RecordingState::Idle, None);
+                        return;
+                    }
+
+                    // Hide pill window first (only if show_pill_indicator is false),
+                    // unless it should linger after completion
+                    if overlay_timeout_for_task == 0 && should_hide_pill(&app_for_process).await {
                         if let Some(window_manager) = app_state.get_window_manager() {
                             if let Err(e) = window_manager.hide_pill_window().await {
                                 log::error!("Failed to hide pill window: {}", e);
@@ -1916,15 +2654,36 @@ pub async fn stop_recording(
                     // Reduced delay to ensure UI is stable (was 100ms, now 50ms)
                     tokio::time::sleep(std::time::Duration::from_millis(50)).await;
 
-                    // Now handle text insertion with stable UI
-                    match crate::commands::text::insert_text(
-                        app_for_process.clone(),
-                        final_text.clone(),
-                    )
-                    .await
-                    {
-                        Ok(_) => log::debug!("Text inserted at cursor successfully"),
-                        Err(e) => {
+                    // Now deliver the text to each configured output (paste, file, webhook...)
+                    let sinks = if no_speech_detected {
+                        pill_toast(
+                            &app_for_process,
+                            "No speech detected - nothing pasted",
+                            1500,
+                        );
+                        Vec::new()
+                    } else {
+                        crate::output::load_output_sinks(&app_for_process)
+                            .into_iter()
+                            // Incognito text is pasted but never written to files or sent out
+                            .filter(|sink| {
+                                !incognito_for_task
+                                    || *sink == crate::output::OutputSink::ActiveAppPaste
+                            })
+                            .collect()
+                    };
+                    let sink_results =
+                        crate::output::run_output_sinks(&app_for_process, &sinks, &final_text)
+                            .await;
+
+                    let paste_error = sinks
+                        .iter()
+                        .zip(&sink_results)
+                        .find(|(sink, _)| **sink == crate::output::OutputSink::ActiveAppPaste)
+                        .and_then(|(_, result)| result.error.clone());
+                    match paste_error {
+                        None => log::debug!("Transcription delivered to {} output(s)", sinks.len()),
+                        Some(e) => {
                             log::error!("Failed to insert text: {}", e);
 
                             // Check if it's an accessibility permission issue
@@ -1945,31 +2704,130 @@ pub async fn stop_recording(
                             }
                         }
                     }
+                    if sink_results.iter().any(|r| !r.success) {
+                        let _ = emit_to_window(
+                            &app_for_process,
+                            "main",
+                            "output-sinks-failed",
+                            &sink_results,
+                        );
+                    }
+
+                    if !no_speech_detected {
+                        // Optional automation hook; runs in the background and never blocks pasting
+                        if !incognito_for_task {
+                            let posted: Vec<String> = sinks
+                                .iter()
+                                .filter_map(|sink| match sink {
+                                    crate::output::OutputSink::WebhookPost { url } => {
+                                        Some(url.clone())
+                                    }
+                                    _ => None,
+                                })
+                                .collect();
+                            crate::output::webhook::spawn_transcription_webhook(
+                                &app_for_process,
+                                crate::output::webhook::WebhookPayload {
+                                    text: final_text.clone(),
+                                    timestamp: chrono::Utc::now().to_rfc3339(),
+                                    duration: audio_duration_secs,
+                                    model: model_for_process.clone(),
+                                },
+                                &posted,
+                            );
+
+                            // Let the user know when dictating into a background
+                            // window. Skipped in incognito, since the OS keeps
+                            // notification text around.
+                            crate::utils::notifications::notify_transcription_complete(
+                                &app_for_process,
+                                &final_text,
+                            );
+                        }
+                        crate::audio::sound_cues::play_cue(
+                            &app_for_process,
+                            crate::audio::sound_cues::SoundCue::TranscriptionComplete,
+                        );
+                    }
 
                     // 5. Save transcription to history (async, non-blocking)
-                    let app_for_history = app_for_process.clone();
-                    let history_text = final_text.clone();
-                    let history_model = model_for_process.clone();
-                    tokio::spawn(async move {
-                        match save_transcription(
-                            app_for_history.clone(),
-                            history_text,
-                            history_model,
-                        )
-                        .await
-                        {
-                            Ok(_) => {
-                                // Emit history-updated event to refresh UI
-                                let _ =
-                                    emit_to_window(&app_for_history, "main", "history-updated", ());
-                                log::debug!("Transcription saved to history successfully");
-                            }
-                            Err(e) => log::error!("Failed to save transcription to history: {}", e),
+                    if incognito_for_task {
+                        log::info!("Incognito mode: transcription not saved to history");
+                    } else if no_speech_detected {
+                        // Likely hallucinated from silence; keep nothing
+                        log::info!("No speech detected: transcription not saved to history");
+                        if let Some(recording) = &kept_recording {
+                            crate::utils::recordings::remove_recording(recording);
                         }
-                    });
+                    } else {
+                        let app_for_history = app_for_process.clone();
+                        let history_text = final_text.clone();
+                        let history_model = model_for_process.clone();
+                        tokio::spawn(async move {
+                            match save_transcription_with_metadata(
+                                app_for_history.clone(),
+                                history_text,
+                                history_model,
+                                history_metadata,
+                            )
+                            .await
+                            {
+                                Ok(saved_id) => {
+                                    // Emit history-updated event to refresh UI
+                                    let _ = emit_to_window(
+                                        &app_for_history,
+                                        "main",
+                                        "history-updated",
+                                        (),
+                                    );
+                                    log::debug!("Transcription saved to history successfully");
+                                    // The recording is finalized only once its entry exists
+                                    if let Some(recording) = &kept_recording {
+                                        let finalized = saved_id.map(|id| {
+                                            crate::utils::recordings::finalize_recording(
+                                                recording, &id,
+                                            )
+                                        });
+                                        match finalized {
+                                            Some(Ok(())) => {}
+                                            Some(Err(e)) => log::warn!("{}", e),
+                                            // Duplicate entry was skipped; nothing links to it
+                                            None => crate::utils::recordings::remove_recording(
+                                                recording,
+                                            ),
+                                        }
+                                    }
+                                }
+                                Err(e) => {
+                                    log::error!("Failed to save transcription to history: {}", e);
+                                    if let Some(recording) = &kept_recording {
+                                        crate::utils::recordings::remove_recording(recording);
+                                    }
+                                }
+                            }
+                        });
+                    }
 
                     // 6. Transition to idle state
                     update_recording_state(&app_for_process, RecordingState::Idle, None);
+
+                    // Auto-hide a lingering overlay, unless a new recording took it over
+                    if overlay_timeout_for_task > 0 {
+                        tokio::time::sleep(std::time::Duration::from_millis(
+                            overlay_timeout_for_task as u64,
+                        ))
+                        .await;
+                        if crate::get_recording_state(&app_for_process) == RecordingState::Idle
+                            && should_hide_pill(&app_for_process).await
+                        {
+                            if let Err(e) =
+                                crate::commands::window::hide_pill_widget(app_for_process.clone())
+                                    .await
+                            {
+                                log::error!("Failed to hide pill window: {}", e);
+                            }
+                        }
+                    }
                 });
             }
             Err(e) => {
@@ -1990,7 +2848,9 @@ pub async fn stop_recording(
                     log::info!("Recording was too short: {}", e);
 
                     // Clean up the audio file
-                    if let Err(cleanup_err) = std::fs::remove_file(&audio_path_clone) {
+                    if let Err(cleanup_err) =
+                        crate::utils::secure_delete::remove_file(&audio_path_clone)
+                    {
                         log::warn!("Failed to remove short audio file: {}", cleanup_err);
                     }
 
@@ -2113,17 +2973,22 @@ pub async fn get_current_audio_device(app: AppHandle) -> Result<String, String>
 #[tauri::command]
 pub async fn cleanup_old_transcriptions(app: AppHandle, days: Option<u32>) -> Result<(), String> {
     if let Some(days) = days {
-        let store = app.store("transcriptions").map_err(|e| e.to_string())?;
+        let store =
+            crate::utils::data_dir::transcriptions_store(&app).map_err(|e| e.to_string())?;
 
         let cutoff_date = chrono::Utc::now() - chrono::Duration::days(days as i64);
 
-        // Get all keys
-        let keys: Vec<String> = store.keys().into_iter().map(|k| k.to_string()).collect();
+        let recordings_dir = crate::utils::data_dir::data_dir(&app)
+            .ok()
+            .map(|dir| dir.join("recordings"));
 
-        // Remove old entries
-        for key in keys {
+        // Remove old entries, and the recordings kept for them
+        for (key, entry) in store.entries() {
             if let Ok(date) = chrono::DateTime::parse_from_rfc3339(&key) {
                 if date < cutoff_date {
+                    if let Some(dir) = &recordings_dir {
+                        crate::utils::recordings::remove_entry_recording(dir, &entry);
+                    }
                     store.delete(&key);
                 }
             }
@@ -2137,8 +3002,21 @@ pub async fn cleanup_old_transcriptions(app: AppHandle, days: Option<u32>) -> Re
 
 #[tauri::command]
 pub async fn save_transcription(app: AppHandle, text: String, model: String) -> Result<(), String> {
-    // De-dup guard: skip saving if the most recent entry matches the same text & model within a short window
-    if let Ok(store) = app.store("transcriptions") {
+    save_transcription_with_metadata(app, text, model, serde_json::Map::new())
+        .await
+        .map(|_| ())
+}
+
+/// Save a transcription along with extra per-recording details (e.g. peak memory).
+/// Returns the new entry's id, or `None` when it was skipped as a duplicate.
+pub async fn save_transcription_with_metadata(
+    app: AppHandle,
+    text: String,
+    model: String,
+    metadata: serde_json::Map<String, serde_json::Value>,
+) -> Result<Option<String>, String> {
+    // De-dup guard: skip saving if the most recent entry matches the same text & model within a short window
+    if let Ok(store) = crate::utils::data_dir::transcriptions_store(&app) {
         // Find most recent entry
         let mut latest: Option<(String, serde_json::Value)> = None;
         for key in store.keys() {
@@ -2177,22 +3055,24 @@ pub async fn save_transcription(app: AppHandle, text: String, model: String) ->
                 .unwrap_or(false);
             if same_text && same_model && within_window {
                 log::info!("Skipping duplicate transcription save (same text/model within 2s)");
-                return Ok(());
+                return Ok(None);
             }
         }
     }
 
     // Save transcription to store with current timestamp
-    let store = app
-        .store("transcriptions")
+    let store = crate::utils::data_dir::transcriptions_store(&app)
         .map_err(|e| format!("Failed to get transcriptions store: {}", e))?;
 
     let timestamp = chrono::Utc::now().to_rfc3339();
-    let transcription_data = serde_json::json!({
+    let mut transcription_data = serde_json::json!({
         "text": text.clone(),
         "model": model,
         "timestamp": timestamp.clone()
     });
+    if let Some(entry) = transcription_data.as_object_mut() {
+        entry.extend(metadata);
+    }
 
     store.set(&timestamp, transcription_data.clone());
 
@@ -2212,7 +3092,7 @@ pub async fn save_transcription(app: AppHandle, text: String, model: String) ->
     }
 
     log::info!("Saved transcription with {} characters", text.len());
-    Ok(())
+    Ok(Some(timestamp))
 }
 
 #[tauri::command]
@@ -2220,7 +3100,7 @@ pub async fn get_transcription_history(
     app: AppHandle,
     limit: Option<usize>,
 ) -> Result<Vec<serde_json::Value>, String> {
-    let store = app.store("transcriptions").map_err(|e| e.to_string())?;
+    let store = crate::utils::data_dir::transcriptions_store(&app).map_err(|e| e.to_string())?;
 
     let mut entries: Vec<(String, serde_json::Value)> = Vec::new();
 
@@ -2238,8 +3118,212 @@ pub async fn get_transcription_history(
     let limit = limit.unwrap_or(50);
     entries.truncate(limit);
 
-    // Return just the values
-    Ok(entries.into_iter().map(|(_, v)| v).collect())
+    // Return just the values, defaulting missing titles so older entries need no migration
+    Ok(entries
+        .into_iter()
+        .map(|(_, mut v)| {
+            if v.is_object() && v.get("title").is_none() {
+                v["title"] = serde_json::Value::Null;
+            }
+            v
+        })
+        .collect())
+}
+
+/// App name saved when the frontmost app couldn't be detected
+pub const UNKNOWN_APP: &str = "unknown";
+
+/// Whether a history entry was dictated into `app_name`, matched
+/// case-insensitively against the app's name or bundle id
+pub fn entry_matches_app(entry: &serde_json::Value, app_name: &str) -> bool {
+    let app = &entry["app"];
+    [&app["name"], &app["id"]]
+        .iter()
+        .filter_map(|v| v.as_str())
+        .any(|v| v.eq_ignore_ascii_case(app_name))
+}
+
+/// History entries dictated into `app_name` (name or bundle id), newest first.
+/// Entries saved before app capture match "unknown".
+#[tauri::command]
+pub async fn list_transcriptions_by_app(
+    app: AppHandle,
+    app_name: String,
+    limit: Option<usize>,
+) -> Result<Vec<serde_json::Value>, String> {
+    let store = crate::utils::data_dir::transcriptions_store(&app).map_err(|e| e.to_string())?;
+
+    let mut entries: Vec<(String, serde_json::Value)> = store
+        .keys()
+        .into_iter()
+        .filter_map(|key| store.get(&key).map(|value| (key.to_string(), value)))
+        .filter(|(_, value)| {
+            if value.get("app").is_none() {
+                app_name.eq_ignore_ascii_case(UNKNOWN_APP)
+            } else {
+                entry_matches_app(value, &app_name)
+            }
+        })
+        .collect();
+    entries.sort_by(|a, b| b.0.cmp(&a.0));
+    entries.truncate(limit.unwrap_or(50));
+    Ok(entries.into_iter().map(|(_, value)| value).collect())
+}
+
+/// Most history entries `preview_filters_on_history` will sample
+const MAX_FILTER_PREVIEW_SAMPLE: usize = 500;
+
+/// Show what a candidate post-processing pipeline would do to the most recent
+/// `sample_size` transcriptions. Nothing is modified.
+#[tauri::command]
+pub async fn preview_filters_on_history(
+    app: AppHandle,
+    filters: Vec<crate::post_processing::filters::TextFilter>,
+    sample_size: usize,
+) -> Result<Vec<crate::post_processing::filters::FilterPreview>, String> {
+    use crate::post_processing::filters::FilterPreview;
+
+    if sample_size == 0 || sample_size > MAX_FILTER_PREVIEW_SAMPLE {
+        return Err(format!(
+            "Sample size must be between 1 and {}",
+            MAX_FILTER_PREVIEW_SAMPLE
+        ));
+    }
+
+    let store = crate::utils::data_dir::transcriptions_store(&app).map_err(|e| e.to_string())?;
+    let mut keys = store.keys();
+    keys.sort_by(|a, b| b.cmp(a));
+
+    Ok(keys
+        .into_iter()
+        .filter_map(|key| {
+            let text = store.get(&key)?.get("text")?.as_str()?.to_string();
+            Some((key, text))
+        })
+        .take(sample_size)
+        .map(|(key, text)| FilterPreview::new(key, text, &filters))
+        .collect())
+}
+
+/// Progress of a chunked file transcription, sent per finished chunk
+#[derive(Clone, serde::Serialize)]
+pub struct ChunkProgress {
+    pub chunk: usize,
+    pub total: usize,
+    /// Stitched text of the chunks done so far
+    pub text: String,
+}
+
+/// File transcriptions run outside the recording state machine; this lets
+/// `cancel_transcription` stop one between (and within) chunks. Managed as
+/// app state.
+#[derive(Default)]
+pub struct FileTranscriptionState {
+    active: AtomicBool,
+    cancel: AtomicBool,
+    /// Held for the whole of a file transcription (uploads, dropped files,
+    /// recovered recordings), so runs don't clear each other's flags
+    pub(crate) lock: AsyncMutex<()>,
+}
+
+impl FileTranscriptionState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn begin(&self) {
+        self.cancel.store(false, AtomicOrdering::SeqCst);
+        self.active.store(true, AtomicOrdering::SeqCst);
+    }
+
+    fn end(&self) {
+        self.active.store(false, AtomicOrdering::SeqCst);
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancel.load(AtomicOrdering::SeqCst)
+    }
+
+    /// Ask the running file transcription to stop. Returns false if none is running.
+    fn request_cancel(&self) -> bool {
+        if !self.active.load(AtomicOrdering::SeqCst) {
+            return false;
+        }
+        self.cancel.store(true, AtomicOrdering::SeqCst);
+        true
+    }
+}
+
+/// Transcribe a normalized WAV with whisper, splitting it into overlapping
+/// chunks when it's longer than the configured chunk length
+fn transcribe_file_in_chunks(
+    app: &AppHandle,
+    transcriber: &crate::whisper::transcriber::Transcriber,
+    audio_path: &Path,
+    language: &str,
+    translate: bool,
+    chunking: crate::audio::chunking::ChunkingConfig,
+) -> Result<String, String> {
+    use crate::audio::chunking::{extract_chunk_wav, plan_chunks, stitch_transcripts};
+
+    let file_state = app.state::<FileTranscriptionState>();
+    let should_cancel = || file_state.is_cancelled();
+    let (total_samples, sample_rate) = {
+        let reader = hound::WavReader::open(audio_path)
+            .map_err(|e| format!("Failed to open audio for transcription: {}", e))?;
+        (reader.duration() as usize, reader.spec().sample_rate)
+    };
+    if !chunking.applies_to(total_samples, sample_rate) {
+        return transcriber.transcribe_with_cancellation(
+            audio_path,
+            Some(language),
+            translate,
+            should_cancel,
+        );
+    }
+
+    let chunks = plan_chunks(
+        total_samples,
+        sample_rate,
+        chunking.chunk_seconds,
+        chunking.overlap_seconds,
+    );
+    log::info!(
+        "[UPLOAD] Transcribing {:.0}s of audio in {} chunks",
+        total_samples as f64 / sample_rate as f64,
+        chunks.len()
+    );
+
+    let mut text = String::new();
+    for (index, range) in chunks.iter().enumerate() {
+        if should_cancel() {
+            return Err("Transcription cancelled".to_string());
+        }
+
+        // Only the current chunk is ever held in memory
+        let chunk_path = audio_path.with_extension(format!("chunk{}.wav", index));
+        let chunk_text = extract_chunk_wav(audio_path, range.clone(), &chunk_path).and_then(|_| {
+            transcriber.transcribe_with_cancellation(
+                &chunk_path,
+                Some(language),
+                translate,
+                should_cancel,
+            )
+        });
+        let _ = crate::utils::secure_delete::remove_file(&chunk_path);
+        text = stitch_transcripts(&text, &chunk_text?);
+
+        let _ = emit_to_all(
+            app,
+            "transcription-chunk-progress",
+            ChunkProgress {
+                chunk: index + 1,
+                total: chunks.len(),
+                text: text.clone(),
+            },
+        );
+    }
+    Ok(text)
 }
 
 #[tauri::command]
@@ -2248,6 +3332,18 @@ pub async fn transcribe_audio_file(
     file_path: String,
     model_name: String,
     model_engine: Option<String>,
+) -> Result<String, String> {
+    let file_state = app.state::<FileTranscriptionState>();
+    let _guard = file_state.lock.lock().await;
+    transcribe_audio_file_locked(app.clone(), file_path, model_name, model_engine).await
+}
+
+/// `transcribe_audio_file` for callers already holding `FileTranscriptionState::lock`
+pub(crate) async fn transcribe_audio_file_locked(
+    app: AppHandle,
+    file_path: String,
+    model_name: String,
+    model_engine: Option<String>,
 ) -> Result<String, String> {
     log::info!(
         "[UPLOAD] transcribe_audio_file START | file_path={:?}, model_name={}, engine_hint={:?}",
@@ -2267,11 +3363,7 @@ pub async fn transcribe_audio_file(
     }
 
     // Convert to WAV if needed
-    let recordings_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| e.to_string())?
-        .join("recordings");
+    let recordings_dir = crate::utils::data_dir::data_dir(&app)?.join("recordings");
 
     std::fs::create_dir_all(&recordings_dir)
         .map_err(|e| format!("Failed to create recordings directory: {}", e))?;
@@ -2304,6 +3396,19 @@ pub async fn transcribe_audio_file(
         .and_then(|v| v.as_bool())
         .unwrap_or(false);
 
+    let chunking = crate::audio::chunking::ChunkingConfig {
+        chunk_seconds: store
+            .get("chunk_seconds")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as u32)
+            .unwrap_or(crate::audio::chunking::DEFAULT_CHUNK_SECONDS),
+        overlap_seconds: store
+            .get("overlap_seconds")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as u32)
+            .unwrap_or(crate::audio::chunking::DEFAULT_OVERLAP_SECONDS),
+    };
+
     log::info!(
         "[LANGUAGE] transcribe_audio_file using language: {}, translate: {}",
         language,
@@ -2330,12 +3435,36 @@ pub async fn transcribe_audio_file(
                 cache.get_or_create(&model_path)?
             };
 
-            let result = transcriber.transcribe_with_translation(
+            let audio_secs = crate::utils::telemetry::wav_duration_secs(&normalized_path);
+            let started = Instant::now();
+            let file_state = app.state::<FileTranscriptionState>();
+            file_state.begin();
+            let result = transcribe_file_in_chunks(
+                &app,
+                &transcriber,
                 &normalized_path,
-                Some(&language),
+                &language,
                 translate_to_english,
-            )?;
-            let _ = std::fs::remove_file(&normalized_path);
+                chunking,
+            );
+            file_state.end();
+            let result = match result {
+                Ok(text) => text,
+                Err(e) => {
+                    let _ = crate::utils::secure_delete::remove_file(&normalized_path);
+                    return Err(e);
+                }
+            };
+            if let Some(audio_secs) = audio_secs {
+                crate::utils::transcription_eta::record_transcription_time(
+                    &app,
+                    "whisper",
+                    &model_name,
+                    audio_secs,
+                    started.elapsed(),
+                );
+            }
+            let _ = crate::utils::secure_delete::remove_file(&normalized_path);
             result
         }
         ActiveEngineSelection::Parakeet { model_name } => {
@@ -2357,6 +3486,8 @@ pub async fn transcribe_audio_file(
                 .await
                 .map_err(|e| format!("Failed to load Parakeet model: {}", e))?;
 
+            let audio_secs = crate::utils::telemetry::wav_duration_secs(&normalized_path);
+            let started = Instant::now();
             match parakeet_manager
                 .transcribe(
                     &app,
@@ -2368,7 +3499,16 @@ pub async fn transcribe_audio_file(
                 .await
             {
                 Ok(ParakeetResponse::Transcription { text, .. }) => {
-                    let _ = std::fs::remove_file(&normalized_path);
+                    if let Some(audio_secs) = audio_secs {
+                        crate::utils::transcription_eta::record_transcription_time(
+                            &app,
+                            "parakeet",
+                            &model_name,
+                            audio_secs,
+                            started.elapsed(),
+                        );
+                    }
+                    let _ = crate::utils::secure_delete::remove_file(&normalized_path);
                     text
                 }
                 Ok(other) => {
@@ -2391,6 +3531,379 @@ pub async fn transcribe_audio_file(
     Ok(text)
 }
 
+/// Progress of `retranscribe_low_confidence`, sent after each entry
+#[derive(Clone, serde::Serialize)]
+pub struct RetranscribeProgress {
+    pub done: u32,
+    pub total: u32,
+    /// Entries whose text was replaced so far
+    pub updated: u32,
+    /// History key of the entry just processed
+    pub timestamp: Option<String>,
+    pub finished: bool,
+    pub cancelled: bool,
+}
+
+/// Re-transcribe one kept recording with `transcriber` and store the result
+/// in its history entry, keeping the first original text in `original_text`.
+/// The entry's own language is used when it has one, and the text goes
+/// through the same post-processing as a fresh dictation.
+async fn retranscribe_entry(
+    app: &AppHandle,
+    transcriber: std::sync::Arc<crate::whisper::transcriber::Transcriber>,
+    key: &str,
+    recording: &Path,
+    model_name: &str,
+    config: &RecordingConfig,
+) -> Result<bool, String> {
+    let normalized = std::env::temp_dir().join(format!(
+        "voicetypr-retranscribe-{}.wav",
+        chrono::Utc::now().timestamp_millis()
+    ));
+    crate::ffmpeg::normalize_streaming(app, recording, &normalized)
+        .await
+        .map_err(|e| format!("Audio normalization (ffmpeg) failed: {}", e))?;
+
+    let store = crate::utils::data_dir::transcriptions_store(app).map_err(|e| e.to_string())?;
+    let language = store
+        .get(key)
+        .and_then(|entry| {
+            entry
+                .get("language")
+                .and_then(|v| v.as_str().map(|s| s.to_string()))
+        })
+        .filter(|lang| !lang.is_empty())
+        .unwrap_or_else(|| config.language.clone());
+    let language = validate_language(Some(&language)).to_string();
+
+    let path = normalized.clone();
+    let translate = config.translate_to_english;
+    let result = tauri::async_runtime::spawn_blocking(move || {
+        transcriber.transcribe_segments(&path, Some(&language), translate, false, || {
+            RETRANSCRIBE_CANCEL.load(AtomicOrdering::SeqCst)
+        })
+    })
+    .await
+    .map_err(|e| format!("Transcription task failed: {}", e));
+    let _ = crate::utils::secure_delete::remove_file(&normalized);
+    let transcription = result??;
+
+    let raw = transcription.text.trim();
+    if raw.is_empty() {
+        return Ok(false);
+    }
+    let text = crate::post_processing::apply_post_processing(app, raw);
+    let text = crate::post_processing::apply_output_formatting(app, &text);
+    // The entry may have been deleted while this one was transcribing
+    let Some(mut entry) = store.get(key) else {
+        return Ok(false);
+    };
+    let Some(fields) = entry.as_object_mut() else {
+        return Ok(false);
+    };
+    if !fields.contains_key("original_text") {
+        let original = fields.get("text").cloned().unwrap_or_default();
+        fields.insert("original_text".to_string(), original);
+    }
+    fields.insert("text".to_string(), text.into());
+    fields.insert("model".to_string(), model_name.into());
+    if let Some(language) = &transcription.language {
+        fields.insert("language".to_string(), language.clone().into());
+    }
+    fields.insert(
+        "segments".to_string(),
+        serde_json::json!(transcription.segments),
+    );
+    fields.remove("low_confidence");
+    match transcription.confidence() {
+        Some(confidence) => {
+            fields.insert("confidence".to_string(), confidence.into());
+            if crate::whisper::confidence::is_low_confidence(
+                Some(confidence),
+                config.min_confidence,
+            ) {
+                fields.insert("low_confidence".to_string(), true.into());
+            }
+        }
+        None => {
+            fields.remove("confidence");
+        }
+    }
+    fields.insert(
+        "retranscribed_at".to_string(),
+        chrono::Utc::now().to_rfc3339().into(),
+    );
+    store.set(key, entry);
+    store
+        .save()
+        .map_err(|e| format!("Failed to save transcription: {}", e))?;
+    Ok(true)
+}
+
+/// Re-transcribe, in the background, every history entry scored below
+/// `threshold` whose recording was kept, using the Whisper model
+/// `model_name`. Returns how many entries were queued; progress is sent as
+/// `retranscribe-progress` and the job stops early on
+/// `cancel_retranscription`.
+#[tauri::command]
+pub async fn retranscribe_low_confidence(
+    app: AppHandle,
+    model_name: String,
+    threshold: f32,
+) -> Result<u32, String> {
+    if !(0.0..=1.0).contains(&threshold) {
+        return Err(format!(
+            "Threshold must be between 0 and 1, got {}",
+            threshold
+        ));
+    }
+    let ActiveEngineSelection::Whisper { model_path, .. } =
+        resolve_engine_for_model(&app, &model_name, Some("whisper")).await?
+    else {
+        return Err("Re-transcription needs a Whisper model".to_string());
+    };
+    let config = RecordingConfig::load_from_store(&app).await?;
+
+    let store = crate::utils::data_dir::transcriptions_store(&app).map_err(|e| e.to_string())?;
+    let recordings_dir = crate::utils::data_dir::data_dir(&app)?.join("recordings");
+    let queued = crate::utils::recordings::low_confidence_recordings(
+        &recordings_dir,
+        store.entries(),
+        threshold,
+    );
+    let total = queued.len() as u32;
+    if total == 0 {
+        return Ok(0);
+    }
+
+    if RETRANSCRIBE_ACTIVE.swap(true, AtomicOrdering::SeqCst) {
+        return Err("Re-transcription is already running".to_string());
+    }
+    RETRANSCRIBE_CANCEL.store(false, AtomicOrdering::SeqCst);
+
+    let transcriber = {
+        let cache_state = app.state::<AsyncMutex<TranscriberCache>>();
+        let mut cache = cache_state.lock().await;
+        match cache.get_or_create(&model_path) {
+            Ok(transcriber) => transcriber,
+            Err(e) => {
+                RETRANSCRIBE_ACTIVE.store(false, AtomicOrdering::SeqCst);
+                return Err(e);
+            }
+        }
+    };
+
+    log::info!(
+        "Re-transcribing {} low-confidence entries with {}",
+        total,
+        model_name
+    );
+    tauri::async_runtime::spawn(async move {
+        let mut progress = RetranscribeProgress {
+            done: 0,
+            total,
+            updated: 0,
+            timestamp: None,
+            finished: false,
+            cancelled: false,
+        };
+        for (key, recording) in queued {
+            if RETRANSCRIBE_CANCEL.load(AtomicOrdering::SeqCst) {
+                progress.cancelled = true;
+                break;
+            }
+            match retranscribe_entry(
+                &app,
+                transcriber.clone(),
+                &key,
+                &recording,
+                &model_name,
+                &config,
+            )
+            .await
+            {
+                Ok(true) => progress.updated += 1,
+                Ok(false) => {}
+                Err(e) => log::warn!("Failed to re-transcribe {}: {}", key, e),
+            }
+            progress.done += 1;
+            progress.timestamp = Some(key);
+            let _ = emit_to_all(&app, "retranscribe-progress", progress.clone());
+        }
+
+        progress.cancelled |= RETRANSCRIBE_CANCEL.load(AtomicOrdering::SeqCst);
+        progress.finished = true;
+        progress.timestamp = None;
+        RETRANSCRIBE_ACTIVE.store(false, AtomicOrdering::SeqCst);
+        log::info!(
+            "Re-transcription {}: {}/{} entries updated",
+            if progress.cancelled {
+                "cancelled"
+            } else {
+                "finished"
+            },
+            progress.updated,
+            progress.total
+        );
+        let _ = emit_to_all(&app, "retranscribe-progress", progress);
+        let _ = crate::emit_to_window(&app, "main", "history-updated", ());
+    });
+
+    Ok(total)
+}
+
+/// Stop a running `retranscribe_low_confidence` job after the current entry
+#[tauri::command]
+pub async fn cancel_retranscription() -> Result<(), String> {
+    if !RETRANSCRIBE_ACTIVE.load(AtomicOrdering::SeqCst) {
+        return Err("No re-transcription in progress".to_string());
+    }
+    RETRANSCRIBE_CANCEL.store(true, AtomicOrdering::SeqCst);
+    log::info!("Re-transcription cancellation requested");
+    Ok(())
+}
+
+/// Most parameter sets `compare_params` runs in one call
+const MAX_COMPARE_PARAM_SETS: usize = 8;
+
+/// Output of one parameter set in `compare_params`
+#[derive(Clone, serde::Serialize)]
+pub struct ParamResult {
+    /// The parameters as applied, after clamping
+    pub params: crate::whisper::params::WhisperParams,
+    pub text: String,
+    pub confidence: Option<f32>,
+    pub elapsed_ms: u64,
+    pub error: Option<String>,
+}
+
+/// Transcribe the recording kept for history entry `audio_id` once per
+/// parameter set with the current Whisper model, so the outputs and timings
+/// can be compared. History is left unchanged.
+#[tauri::command]
+pub async fn compare_params(
+    app: AppHandle,
+    audio_id: String,
+    param_sets: Vec<crate::whisper::params::WhisperParams>,
+) -> Result<Vec<ParamResult>, String> {
+    if param_sets.is_empty() || param_sets.len() > MAX_COMPARE_PARAM_SETS {
+        return Err(format!(
+            "Provide between 1 and {} parameter sets",
+            MAX_COMPARE_PARAM_SETS
+        ));
+    }
+    let recording_state = crate::get_recording_state(&app);
+    if recording_state != RecordingState::Idle && recording_state != RecordingState::Error {
+        return Err("Cannot compare parameters while recording or transcribing".to_string());
+    }
+
+    let recording = crate::commands::utils::kept_recording_path(&app, &audio_id)?;
+    let config = RecordingConfig::load_from_store(&app).await?;
+    let ActiveEngineSelection::Whisper { model_path, .. } =
+        resolve_engine_for_model(&app, &config.current_model, Some("whisper")).await?
+    else {
+        return Err("Comparing parameters needs a Whisper model".to_string());
+    };
+    let transcriber = {
+        let cache_state = app.state::<AsyncMutex<TranscriberCache>>();
+        let mut cache = cache_state.lock().await;
+        cache.get_or_create(&model_path)?
+    };
+
+    let normalized = std::env::temp_dir().join(format!(
+        "voicetypr-compare-{}.wav",
+        chrono::Utc::now().timestamp_millis()
+    ));
+    crate::ffmpeg::normalize_streaming(&app, &recording, &normalized)
+        .await
+        .map_err(|e| format!("Audio normalization (ffmpeg) failed: {}", e))?;
+
+    log::info!(
+        "Comparing {} parameter sets on {:?} with {}",
+        param_sets.len(),
+        recording,
+        config.current_model
+    );
+    let mut results = Vec::with_capacity(param_sets.len());
+    for params in param_sets {
+        let params = params.validated();
+        let transcriber = transcriber.clone();
+        let path = normalized.clone();
+        let language = config.language.clone();
+        let translate = config.translate_to_english;
+        let started = Instant::now();
+        let outcome = tauri::async_runtime::spawn_blocking(move || {
+            transcriber.transcribe_segments_with_params(
+                &path,
+                Some(&language),
+                translate,
+                false,
+                params,
+                || false,
+            )
+        })
+        .await
+        .map_err(|e| format!("Transcription task failed: {}", e))
+        .and_then(|result| result);
+        let elapsed_ms = started.elapsed().as_millis() as u64;
+
+        results.push(match outcome {
+            Ok(transcription) => ParamResult {
+                params,
+                text: transcription.text.trim().to_string(),
+                confidence: transcription.confidence(),
+                elapsed_ms,
+                error: None,
+            },
+            Err(e) => ParamResult {
+                params,
+                text: String::new(),
+                confidence: None,
+                elapsed_ms,
+                error: Some(e),
+            },
+        });
+    }
+    let _ = crate::utils::secure_delete::remove_file(&normalized);
+    Ok(results)
+}
+
+/// Predicted wall-clock seconds to transcribe `audio_seconds` of audio with
+/// `model_name`, based on that model's calibrated realtime factor
+#[tauri::command]
+pub async fn estimate_transcription_time(
+    app: AppHandle,
+    audio_seconds: f64,
+    model_name: String,
+) -> Result<f64, String> {
+    if !audio_seconds.is_finite() || audio_seconds < 0.0 {
+        return Err(format!("Invalid audio duration: {}", audio_seconds));
+    }
+
+    let engine_selection = resolve_engine_for_model(&app, &model_name, None).await?;
+    let factor = crate::utils::transcription_eta::realtime_factor_for(
+        &app,
+        engine_selection.engine_name(),
+        &model_name,
+    );
+    Ok(crate::utils::transcription_eta::estimate_seconds(
+        audio_seconds,
+        factor,
+    ))
+}
+
+/// Duration in seconds of any audio/video file ffprobe can read
+#[tauri::command]
+pub async fn get_audio_file_duration(app: AppHandle, file_path: String) -> Result<f64, String> {
+    let probe = crate::ffmpeg::probe_json(&app, std::path::Path::new(&file_path)).await?;
+    probe
+        .get("format")
+        .and_then(|format| format.get("duration"))
+        .and_then(|duration| duration.as_str())
+        .and_then(|duration| duration.parse::<f64>().ok())
+        .ok_or_else(|| format!("Could not determine duration of {}", file_path))
+}
+
 #[tauri::command]
 pub async fn transcribe_audio(
     app: AppHandle,
@@ -2408,11 +3921,7 @@ pub async fn transcribe_audio(
     validate_recording_requirements(&app).await?;
 
     // Save audio data to app data directory
-    let recordings_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| e.to_string())?
-        .join("recordings");
+    let recordings_dir = crate::utils::data_dir::data_dir(&app)?.join("recordings");
 
     // Ensure directory exists
     std::fs::create_dir_all(&recordings_dir)
@@ -2490,7 +3999,7 @@ pub async fn transcribe_audio(
     };
 
     // Clean up
-    if let Err(e) = std::fs::remove_file(&temp_path) {
+    if let Err(e) = crate::utils::secure_delete::remove_file(&temp_path) {
         log::warn!("Failed to remove test audio file: {}", e);
     }
 
@@ -2513,7 +4022,9 @@ async fn soniox_transcribe_async(
         .await
         .map_err(|e| format!("Failed to read audio file: {}", e))?;
 
-    let client = reqwest::Client::new();
+    let client = crate::utils::proxy::client_builder()
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
     let base = "https://api.soniox.com/v1";
 
     // 1) Upload file -> file_id
@@ -2662,6 +4173,115 @@ async fn soniox_transcribe_async(
     Err("Soniox transcript format not recognized".to_string())
 }
 
+/// Pause the current recording without closing the input stream. Audio captured
+/// while paused is discarded, so the transcription skips the gap.
+#[tauri::command]
+pub async fn pause_recording(
+    app: AppHandle,
+    state: State<'_, RecorderState>,
+) -> Result<(), String> {
+    if crate::get_recording_state(&app) != RecordingState::Recording {
+        return Err("No recording in progress".to_string());
+    }
+
+    state
+        .inner()
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire recorder lock: {}", e))?
+        .pause()?;
+
+    log::info!("⏸️ Recording paused");
+    let _ = emit_to_all(&app, "recording-paused", ());
+    Ok(())
+}
+
+/// Save the input gain and apply it to the recorder right away, so moving the
+/// slider mid-recording is reflected in the mic-level meter
+#[tauri::command]
+pub async fn set_recording_gain(
+    app: AppHandle,
+    state: State<'_, RecorderState>,
+    gain_db: f32,
+) -> Result<f32, String> {
+    let gain_db = crate::audio::gain::clamp_gain_db(gain_db);
+    state
+        .inner()
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire recorder lock: {}", e))?
+        .set_gain_db(gain_db);
+
+    let store = app.store("settings").map_err(|e| e.to_string())?;
+    store.set("recording_gain_db", serde_json::json!(gain_db));
+    store
+        .save()
+        .map_err(|e| format!("Failed to save recording gain: {}", e))?;
+    Ok(gain_db)
+}
+
+#[tauri::command]
+pub async fn resume_recording(
+    app: AppHandle,
+    state: State<'_, RecorderState>,
+) -> Result<(), String> {
+    if crate::get_recording_state(&app) != RecordingState::Recording {
+        return Err("No recording in progress".to_string());
+    }
+
+    state
+        .inner()
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire recorder lock: {}", e))?
+        .resume()?;
+
+    log::info!("▶️ Recording resumed");
+    let _ = emit_to_all(&app, "recording-resumed", ());
+    Ok(())
+}
+
+/// Whisper model that has given the most confident results for audio like the
+/// latest recording (similar length and background noise), if one stands out.
+/// Only installed models are suggested.
+#[tauri::command]
+pub async fn suggest_model_for_current_conditions(
+    app: AppHandle,
+) -> Result<Option<String>, String> {
+    let installed = {
+        let whisper_state = app.state::<AsyncRwLock<WhisperManager>>();
+        let manager = whisper_state.read().await;
+        manager.get_downloaded_model_names()
+    };
+    Ok(crate::utils::model_suggestions::suggest_model(
+        &app, &installed,
+    ))
+}
+
+/// Abort the transcription in progress without saving it. Whisper stops at its
+/// next abort check; other engines finish their request and the result is discarded.
+#[tauri::command]
+pub async fn cancel_transcription(app: AppHandle) -> Result<(), String> {
+    let app_state = app.state::<AppState>();
+    if app.state::<FileTranscriptionState>().request_cancel() {
+        log::info!("File transcription cancellation requested");
+        let _ = emit_to_all(&app, "transcription-cancelled", ());
+        return Ok(());
+    }
+    if app_state.get_current_state() != RecordingState::Transcribing {
+        return Err("No transcription in progress".to_string());
+    }
+
+    // Cleared again when the next recording starts
+    app_state.request_cancellation();
+    app_state
+        .start_after_transcription
+        .store(false, AtomicOrdering::SeqCst);
+    log::info!("Transcription cancellation requested");
+    let _ = emit_to_all(&app, "transcription-cancelled", ());
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn cancel_recording(app: AppHandle) -> Result<(), String> {
     log::info!("=== CANCEL RECORDING CALLED ===");
@@ -2669,6 +4289,9 @@ pub async fn cancel_recording(app: AppHandle) -> Result<(), String> {
     // Request cancellation FIRST
     let app_state = app.state::<AppState>();
     app_state.request_cancellation();
+    app_state
+        .start_after_transcription
+        .store(false, AtomicOrdering::SeqCst);
     log::info!("Cancellation requested in app state");
 
     // Get current state
@@ -2705,12 +4328,13 @@ pub async fn cancel_recording(app: AppHandle) -> Result<(), String> {
                 .map_err(|e| format!("Failed to acquire recorder lock: {}", e))?;
             let _ = recorder.stop_recording()?;
         }
+        crate::utils::crash_recovery::release_app_recording_lock(&app);
 
         // Clean up audio file if it exists
         if let Ok(path_guard) = app_state.current_recording_path.lock() {
             if let Some(audio_path) = path_guard.as_ref() {
                 log::info!("Removing cancelled recording file");
-                if let Err(e) = std::fs::remove_file(audio_path) {
+                if let Err(e) = crate::utils::secure_delete::remove_file(audio_path) {
                     log::warn!("Failed to remove cancelled recording: {}", e);
                 }
             }
@@ -2786,11 +4410,15 @@ pub async fn cancel_recording(app: AppHandle) -> Result<(), String> {
 
 #[tauri::command]
 pub async fn delete_transcription_entry(app: AppHandle, timestamp: String) -> Result<(), String> {
-    let store = app
-        .store("transcriptions")
+    let store = crate::utils::data_dir::transcriptions_store(&app)
         .map_err(|e| format!("Failed to get transcriptions store: {}", e))?;
 
-    // Delete the entry
+    // Delete the entry, and the recording kept for it
+    if let Some(entry) = store.get(&timestamp) {
+        if let Ok(dir) = crate::utils::data_dir::data_dir(&app) {
+            crate::utils::recordings::remove_entry_recording(&dir.join("recordings"), &entry);
+        }
+    }
     store.delete(&timestamp);
 
     // Save the store
@@ -2810,19 +4438,348 @@ pub async fn delete_transcription_entry(app: AppHandle, timestamp: String) -> Re
     Ok(())
 }
 
+/// Maximum length of a user-assigned transcription title
+const MAX_TRANSCRIPTION_TITLE_LEN: usize = 200;
+
+#[tauri::command]
+pub async fn rename_transcription(
+    app: AppHandle,
+    id: String,
+    title: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let store = crate::utils::data_dir::transcriptions_store(&app)
+        .map_err(|e| format!("Failed to get transcriptions store: {}", e))?;
+
+    let mut entry = store
+        .get(&id)
+        .ok_or_else(|| format!("Transcription not found: {}", id))?;
+
+    // Blank titles clear the label so the UI falls back to a text snippet
+    let title = title
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty());
+    if let Some(ref t) = title {
+        if t.chars().count() > MAX_TRANSCRIPTION_TITLE_LEN {
+            return Err(format!(
+                "Title is too long (max {} characters)",
+                MAX_TRANSCRIPTION_TITLE_LEN
+            ));
+        }
+    }
+
+    entry["title"] = serde_json::json!(title);
+    store.set(&id, entry.clone());
+    store
+        .save()
+        .map_err(|e| format!("Failed to save renamed transcription: {}", e))?;
+
+    let _ = emit_to_window(&app, "main", "history-updated", ());
+
+    log::info!("Renamed transcription entry: {}", id);
+    Ok(entry)
+}
+
+/// Combine several history entries into a single entry.
+///
+/// Texts are joined in the order given. If entries carry `segments` (objects with
+/// `start`/`end` in seconds), each entry's segments are shifted past the end of the
+/// previous entry so the merged timeline stays monotonic.
+pub fn merge_transcription_entries(
+    entries: &[serde_json::Value],
+    separator: &str,
+    timestamp: &str,
+) -> serde_json::Value {
+    let mut texts: Vec<&str> = Vec::with_capacity(entries.len());
+    let mut models: Vec<String> = Vec::new();
+    let mut segments: Vec<serde_json::Value> = Vec::new();
+    let mut offset = 0.0_f64;
+
+    for entry in entries {
+        texts.push(entry.get("text").and_then(|t| t.as_str()).unwrap_or(""));
+
+        if let Some(model) = entry.get("model").and_then(|m| m.as_str()) {
+            if !models.iter().any(|m| m == model) {
+                models.push(model.to_string());
+            }
+        }
+
+        let mut entry_end = offset;
+        if let Some(entry_segments) = entry.get("segments").and_then(|s| s.as_array()) {
+            for segment in entry_segments {
+                let mut shifted = segment.clone();
+                for field in ["start", "end"] {
+                    if let Some(value) = segment.get(field).and_then(|v| v.as_f64()) {
+                        shifted[field] = serde_json::json!(value + offset);
+                        entry_end = entry_end.max(value + offset);
+                    }
+                }
+                segments.push(shifted);
+            }
+        }
+        offset = entry_end;
+    }
+
+    let mut merged = serde_json::json!({
+        "text": texts.join(separator),
+        "model": models.join(", "),
+        "timestamp": timestamp,
+        "merged_from": entries
+            .iter()
+            .filter_map(|e| e.get("timestamp").cloned())
+            .collect::<Vec<_>>(),
+    });
+    if !segments.is_empty() {
+        merged["segments"] = serde_json::Value::Array(segments);
+    }
+    merged
+}
+
+#[tauri::command]
+pub async fn merge_transcriptions(
+    app: AppHandle,
+    ids: Vec<String>,
+    separator: String,
+    delete_sources: Option<bool>,
+) -> Result<serde_json::Value, String> {
+    if ids.len() < 2 {
+        return Err("At least two transcriptions are required to merge".to_string());
+    }
+
+    let store = crate::utils::data_dir::transcriptions_store(&app)
+        .map_err(|e| format!("Failed to get transcriptions store: {}", e))?;
+
+    // Validate every id before touching the store
+    let mut entries = Vec::with_capacity(ids.len());
+    for id in &ids {
+        let mut entry = store
+            .get(id)
+            .ok_or_else(|| format!("Transcription not found: {}", id))?;
+        if entry.get("timestamp").is_none() {
+            entry["timestamp"] = serde_json::json!(id);
+        }
+        entries.push(entry);
+    }
+
+    let timestamp = chrono::Utc::now().to_rfc3339();
+    let merged = merge_transcription_entries(&entries, &separator, &timestamp);
+
+    store.set(&timestamp, merged.clone());
+    if delete_sources.unwrap_or(false) {
+        // The merged entry doesn't keep any recording, so the sources' go too
+        let recordings_dir = crate::utils::data_dir::data_dir(&app)
+            .ok()
+            .map(|dir| dir.join("recordings"));
+        for (id, entry) in ids.iter().zip(&entries) {
+            if let Some(dir) = &recordings_dir {
+                crate::utils::recordings::remove_entry_recording(dir, entry);
+            }
+            store.delete(id);
+        }
+    }
+
+    store
+        .save()
+        .map_err(|e| format!("Failed to save merged transcription: {}", e))?;
+
+    let _ = emit_to_window(&app, "main", "history-updated", ());
+
+    if let Err(e) = crate::commands::settings::update_tray_menu(app.clone()).await {
+        log::warn!("Failed to update tray menu after merge: {}", e);
+    }
+
+    log::info!("Merged {} transcriptions into {}", ids.len(), timestamp);
+    Ok(merged)
+}
+
+/// A set of history entries whose texts are (nearly) the same
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+pub struct DuplicateGroup {
+    /// Text of the newest entry in the group
+    pub text: String,
+    /// Entry ids (timestamps), newest first
+    pub timestamps: Vec<String>,
+}
+
+/// Which entry of a duplicate group survives deduplication
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum KeepStrategy {
+    Newest,
+    Oldest,
+}
+
+/// Lowercase, drop punctuation and collapse whitespace so "Test, test." == "test test"
+pub fn normalize_for_comparison(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric() || c.is_whitespace())
+        .collect::<String>()
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Similarity in [0, 1] of two normalized texts, based on edit distance
+pub fn text_similarity(a: &str, b: &str) -> f32 {
+    let longest = a.chars().count().max(b.chars().count());
+    if longest == 0 {
+        return 1.0;
+    }
+    let distance = crate::post_processing::corrections::edit_distance(a, b);
+    1.0 - distance as f32 / longest as f32
+}
+
+/// Group `(timestamp, text)` entries whose normalized texts are at least `similarity`
+/// alike (1.0 = exact match after normalization). Only groups with 2+ entries are returned.
+pub fn find_duplicate_groups(entries: &[(String, String)], similarity: f32) -> Vec<DuplicateGroup> {
+    let threshold = similarity.clamp(0.0, 1.0);
+
+    let mut sorted: Vec<&(String, String)> = entries.iter().collect();
+    sorted.sort_by(|a, b| b.0.cmp(&a.0));
+
+    // Each group is compared against its newest (first) entry
+    let mut groups: Vec<(String, DuplicateGroup)> = Vec::new();
+    for (timestamp, text) in sorted {
+        let normalized = normalize_for_comparison(text);
+        if normalized.is_empty() {
+            continue;
+        }
+        let len = normalized.chars().count() as f32;
+
+        let existing = groups.iter_mut().find(|(representative, _)| {
+            if threshold >= 1.0 {
+                return *representative == normalized;
+            }
+            // Cheap length check before the quadratic edit distance
+            let rep_len = representative.chars().count() as f32;
+            if len.min(rep_len) / len.max(rep_len) < threshold {
+                return false;
+            }
+            text_similarity(representative, &normalized) >= threshold
+        });
+
+        match existing {
+            Some((_, group)) => group.timestamps.push(timestamp.clone()),
+            None => groups.push((
+                normalized,
+                DuplicateGroup {
+                    text: text.clone(),
+                    timestamps: vec![timestamp.clone()],
+                },
+            )),
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(_, group)| group)
+        .filter(|group| group.timestamps.len() > 1)
+        .collect()
+}
+
+fn load_history_texts(app: &AppHandle) -> Result<Vec<(String, String)>, String> {
+    let store = crate::utils::data_dir::transcriptions_store(app)
+        .map_err(|e| format!("Failed to get transcriptions store: {}", e))?;
+
+    Ok(store
+        .keys()
+        .into_iter()
+        .filter_map(|key| {
+            let text = store
+                .get(&key)?
+                .get("text")
+                .and_then(|t| t.as_str())
+                .map(|t| t.to_string())?;
+            Some((key.to_string(), text))
+        })
+        .collect())
+}
+
+#[tauri::command]
+pub async fn find_duplicate_transcriptions(
+    app: AppHandle,
+    similarity: f32,
+) -> Result<Vec<DuplicateGroup>, String> {
+    let entries = load_history_texts(&app)?;
+    tokio::task::spawn_blocking(move || find_duplicate_groups(&entries, similarity))
+        .await
+        .map_err(|e| format!("Failed to find duplicates: {}", e))
+}
+
+/// Collapse duplicate groups (exact matches unless `similarity` is given), keeping one entry each.
+/// Returns the number of entries removed.
+#[tauri::command]
+pub async fn dedupe_transcriptions(
+    app: AppHandle,
+    keep: KeepStrategy,
+    similarity: Option<f32>,
+) -> Result<usize, String> {
+    let groups = find_duplicate_transcriptions(app.clone(), similarity.unwrap_or(1.0)).await?;
+    if groups.is_empty() {
+        return Ok(0);
+    }
+
+    let store = crate::utils::data_dir::transcriptions_store(&app)
+        .map_err(|e| format!("Failed to get transcriptions store: {}", e))?;
+
+    let recordings_dir = crate::utils::data_dir::data_dir(&app)
+        .ok()
+        .map(|dir| dir.join("recordings"));
+    let mut removed = 0;
+    for group in &groups {
+        // Timestamps are newest first
+        let survivor = match keep {
+            KeepStrategy::Newest => group.timestamps.first(),
+            KeepStrategy::Oldest => group.timestamps.last(),
+        };
+        for timestamp in &group.timestamps {
+            if Some(timestamp) == survivor {
+                continue;
+            }
+            if let (Some(dir), Some(entry)) = (&recordings_dir, store.get(timestamp)) {
+                crate::utils::recordings::remove_entry_recording(dir, &entry);
+            }
+            if store.delete(timestamp) {
+                removed += 1;
+            }
+        }
+    }
+
+    store
+        .save()
+        .map_err(|e| format!("Failed to save deduplicated history: {}", e))?;
+
+    let _ = emit_to_window(&app, "main", "history-updated", ());
+    if let Err(e) = crate::commands::settings::update_tray_menu(app.clone()).await {
+        log::warn!("Failed to update tray menu after dedupe: {}", e);
+    }
+
+    log::info!(
+        "Removed {} duplicate transcriptions from {} groups",
+        removed,
+        groups.len()
+    );
+    Ok(removed)
+}
+
 #[tauri::command]
 pub async fn clear_all_transcriptions(app: AppHandle) -> Result<(), String> {
     log::info!("[Clear All] Clearing all transcriptions");
 
-    let store = app
-        .store("transcriptions")
+    let store = crate::utils::data_dir::transcriptions_store(&app)
         .map_err(|e| format!("Failed to get transcriptions store: {}", e))?;
 
-    // Get all keys and delete them
-    let keys: Vec<String> = store.keys().into_iter().map(|k| k.to_string()).collect();
-    let count = keys.len();
+    // Delete every entry along with its kept recording
+    let recordings_dir = crate::utils::data_dir::data_dir(&app)
+        .ok()
+        .map(|dir| dir.join("recordings"));
+    let entries = store.entries();
+    let count = entries.len();
 
-    for key in keys {
+    for (key, entry) in entries {
+        if let Some(dir) = &recordings_dir {
+            crate::utils::recordings::remove_entry_recording(dir, &entry);
+        }
         store.delete(&key);
     }
 
@@ -2847,6 +4804,7 @@ pub async fn clear_all_transcriptions(app: AppHandle) -> Result<(), String> {
 pub struct RecordingStateResponse {
     state: String,
     error: Option<String>,
+    paused: bool,
 }
 
 #[tauri::command]
@@ -2865,5 +4823,226 @@ pub fn get_current_recording_state(app: AppHandle) -> RecordingStateResponse {
         }
         .to_string(),
         error: None,
+        paused: current_state == RecordingState::Recording
+            && app
+                .state::<RecorderState>()
+                .0
+                .lock()
+                .map(|recorder| recorder.is_paused())
+                .unwrap_or(false),
+    }
+}
+
+/// Recording state as seen by a freshly loaded UI
+#[derive(serde::Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ActiveRecordingState {
+    Idle,
+    Starting,
+    Recording,
+    Paused,
+    Stopping,
+    Transcribing,
+    Cancelling,
+    Error,
+}
+
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct RecordingStateSnapshot {
+    pub state: ActiveRecordingState,
+    /// Time since the recording began, including any paused time
+    pub elapsed_ms: Option<u64>,
+    pub current_model: Option<String>,
+}
+
+/// Snapshot of the backend recording state so the UI can resync after a
+/// reload instead of relying on the events it saw
+#[tauri::command]
+pub fn get_recording_state(app: AppHandle) -> Result<RecordingStateSnapshot, String> {
+    let app_state = app.state::<AppState>();
+    let current_state = app_state.get_current_state();
+    let paused = current_state == RecordingState::Recording
+        && app
+            .state::<RecorderState>()
+            .0
+            .lock()
+            .map(|recorder| recorder.is_paused())
+            .unwrap_or(false);
+
+    let state = match current_state {
+        RecordingState::Idle => ActiveRecordingState::Idle,
+        RecordingState::Error => ActiveRecordingState::Error,
+        _ if app_state.is_cancellation_requested() => ActiveRecordingState::Cancelling,
+        RecordingState::Starting => ActiveRecordingState::Starting,
+        RecordingState::Recording if paused => ActiveRecordingState::Paused,
+        RecordingState::Recording => ActiveRecordingState::Recording,
+        RecordingState::Stopping => ActiveRecordingState::Stopping,
+        RecordingState::Transcribing => ActiveRecordingState::Transcribing,
+    };
+
+    let elapsed_ms = app_state
+        .recording_started_at
+        .lock()
+        .map_err(|e| format!("Failed to acquire recording start lock: {}", e))?
+        .map(|started| started.elapsed().as_millis() as u64);
+
+    let current_model = app
+        .store("settings")
+        .map_err(|e| format!("Failed to get store: {}", e))?
+        .get("current_model")
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .filter(|model| !model.is_empty());
+
+    Ok(RecordingStateSnapshot {
+        state,
+        elapsed_ms,
+        current_model,
+    })
+}
+
+/// Write `value` as minified JSON to `path` via a temporary file and rename, so an
+/// interrupted write never leaves a truncated file behind. Returns the bytes written.
+pub fn write_json_compact_atomic(path: &Path, value: &serde_json::Value) -> Result<u64, String> {
+    use std::io::Write;
+
+    let data =
+        serde_json::to_vec(value).map_err(|e| format!("Failed to serialize store: {}", e))?;
+
+    let tmp_path = path.with_extension("compact.tmp");
+    {
+        let mut file = std::fs::File::create(&tmp_path)
+            .map_err(|e| format!("Failed to create temporary store file: {}", e))?;
+        file.write_all(&data)
+            .map_err(|e| format!("Failed to write temporary store file: {}", e))?;
+        file.sync_all()
+            .map_err(|e| format!("Failed to flush temporary store file: {}", e))?;
+    }
+
+    if let Err(e) = std::fs::rename(&tmp_path, path) {
+        let _ = std::fs::remove_file(&tmp_path);
+        return Err(format!("Failed to replace store file: {}", e));
+    }
+
+    Ok(data.len() as u64)
+}
+
+/// Rewrite the transcriptions store file minified, dropping anything that is no
+/// longer part of the in-memory store. The store itself saves minified JSON, so
+/// this mostly shrinks files written pretty-printed by older versions. Returns
+/// the number of bytes saved.
+#[tauri::command]
+pub async fn compact_transcriptions_store(app: AppHandle) -> Result<u64, String> {
+    let store_path = crate::utils::data_dir::transcriptions_store_path(&app)?;
+
+    let store = crate::utils::data_dir::transcriptions_store(&app)
+        .map_err(|e| format!("Failed to access transcriptions store: {}", e))?;
+
+    // Persist pending changes first so the file reflects the current history
+    store
+        .save()
+        .map_err(|e| format!("Failed to save transcriptions store: {}", e))?;
+
+    let before = std::fs::metadata(&store_path).map(|m| m.len()).unwrap_or(0);
+    let entries: serde_json::Map<String, serde_json::Value> = store.entries().into_iter().collect();
+
+    let after = tauri::async_runtime::spawn_blocking(move || {
+        write_json_compact_atomic(&store_path, &serde_json::Value::Object(entries))
+    })
+    .await
+    .map_err(|e| format!("Compaction task failed: {}", e))??;
+
+    let saved = before.saturating_sub(after);
+    log::info!(
+        "Compacted transcriptions store: {} -> {} bytes ({} saved)",
+        before,
+        after,
+        saved
+    );
+
+    Ok(saved)
+}
+
+pub fn is_shutdown_prepared() -> bool {
+    SHUTDOWN_PREPARED.load(AtomicOrdering::SeqCst)
+}
+
+/// Get the app into a safe state to exit: finish an in-progress recording through
+/// the normal pipeline, wait for its transcription, stop capture and flush stores.
+/// Only the exit handler runs this; it is not exposed to the frontend.
+pub async fn prepare_shutdown(app: AppHandle) -> Result<(), String> {
+    log::info!("Preparing for shutdown");
+    let app_state = app.state::<AppState>();
+
+    // 1. Stop an active recording so the captured audio is transcribed and saved
+    if matches!(
+        app_state.get_current_state(),
+        RecordingState::Starting | RecordingState::Recording
+    ) {
+        log::info!("Recording in progress at shutdown, stopping it");
+        if let Err(e) = stop_recording(app.clone(), app.state::<RecorderState>()).await {
+            log::warn!("Failed to stop recording during shutdown: {}", e);
+        }
+    }
+
+    // 2. Let any pending transcription finish (bounded so quit can't hang)
+    let wait_start = Instant::now();
+    while matches!(
+        app_state.get_current_state(),
+        RecordingState::Stopping | RecordingState::Transcribing
+    ) {
+        if wait_start.elapsed() >= SHUTDOWN_TRANSCRIPTION_TIMEOUT {
+            log::warn!("Timed out waiting for transcription to finish before shutdown");
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+
+    // 3. Close any remaining input stream (e.g. pre-roll capture)
+    {
+        let recorder_state = app.state::<RecorderState>();
+        match recorder_state.0.lock() {
+            Ok(mut recorder) => {
+                if recorder.is_recording() {
+                    if let Err(e) = recorder.stop_recording() {
+                        log::warn!("Failed to stop audio stream during shutdown: {}", e);
+                    }
+                    crate::utils::crash_recovery::release_app_recording_lock(&app);
+                }
+                recorder.configure_preroll(0, None);
+            }
+            Err(e) => log::warn!("Failed to acquire recorder lock during shutdown: {}", e),
+        }
+    }
+
+    // 4. Stop background cleanup so it doesn't touch files mid-exit
+    if let Some(scheduler) = app.try_state::<crate::utils::auto_cleanup::AutoCleanupScheduler>() {
+        scheduler.stop();
+    }
+
+    // 5. Flush stores to disk
+    let mut errors = Vec::new();
+    for name in ["settings", "transcriptions"] {
+        let store = if name == "transcriptions" {
+            crate::utils::data_dir::transcriptions_store(&app)
+        } else {
+            app.store(name)
+        };
+        match store {
+            Ok(store) => {
+                if let Err(e) = store.save() {
+                    errors.push(format!("Failed to flush {} store: {}", name, e));
+                }
+            }
+            Err(e) => errors.push(format!("Failed to open {} store: {}", name, e)),
+        }
+    }
+
+    SHUTDOWN_PREPARED.store(true, AtomicOrdering::SeqCst);
+
+    if errors.is_empty() {
+        log::info!("Shutdown preparation complete");
+        Ok(())
+    } else {
+        Err(errors.join("; "))
     }
 }
@@ -0,0 +1,216 @@
+// Audio files dropped onto the main window are transcribed with the current
+// model and saved to history. Only file paths are handled; audio data copied
+// to the clipboard isn't. Several dropped files are queued and transcribed
+// one at a time, the same way a single file goes through
+// `transcribe_audio_file`.
+
+use once_cell::sync::Lazy;
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+
+use crate::emit_to_all;
+
+/// Same formats the upload dialog accepts
+pub const AUDIO_FILE_EXTENSIONS: &[&str] = &["wav", "mp3", "m4a", "flac", "ogg", "mp4", "webm"];
+
+/// Dropped files waiting to be transcribed
+static DROP_QUEUE: Lazy<Mutex<VecDeque<PathBuf>>> = Lazy::new(|| Mutex::new(VecDeque::new()));
+static DROP_WORKER_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, serde::Serialize)]
+pub struct DroppedFileResult {
+    pub path: String,
+    pub text: Option<String>,
+    pub error: Option<String>,
+    /// Files still queued after this one
+    pub remaining: usize,
+}
+
+/// Resolve a dropped path (plain, quoted or a `file://` URL) to an existing
+/// audio file
+pub fn dropped_audio_path(raw: &str) -> Result<PathBuf, String> {
+    let raw = raw.trim().trim_matches(|c| c == '"' || c == '\'');
+    let path = if raw.starts_with("file://") {
+        tauri::Url::parse(raw)
+            .ok()
+            .and_then(|url| url.to_file_path().ok())
+            .ok_or_else(|| format!("Invalid file URL: {}", raw))?
+    } else {
+        PathBuf::from(raw)
+    };
+
+    let supported = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| AUDIO_FILE_EXTENSIONS.contains(&ext.to_lowercase().as_str()));
+    if !supported {
+        return Err(format!(
+            "Unsupported file format: {}. Supported formats: {}",
+            path.display(),
+            AUDIO_FILE_EXTENSIONS.join(", ")
+        ));
+    }
+    if !path.is_file() {
+        return Err(format!("Audio file not found: {}", path.display()));
+    }
+    Ok(path)
+}
+
+/// Transcribe `path` with the current model and save the result to history
+async fn transcribe_and_save(app: &AppHandle, path: &Path) -> Result<String, String> {
+    // Shared with uploads, and held through the save so results keep their order
+    let file_state = app.state::<super::audio::FileTranscriptionState>();
+    let _guard = file_state.lock.lock().await;
+    let store = app.store("settings").map_err(|e| e.to_string())?;
+    let model = store
+        .get("current_model")
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .filter(|model| !model.is_empty())
+        .ok_or("No model selected. Choose a model before transcribing files.")?;
+    let engine = store
+        .get("current_model_engine")
+        .and_then(|v| v.as_str().map(|s| s.to_string()));
+
+    let text = super::audio::transcribe_audio_file_locked(
+        app.clone(),
+        path.to_string_lossy().to_string(),
+        model.clone(),
+        engine,
+    )
+    .await?;
+    if text.trim().is_empty() || text.trim() == "[BLANK_AUDIO]" {
+        return Err("No speech detected in the audio file".to_string());
+    }
+
+    let mut metadata = serde_json::Map::new();
+    if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+        metadata.insert("source_file".to_string(), name.into());
+    }
+    super::audio::save_transcription_with_metadata(app.clone(), text.clone(), model, metadata)
+        .await?;
+    Ok(text)
+}
+
+/// Transcribe an audio file given by path, e.g. one dragged onto the window,
+/// and save it to history. Returns the transcribed text.
+#[tauri::command]
+pub async fn transcribe_dropped_path(app: AppHandle, path: String) -> Result<String, String> {
+    let path = dropped_audio_path(&path)?;
+    log::info!("Transcribing dropped file {:?}", path);
+    let result = transcribe_and_save(&app, &path).await;
+    let _ = emit_to_all(
+        &app,
+        "dropped-file-transcribed",
+        DroppedFileResult {
+            path: path.to_string_lossy().to_string(),
+            text: result.as_ref().ok().cloned(),
+            error: result.as_ref().err().cloned(),
+            remaining: 0,
+        },
+    );
+    result
+}
+
+/// Queue files dropped onto the main window. Non-audio files are skipped.
+pub fn handle_dropped_paths(app: &AppHandle, paths: Vec<PathBuf>) {
+    let mut rejected = Vec::new();
+    {
+        let Ok(mut queue) = DROP_QUEUE.lock() else {
+            return;
+        };
+        for path in paths {
+            match dropped_audio_path(&path.to_string_lossy()) {
+                Ok(path) => queue.push_back(path),
+                Err(e) => rejected.push((path.to_string_lossy().to_string(), e)),
+            }
+        }
+        log::info!(
+            "Dropped files: {} waiting, {} skipped",
+            queue.len(),
+            rejected.len()
+        );
+    }
+    for (path, error) in rejected {
+        let _ = emit_to_all(
+            app,
+            "dropped-file-transcribed",
+            DroppedFileResult {
+                path,
+                text: None,
+                error: Some(error),
+                remaining: 0,
+            },
+        );
+    }
+
+    if DROP_WORKER_ACTIVE.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let next = DROP_QUEUE
+                .lock()
+                .ok()
+                .and_then(|mut queue| queue.pop_front().map(|path| (path, queue.len())));
+            let Some((path, remaining)) = next else {
+                DROP_WORKER_ACTIVE.store(false, Ordering::SeqCst);
+                // A drop may have been queued just before the flag was cleared
+                let pending = DROP_QUEUE.lock().is_ok_and(|queue| !queue.is_empty());
+                if pending && !DROP_WORKER_ACTIVE.swap(true, Ordering::SeqCst) {
+                    continue;
+                }
+                break;
+            };
+            let result = transcribe_and_save(&app, &path).await;
+            if let Err(e) = &result {
+                log::warn!("Failed to transcribe dropped file {:?}: {}", path, e);
+            }
+            let _ = emit_to_all(
+                &app,
+                "dropped-file-transcribed",
+                DroppedFileResult {
+                    path: path.to_string_lossy().to_string(),
+                    text: result.as_ref().ok().cloned(),
+                    error: result.err(),
+                    remaining,
+                },
+            );
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dropped_audio_path_validation() {
+        let dir = tempfile::tempdir().unwrap();
+        let audio = dir.path().join("memo one.M4A");
+        std::fs::write(&audio, b"audio").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), b"text").unwrap();
+
+        assert_eq!(
+            dropped_audio_path(&format!("\"{}\"", audio.display())).unwrap(),
+            audio
+        );
+        let url = tauri::Url::from_file_path(&audio).unwrap();
+        assert_eq!(dropped_audio_path(url.as_str()).unwrap(), audio);
+
+        assert!(
+            dropped_audio_path(&dir.path().join("notes.txt").to_string_lossy())
+                .unwrap_err()
+                .starts_with("Unsupported file format")
+        );
+        assert!(
+            dropped_audio_path(&dir.path().join("missing.wav").to_string_lossy())
+                .unwrap_err()
+                .starts_with("Audio file not found")
+        );
+    }
+}
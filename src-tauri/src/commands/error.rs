@@ -0,0 +1,150 @@
+// Structured errors for commands whose failures the frontend needs to tell apart.
+// Serialized as `{ "code": "disk_full", "message": "..." }` so the UI can branch
+// on `code` and still show `message` to the user.
+
+use std::fmt;
+
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "code", content = "message", rename_all = "snake_case")]
+pub enum CommandError {
+    NotFound(String),
+    PermissionDenied(String),
+    DiskFull(String),
+    ModelMissing(String),
+    InvalidInput(String),
+    /// Another operation (recording, transcription) is in progress
+    Busy(String),
+    Cancelled(String),
+    LicenseRequired(String),
+    InsufficientMemory(String),
+    VerificationFailed(String),
+    Io(String),
+    Internal(String),
+}
+
+impl CommandError {
+    /// Machine-readable code, matching the serialized `code` field
+    pub fn code(&self) -> &'static str {
+        match self {
+            CommandError::NotFound(_) => "not_found",
+            CommandError::PermissionDenied(_) => "permission_denied",
+            CommandError::DiskFull(_) => "disk_full",
+            CommandError::ModelMissing(_) => "model_missing",
+            CommandError::InvalidInput(_) => "invalid_input",
+            CommandError::Busy(_) => "busy",
+            CommandError::Cancelled(_) => "cancelled",
+            CommandError::LicenseRequired(_) => "license_required",
+            CommandError::InsufficientMemory(_) => "insufficient_memory",
+            CommandError::VerificationFailed(_) => "verification_failed",
+            CommandError::Io(_) => "io",
+            CommandError::Internal(_) => "internal",
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            CommandError::NotFound(m)
+            | CommandError::PermissionDenied(m)
+            | CommandError::DiskFull(m)
+            | CommandError::ModelMissing(m)
+            | CommandError::InvalidInput(m)
+            | CommandError::Busy(m)
+            | CommandError::Cancelled(m)
+            | CommandError::LicenseRequired(m)
+            | CommandError::InsufficientMemory(m)
+            | CommandError::VerificationFailed(m)
+            | CommandError::Io(m)
+            | CommandError::Internal(m) => m,
+        }
+    }
+
+    /// Classify a filesystem error, prefixing the message with `context`
+    pub fn io(context: &str, error: std::io::Error) -> Self {
+        let message = format!("{}: {}", context, error);
+        if is_disk_full(&error) {
+            return CommandError::DiskFull(message);
+        }
+        match error.kind() {
+            std::io::ErrorKind::NotFound => CommandError::NotFound(message),
+            std::io::ErrorKind::PermissionDenied => CommandError::PermissionDenied(message),
+            _ => CommandError::Io(message),
+        }
+    }
+}
+
+fn is_disk_full(error: &std::io::Error) -> bool {
+    // ENOSPC on Unix; ERROR_HANDLE_DISK_FULL / ERROR_DISK_FULL on Windows
+    #[cfg(unix)]
+    const DISK_FULL_CODES: &[i32] = &[28];
+    #[cfg(windows)]
+    const DISK_FULL_CODES: &[i32] = &[39, 112];
+    #[cfg(not(any(unix, windows)))]
+    const DISK_FULL_CODES: &[i32] = &[];
+
+    error
+        .raw_os_error()
+        .is_some_and(|code| DISK_FULL_CODES.contains(&code))
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+/// Plain string errors from helpers that haven't been classified
+impl From<String> for CommandError {
+    fn from(message: String) -> Self {
+        CommandError::Internal(message)
+    }
+}
+
+impl From<&str> for CommandError {
+    fn from(message: &str) -> Self {
+        CommandError::Internal(message.to_string())
+    }
+}
+
+impl From<std::io::Error> for CommandError {
+    fn from(error: std::io::Error) -> Self {
+        CommandError::io("I/O error", error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serializes_code_and_message() {
+        let error = CommandError::ModelMissing("Model 'tiny' not found".to_string());
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            serde_json::json!({ "code": "model_missing", "message": "Model 'tiny' not found" })
+        );
+        assert_eq!(error.code(), "model_missing");
+        assert_eq!(error.to_string(), "Model 'tiny' not found");
+    }
+
+    #[test]
+    fn test_io_errors_are_classified() {
+        let denied = std::io::Error::from(std::io::ErrorKind::PermissionDenied);
+        assert_eq!(
+            CommandError::io("Failed to open folder", denied).code(),
+            "permission_denied"
+        );
+
+        let missing = std::io::Error::from(std::io::ErrorKind::NotFound);
+        assert_eq!(CommandError::from(missing).code(), "not_found");
+
+        #[cfg(unix)]
+        assert_eq!(
+            CommandError::io("Failed to write", std::io::Error::from_raw_os_error(28)).code(),
+            "disk_full"
+        );
+
+        assert_eq!(CommandError::from("boom").code(), "internal");
+    }
+}
@@ -0,0 +1,165 @@
+// Deterministic history for automated UI tests. Only built with the
+// `test-fixtures` feature, so release builds never include it.
+
+use std::path::Path;
+use tauri::AppHandle;
+
+/// Most entries `seed_test_data` will write in one call
+const MAX_FIXTURE_ENTRIES: usize = 10_000;
+
+/// First fixture timestamp; later entries are six hours apart, so seeded
+/// history spans days to months for retention and date filters
+const FIXTURE_START: &str = "2024-01-01T09:00:00+00:00";
+const FIXTURE_SPACING_HOURS: i64 = 6;
+
+const FIXTURE_TEXTS: &[&str] = &[
+    "Schedule the design review for Thursday afternoon.",
+    "Remind me to send the quarterly report to finance.",
+    "The deployment to staging finished without errors.",
+    "Add oat milk, eggs and coffee to the shopping list.",
+    "Let's move the standup to ten thirty tomorrow.",
+    "Follow up with the vendor about the delayed invoice.",
+];
+const FIXTURE_MODELS: &[&str] = &["base.en", "large-v3-turbo", "small.en"];
+const FIXTURE_APPS: &[(&str, &str)] = &[
+    ("Slack", "com.tinyspeck.slackmacgap"),
+    ("Mail", "com.apple.mail"),
+    ("Visual Studio Code", "com.microsoft.VSCode"),
+];
+
+fn fixture_recording_name(index: usize) -> String {
+    format!("fixture_{:05}.wav", index)
+}
+
+/// `count` history entries, identical on every call. With `with_recordings`
+/// each entry references `fixture_<n>.wav`.
+pub fn fixture_entries(count: usize, with_recordings: bool) -> Vec<(String, serde_json::Value)> {
+    let start = chrono::DateTime::parse_from_rfc3339(FIXTURE_START)
+        .expect("valid fixture start")
+        .with_timezone(&chrono::Utc);
+    (0..count)
+        .map(|i| {
+            let timestamp =
+                (start + chrono::Duration::hours(FIXTURE_SPACING_HOURS * i as i64)).to_rfc3339();
+            let confidence = 0.5 + (i % 5) as f64 * 0.1;
+            let (app_name, app_id) = FIXTURE_APPS[i % FIXTURE_APPS.len()];
+            let mut entry = serde_json::json!({
+                "text": format!("{} (#{})", FIXTURE_TEXTS[i % FIXTURE_TEXTS.len()], i + 1),
+                "model": FIXTURE_MODELS[i % FIXTURE_MODELS.len()],
+                "timestamp": timestamp,
+                "title": if i % 3 == 0 { Some(format!("Fixture {}", i + 1)) } else { None },
+                "confidence": confidence,
+                "app": { "name": app_name, "id": app_id },
+            });
+            if confidence < 0.6 {
+                entry["low_confidence"] = true.into();
+            }
+            if with_recordings {
+                entry["recording_file"] = fixture_recording_name(i).into();
+            }
+            (timestamp, entry)
+        })
+        .collect()
+}
+
+/// One second of silence, finalized for `transcription_id` so it's treated
+/// like a kept recording
+fn write_dummy_recording(path: &Path, transcription_id: &str) -> Result<(), String> {
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: 16000,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut writer = hound::WavWriter::create(path, spec)
+        .map_err(|e| format!("Failed to create dummy recording: {}", e))?;
+    for _ in 0..spec.sample_rate {
+        writer
+            .write_sample(0i16)
+            .map_err(|e| format!("Failed to write dummy recording: {}", e))?;
+    }
+    writer
+        .finalize()
+        .map_err(|e| format!("Failed to finalize dummy recording: {}", e))?;
+    crate::utils::recordings::finalize_recording(path, transcription_id)
+}
+
+/// Write `entries` deterministic fixture transcriptions (and, with
+/// `with_recordings`, a silent recording for each) into history. Running it
+/// again overwrites the same entries; other history is left alone.
+#[tauri::command]
+pub async fn seed_test_data(
+    app: AppHandle,
+    entries: usize,
+    with_recordings: Option<bool>,
+) -> Result<usize, String> {
+    if entries == 0 || entries > MAX_FIXTURE_ENTRIES {
+        return Err(format!(
+            "Entries must be between 1 and {}",
+            MAX_FIXTURE_ENTRIES
+        ));
+    }
+    let with_recordings = with_recordings.unwrap_or(false);
+    let fixtures = fixture_entries(entries, with_recordings);
+
+    if with_recordings {
+        let recordings_dir = crate::utils::data_dir::data_dir(&app)?.join("recordings");
+        std::fs::create_dir_all(&recordings_dir)
+            .map_err(|e| format!("Failed to create recordings directory: {}", e))?;
+        let files: Vec<(std::path::PathBuf, String)> = fixtures
+            .iter()
+            .enumerate()
+            .map(|(i, (key, _))| (recordings_dir.join(fixture_recording_name(i)), key.clone()))
+            .collect();
+        tokio::task::spawn_blocking(move || {
+            files
+                .iter()
+                .try_for_each(|(path, key)| write_dummy_recording(path, key))
+        })
+        .await
+        .map_err(|e| format!("Failed to write dummy recordings: {}", e))??;
+    }
+
+    let store = crate::utils::data_dir::transcriptions_store(&app).map_err(|e| e.to_string())?;
+    for (key, entry) in fixtures {
+        store.set(key, entry);
+    }
+    store
+        .save()
+        .map_err(|e| format!("Failed to save fixture transcriptions: {}", e))?;
+
+    log::info!(
+        "Seeded {} fixture transcriptions{}",
+        entries,
+        if with_recordings {
+            " with recordings"
+        } else {
+            ""
+        }
+    );
+    let _ = crate::emit_to_window(&app, "main", "history-updated", ());
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixture_entries_are_deterministic() {
+        let entries = fixture_entries(4, true);
+        assert_eq!(entries, fixture_entries(4, true));
+        assert_eq!(entries[0].0, "2024-01-01T09:00:00+00:00");
+        assert_eq!(entries[1].0, "2024-01-01T15:00:00+00:00");
+        assert_eq!(entries[0].1["recording_file"], "fixture_00000.wav");
+        assert_eq!(entries[0].1["title"], "Fixture 1");
+        assert!(entries[1].1["title"].is_null());
+        assert_eq!(entries[0].1["low_confidence"], true);
+        assert!(entries[1].1.get("low_confidence").is_none());
+
+        assert!(fixture_entries(1, false)[0]
+            .1
+            .get("recording_file")
+            .is_none());
+    }
+}
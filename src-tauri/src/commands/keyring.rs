@@ -32,9 +32,17 @@ fn validate_key(key: &str) -> Result<(), String> {
 }
 
 #[tauri::command]
-pub fn keyring_set(app: AppHandle, key: String, value: String) -> Result<(), String> {
+pub fn keyring_set(
+    app: AppHandle,
+    key: String,
+    value: String,
+    namespace: Option<String>,
+) -> Result<(), String> {
     // Validate key first
     validate_key(&key)?;
+    if let Some(namespace) = &namespace {
+        validate_key(namespace)?;
+    }
 
     // Validate value isn't too large (10MB limit)
     if value.len() > 10 * 1024 * 1024 {
@@ -42,27 +50,50 @@ pub fn keyring_set(app: AppHandle, key: String, value: String) -> Result<(), Str
     }
 
     // Save to secure store
-    secure_store::secure_set(&app, &key, &value)?;
+    match &namespace {
+        Some(namespace) => secure_store::secure_set_ns(&app, namespace, &key, &value)?,
+        None => secure_store::secure_set(&app, &key, &value)?,
+    }
     log::info!("Saved to secure store: {}", key);
     Ok(())
 }
 
 #[tauri::command]
-pub fn keyring_get(app: AppHandle, key: String) -> Result<Option<String>, String> {
+pub fn keyring_get(
+    app: AppHandle,
+    key: String,
+    namespace: Option<String>,
+) -> Result<Option<String>, String> {
     // Validate key first
     validate_key(&key)?;
 
     // Get from secure store
-    secure_store::secure_get(&app, &key)
+    match &namespace {
+        Some(namespace) => {
+            validate_key(namespace)?;
+            secure_store::secure_get_ns(&app, namespace, &key)
+        }
+        None => secure_store::secure_get(&app, &key),
+    }
 }
 
 #[tauri::command]
-pub fn keyring_delete(app: AppHandle, key: String) -> Result<(), String> {
+pub fn keyring_delete(
+    app: AppHandle,
+    key: String,
+    namespace: Option<String>,
+) -> Result<(), String> {
     // Validate key first
     validate_key(&key)?;
 
     // Delete from secure store
-    secure_store::secure_delete(&app, &key)?;
+    match &namespace {
+        Some(namespace) => {
+            validate_key(namespace)?;
+            secure_store::secure_delete_ns(&app, namespace, &key)?;
+        }
+        None => secure_store::secure_delete(&app, &key)?,
+    }
     log::info!("Deleted from secure store: {}", key);
     Ok(())
 }
@@ -75,3 +106,39 @@ pub fn keyring_has(app: AppHandle, key: String) -> Result<bool, String> {
     // Check secure store
     secure_store::secure_has(&app, &key)
 }
+
+#[tauri::command]
+pub fn keyring_list(app: AppHandle, namespace: String) -> Result<Vec<String>, String> {
+    validate_key(&namespace)?;
+
+    // Keys are returned without the namespace prefix
+    secure_store::secure_list(&app, &namespace)
+}
+
+/// Check that the secure store can be read and every entry decrypts, so a
+/// damaged secure.dat shows up as such instead of as "invalid key" errors
+#[tauri::command]
+pub async fn check_secure_store(app: AppHandle) -> Result<secure_store::SecureStoreHealth, String> {
+    let health = secure_store::check_health(&app)?;
+    log::info!(
+        "Secure store check: {:?}, {} key(s), {} unreadable",
+        health.status,
+        health.key_count,
+        health.corrupted_keys.len()
+    );
+    Ok(health)
+}
+
+/// Replace a corrupted or missing secure store with an empty one. The old
+/// file is kept next to it as a backup. Refused while the store is healthy.
+#[tauri::command]
+pub async fn reset_secure_store(app: AppHandle) -> Result<(), String> {
+    if secure_store::check_health(&app)?.status == secure_store::SecureStoreStatus::Healthy {
+        return Err("Secure store is healthy; nothing to reset".to_string());
+    }
+
+    let backup = secure_store::reset(&app)?;
+    crate::commands::ai::clear_all_api_key_cache()?;
+    log::warn!("Secure store reset (backup: {:?})", backup);
+    Ok(())
+}
@@ -0,0 +1,370 @@
+use crate::parakeet::ParakeetManager;
+use crate::whisper::cache::TranscriberCache;
+use crate::whisper::manager::WhisperManager;
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+use tauri::async_runtime::{Mutex as AsyncMutex, RwLock as AsyncRwLock};
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Length of the synthetic clip run through the pipeline
+const CLIP_SECONDS: u32 = 3;
+const CLIP_SAMPLE_RATE: u32 = 16000;
+/// Give up on the input device if no audio arrives within this long
+const DEVICE_OPEN_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Time spent in each stage of a dictation, in milliseconds. A stage is
+/// `None` when it was skipped or failed; `notes` says why.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct LatencyBreakdown {
+    /// Opening the input device until the first buffer arrives
+    pub device_open_ms: Option<u64>,
+    /// Writing the captured clip to disk
+    pub capture_ms: Option<u64>,
+    /// Noise suppression, silence trimming and peak normalization, as configured
+    pub preprocessing_ms: Option<u64>,
+    /// Model load (if not cached) and transcription
+    pub inference_ms: Option<u64>,
+    /// Clipboard round trip used for pasting (no keystrokes are sent)
+    pub injection_ms: Option<u64>,
+    /// Wall-clock time of the whole check, including work between stages
+    pub total_ms: u64,
+    /// Name of the slowest stage
+    pub bottleneck: Option<String>,
+    pub notes: Vec<String>,
+}
+
+impl LatencyBreakdown {
+    fn stages(&self) -> [(&'static str, Option<u64>); 5] {
+        [
+            ("device_open", self.device_open_ms),
+            ("capture", self.capture_ms),
+            ("preprocessing", self.preprocessing_ms),
+            ("inference", self.inference_ms),
+            ("injection", self.injection_ms),
+        ]
+    }
+
+    /// Fill in the total and the slowest stage. `total_ms` is the measured
+    /// wall-clock time, never less than the stages it contains.
+    pub fn finish(mut self, total_ms: u64) -> Self {
+        let stages_ms: u64 = self.stages().iter().filter_map(|(_, ms)| *ms).sum();
+        self.total_ms = total_ms.max(stages_ms);
+        self.bottleneck = self
+            .stages()
+            .iter()
+            .filter_map(|(name, ms)| ms.map(|ms| (*name, ms)))
+            .max_by_key(|(_, ms)| *ms)
+            .map(|(name, _)| name.to_string());
+        self
+    }
+}
+
+/// Run `f` and return its result with the elapsed milliseconds
+fn timed<T>(f: impl FnOnce() -> T) -> (T, u64) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed().as_millis() as u64)
+}
+
+/// Open the selected input device and wait for its first buffer
+fn measure_device_open(selected: Option<String>) -> Result<u64, String> {
+    let start = Instant::now();
+    let host = cpal::default_host();
+    let device = match selected {
+        Some(name) => host
+            .input_devices()
+            .map_err(|e| format!("Failed to list input devices: {}", e))?
+            .find(|d| d.name().ok().as_deref() == Some(name.as_str())),
+        None => None,
+    }
+    .or_else(|| host.default_input_device())
+    .ok_or_else(|| "No input device available".to_string())?;
+    let config = device
+        .default_input_config()
+        .map_err(|e| format!("Failed to read input config: {}", e))?;
+
+    let (tx, rx) = mpsc::sync_channel::<()>(1);
+    let stream = device
+        .build_input_stream_raw(
+            &config.config(),
+            config.sample_format(),
+            move |_data: &cpal::Data, _: &cpal::InputCallbackInfo| {
+                let _ = tx.try_send(());
+            },
+            |e| log::warn!("Input stream error during latency check: {}", e),
+            None,
+        )
+        .map_err(|e| format!("Failed to open input stream: {}", e))?;
+    stream
+        .play()
+        .map_err(|e| format!("Failed to start input stream: {}", e))?;
+    rx.recv_timeout(DEVICE_OPEN_TIMEOUT)
+        .map_err(|_| "Input device produced no audio".to_string())?;
+    Ok(start.elapsed().as_millis() as u64)
+}
+
+/// A speech-band tone with a syllable-like envelope, so preprocessing has
+/// something to work on
+fn synthetic_clip() -> Vec<i16> {
+    let total = (CLIP_SECONDS * CLIP_SAMPLE_RATE) as usize;
+    (0..total)
+        .map(|i| {
+            let t = i as f32 / CLIP_SAMPLE_RATE as f32;
+            let envelope = (std::f32::consts::PI * 4.0 * t).sin().abs();
+            let tone = (2.0 * std::f32::consts::PI * 220.0 * t).sin();
+            (tone * envelope * 0.3 * i16::MAX as f32) as i16
+        })
+        .collect()
+}
+
+fn write_clip(path: &Path, samples: &[i16]) -> Result<(), String> {
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: CLIP_SAMPLE_RATE,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut writer = hound::WavWriter::create(path, spec)
+        .map_err(|e| format!("Failed to create clip: {}", e))?;
+    for sample in samples {
+        writer
+            .write_sample(*sample)
+            .map_err(|e| format!("Failed to write clip: {}", e))?;
+    }
+    writer
+        .finalize()
+        .map_err(|e| format!("Failed to finalize clip: {}", e))
+}
+
+fn preprocess(path: &Path, config: &crate::commands::audio::RecordingConfig) -> Result<(), String> {
+    if config.noise_suppression {
+        crate::audio::denoise::denoise_wav_file(path, config.noise_suppression_strength)?;
+    }
+    if config.trim_silence {
+        crate::audio::trim::trim_wav_file(path, crate::audio::trim::DEFAULT_TRIM_THRESHOLD_DB)?;
+    }
+    if config.normalize_peak {
+        crate::audio::gain::normalize_wav_file(path, config.normalize_target_peak)?;
+    }
+    Ok(())
+}
+
+async fn run_inference(
+    app: &AppHandle,
+    config: &crate::commands::audio::RecordingConfig,
+    clip: PathBuf,
+) -> Result<(), String> {
+    let model = config.current_model.clone();
+    match config.current_engine.as_str() {
+        "parakeet" => {
+            let manager = app.state::<ParakeetManager>();
+            manager
+                .load_model(app, &model)
+                .await
+                .map_err(|e| format!("Failed to load Parakeet model: {:?}", e))?;
+            manager
+                .transcribe(app, &model, clip, Some(config.language.clone()), false)
+                .await
+                .map(|_| ())
+                .map_err(|e| format!("Parakeet transcription failed: {:?}", e))
+        }
+        "whisper" => {
+            let model_path = app
+                .state::<AsyncRwLock<WhisperManager>>()
+                .read()
+                .await
+                .get_model_path(&model)
+                .ok_or_else(|| format!("Model '{}' is not downloaded", model))?;
+            let transcriber = app
+                .state::<AsyncMutex<TranscriberCache>>()
+                .lock()
+                .await
+                .get_or_create(&model_path)?;
+            let language = config.language.clone();
+            tauri::async_runtime::spawn_blocking(move || {
+                transcriber
+                    .transcribe_with_cancellation(&clip, Some(&language), false, || false)
+                    .map(|_| ())
+            })
+            .await
+            .map_err(|e| format!("Transcription task failed: {}", e))?
+        }
+        engine => Err(format!(
+            "Inference isn't measured for the '{}' engine (cloud latency varies per request)",
+            engine
+        )),
+    }
+}
+
+/// Clipboard contents saved before the check and put back when dropped, so
+/// every exit path leaves the clipboard as it was
+struct ClipboardRestore {
+    clipboard: arboard::Clipboard,
+    previous: Option<PreviousClipboard>,
+}
+
+enum PreviousClipboard {
+    Text(String),
+    Image(arboard::ImageData<'static>),
+    Empty,
+}
+
+impl ClipboardRestore {
+    fn save(mut clipboard: arboard::Clipboard) -> Self {
+        let previous = match clipboard.get_text() {
+            Ok(text) => PreviousClipboard::Text(text),
+            Err(_) => match clipboard.get_image() {
+                Ok(image) => PreviousClipboard::Image(image),
+                Err(_) => PreviousClipboard::Empty,
+            },
+        };
+        Self {
+            clipboard,
+            previous: Some(previous),
+        }
+    }
+}
+
+impl Drop for ClipboardRestore {
+    fn drop(&mut self) {
+        let restored = match self.previous.take() {
+            Some(PreviousClipboard::Text(text)) => self.clipboard.set_text(text),
+            Some(PreviousClipboard::Image(image)) => self.clipboard.set_image(image),
+            Some(PreviousClipboard::Empty) => self.clipboard.clear(),
+            None => Ok(()),
+        };
+        if let Err(e) = restored {
+            log::warn!("Failed to restore clipboard after latency check: {}", e);
+        }
+    }
+}
+
+/// Put text on the clipboard and restore what was there, as a paste does
+fn measure_injection() -> Result<u64, String> {
+    let (result, ms) = timed(|| {
+        let clipboard = arboard::Clipboard::new()
+            .map_err(|e| format!("Failed to initialize clipboard: {}", e))?;
+        let mut guard = ClipboardRestore::save(clipboard);
+        guard
+            .clipboard
+            .set_text("voicetypr latency check")
+            .map_err(|e| format!("Failed to set clipboard: {}", e))
+        // `guard` restores the previous contents here, inside the timing
+    });
+    result.map(|_| ms)
+}
+
+/// Write the synthetic clip to `clip`, then preprocess and transcribe it
+async fn measure_clip_stages(
+    app: &AppHandle,
+    config: &crate::commands::audio::RecordingConfig,
+    clip: &Path,
+    breakdown: &mut LatencyBreakdown,
+) -> Result<(), String> {
+    let samples = synthetic_clip();
+    let (written, ms) = timed(|| write_clip(clip, &samples));
+    written?;
+    breakdown.capture_ms = Some(ms);
+
+    let clip_for_preprocess = clip.to_path_buf();
+    let config_for_preprocess = config.clone();
+    let (preprocessed, ms) = tauri::async_runtime::spawn_blocking(move || {
+        timed(|| preprocess(&clip_for_preprocess, &config_for_preprocess))
+    })
+    .await
+    .map_err(|e| format!("Preprocessing task failed: {}", e))?;
+    match preprocessed {
+        Ok(()) => breakdown.preprocessing_ms = Some(ms),
+        Err(e) => breakdown.notes.push(format!("preprocessing: {}", e)),
+    }
+
+    let inference_start = Instant::now();
+    match run_inference(app, config, clip.to_path_buf()).await {
+        Ok(()) => breakdown.inference_ms = Some(inference_start.elapsed().as_millis() as u64),
+        Err(e) => breakdown.notes.push(format!("inference: {}", e)),
+    }
+    Ok(())
+}
+
+/// Run a synthetic clip through each stage of a dictation and report where
+/// the time goes. Emits `latency-measured` with the breakdown.
+#[tauri::command]
+pub async fn measure_latency(app: AppHandle) -> Result<LatencyBreakdown, String> {
+    if crate::get_recording_state(&app) != crate::RecordingState::Idle {
+        return Err("Cannot measure latency while recording or transcribing".to_string());
+    }
+    let config = crate::commands::audio::RecordingConfig::load_from_store(&app).await?;
+    let selected_microphone = crate::commands::settings::get_settings(app.clone())
+        .await?
+        .selected_microphone;
+
+    let started = Instant::now();
+    let mut breakdown = LatencyBreakdown::default();
+
+    match tauri::async_runtime::spawn_blocking(move || measure_device_open(selected_microphone))
+        .await
+        .map_err(|e| format!("Device check failed: {}", e))?
+    {
+        Ok(ms) => breakdown.device_open_ms = Some(ms),
+        Err(e) => breakdown.notes.push(format!("device_open: {}", e)),
+    }
+
+    let clip = std::env::temp_dir().join(format!(
+        "voicetypr-latency-{}.wav",
+        chrono::Utc::now().timestamp_millis()
+    ));
+    let clip_result = measure_clip_stages(&app, &config, &clip, &mut breakdown).await;
+    let _ = std::fs::remove_file(&clip);
+    clip_result?;
+
+    match tauri::async_runtime::spawn_blocking(measure_injection)
+        .await
+        .map_err(|e| format!("Clipboard check failed: {}", e))?
+    {
+        Ok(ms) => breakdown.injection_ms = Some(ms),
+        Err(e) => breakdown.notes.push(format!("injection: {}", e)),
+    }
+
+    let breakdown = breakdown.finish(started.elapsed().as_millis() as u64);
+    log::info!(
+        "Latency: device_open={:?} capture={:?} preprocessing={:?} inference={:?} injection={:?} total={}ms bottleneck={:?}",
+        breakdown.device_open_ms,
+        breakdown.capture_ms,
+        breakdown.preprocessing_ms,
+        breakdown.inference_ms,
+        breakdown.injection_ms,
+        breakdown.total_ms,
+        breakdown.bottleneck
+    );
+    if let Err(e) = app.emit("latency-measured", &breakdown) {
+        log::warn!("Failed to emit latency-measured: {}", e);
+    }
+    Ok(breakdown)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finish_sums_measured_stages_and_finds_bottleneck() {
+        let breakdown = LatencyBreakdown {
+            device_open_ms: Some(40),
+            capture_ms: Some(5),
+            preprocessing_ms: None,
+            inference_ms: Some(900),
+            injection_ms: Some(12),
+            ..Default::default()
+        };
+        // Time between stages counts toward the total
+        let finished = breakdown.clone().finish(1000);
+        assert_eq!(finished.total_ms, 1000);
+        assert_eq!(finished.bottleneck.as_deref(), Some("inference"));
+        assert_eq!(breakdown.finish(0).total_ms, 957);
+
+        assert_eq!(LatencyBreakdown::default().finish(0).bottleneck, None);
+    }
+}
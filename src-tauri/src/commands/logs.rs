@@ -1,13 +1,59 @@
-use chrono::{Local, NaiveDate};
+use crate::commands::error::CommandError;
+use crate::utils::log_format::{meets_level, parse_log_line, LogEntry};
+use chrono::{DateTime, Local, NaiveDate};
 use std::fs;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
 use tauri::Manager;
 
-#[tauri::command]
-pub async fn clear_old_logs(app: tauri::AppHandle, days_to_keep: u32) -> Result<u32, String> {
-    let log_dir = app
-        .path()
+// Upper bound on bytes returned by a single incremental read
+const MAX_INCREMENTAL_READ_BYTES: u64 = 1024 * 1024;
+
+pub(crate) fn log_directory(app: &tauri::AppHandle) -> Result<std::path::PathBuf, CommandError> {
+    app.path()
         .app_log_dir()
-        .map_err(|e| format!("Failed to get log directory: {}", e))?;
+        .map_err(|e| CommandError::Internal(format!("Failed to get log directory: {}", e)))
+}
+
+/// A log file's name date and modification date may legitimately differ by a
+/// day (a session running past midnight); beyond this one of them is wrong
+const MAX_LOG_DATE_SKEW_DAYS: i64 = 1;
+
+/// Files or entries dated further ahead of the system clock than this are
+/// flagged as coming from the future
+const FUTURE_TOLERANCE_MINUTES: i64 = 5;
+
+/// Date from a `voicetypr-YYYY-MM-DD.log` file name
+fn log_file_date(file_name: &str) -> Option<NaiveDate> {
+    let date_str = file_name
+        .strip_prefix("voicetypr-")
+        .and_then(|s| s.strip_suffix(".log"))?;
+    NaiveDate::parse_from_str(date_str, "%Y-%m-%d").ok()
+}
+
+fn modified_local(path: &Path) -> Option<DateTime<Local>> {
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .map(DateTime::<Local>::from)
+}
+
+/// The date retention should use for a log file. A session can outlive the
+/// day its log is named for, but a file can't be last written before that
+/// day; when the two disagree by more than a day the clock was wrong when one
+/// of them was recorded, and the modification date is the safer one.
+pub fn effective_log_date(name_date: NaiveDate, modified: Option<NaiveDate>) -> NaiveDate {
+    match modified {
+        Some(modified) if (name_date - modified).num_days().abs() > MAX_LOG_DATE_SKEW_DAYS => {
+            modified
+        }
+        _ => name_date,
+    }
+}
+
+#[tauri::command]
+pub async fn clear_old_logs(app: tauri::AppHandle, days_to_keep: u32) -> Result<u32, CommandError> {
+    let log_dir = log_directory(&app)?;
 
     if !log_dir.exists() {
         return Ok(0);
@@ -17,10 +63,10 @@ pub async fn clear_old_logs(app: tauri::AppHandle, days_to_keep: u32) -> Result<
     let mut deleted_count = 0;
 
     let entries =
-        fs::read_dir(&log_dir).map_err(|e| format!("Failed to read log directory: {}", e))?;
+        fs::read_dir(&log_dir).map_err(|e| CommandError::io("Failed to read log directory", e))?;
 
     for entry in entries {
-        let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+        let entry = entry.map_err(|e| CommandError::io("Failed to read directory entry", e))?;
         let path = entry.path();
 
         if path.is_file() {
@@ -30,19 +76,21 @@ pub async fn clear_old_logs(app: tauri::AppHandle, days_to_keep: u32) -> Result<
                 .unwrap_or("")
                 .to_string();
 
-            if file_name.starts_with("voicetypr-") && file_name.ends_with(".log") {
-                let date_str = file_name
-                    .strip_prefix("voicetypr-")
-                    .and_then(|s| s.strip_suffix(".log"))
-                    .unwrap_or("");
-
-                if let Ok(file_date) = NaiveDate::parse_from_str(date_str, "%Y-%m-%d") {
-                    if file_date < cutoff_date {
-                        fs::remove_file(&path)
-                            .map_err(|e| format!("Failed to delete log file: {}", e))?;
-                        deleted_count += 1;
-                        log::info!("Deleted old log file: {}", file_name);
-                    }
+            if let Some(name_date) = log_file_date(&file_name) {
+                let modified = modified_local(&path).map(|m| m.date_naive());
+                let file_date = effective_log_date(name_date, modified);
+                if file_date != name_date {
+                    log::warn!(
+                        "Log file {} was last modified {:?}; using that date for cleanup",
+                        file_name,
+                        modified
+                    );
+                }
+                if file_date < cutoff_date {
+                    fs::remove_file(&path)
+                        .map_err(|e| CommandError::io("Failed to delete log file", e))?;
+                    deleted_count += 1;
+                    log::info!("Deleted old log file: {}", file_name);
                 }
             }
         }
@@ -51,25 +99,339 @@ pub async fn clear_old_logs(app: tauri::AppHandle, days_to_keep: u32) -> Result<
     Ok(deleted_count)
 }
 
+/// A log file whose name date and modification date disagree
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+pub struct LogDateMismatch {
+    pub file: String,
+    pub name_date: NaiveDate,
+    pub modified: String,
+    /// Name date minus modification date
+    pub skew_days: i64,
+}
+
+/// Result of `check_time_consistency`
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct TimeReport {
+    /// System time, local with offset
+    pub system_time: String,
+    pub utc_offset_minutes: i32,
+    pub log_files_checked: u32,
+    pub log_date_mismatches: Vec<LogDateMismatch>,
+    /// Log files modified after the current system time
+    pub future_log_files: Vec<String>,
+    /// History entries timestamped after the current system time
+    pub future_transcriptions: u32,
+    pub newest_transcription: Option<String>,
+    /// Human-readable problems; empty when everything agrees
+    pub issues: Vec<String>,
+}
+
+/// Compare log file names, their modification times and history timestamps
+/// against the system clock and report anything that suggests the clock or
+/// timezone is (or was) wrong
+pub fn build_time_report(
+    now: DateTime<Local>,
+    log_files: &[(String, Option<DateTime<Local>>)],
+    transcription_keys: &[String],
+) -> TimeReport {
+    let tolerance = chrono::Duration::minutes(FUTURE_TOLERANCE_MINUTES);
+    let mut report = TimeReport {
+        system_time: now.to_rfc3339(),
+        utc_offset_minutes: now.offset().local_minus_utc() / 60,
+        log_files_checked: 0,
+        log_date_mismatches: Vec::new(),
+        future_log_files: Vec::new(),
+        future_transcriptions: 0,
+        newest_transcription: None,
+        issues: Vec::new(),
+    };
+
+    for (file, modified) in log_files {
+        let Some(name_date) = log_file_date(file) else {
+            continue;
+        };
+        report.log_files_checked += 1;
+        if name_date > now.date_naive() + chrono::Duration::days(MAX_LOG_DATE_SKEW_DAYS) {
+            report.future_log_files.push(file.clone());
+        }
+        let Some(modified) = modified else {
+            continue;
+        };
+        if *modified > now + tolerance && !report.future_log_files.contains(file) {
+            report.future_log_files.push(file.clone());
+        }
+        if effective_log_date(name_date, Some(modified.date_naive())) != name_date {
+            report.log_date_mismatches.push(LogDateMismatch {
+                file: file.clone(),
+                name_date,
+                modified: modified.to_rfc3339(),
+                skew_days: (name_date - modified.date_naive()).num_days(),
+            });
+        }
+    }
+
+    let timestamps: Vec<DateTime<chrono::Utc>> = transcription_keys
+        .iter()
+        .filter_map(|key| DateTime::parse_from_rfc3339(key).ok())
+        .map(|dt| dt.with_timezone(&chrono::Utc))
+        .collect();
+    report.future_transcriptions = timestamps
+        .iter()
+        .filter(|ts| **ts > now.with_timezone(&chrono::Utc) + tolerance)
+        .count() as u32;
+    report.newest_transcription = timestamps.iter().max().map(|ts| ts.to_rfc3339());
+
+    if !report.future_log_files.is_empty() {
+        report.issues.push(format!(
+            "{} log file(s) are dated after the current system time; the clock may have gone backwards",
+            report.future_log_files.len()
+        ));
+    }
+    if !report.log_date_mismatches.is_empty() {
+        report.issues.push(format!(
+            "{} log file(s) are named for a different day than they were written; cleanup uses their modification date",
+            report.log_date_mismatches.len()
+        ));
+    }
+    if report.future_transcriptions > 0 {
+        report.issues.push(format!(
+            "{} transcription(s) are timestamped in the future; history order and date ranges may be off",
+            report.future_transcriptions
+        ));
+    }
+    report
+}
+
+/// Check the system clock and timezone against log file dates and
+/// transcription timestamps
 #[tauri::command]
-pub async fn get_log_directory(app: tauri::AppHandle) -> Result<String, String> {
-    app.path()
-        .app_log_dir()
-        .map(|p| p.to_string_lossy().to_string())
-        .map_err(|e| format!("Failed to get log directory: {}", e))
+pub async fn check_time_consistency(app: tauri::AppHandle) -> Result<TimeReport, CommandError> {
+    let log_dir = log_directory(&app)?;
+    let log_files: Vec<(String, Option<DateTime<Local>>)> = match fs::read_dir(&log_dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file())
+            .filter_map(|path| {
+                let name = path.file_name()?.to_str()?.to_string();
+                Some((name, modified_local(&path)))
+            })
+            .collect(),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => return Err(CommandError::io("Failed to read log directory", e)),
+    };
+
+    let transcription_keys = crate::utils::data_dir::transcriptions_store(&app)
+        .map(|store| store.keys())
+        .unwrap_or_default();
+
+    let report = build_time_report(Local::now(), &log_files, &transcription_keys);
+    for issue in &report.issues {
+        log::warn!("Time consistency: {}", issue);
+    }
+    Ok(report)
 }
 
+/// Run the scheduled log/recording cleanup immediately. Returns `None` when a
+/// run is already in progress.
 #[tauri::command]
-pub async fn open_logs_folder(app: tauri::AppHandle) -> Result<(), String> {
-    let log_dir = app
-        .path()
-        .app_log_dir()
-        .map_err(|e| format!("Failed to get log directory: {}", e))?;
+pub async fn run_auto_cleanup(
+    app: tauri::AppHandle,
+) -> Result<Option<crate::utils::auto_cleanup::AutoCleanupReport>, CommandError> {
+    let scheduler = app.state::<crate::utils::auto_cleanup::AutoCleanupScheduler>();
+    Ok(scheduler.run_now(&app).await)
+}
+
+/// Read complete lines appended to `path` after `offset`.
+///
+/// Returns the new lines and the offset to pass on the next call. A trailing
+/// partial line is left for the next read, unless it fills the whole read
+/// window: then it is returned in pieces so tailing never stalls on it. If the
+/// file shrank (truncated or rotated) reading restarts from the beginning.
+pub fn read_lines_since(path: &Path, offset: u64) -> Result<(Vec<String>, u64), String> {
+    let mut file = match fs::File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok((Vec::new(), 0)),
+        Err(e) => return Err(format!("Failed to open log file: {}", e)),
+    };
+
+    let len = file
+        .metadata()
+        .map_err(|e| format!("Failed to read log metadata: {}", e))?
+        .len();
+
+    let start = if len < offset {
+        log::debug!("Log file shrank ({} < {}), resetting offset", len, offset);
+        0
+    } else {
+        offset
+    };
+
+    file.seek(SeekFrom::Start(start))
+        .map_err(|e| format!("Failed to seek log file: {}", e))?;
+
+    let mut buf = Vec::new();
+    file.take(MAX_INCREMENTAL_READ_BYTES)
+        .read_to_end(&mut buf)
+        .map_err(|e| format!("Failed to read log file: {}", e))?;
+
+    // Only consume up to the last newline so partial lines aren't split
+    let consumed = match buf.iter().rposition(|&b| b == b'\n') {
+        Some(pos) => pos + 1,
+        None if buf.len() as u64 == MAX_INCREMENTAL_READ_BYTES => buf.len(),
+        None => 0,
+    };
+
+    let lines = String::from_utf8_lossy(&buf[..consumed])
+        .lines()
+        .map(|line| line.trim_end_matches('\r').to_string())
+        .collect();
+
+    Ok((lines, start + consumed as u64))
+}
+
+/// Incrementally read the log for `date`, starting at `byte_offset`
+#[tauri::command]
+pub async fn read_log_since(
+    app: tauri::AppHandle,
+    date: NaiveDate,
+    byte_offset: u64,
+) -> Result<(Vec<String>, u64), CommandError> {
+    let path = log_directory(&app)?.join(format!("voicetypr-{}.log", date.format("%Y-%m-%d")));
+
+    tokio::task::spawn_blocking(move || read_lines_since(&path, byte_offset))
+        .await
+        .map_err(|e| CommandError::Internal(format!("Task failed: {}", e)))?
+        .map_err(CommandError::Io)
+}
+
+/// Parse log text written in either format. Lines that aren't entries on
+/// their own (continuations of multi-line messages) are appended to the
+/// previous entry.
+pub fn parse_log_entries(contents: &str) -> Vec<LogEntry> {
+    let mut entries: Vec<LogEntry> = Vec::new();
+    for line in contents.lines() {
+        match parse_log_line(line) {
+            Some(entry) => entries.push(entry),
+            None => {
+                if let Some(last) = entries.last_mut() {
+                    last.msg.push('\n');
+                    last.msg.push_str(line.trim_end_matches('\r'));
+                }
+            }
+        }
+    }
+    entries
+}
+
+fn read_log_entries(path: &Path) -> Result<Vec<LogEntry>, String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(parse_log_entries(&contents)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(format!("Failed to read log file: {}", e)),
+    }
+}
+
+/// Entries from the log for `date` at `level` or above whose message or
+/// target contains `query`. Returns the most recent `limit` matches.
+#[tauri::command]
+pub async fn read_log_filtered(
+    app: tauri::AppHandle,
+    date: NaiveDate,
+    level: Option<String>,
+    query: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<LogEntry>, CommandError> {
+    let path = log_directory(&app)?.join(format!("voicetypr-{}.log", date.format("%Y-%m-%d")));
+    let query = query.map(|q| q.to_lowercase()).filter(|q| !q.is_empty());
+
+    let entries = tokio::task::spawn_blocking(move || read_log_entries(&path))
+        .await
+        .map_err(|e| CommandError::Internal(format!("Task failed: {}", e)))?
+        .map_err(CommandError::Io)?;
+
+    let mut matches: Vec<LogEntry> = entries
+        .into_iter()
+        .filter(|entry| level.as_deref().is_none_or(|l| meets_level(entry, l)))
+        .filter(|entry| {
+            query.as_deref().is_none_or(|q| {
+                entry.msg.to_lowercase().contains(q) || entry.target.to_lowercase().contains(q)
+            })
+        })
+        .collect();
+    if let Some(limit) = limit {
+        let skip = matches.len().saturating_sub(limit);
+        matches.drain(..skip);
+    }
+    Ok(matches)
+}
+
+/// The last `lines` entries from the log for `date`
+#[tauri::command]
+pub async fn tail_log(
+    app: tauri::AppHandle,
+    date: NaiveDate,
+    lines: usize,
+) -> Result<Vec<LogEntry>, CommandError> {
+    read_log_filtered(app, date, None, None, Some(lines)).await
+}
+
+/// A crash report left by the panic hook, with the file name to dismiss it by
+#[derive(serde::Serialize)]
+pub struct PendingCrashReport {
+    pub file_name: String,
+    pub path: String,
+    #[serde(flatten)]
+    pub report: crate::utils::crash_report::CrashReport,
+}
+
+/// Crash reports from previous runs, oldest first. They stay until dismissed,
+/// so the user can decide whether to share them.
+#[tauri::command]
+pub async fn get_pending_crash_reports(
+    app: tauri::AppHandle,
+) -> Result<Vec<PendingCrashReport>, CommandError> {
+    let log_dir = log_directory(&app)?;
+    Ok(crate::utils::crash_report::pending_crash_reports(&log_dir)
+        .into_iter()
+        .map(|(file_name, report)| PendingCrashReport {
+            path: log_dir.join(&file_name).to_string_lossy().to_string(),
+            file_name,
+            report,
+        })
+        .collect())
+}
+
+/// Delete a crash report once the user has shared or declined it
+#[tauri::command]
+pub async fn dismiss_crash_report(
+    app: tauri::AppHandle,
+    file_name: String,
+) -> Result<(), CommandError> {
+    let is_plain_name = Path::new(&file_name).file_name() == Some(std::ffi::OsStr::new(&file_name));
+    if !is_plain_name || !crate::utils::crash_report::is_crash_report(&file_name) {
+        return Err(CommandError::InvalidInput(format!(
+            "Not a crash report: {}",
+            file_name
+        )));
+    }
+    fs::remove_file(log_directory(&app)?.join(&file_name))
+        .map_err(|e| CommandError::io("Failed to delete crash report", e))
+}
+
+#[tauri::command]
+pub async fn get_log_directory(app: tauri::AppHandle) -> Result<String, CommandError> {
+    log_directory(&app).map(|p| p.to_string_lossy().to_string())
+}
+
+#[tauri::command]
+pub async fn open_logs_folder(app: tauri::AppHandle) -> Result<(), CommandError> {
+    let log_dir = log_directory(&app)?;
 
     // Create directory if it doesn't exist
     if !log_dir.exists() {
         std::fs::create_dir_all(&log_dir)
-            .map_err(|e| format!("Failed to create log directory: {}", e))?;
+            .map_err(|e| CommandError::io("Failed to create log directory", e))?;
     }
 
     // Open the directory using the system's file manager
@@ -78,7 +440,7 @@ pub async fn open_logs_folder(app: tauri::AppHandle) -> Result<(), String> {
         std::process::Command::new("open")
             .arg(&log_dir)
             .spawn()
-            .map_err(|e| format!("Failed to open folder: {}", e))?;
+            .map_err(|e| CommandError::io("Failed to open folder", e))?;
     }
 
     #[cfg(target_os = "windows")]
@@ -90,7 +452,7 @@ pub async fn open_logs_folder(app: tauri::AppHandle) -> Result<(), String> {
             .arg(&log_dir)
             .creation_flags(CREATE_NO_WINDOW)
             .spawn()
-            .map_err(|e| format!("Failed to open folder: {}", e))?;
+            .map_err(|e| CommandError::io("Failed to open folder", e))?;
     }
 
     #[cfg(target_os = "linux")]
@@ -98,8 +460,136 @@ pub async fn open_logs_folder(app: tauri::AppHandle) -> Result<(), String> {
         std::process::Command::new("xdg-open")
             .arg(&log_dir)
             .spawn()
-            .map_err(|e| format!("Failed to open folder: {}", e))?;
+            .map_err(|e| CommandError::io("Failed to open folder", e))?;
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_read_lines_since_returns_only_new_complete_lines() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("voicetypr-2024-01-01.log");
+        fs::write(&path, "first\nsecond\npart").unwrap();
+
+        let (lines, offset) = read_lines_since(&path, 0).unwrap();
+        assert_eq!(lines, vec!["first", "second"]);
+        assert_eq!(offset, 13);
+
+        let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"ial\nthird\n").unwrap();
+
+        let (lines, offset) = read_lines_since(&path, offset).unwrap();
+        assert_eq!(lines, vec!["partial", "third"]);
+        assert_eq!(offset, fs::metadata(&path).unwrap().len());
+    }
+
+    #[test]
+    fn test_read_lines_since_splits_line_longer_than_window() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("voicetypr-2024-01-01.log");
+        let long = "x".repeat(MAX_INCREMENTAL_READ_BYTES as usize + 10);
+        fs::write(&path, format!("{}\nnext\n", long)).unwrap();
+
+        let (lines, offset) = read_lines_since(&path, 0).unwrap();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].len(), MAX_INCREMENTAL_READ_BYTES as usize);
+        assert_eq!(offset, MAX_INCREMENTAL_READ_BYTES);
+
+        let (lines, offset) = read_lines_since(&path, offset).unwrap();
+        assert_eq!(lines, vec!["x".repeat(10), "next".to_string()]);
+        assert_eq!(offset, fs::metadata(&path).unwrap().len());
+    }
+
+    #[test]
+    fn test_read_lines_since_resets_after_truncation() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("voicetypr-2024-01-01.log");
+        fs::write(&path, "rotated\n").unwrap();
+
+        let (lines, offset) = read_lines_since(&path, 500).unwrap();
+        assert_eq!(lines, vec!["rotated"]);
+        assert_eq!(offset, 8);
+    }
+
+    #[test]
+    fn test_read_lines_since_missing_file() {
+        let dir = TempDir::new().unwrap();
+        let (lines, offset) = read_lines_since(&dir.path().join("missing.log"), 42).unwrap();
+        assert!(lines.is_empty());
+        assert_eq!(offset, 0);
+    }
+
+    #[test]
+    fn test_parse_log_entries_handles_mixed_formats_and_continuations() {
+        let contents = "[2024-01-01][12:00:00][INFO][voicetypr_lib] Started\n\
+            {\"ts\":\"2024-01-01T12:00:01.000Z\",\"level\":\"ERROR\",\"target\":\"voicetypr_lib\",\"msg\":\"Failed\"}\n\
+            [2024-01-01][12:00:02][WARN][voicetypr_lib] Stack:\n\
+            at frame one\n";
+        let entries = parse_log_entries(contents);
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].msg, "Started");
+        assert_eq!(entries[1].level, "ERROR");
+        assert_eq!(entries[2].msg, "Stack:\nat frame one");
+    }
+
+    #[test]
+    fn test_effective_log_date_falls_back_to_mtime_when_implausible() {
+        let date = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        // Session running past midnight
+        assert_eq!(
+            effective_log_date(date("2024-01-01"), Some(date("2024-01-02"))),
+            date("2024-01-01")
+        );
+        // Named in the future by a skewed clock
+        assert_eq!(
+            effective_log_date(date("2030-01-01"), Some(date("2024-01-05"))),
+            date("2024-01-05")
+        );
+        assert_eq!(
+            effective_log_date(date("2024-01-01"), None),
+            date("2024-01-01")
+        );
+    }
+
+    #[test]
+    fn test_time_report_flags_future_files_and_entries() {
+        let now = DateTime::parse_from_rfc3339("2024-06-01T12:00:00+00:00")
+            .unwrap()
+            .with_timezone(&Local);
+        let modified = |s: &str| {
+            Some(
+                DateTime::parse_from_rfc3339(s)
+                    .unwrap()
+                    .with_timezone(&Local),
+            )
+        };
+        let logs = vec![
+            (
+                "voicetypr-2024-06-01.log".to_string(),
+                modified("2024-06-01T11:00:00+00:00"),
+            ),
+            (
+                "voicetypr-2024-07-15.log".to_string(),
+                modified("2024-07-15T09:00:00+00:00"),
+            ),
+            ("other.txt".to_string(), None),
+        ];
+        let keys = vec![
+            "2024-05-31T10:00:00Z".to_string(),
+            "2025-01-01T00:00:00Z".to_string(),
+        ];
+
+        let report = build_time_report(now, &logs, &keys);
+        assert_eq!(report.log_files_checked, 2);
+        assert_eq!(report.future_log_files, vec!["voicetypr-2024-07-15.log"]);
+        assert_eq!(report.future_transcriptions, 1);
+        assert_eq!(report.issues.len(), 2);
+    }
+}
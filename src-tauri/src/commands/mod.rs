@@ -3,13 +3,21 @@ pub mod audio;
 pub mod clipboard;
 pub mod debug;
 pub mod device;
+pub mod dropped;
+pub mod error;
+#[cfg(feature = "test-fixtures")]
+pub mod fixtures;
 pub mod key_normalizer;
 pub mod keyring;
+pub mod latency;
 pub mod license;
 pub mod logs;
 pub mod model;
 pub mod permissions;
+pub mod post_processing;
 pub mod reset;
+pub mod segments;
+pub mod self_test;
 pub mod settings;
 pub mod stt;
 pub mod text;
@@ -1,12 +1,15 @@
+use crate::commands::error::CommandError;
 use crate::commands::license::check_license_status_internal;
 use crate::emit_to_all;
 use crate::license::LicenseState;
 use crate::parakeet::{ParakeetManager, ParakeetModelStatus};
 use crate::secure_store;
+use crate::utils::download_queue::{DownloadQueue, DownloadQueueItem, DownloadStatus};
 use crate::utils::onboarding_logger;
 #[cfg(debug_assertions)]
 use crate::utils::system_monitor;
 use crate::whisper::manager::{ModelInfo, WhisperManager};
+use crate::whisper::params::{WhisperParams, WHISPER_PARAMS_KEY};
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex as StdMutex};
@@ -14,6 +17,9 @@ use std::time::Instant;
 use tauri::async_runtime::RwLock;
 use tauri::{AppHandle, Emitter, Manager, State};
 
+/// Extra memory kept free for the OS and the rest of the app when sizing models
+const MEMORY_HEADROOM_BYTES: u64 = 1024 * 1024 * 1024; // 1 GiB
+
 type ActiveDownloadsState<'a> = State<'a, Arc<StdMutex<HashMap<String, Arc<AtomicBool>>>>>;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -31,6 +37,53 @@ impl ModelEngine {
     }
 }
 
+fn emit_download_queue(app: &AppHandle) {
+    let queue = app.state::<DownloadQueue>().snapshot();
+    if let Err(e) = emit_to_all(app, "download-queue-updated", queue) {
+        log::warn!("Failed to emit download queue: {}", e);
+    }
+}
+
+/// Mark a finished download as failed verification and tell the UI
+fn fail_verification(
+    app: &AppHandle,
+    model_name: &str,
+    generation: u64,
+    engine: ModelEngine,
+    msg: String,
+) -> CommandError {
+    log::warn!("{}", msg);
+    app.state::<DownloadQueue>().set_status(
+        model_name,
+        generation,
+        DownloadStatus::Failed,
+        Some(msg.clone()),
+    );
+    emit_download_queue(app);
+    if let Err(emit_err) = emit_to_all(
+        app,
+        "download-error",
+        serde_json::json!({
+            "model": model_name,
+            "engine": engine.as_str(),
+            "error": msg
+        }),
+    ) {
+        log::warn!("Failed to emit download-error event: {}", emit_err);
+    }
+    CommandError::VerificationFailed(msg)
+}
+
+/// Load a whisper model into a throwaway context and drop it again, without
+/// touching the transcriber cache
+async fn try_load_whisper_model(model_path: std::path::PathBuf) -> Result<(), String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        crate::whisper::transcriber::Transcriber::new(&model_path).map(drop)
+    })
+    .await
+    .map_err(|e| format!("Model load task failed: {}", e))?
+}
+
 #[derive(Clone, Copy, Debug)]
 struct DownloadTarget {
     engine: ModelEngine,
@@ -44,7 +97,7 @@ pub async fn download_model(
     whisper_state: State<'_, RwLock<WhisperManager>>,
     parakeet_manager: State<'_, ParakeetManager>,
     active_downloads: ActiveDownloadsState<'_>,
-) -> Result<(), String> {
+) -> Result<(), CommandError> {
     let download_start = Instant::now();
 
     let download_target =
@@ -73,11 +126,26 @@ pub async fn download_model(
             }
             Err(e) => {
                 log::error!("Failed to lock active downloads for inserting: {}", e);
-                return Err("Failed to initialize download tracking".to_string());
+                return Err("Failed to initialize download tracking".into());
             }
         }
     }
 
+    // Wait behind earlier downloads; cancelling or pausing ends the wait
+    let queue = app.state::<DownloadQueue>();
+    let generation = queue.enqueue(&model_name, download_target.engine.as_str());
+    emit_download_queue(&app);
+    queue
+        .wait_for_turn(&model_name, generation, || {
+            cancel_flag.load(Ordering::Relaxed)
+                || queue.status_for(&model_name, generation) != Some(DownloadStatus::Queued)
+        })
+        .await;
+    let started = !cancel_flag.load(Ordering::Relaxed) && queue.start(&model_name, generation);
+    if started {
+        emit_download_queue(&app);
+    }
+
     let model_name_clone = model_name.clone();
 
     // Create an async-safe wrapper for progress callback
@@ -100,7 +168,13 @@ pub async fn download_model(
                 logger.log_model_download_progress(&model_name_clone, progress as u8);
             });
 
-            // Progress is already being emitted via events, no need for state storage
+            app_handle.state::<DownloadQueue>().set_progress(
+                &model_name_clone,
+                generation,
+                downloaded,
+                total,
+            );
+            emit_download_queue(&app_handle);
 
             if let Err(e) = emit_to_all(
                 &app_handle,
@@ -138,7 +212,7 @@ pub async fn download_model(
     });
 
     // Execute download (no retry - user can click download again if it fails)
-    let download_result = if cancel_flag.load(Ordering::Relaxed) {
+    let download_result = if !started {
         log::info!("Download cancelled for model: {}", model_name);
         Err("Download cancelled by user".to_string())
     } else {
@@ -201,11 +275,20 @@ pub async fn download_model(
     // Ensure progress handler completes
     let _ = progress_handle.await;
 
-    // Clean up the cancellation flag
+    if started {
+        queue.finish(&model_name);
+    }
+
+    // Clean up the cancellation flag, unless a resumed download replaced it
     {
         match active_downloads.lock() {
             Ok(mut downloads) => {
-                downloads.remove(&model_name);
+                if downloads
+                    .get(&model_name)
+                    .is_some_and(|flag| Arc::ptr_eq(flag, &cancel_flag))
+                {
+                    downloads.remove(&model_name);
+                }
             }
             Err(e) => {
                 log::warn!("Failed to lock active downloads for cleanup: {}", e);
@@ -214,10 +297,24 @@ pub async fn download_model(
         }
     }
 
+    // Paused and already resumed: the new download owns the queue entry
+    if queue.status_for(&model_name, generation).is_none() {
+        log::info!("Download for {} was replaced by a resumed one", model_name);
+        return Err(CommandError::Cancelled("Download paused".to_string()));
+    }
+
     log::info!("Processing download result for model: {}", model_name);
     match download_result {
         Err(ref e) if e.contains("cancelled") => {
+            // A paused download stays queued for resume_download
+            if queue.status_for(&model_name, generation) == Some(DownloadStatus::Paused) {
+                log::info!("Download paused for model: {}", model_name);
+                emit_download_queue(&app);
+                return Err(CommandError::Cancelled("Download paused".to_string()));
+            }
             log::info!("Download was cancelled");
+            queue.remove(&model_name, generation);
+            emit_download_queue(&app);
             // Emit download-cancelled event
             if let Err(e) = emit_to_all(
                 &app,
@@ -229,7 +326,7 @@ pub async fn download_model(
             ) {
                 log::warn!("Failed to emit download-cancelled event: {}", e);
             }
-            Err(e.clone())
+            Err(CommandError::Cancelled(e.clone()))
         }
         Ok(_) => {
             log::info!("Download completed successfully for model: {}", model_name);
@@ -250,8 +347,33 @@ pub async fn download_model(
             // Refresh/verify downloaded status
             match download_target.engine {
                 ModelEngine::Whisper => {
-                    let mut manager = whisper_state.write().await;
-                    manager.refresh_downloaded_status();
+                    let model_path = {
+                        let mut manager = whisper_state.write().await;
+                        manager.refresh_downloaded_status();
+                        manager.get_model_path(&model_name)
+                    };
+
+                    // A file can match its checksum and still fail to load
+                    // (e.g. a format the bundled whisper.cpp doesn't read)
+                    if let Some(model_path) = model_path {
+                        if let Err(e) = try_load_whisper_model(model_path).await {
+                            if let Err(delete_err) =
+                                whisper_state.write().await.delete_model_file(&model_name)
+                            {
+                                log::error!("Failed to delete unloadable model: {}", delete_err);
+                            }
+                            return Err(fail_verification(
+                                &app,
+                                &model_name,
+                                generation,
+                                download_target.engine,
+                                format!(
+                                    "Model '{}' downloaded but failed to load and was deleted: {}",
+                                    model_name, e
+                                ),
+                            ));
+                        }
+                    }
                 }
                 ModelEngine::Parakeet => {
                     // Verify Parakeet reports the requested model as downloaded
@@ -261,28 +383,23 @@ pub async fn download_model(
                         .any(|m| m.name == model_name && m.downloaded);
 
                     if !verified {
-                        let msg = format!(
-                            "Parakeet sidecar did not confirm '{}' as downloaded. Please try again.",
-                            model_name
-                        );
-                        log::warn!("{}", msg);
-                        // Emit download-error event and return Err
-                        if let Err(emit_err) = emit_to_all(
+                        return Err(fail_verification(
                             &app,
-                            "download-error",
-                            serde_json::json!({
-                                "model": model_name,
-                                "engine": download_target.engine.as_str(),
-                                "error": msg
-                            }),
-                        ) {
-                            log::warn!("Failed to emit download-error event: {}", emit_err);
-                        }
-                        return Err("verification_failed".to_string());
+                            &model_name,
+                            generation,
+                            download_target.engine,
+                            format!(
+                                "Parakeet sidecar did not confirm '{}' as downloaded. Please try again.",
+                                model_name
+                            ),
+                        ));
                     }
                 }
             }
 
+            queue.set_status(&model_name, generation, DownloadStatus::Done, None);
+            emit_download_queue(&app);
+
             // Emit success event after verification
             log::info!("Emitting model-downloaded event for {}", model_name);
             if let Err(e) = emit_to_all(
@@ -311,6 +428,14 @@ pub async fn download_model(
                 logger.log_model_download_failed(&model_name, &e);
             });
 
+            queue.set_status(
+                &model_name,
+                generation,
+                DownloadStatus::Failed,
+                Some(e.to_string()),
+            );
+            emit_download_queue(&app);
+
             // Emit download-error event
             if let Err(emit_err) = emit_to_all(
                 &app,
@@ -326,7 +451,7 @@ pub async fn download_model(
 
             // Progress tracking is event-based, no state cleanup needed
 
-            Err(e)
+            Err(e.into())
         }
     }
 }
@@ -350,6 +475,12 @@ pub struct UnifiedModelInfo {
     pub engine: String,
     pub kind: String,
     pub requires_setup: bool,
+    /// Installed whisper.cpp quantizations (e.g. "f16", "q5_0")
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub quantizations: Vec<String>,
+    /// The quantization that will be loaded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_quantization: Option<String>,
 }
 
 /// Returns status of all available speech recognition models (Whisper + Parakeet).
@@ -365,20 +496,26 @@ pub async fn get_model_status(
     whisper_state: State<'_, RwLock<WhisperManager>>,
     parakeet_manager: State<'_, ParakeetManager>,
     app: tauri::AppHandle,
-) -> Result<ModelStatusResponse, String> {
+) -> Result<ModelStatusResponse, CommandError> {
     log::info!("[GET_MODEL_STATUS] Refreshing downloaded status...");
 
-    let whisper_models_map = {
+    let mut models: Vec<UnifiedModelInfo> = {
         let mut manager = whisper_state.write().await;
         manager.refresh_downloaded_status();
-        manager.get_models_status()
+        manager
+            .get_models_status()
+            .into_iter()
+            .map(|(name, info)| {
+                let quantizations = manager.installed_quantizations(&name);
+                let active_quantization = manager.active_quantization(&name);
+                let mut model = convert_whisper_model(name, info);
+                model.quantizations = quantizations;
+                model.active_quantization = active_quantization;
+                model
+            })
+            .collect()
     };
 
-    let mut models: Vec<UnifiedModelInfo> = whisper_models_map
-        .into_iter()
-        .map(|(name, info)| convert_whisper_model(name, info))
-        .collect();
-
     let parakeet_models = parakeet_manager.list_models();
     models.extend(parakeet_models.into_iter().map(convert_parakeet_model));
 
@@ -403,7 +540,7 @@ pub async fn delete_model(
     model_name: String,
     whisper_state: State<'_, RwLock<WhisperManager>>,
     parakeet_manager: State<'_, ParakeetManager>,
-) -> Result<(), String> {
+) -> Result<(), CommandError> {
     let engine = determine_model_engine(&model_name, &whisper_state, &parakeet_manager).await?;
 
     match engine {
@@ -437,7 +574,7 @@ pub async fn delete_model(
 #[tauri::command]
 pub async fn list_downloaded_models(
     state: State<'_, RwLock<WhisperManager>>,
-) -> Result<Vec<String>, String> {
+) -> Result<Vec<String>, CommandError> {
     let manager = state.read().await;
     Ok(manager.list_downloaded_files())
 }
@@ -446,7 +583,7 @@ async fn identify_download_target(
     model_name: &str,
     whisper_state: &State<'_, RwLock<WhisperManager>>,
     parakeet_manager: &ParakeetManager,
-) -> Result<DownloadTarget, String> {
+) -> Result<DownloadTarget, CommandError> {
     let engine = determine_model_engine(model_name, whisper_state, parakeet_manager).await?;
 
     match engine {
@@ -458,10 +595,10 @@ async fn identify_download_target(
                     size_bytes: info.size,
                 })
             } else {
-                Err(format!(
+                Err(CommandError::ModelMissing(format!(
                     "Model '{}' not found in Whisper registry",
                     model_name
-                ))
+                )))
             }
         }
         ModelEngine::Parakeet => {
@@ -471,10 +608,10 @@ async fn identify_download_target(
                     size_bytes: definition.estimated_size,
                 })
             } else {
-                Err(format!(
+                Err(CommandError::ModelMissing(format!(
                     "Model '{}' not found in Parakeet registry",
                     model_name
-                ))
+                )))
             }
         }
     }
@@ -484,7 +621,7 @@ async fn determine_model_engine(
     model_name: &str,
     whisper_state: &State<'_, RwLock<WhisperManager>>,
     parakeet_manager: &ParakeetManager,
-) -> Result<ModelEngine, String> {
+) -> Result<ModelEngine, CommandError> {
     {
         let manager = whisper_state.read().await;
         if manager.get_models_status().contains_key(model_name) {
@@ -496,7 +633,10 @@ async fn determine_model_engine(
         return Ok(ModelEngine::Parakeet);
     }
 
-    Err(format!("Invalid model name: {}", model_name))
+    Err(CommandError::ModelMissing(format!(
+        "Invalid model name: {}",
+        model_name
+    )))
 }
 
 fn convert_whisper_model(name: String, info: ModelInfo) -> UnifiedModelInfo {
@@ -513,6 +653,8 @@ fn convert_whisper_model(name: String, info: ModelInfo) -> UnifiedModelInfo {
         engine: ModelEngine::Whisper.as_str().to_string(),
         kind: "local".to_string(),
         requires_setup: false,
+        quantizations: Vec::new(),
+        active_quantization: None,
     }
 }
 
@@ -530,6 +672,8 @@ fn convert_parakeet_model(status: ParakeetModelStatus) -> UnifiedModelInfo {
         engine: ModelEngine::Parakeet.as_str().to_string(),
         kind: "local".to_string(),
         requires_setup: false,
+        quantizations: Vec::new(),
+        active_quantization: None,
     }
 }
 
@@ -556,14 +700,17 @@ fn collect_cloud_models(app: &AppHandle) -> Vec<UnifiedModelInfo> {
         engine: "soniox".to_string(),
         kind: "cloud".to_string(),
         requires_setup: !has_soniox_key,
+        quantizations: Vec::new(),
+        active_quantization: None,
     }]
 }
 
 #[tauri::command]
 pub async fn cancel_download(
+    app: AppHandle,
     model_name: String,
     active_downloads: ActiveDownloadsState<'_>,
-) -> Result<(), String> {
+) -> Result<(), CommandError> {
     log::info!("Cancelling download for model: {}", model_name);
 
     // Set the cancellation flag
@@ -573,6 +720,8 @@ pub async fn cancel_download(
                 if let Some(cancel_flag) = downloads.get(&model_name) {
                     cancel_flag.store(true, Ordering::Relaxed);
                     log::info!("Set cancellation flag for model: {}", model_name);
+                    // Wake the download if it is still waiting in the queue
+                    app.state::<DownloadQueue>().notify();
                 } else {
                     log::warn!("No active download found for model: {}", model_name);
                     return Ok(()); // Not an error if download doesn't exist
@@ -580,7 +729,7 @@ pub async fn cancel_download(
             }
             Err(e) => {
                 log::error!("Failed to lock active downloads for cancellation: {}", e);
-                return Err("Failed to access download tracking".to_string());
+                return Err("Failed to access download tracking".into());
             }
         }
     }
@@ -588,12 +737,89 @@ pub async fn cancel_download(
     Ok(())
 }
 
+/// Current download queue, in order
+#[tauri::command]
+pub async fn get_download_queue(app: AppHandle) -> Result<Vec<DownloadQueueItem>, CommandError> {
+    Ok(app.state::<DownloadQueue>().snapshot())
+}
+
+/// Move a download to `position` in the queue (0 is next)
+#[tauri::command]
+pub async fn reorder_download(
+    app: AppHandle,
+    model: String,
+    position: usize,
+) -> Result<(), CommandError> {
+    app.state::<DownloadQueue>()
+        .reorder(&model, position)
+        .map_err(CommandError::NotFound)?;
+    emit_download_queue(&app);
+    Ok(())
+}
+
+/// Pause a queued or running download. Partial files are discarded, so a
+/// resumed download starts over.
+#[tauri::command]
+pub async fn pause_download(
+    app: AppHandle,
+    model_name: String,
+    active_downloads: ActiveDownloadsState<'_>,
+) -> Result<(), CommandError> {
+    let queue = app.state::<DownloadQueue>();
+    if !queue.pause(&model_name) {
+        return Err(CommandError::InvalidInput(format!(
+            "Model '{}' is not queued or downloading",
+            model_name
+        )));
+    }
+    if let Some(flag) = active_downloads
+        .lock()
+        .map_err(|_| CommandError::Internal("Failed to access download tracking".into()))?
+        .get(&model_name)
+    {
+        flag.store(true, Ordering::Relaxed);
+    }
+    log::info!("Paused download for model: {}", model_name);
+    emit_download_queue(&app);
+    Ok(())
+}
+
+/// Put a paused download back in the queue
+#[tauri::command]
+pub async fn resume_download(app: AppHandle, model_name: String) -> Result<(), CommandError> {
+    // Claimed under the queue lock, so a second resume (or the paused worker
+    // still winding down) can't start another download
+    if !app.state::<DownloadQueue>().resume(&model_name) {
+        return Err(CommandError::InvalidInput(format!(
+            "Download for '{}' is not paused",
+            model_name
+        )));
+    }
+
+    log::info!("Resuming download for model: {}", model_name);
+    let app_for_task = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let result = download_model(
+            app_for_task.clone(),
+            model_name.clone(),
+            app_for_task.state(),
+            app_for_task.state(),
+            app_for_task.state(),
+        )
+        .await;
+        if let Err(e) = result {
+            log::warn!("Resumed download for {} ended: {}", model_name, e);
+        }
+    });
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn verify_model(
     app: AppHandle,
     model_name: String,
     state: State<'_, RwLock<WhisperManager>>,
-) -> Result<(), String> {
+) -> Result<(), CommandError> {
     log::info!("Verifying model: {}", model_name);
 
     // Get model info and check if it exists
@@ -602,24 +828,27 @@ pub async fn verify_model(
         let info = manager
             .get_models_status()
             .get(&model_name)
-            .ok_or(format!("Model '{}' not found", model_name))?
+            .ok_or_else(|| CommandError::ModelMissing(format!("Model '{}' not found", model_name)))?
             .clone();
-        let path = manager
-            .get_model_path(&model_name)
-            .ok_or(format!("Model '{}' path not found", model_name))?;
+        let path = manager.get_model_path(&model_name).ok_or_else(|| {
+            CommandError::ModelMissing(format!("Model '{}' path not found", model_name))
+        })?;
         (info, path)
     };
 
     // Check if file exists
     if !model_path.exists() {
         log::warn!("Model file does not exist: {:?}", model_path);
-        return Err(format!("Model file not found: {}", model_name));
+        return Err(CommandError::ModelMissing(format!(
+            "Model file not found: {}",
+            model_name
+        )));
     }
 
     // Check file size
     let metadata = tokio::fs::metadata(&model_path)
         .await
-        .map_err(|e| format!("Cannot read model file metadata: {}", e))?;
+        .map_err(|e| CommandError::io("Cannot read model file metadata", e))?;
 
     let file_size = metadata.len();
     let expected_size = model_info.size;
@@ -647,10 +876,10 @@ pub async fn verify_model(
             manager.refresh_downloaded_status();
         }
 
-        return Err(format!(
+        return Err(CommandError::VerificationFailed(format!(
             "Model '{}' is corrupted and has been deleted. Please re-download.",
             model_name
-        ));
+        )));
     }
 
     // File looks good - mark as downloaded
@@ -669,12 +898,61 @@ pub async fn verify_model(
     Ok(())
 }
 
+/// Check that a downloaded model actually initializes by loading it once and
+/// releasing it. Returns false (with the reason logged) if loading fails.
+#[tauri::command]
+pub async fn test_load_model(
+    app: AppHandle,
+    model_name: String,
+    whisper_state: State<'_, RwLock<WhisperManager>>,
+    parakeet_manager: State<'_, ParakeetManager>,
+) -> Result<bool, CommandError> {
+    let engine = determine_model_engine(&model_name, &whisper_state, &parakeet_manager).await?;
+
+    let result = match engine {
+        ModelEngine::Whisper => {
+            let model_path = whisper_state
+                .read()
+                .await
+                .get_model_path(&model_name)
+                .filter(|path| path.exists())
+                .ok_or_else(|| {
+                    CommandError::ModelMissing(format!("Model '{}' is not downloaded", model_name))
+                })?;
+            try_load_whisper_model(model_path).await
+        }
+        ModelEngine::Parakeet => {
+            // The sidecar holds one model at a time, so the active model is
+            // reloaded on the next dictation
+            let loaded = parakeet_manager
+                .load_model(&app, &model_name)
+                .await
+                .map_err(|e| format!("{:?}", e));
+            if let Err(e) = parakeet_manager.unload_model(&app).await {
+                log::warn!("Failed to unload Parakeet after test load: {:?}", e);
+            }
+            loaded
+        }
+    };
+
+    match result {
+        Ok(()) => {
+            log::info!("Model '{}' loaded successfully", model_name);
+            Ok(true)
+        }
+        Err(e) => {
+            log::warn!("Model '{}' failed to load: {}", model_name, e);
+            Ok(false)
+        }
+    }
+}
+
 #[tauri::command]
 pub async fn preload_model(
     app: AppHandle,
     model_name: String,
     state: State<'_, RwLock<WhisperManager>>,
-) -> Result<(), String> {
+) -> Result<(), CommandError> {
     use crate::whisper::cache::TranscriberCache;
     use tauri::async_runtime::Mutex as AsyncMutex;
 
@@ -685,7 +963,9 @@ pub async fn preload_model(
         license_status.status,
         LicenseState::Expired | LicenseState::None
     ) {
-        return Err("License required to preload models".to_string());
+        return Err(CommandError::LicenseRequired(
+            "License required to preload models".to_string(),
+        ));
     }
 
     log::info!("Preloading model: {}", model_name);
@@ -693,11 +973,28 @@ pub async fn preload_model(
     // Get model path
     let model_path = {
         let manager = state.read().await;
-        manager
-            .get_model_path(&model_name)
-            .ok_or(format!("Model '{}' not found", model_name))?
+        manager.get_model_path(&model_name).ok_or_else(|| {
+            CommandError::ModelMissing(format!("Model '{}' not found", model_name))
+        })?
     };
 
+    // Refuse to load models that clearly won't fit instead of risking an OOM crash
+    let model_size = {
+        let manager = state.read().await;
+        manager.get_models_status().get(&model_name).map(|m| m.size)
+    };
+    if let Some(size) = model_size {
+        let (_, available) = read_system_memory();
+        if !model_fits_in_memory(size, available) {
+            return Err(CommandError::InsufficientMemory(format!(
+                "Not enough memory to load model '{}': needs about {} MB, {} MB available",
+                model_name,
+                estimated_model_memory(size) / (1024 * 1024),
+                available / (1024 * 1024)
+            )));
+        }
+    }
+
     // Load into cache
     let cache_state = app.state::<AsyncMutex<TranscriberCache>>();
     let mut cache = cache_state.lock().await;
@@ -709,3 +1006,580 @@ pub async fn preload_model(
 
     Ok(())
 }
+
+/// Approximate resident memory needed to run a Whisper model of the given file size
+/// (weights plus inference buffers).
+pub fn estimated_model_memory(model_size: u64) -> u64 {
+    model_size + model_size / 2
+}
+
+/// Whether a model can be loaded while leaving headroom for the rest of the system
+pub fn model_fits_in_memory(model_size: u64, available_bytes: u64) -> bool {
+    estimated_model_memory(model_size) + MEMORY_HEADROOM_BYTES <= available_bytes
+}
+
+/// Pick the largest model that fits in the available memory, falling back to the
+/// smallest model when none fit.
+pub fn pick_model_for_memory(models: &[(String, u64)], available_bytes: u64) -> Option<String> {
+    models
+        .iter()
+        .filter(|(_, size)| model_fits_in_memory(*size, available_bytes))
+        .max_by_key(|(_, size)| *size)
+        .or_else(|| models.iter().min_by_key(|(_, size)| *size))
+        .map(|(name, _)| name.clone())
+}
+
+/// Read (total, available) system memory in bytes
+fn read_system_memory() -> (u64, u64) {
+    let mut system = sysinfo::System::new();
+    system.refresh_memory();
+    (system.total_memory(), system.available_memory())
+}
+
+#[tauri::command]
+pub async fn recommend_model_for_system(app: AppHandle) -> Result<String, CommandError> {
+    let models: Vec<(String, u64)> = {
+        let state = app.state::<RwLock<WhisperManager>>();
+        let manager = state.read().await;
+        manager
+            .get_models_status()
+            .into_values()
+            .map(|m| (m.name, m.size))
+            .collect()
+    };
+
+    let (total, available) = read_system_memory();
+    let recommended = pick_model_for_memory(&models, available)
+        .ok_or_else(|| CommandError::NotFound("No models available to recommend".to_string()))?;
+
+    log::info!(
+        "Recommending model '{}' (total memory: {} MB, available: {} MB)",
+        recommended,
+        total / (1024 * 1024),
+        available / (1024 * 1024)
+    );
+
+    Ok(recommended)
+}
+
+/// Local transcription backend selectable at runtime
+#[derive(serde::Deserialize, serde::Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Backend {
+    Whisper,
+    ParakeetSidecar,
+}
+
+impl Backend {
+    /// Value stored in the `current_model_engine` setting
+    fn engine(&self) -> ModelEngine {
+        match self {
+            Backend::Whisper => ModelEngine::Whisper,
+            Backend::ParakeetSidecar => ModelEngine::Parakeet,
+        }
+    }
+
+    /// Whether the backend can run on this platform at all
+    pub fn is_supported_on_platform(&self) -> bool {
+        match self {
+            Backend::Whisper => true,
+            Backend::ParakeetSidecar => cfg!(target_os = "macos"),
+        }
+    }
+}
+
+/// Switch between Whisper and the Parakeet sidecar without restarting.
+/// Keeps the selected model if it already belongs to the new backend,
+/// otherwise picks the best downloaded one.
+#[tauri::command]
+pub async fn set_backend(app: AppHandle, backend: Backend) -> Result<(), CommandError> {
+    use crate::whisper::cache::TranscriberCache;
+    use tauri::async_runtime::Mutex as AsyncMutex;
+    use tauri_plugin_store::StoreExt;
+
+    if !backend.is_supported_on_platform() {
+        return Err(CommandError::InvalidInput(format!(
+            "{:?} is not available on this platform",
+            backend
+        )));
+    }
+
+    let recording_state = crate::get_recording_state(&app);
+    if recording_state != crate::RecordingState::Idle
+        && recording_state != crate::RecordingState::Error
+    {
+        return Err(CommandError::Busy(
+            "Cannot switch backend while recording or transcribing".to_string(),
+        ));
+    }
+
+    let store = app
+        .store("settings")
+        .map_err(|e| CommandError::Internal(e.to_string()))?;
+    let current_engine = store
+        .get("current_model_engine")
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .unwrap_or_else(|| "whisper".to_string());
+    let current_model = store
+        .get("current_model")
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .unwrap_or_default();
+
+    // Pick the model the new backend will use
+    let whisper_state = app.state::<RwLock<WhisperManager>>();
+    let parakeet_manager = app.state::<ParakeetManager>();
+    let model = match backend {
+        Backend::Whisper => {
+            let downloaded = whisper_state.read().await.get_downloaded_model_names();
+            if downloaded.contains(&current_model) {
+                Some(current_model.clone())
+            } else {
+                crate::recognition::pick_best_whisper_model(&whisper_state).await
+            }
+        }
+        Backend::ParakeetSidecar => {
+            let models = parakeet_manager.list_models();
+            if models
+                .iter()
+                .any(|m| m.downloaded && m.name == current_model)
+            {
+                Some(current_model.clone())
+            } else {
+                crate::recognition::pick_best_parakeet_model(models)
+            }
+        }
+    }
+    .ok_or_else(|| {
+        CommandError::ModelMissing(format!("No downloaded models available for {:?}", backend))
+    })?;
+
+    // Tear down the current backend
+    match current_engine.as_str() {
+        "parakeet" if backend != Backend::ParakeetSidecar => {
+            if let Err(e) = parakeet_manager.unload_model(&app).await {
+                log::warn!("Failed to unload Parakeet model: {:?}", e);
+            }
+        }
+        "whisper" if backend != Backend::Whisper => {
+            app.state::<AsyncMutex<TranscriberCache>>()
+                .lock()
+                .await
+                .clear();
+        }
+        _ => {}
+    }
+
+    // Initialize the new backend
+    match backend {
+        Backend::Whisper => {
+            let model_path = whisper_state
+                .read()
+                .await
+                .get_model_path(&model)
+                .ok_or_else(|| {
+                    CommandError::ModelMissing(format!("Model '{}' not found", model))
+                })?;
+            app.state::<AsyncMutex<TranscriberCache>>()
+                .lock()
+                .await
+                .get_or_create(&model_path)?;
+        }
+        Backend::ParakeetSidecar => {
+            parakeet_manager
+                .load_model(&app, &model)
+                .await
+                .map_err(|e| {
+                    CommandError::Internal(format!(
+                        "Failed to load Parakeet model '{}': {}",
+                        model, e
+                    ))
+                })?;
+        }
+    }
+
+    // Persist so subsequent transcriptions (and restarts) use the new backend
+    let engine = backend.engine().as_str();
+    store.set("current_model_engine", serde_json::json!(engine));
+    store.set("current_model", serde_json::json!(model));
+    store
+        .save()
+        .map_err(|e| CommandError::Internal(format!("Failed to save backend selection: {}", e)))?;
+    crate::commands::audio::invalidate_recording_config_cache(&app).await;
+
+    log::info!("Switched transcription backend to {} ({})", engine, model);
+    // Same event as a model switch from the tray, so SettingsContext reloads
+    // and a later save_settings doesn't write the old model back
+    if let Err(e) = app.emit(
+        "model-changed",
+        serde_json::json!({ "model": model, "engine": engine }),
+    ) {
+        log::warn!("Failed to emit model-changed event: {}", e);
+    }
+
+    if let Err(e) = crate::commands::settings::update_tray_menu(app.clone()).await {
+        log::warn!("Failed to refresh tray menu after backend change: {}", e);
+    }
+
+    Ok(())
+}
+
+/// Settings key holding the quantization picked per Whisper model
+pub const QUANTIZATIONS_KEY: &str = "whisper_quantizations";
+
+/// Per-model quantization choices saved by `set_active_quantization`
+pub fn stored_quantizations(app: &AppHandle) -> HashMap<String, String> {
+    use tauri_plugin_store::StoreExt;
+
+    app.store("settings")
+        .ok()
+        .and_then(|store| store.get(QUANTIZATIONS_KEY))
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default()
+}
+
+/// Choose which installed quantization of a Whisper model is loaded, e.g. a
+/// q5 file for speed or the f16 file for accuracy
+#[tauri::command]
+pub async fn set_active_quantization(
+    app: AppHandle,
+    model: String,
+    quant: String,
+) -> Result<(), CommandError> {
+    use tauri_plugin_store::StoreExt;
+
+    let recording_state = crate::get_recording_state(&app);
+    if recording_state != crate::RecordingState::Idle
+        && recording_state != crate::RecordingState::Error
+    {
+        return Err(CommandError::Busy(
+            "Cannot switch quantization while recording or transcribing".to_string(),
+        ));
+    }
+
+    // The transcriber cache is keyed by file, so the next load picks up the variant
+    app.state::<RwLock<WhisperManager>>()
+        .write()
+        .await
+        .set_active_quantization(&model, &quant)
+        .map_err(CommandError::InvalidInput)?;
+
+    let store = app
+        .store("settings")
+        .map_err(|e| CommandError::Internal(e.to_string()))?;
+    let mut quantizations = stored_quantizations(&app);
+    quantizations.insert(model.clone(), quant.clone());
+    store.set(QUANTIZATIONS_KEY, serde_json::json!(quantizations));
+    store
+        .save()
+        .map_err(|e| CommandError::Internal(format!("Failed to save quantization: {}", e)))?;
+
+    log::info!("Using {} quantization of model {}", quant, model);
+    let _ = app.emit(
+        "model-quantization-changed",
+        serde_json::json!({ "model": model, "quantization": quant }),
+    );
+    Ok(())
+}
+
+/// Approximate memory held by a loaded Whisper model
+#[derive(Clone, serde::Serialize)]
+pub struct ModelMemory {
+    pub name: String,
+    pub quantization: String,
+    pub file_size: u64,
+    /// Weights plus inference buffers, in bytes
+    pub estimated_bytes: u64,
+}
+
+/// Memory used by each Whisper model currently loaded, least recently used
+/// first. whisper.cpp doesn't report its allocations, so this is estimated
+/// from the size of the loaded file.
+#[tauri::command]
+pub async fn get_loaded_models_memory(app: AppHandle) -> Result<Vec<ModelMemory>, String> {
+    use crate::whisper::cache::TranscriberCache;
+    use tauri::async_runtime::Mutex as AsyncMutex;
+
+    let loaded = app
+        .state::<AsyncMutex<TranscriberCache>>()
+        .lock()
+        .await
+        .loaded_paths();
+    let manager = app.state::<RwLock<WhisperManager>>();
+    let manager = manager.read().await;
+
+    Ok(loaded
+        .iter()
+        .map(|path| {
+            let (name, quantization) = manager.model_for_path(path).unwrap_or_else(|| {
+                let name = path
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                (name, crate::whisper::manager::quantization_of(path))
+            });
+            let file_size = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+            ModelMemory {
+                name,
+                quantization,
+                file_size,
+                estimated_bytes: estimated_model_memory(file_size),
+            }
+        })
+        .collect())
+}
+
+/// Model files installed by `update_model`, by model name
+pub fn stored_updated_models(
+    app: &AppHandle,
+) -> HashMap<String, crate::whisper::updates::InstalledModel> {
+    use crate::whisper::updates::UPDATED_MODELS_KEY;
+    use tauri_plugin_store::StoreExt;
+
+    app.store("settings")
+        .ok()
+        .and_then(|store| store.get(UPDATED_MODELS_KEY))
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default()
+}
+
+/// Checksum each downloaded Whisper model was installed with. The manager
+/// already holds the ones saved by `update_model` in place of the built-in ones.
+async fn installed_model_checksums(app: &AppHandle) -> Vec<(String, String)> {
+    app.state::<RwLock<WhisperManager>>()
+        .read()
+        .await
+        .get_models_status()
+        .into_values()
+        .filter(|info| info.downloaded)
+        .map(|info| (info.name, info.sha256))
+        .collect()
+}
+
+/// Downloaded Whisper models with a newer file in the model manifest
+#[tauri::command]
+pub async fn check_model_updates(
+    app: AppHandle,
+) -> Result<Vec<crate::whisper::updates::ModelUpdate>, String> {
+    let manifest = crate::whisper::updates::fetch_manifest().await?;
+    let installed = installed_model_checksums(&app).await;
+    let updates = crate::whisper::updates::available_updates(&manifest, &installed);
+    log::info!("Model update check found {} update(s)", updates.len());
+    Ok(updates)
+}
+
+/// Check for model updates in the background when `auto_check_model_updates`
+/// is on, emitting `model-updates-available` if there are any
+pub fn spawn_model_update_check(app: AppHandle) {
+    use tauri_plugin_store::StoreExt;
+
+    let enabled = app
+        .store("settings")
+        .ok()
+        .and_then(|store| store.get("auto_check_model_updates"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    if !enabled {
+        return;
+    }
+    tauri::async_runtime::spawn(async move {
+        // Stay off the startup path
+        tokio::time::sleep(std::time::Duration::from_secs(30)).await;
+        match check_model_updates(app.clone()).await {
+            Ok(updates) if !updates.is_empty() => {
+                let _ = emit_to_all(&app, "model-updates-available", updates);
+            }
+            Ok(_) => {}
+            Err(e) => log::warn!("Automatic model update check failed: {}", e),
+        }
+    });
+}
+
+/// Download the manifest's newer file for a Whisper model. The current file
+/// keeps working until the new one has downloaded and verified, then is
+/// replaced.
+#[tauri::command]
+pub async fn update_model(app: AppHandle, name: String) -> Result<(), String> {
+    use crate::whisper::cache::TranscriberCache;
+    use crate::whisper::updates::{InstalledModel, UPDATED_MODELS_KEY};
+    use tauri::async_runtime::Mutex as AsyncMutex;
+    use tauri_plugin_store::StoreExt;
+
+    let (info, output_path) = {
+        let state = app.state::<RwLock<WhisperManager>>();
+        let manager = state.read().await;
+        let (info, output_path) = manager.get_model_info(&name)?;
+        if !info.downloaded {
+            return Err(format!("Model '{}' is not downloaded", name));
+        }
+        (info, output_path)
+    };
+    let manifest = crate::whisper::updates::fetch_manifest().await?;
+    let updated = manifest
+        .updated_info(&info)
+        .ok_or_else(|| format!("No update available for model '{}'", name))?;
+
+    let models_dir = output_path
+        .parent()
+        .map(|dir| dir.to_path_buf())
+        .ok_or_else(|| "Invalid model path".to_string())?;
+    let staging = output_path.with_extension("bin.update");
+    let _ = tokio::fs::remove_file(&staging).await;
+
+    log::info!("Updating model {}", name);
+    let app_for_progress = app.clone();
+    let name_for_progress = name.clone();
+    let downloaded = WhisperManager::download_model_file(
+        &updated,
+        &staging,
+        &models_dir,
+        None,
+        move |downloaded, total| {
+            let _ = emit_to_all(
+                &app_for_progress,
+                "model-update-progress",
+                serde_json::json!({
+                    "model": &name_for_progress,
+                    "downloaded": downloaded,
+                    "total": total,
+                }),
+            );
+        },
+    )
+    .await;
+    if let Err(e) = downloaded {
+        let _ = tokio::fs::remove_file(&staging).await;
+        return Err(format!("Failed to update model '{}': {}", name, e));
+    }
+
+    let recording_state = crate::get_recording_state(&app);
+    if recording_state != crate::RecordingState::Idle
+        && recording_state != crate::RecordingState::Error
+    {
+        let _ = tokio::fs::remove_file(&staging).await;
+        return Err("Cannot replace the model while recording or transcribing".to_string());
+    }
+    // Release the loaded copy before replacing its file
+    app.state::<AsyncMutex<TranscriberCache>>()
+        .lock()
+        .await
+        .clear();
+    tokio::fs::rename(&staging, &output_path)
+        .await
+        .map_err(|e| format!("Failed to replace model file: {}", e))?;
+
+    let store = app
+        .store("settings")
+        .map_err(|e| format!("Failed to access settings: {}", e))?;
+    let mut installed = stored_updated_models(&app);
+    installed.insert(
+        name.clone(),
+        InstalledModel {
+            sha256: updated.sha256.clone(),
+            size: updated.size,
+        },
+    );
+    store.set(UPDATED_MODELS_KEY, serde_json::json!(installed));
+    store
+        .save()
+        .map_err(|e| format!("Failed to save updated model: {}", e))?;
+    // Later checks and verification compare against the new file
+    app.state::<RwLock<WhisperManager>>()
+        .write()
+        .await
+        .set_updated_models(&installed);
+
+    log::info!("Model {} updated", name);
+    let _ = emit_to_all(&app, "model-updated", name);
+    Ok(())
+}
+
+/// Reorganize the models directory into the current layout version, e.g.
+/// after restoring an old install. Also runs at startup.
+#[tauri::command]
+pub async fn migrate_models_layout(
+    app: AppHandle,
+) -> Result<crate::whisper::layout::MigrationReport, String> {
+    let recording_state = crate::get_recording_state(&app);
+    if recording_state != crate::RecordingState::Idle
+        && recording_state != crate::RecordingState::Error
+    {
+        return Err("Cannot move model files while recording or transcribing".to_string());
+    }
+
+    let report = app
+        .state::<RwLock<WhisperManager>>()
+        .write()
+        .await
+        .migrate_layout();
+    log::info!(
+        "Models layout v{} -> v{}: {} moved, {} skipped, {} errors",
+        report.from_version,
+        report.to_version,
+        report.moved.len(),
+        report.skipped.len(),
+        report.errors.len()
+    );
+    Ok(report)
+}
+
+/// Decoding parameters saved by `set_whisper_params`, clamped into range
+pub fn stored_whisper_params(app: &AppHandle) -> WhisperParams {
+    use tauri_plugin_store::StoreExt;
+
+    app.store("settings")
+        .ok()
+        .and_then(|store| store.get(WHISPER_PARAMS_KEY))
+        .and_then(|value| serde_json::from_value::<WhisperParams>(value).ok())
+        .unwrap_or_default()
+        .validated()
+}
+
+/// Whisper decoding parameters used for transcription
+#[tauri::command]
+pub async fn get_whisper_params() -> Result<WhisperParams, CommandError> {
+    Ok(crate::whisper::params::active())
+}
+
+/// Save and apply Whisper decoding parameters. Out-of-range values are
+/// clamped; returns what was applied.
+#[tauri::command]
+pub async fn set_whisper_params(
+    app: AppHandle,
+    params: WhisperParams,
+) -> Result<WhisperParams, CommandError> {
+    use tauri_plugin_store::StoreExt;
+
+    let params = params.validated();
+    let store = app
+        .store("settings")
+        .map_err(|e| CommandError::Internal(e.to_string()))?;
+    store.set(WHISPER_PARAMS_KEY, serde_json::json!(params));
+    store
+        .save()
+        .map_err(|e| CommandError::Internal(format!("Failed to save Whisper params: {}", e)))?;
+
+    crate::whisper::params::set_active(params);
+    log::info!("Whisper decoding params set to {:?}", params);
+    let _ = app.emit("whisper-params-changed", params);
+    Ok(params)
+}
+
+/// Restore the default Whisper decoding parameters
+#[tauri::command]
+pub async fn reset_whisper_params(app: AppHandle) -> Result<WhisperParams, CommandError> {
+    use tauri_plugin_store::StoreExt;
+
+    let store = app
+        .store("settings")
+        .map_err(|e| CommandError::Internal(e.to_string()))?;
+    store.delete(WHISPER_PARAMS_KEY);
+    store
+        .save()
+        .map_err(|e| CommandError::Internal(format!("Failed to save Whisper params: {}", e)))?;
+
+    let params = WhisperParams::default();
+    crate::whisper::params::set_active(params);
+    log::info!("Whisper decoding params reset to defaults");
+    let _ = app.emit("whisper-params-changed", params);
+    Ok(params)
+}
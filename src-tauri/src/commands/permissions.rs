@@ -260,3 +260,125 @@ pub async fn test_automation_permission() -> Result<bool, String> {
         Ok(true)
     }
 }
+
+/// Whether an OS permission has been granted, refused, or not asked for yet
+#[derive(serde::Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum PermissionState {
+    Granted,
+    Denied,
+    Undetermined,
+}
+
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum PermissionKind {
+    Microphone,
+    Accessibility,
+}
+
+impl PermissionKind {
+    fn requested_key(&self) -> &'static str {
+        match self {
+            PermissionKind::Microphone => "permission_requested_microphone",
+            PermissionKind::Accessibility => "permission_requested_accessibility",
+        }
+    }
+}
+
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PermissionStatus {
+    pub microphone: PermissionState,
+    pub accessibility: PermissionState,
+}
+
+/// The OS only reports granted or not; a permission that isn't granted counts
+/// as denied once we've shown the prompt, and undetermined before that.
+pub fn resolve_permission_state(granted: bool, requested_before: bool) -> PermissionState {
+    if granted {
+        PermissionState::Granted
+    } else if requested_before {
+        PermissionState::Denied
+    } else {
+        PermissionState::Undetermined
+    }
+}
+
+fn was_requested(app: &tauri::AppHandle, kind: PermissionKind) -> bool {
+    use tauri_plugin_store::StoreExt;
+
+    app.store("settings")
+        .ok()
+        .and_then(|store| store.get(kind.requested_key()))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+fn mark_requested(app: &tauri::AppHandle, kind: PermissionKind) {
+    use tauri_plugin_store::StoreExt;
+
+    if let Ok(store) = app.store("settings") {
+        store.set(kind.requested_key(), serde_json::json!(true));
+        if let Err(e) = store.save() {
+            log::warn!("Failed to record permission request: {}", e);
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn check_permissions(app: tauri::AppHandle) -> Result<PermissionStatus, String> {
+    let microphone = check_microphone_permission().await?;
+    let accessibility = check_accessibility_permission().await?;
+
+    Ok(PermissionStatus {
+        microphone: resolve_permission_state(
+            microphone,
+            was_requested(&app, PermissionKind::Microphone),
+        ),
+        accessibility: resolve_permission_state(
+            accessibility,
+            was_requested(&app, PermissionKind::Accessibility),
+        ),
+    })
+}
+
+#[tauri::command]
+pub async fn request_permission(
+    app: tauri::AppHandle,
+    kind: PermissionKind,
+) -> Result<PermissionState, String> {
+    log::info!("Requesting {:?} permission", kind);
+
+    let granted = match kind {
+        PermissionKind::Microphone => request_microphone_permission(app.clone()).await?,
+        PermissionKind::Accessibility => request_accessibility_permission(app.clone()).await?,
+    };
+    mark_requested(&app, kind);
+
+    Ok(resolve_permission_state(granted, true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_permission_state() {
+        assert_eq!(
+            resolve_permission_state(true, false),
+            PermissionState::Granted
+        );
+        assert_eq!(
+            resolve_permission_state(true, true),
+            PermissionState::Granted
+        );
+        assert_eq!(
+            resolve_permission_state(false, true),
+            PermissionState::Denied
+        );
+        assert_eq!(
+            resolve_permission_state(false, false),
+            PermissionState::Undetermined
+        );
+    }
+}
@@ -0,0 +1,327 @@
+use serde_json::json;
+use std::collections::HashMap;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+use crate::post_processing::corrections::DEFAULT_MAX_DISTANCE;
+
+const DICTIONARY_KEY: &str = "correction_dictionary";
+const MAX_DISTANCE_KEY: &str = "fuzzy_correction_max_distance";
+const SNIPPETS_KEY: &str = "snippets";
+
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct CorrectionDictionary {
+    pub terms: Vec<String>,
+    pub max_distance: usize,
+}
+
+fn load_terms(app: &AppHandle) -> Result<Vec<String>, String> {
+    let store = app.store("settings").map_err(|e| e.to_string())?;
+    Ok(store
+        .get(DICTIONARY_KEY)
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default())
+}
+
+fn save_terms(app: &AppHandle, terms: &[String]) -> Result<(), String> {
+    let store = app.store("settings").map_err(|e| e.to_string())?;
+    store.set(DICTIONARY_KEY, json!(terms));
+    store
+        .save()
+        .map_err(|e| format!("Failed to save correction dictionary: {}", e))
+}
+
+#[tauri::command]
+pub async fn get_correction_dictionary(app: AppHandle) -> Result<CorrectionDictionary, String> {
+    let store = app.store("settings").map_err(|e| e.to_string())?;
+    let max_distance = store
+        .get(MAX_DISTANCE_KEY)
+        .and_then(|v| v.as_u64())
+        .map(|v| v as usize)
+        .unwrap_or(DEFAULT_MAX_DISTANCE);
+
+    Ok(CorrectionDictionary {
+        terms: load_terms(&app)?,
+        max_distance,
+    })
+}
+
+#[tauri::command]
+pub async fn add_correction_term(app: AppHandle, term: String) -> Result<Vec<String>, String> {
+    let term = term.trim().to_string();
+    if term.is_empty() {
+        return Err("Correction term cannot be empty".to_string());
+    }
+
+    let mut terms = load_terms(&app)?;
+    // Re-adding a term with different casing updates its canonical spelling
+    terms.retain(|t| !t.eq_ignore_ascii_case(&term));
+    terms.push(term.clone());
+    save_terms(&app, &terms)?;
+
+    log::info!("Added correction term: {}", term);
+    Ok(terms)
+}
+
+#[tauri::command]
+pub async fn remove_correction_term(app: AppHandle, term: String) -> Result<Vec<String>, String> {
+    let mut terms = load_terms(&app)?;
+    let before = terms.len();
+    terms.retain(|t| !t.eq_ignore_ascii_case(term.trim()));
+    if terms.len() == before {
+        return Err(format!("Correction term not found: {}", term));
+    }
+    save_terms(&app, &terms)?;
+
+    log::info!("Removed correction term: {}", term);
+    Ok(terms)
+}
+
+#[tauri::command]
+pub async fn set_correction_max_distance(
+    app: AppHandle,
+    max_distance: usize,
+) -> Result<(), String> {
+    if max_distance > 5 {
+        return Err("Maximum edit distance must be between 0 and 5".to_string());
+    }
+
+    let store = app.store("settings").map_err(|e| e.to_string())?;
+    store.set(MAX_DISTANCE_KEY, json!(max_distance));
+    store
+        .save()
+        .map_err(|e| format!("Failed to save correction settings: {}", e))
+}
+
+fn load_snippets(app: &AppHandle) -> Result<HashMap<String, String>, String> {
+    let store = app.store("settings").map_err(|e| e.to_string())?;
+    Ok(store
+        .get(SNIPPETS_KEY)
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default())
+}
+
+fn save_snippets(app: &AppHandle, snippets: &HashMap<String, String>) -> Result<(), String> {
+    let store = app.store("settings").map_err(|e| e.to_string())?;
+    store.set(SNIPPETS_KEY, json!(snippets));
+    store
+        .save()
+        .map_err(|e| format!("Failed to save snippets: {}", e))
+}
+
+/// Triggers are matched case-insensitively, so store them lowercased with single spaces
+fn normalize_trigger(trigger: &str) -> String {
+    trigger
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+#[tauri::command]
+pub async fn get_snippets(app: AppHandle) -> Result<HashMap<String, String>, String> {
+    load_snippets(&app)
+}
+
+#[tauri::command]
+pub async fn add_snippet(
+    app: AppHandle,
+    trigger: String,
+    expansion: String,
+) -> Result<HashMap<String, String>, String> {
+    let trigger = normalize_trigger(&trigger);
+    if trigger.is_empty() {
+        return Err("Snippet trigger cannot be empty".to_string());
+    }
+    if expansion.is_empty() {
+        return Err("Snippet expansion cannot be empty".to_string());
+    }
+
+    let mut snippets = load_snippets(&app)?;
+    snippets.insert(trigger.clone(), expansion);
+    save_snippets(&app, &snippets)?;
+
+    log::info!("Saved snippet: {}", trigger);
+    Ok(snippets)
+}
+
+#[tauri::command]
+pub async fn remove_snippet(
+    app: AppHandle,
+    trigger: String,
+) -> Result<HashMap<String, String>, String> {
+    let trigger = normalize_trigger(&trigger);
+    let mut snippets = load_snippets(&app)?;
+    if snippets.remove(&trigger).is_none() {
+        return Err(format!("Snippet not found: {}", trigger));
+    }
+    save_snippets(&app, &snippets)?;
+
+    log::info!("Removed snippet: {}", trigger);
+    Ok(snippets)
+}
+
+/// Current version of the exported dictionaries document
+const DICTIONARIES_VERSION: u32 = 1;
+
+/// Portable bundle of the user's personalization dictionaries
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct DictionariesDocument {
+    pub version: u32,
+    #[serde(default)]
+    pub fuzzy_corrections: Vec<String>,
+    #[serde(default)]
+    pub snippets: HashMap<String, String>,
+}
+
+#[derive(serde::Serialize, Debug, Clone, Default, PartialEq)]
+pub struct DictionariesImportSummary {
+    pub corrections_added: usize,
+    pub snippets_added: usize,
+    /// Entries already present locally; the local version is kept
+    pub skipped: usize,
+}
+
+/// Parse and validate an exported dictionaries document
+pub fn parse_dictionaries(json: &str) -> Result<DictionariesDocument, String> {
+    let document: DictionariesDocument =
+        serde_json::from_str(json).map_err(|e| format!("Invalid dictionaries file: {}", e))?;
+
+    if document.version == 0 || document.version > DICTIONARIES_VERSION {
+        return Err(format!(
+            "Unsupported dictionaries version: {}",
+            document.version
+        ));
+    }
+    if document
+        .fuzzy_corrections
+        .iter()
+        .any(|t| t.trim().is_empty())
+    {
+        return Err("Invalid dictionaries file: empty correction term".to_string());
+    }
+    if document
+        .snippets
+        .iter()
+        .any(|(trigger, expansion)| normalize_trigger(trigger).is_empty() || expansion.is_empty())
+    {
+        return Err("Invalid dictionaries file: empty snippet trigger or expansion".to_string());
+    }
+
+    Ok(document)
+}
+
+/// Merge imported entries into the local ones without overwriting anything local
+pub fn merge_dictionaries(
+    local: &mut DictionariesDocument,
+    incoming: DictionariesDocument,
+) -> DictionariesImportSummary {
+    let mut summary = DictionariesImportSummary::default();
+
+    for term in incoming.fuzzy_corrections {
+        let term = term.trim().to_string();
+        if local
+            .fuzzy_corrections
+            .iter()
+            .any(|t| t.eq_ignore_ascii_case(&term))
+        {
+            summary.skipped += 1;
+        } else {
+            local.fuzzy_corrections.push(term);
+            summary.corrections_added += 1;
+        }
+    }
+
+    for (trigger, expansion) in incoming.snippets {
+        let trigger = normalize_trigger(&trigger);
+        if local.snippets.contains_key(&trigger) {
+            summary.skipped += 1;
+        } else {
+            local.snippets.insert(trigger, expansion);
+            summary.snippets_added += 1;
+        }
+    }
+
+    summary
+}
+
+fn load_dictionaries(app: &AppHandle) -> Result<DictionariesDocument, String> {
+    Ok(DictionariesDocument {
+        version: DICTIONARIES_VERSION,
+        fuzzy_corrections: load_terms(app)?,
+        snippets: load_snippets(app)?,
+    })
+}
+
+#[tauri::command]
+pub async fn export_dictionaries(app: AppHandle) -> Result<String, String> {
+    let document = load_dictionaries(&app)?;
+    serde_json::to_string_pretty(&document)
+        .map_err(|e| format!("Failed to export dictionaries: {}", e))
+}
+
+#[tauri::command]
+pub async fn import_dictionaries(
+    app: AppHandle,
+    json: String,
+) -> Result<DictionariesImportSummary, String> {
+    let incoming = parse_dictionaries(&json)?;
+    let mut local = load_dictionaries(&app)?;
+    let summary = merge_dictionaries(&mut local, incoming);
+
+    save_terms(&app, &local.fuzzy_corrections)?;
+    save_snippets(&app, &local.snippets)?;
+
+    log::info!(
+        "Imported dictionaries: {} corrections, {} snippets added, {} skipped",
+        summary.corrections_added,
+        summary.snippets_added,
+        summary.skipped
+    );
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_dictionaries_validates_structure() {
+        let document =
+            parse_dictionaries(r#"{"version":1,"fuzzy_corrections":["Kubernetes"]}"#).unwrap();
+        assert_eq!(document.fuzzy_corrections, vec!["Kubernetes"]);
+        assert!(document.snippets.is_empty());
+
+        assert!(parse_dictionaries("not json").is_err());
+        assert!(parse_dictionaries(r#"{"version":99}"#).is_err());
+        assert!(parse_dictionaries(r#"{"version":1,"unknown":[]}"#).is_err());
+        assert!(parse_dictionaries(r#"{"version":1,"snippets":{"sig":""}}"#).is_err());
+    }
+
+    #[test]
+    fn test_merge_dictionaries_keeps_local_entries() {
+        let mut local = DictionariesDocument {
+            version: DICTIONARIES_VERSION,
+            fuzzy_corrections: vec!["PostgreSQL".to_string()],
+            snippets: HashMap::from([("sig".to_string(), "Local".to_string())]),
+        };
+        let incoming = DictionariesDocument {
+            version: DICTIONARIES_VERSION,
+            fuzzy_corrections: vec!["postgresql".to_string(), "Kubernetes".to_string()],
+            snippets: HashMap::from([
+                ("SIG".to_string(), "Remote".to_string()),
+                ("my email".to_string(), "a@b.c".to_string()),
+            ]),
+        };
+
+        let summary = merge_dictionaries(&mut local, incoming);
+
+        assert_eq!(summary.corrections_added, 1);
+        assert_eq!(summary.snippets_added, 1);
+        assert_eq!(summary.skipped, 2);
+        assert_eq!(local.fuzzy_corrections, vec!["PostgreSQL", "Kubernetes"]);
+        assert_eq!(local.snippets["sig"], "Local");
+        assert_eq!(local.snippets["my email"], "a@b.c");
+    }
+}
@@ -1,28 +1,447 @@
+use crate::commands::error::CommandError;
 use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_store::StoreExt;
 
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, Clone)]
 pub struct ResetResult {
+    /// True when nothing in `errors` failed; warnings don't affect it
     pub success: bool,
+    /// Steps that truly failed (e.g. data that couldn't be deleted)
     pub errors: Vec<String>,
+    /// Best-effort steps that were skipped or cancelled (e.g. admin prompt dismissed)
+    pub warnings: Vec<String>,
     pub cleared_items: Vec<String>,
+    /// Report written by `reset_app_data` when asked to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report_path: Option<String>,
+}
+
+/// A reset's outcome as written to `reset-report-*.json` for support
+#[derive(serde::Serialize)]
+pub struct ResetReport<'a> {
+    pub created_at: String,
+    pub keep_personalization: bool,
+    pub trim_older_than_days: Option<u32>,
+    /// How much smaller the reset targets got
+    pub bytes_freed: u64,
+    #[serde(flatten)]
+    pub result: &'a ResetResult,
+}
+
+/// Write `report` to a timestamped file in `dir`
+pub fn write_reset_report(dir: &Path, report: &ResetReport) -> Result<PathBuf, String> {
+    fs::create_dir_all(dir).map_err(|e| format!("Failed to create log directory: {}", e))?;
+    let path = dir.join(format!(
+        "reset-report-{}.json",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    let contents = serde_json::to_vec_pretty(report)
+        .map_err(|e| format!("Failed to serialize reset report: {}", e))?;
+    fs::write(&path, contents).map_err(|e| format!("Failed to write reset report: {}", e))?;
+    Ok(path)
+}
+
+/// Settings keys holding the user's tuning (dictionary corrections, snippets and
+/// AI prompt options) that `keep_personalization` carries across a reset
+pub const PERSONALIZATION_KEYS: &[&str] = &[
+    "correction_dictionary",
+    "fuzzy_correction_max_distance",
+    "snippets",
+    "enhancement_options",
+];
+
+/// Values of the personalization keys that are currently set
+pub fn export_personalization(
+    get: impl Fn(&str) -> Option<serde_json::Value>,
+) -> Vec<(String, serde_json::Value)> {
+    PERSONALIZATION_KEYS
+        .iter()
+        .filter_map(|key| get(key).map(|value| (key.to_string(), value)))
+        .collect()
+}
+
+/// How the app was packaged on Linux, detected from the sandbox environment
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone, PartialEq)]
+enum LinuxPackaging {
+    Native,
+    Flatpak { app_id: String },
+    Snap { name: String },
+}
+
+#[cfg(target_os = "linux")]
+impl LinuxPackaging {
+    fn detect() -> Self {
+        if let Ok(app_id) = std::env::var("FLATPAK_ID") {
+            if !app_id.is_empty() {
+                return Self::Flatpak { app_id };
+            }
+        }
+        if std::env::var("SNAP")
+            .map(|v| !v.is_empty())
+            .unwrap_or(false)
+        {
+            let name = std::env::var("SNAP_NAME").unwrap_or_else(|_| "unknown".to_string());
+            return Self::Snap { name };
+        }
+        Self::Native
+    }
+
+    fn is_sandboxed(&self) -> bool {
+        !matches!(self, Self::Native)
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Self::Native => "native".to_string(),
+            Self::Flatpak { app_id } => format!("Flatpak ({})", app_id),
+            Self::Snap { name } => format!("Snap ({})", name),
+        }
+    }
+}
+
+/// A file or directory that `reset_app_data` would delete, as shown before confirming
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct ResetItemPreview {
+    pub label: String,
+    pub path: String,
+    pub exists: bool,
+    pub size_bytes: u64,
+}
+
+/// Total size of a file, or of everything under a directory. Symlinks are not followed.
+pub fn path_size_bytes(path: &Path) -> u64 {
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return 0;
+    };
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+
+    fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| path_size_bytes(&entry.path()))
+                .sum()
+        })
+        .unwrap_or(0)
 }
 
+/// Paths on disk that the reset removes, labelled like its `cleared_items`
+fn reset_path_targets(app: &AppHandle) -> Vec<(String, PathBuf)> {
+    let mut targets = Vec::new();
+    #[allow(unused_variables)]
+    let app_identifier = app.config().identifier.clone();
+
+    if let Ok(app_data_dir) = app.path().app_data_dir() {
+        targets.push(("Stores directory".to_string(), app_data_dir.join("stores")));
+        targets.push(("Downloaded models".to_string(), app_data_dir.join("models")));
+        for dir in ["parakeet-tdt-0.6b-v3", "parakeet-tdt-0.6b-v2"] {
+            targets.push(("Parakeet model data".to_string(), app_data_dir.join(dir)));
+        }
+        targets.push((
+            "Audio recordings".to_string(),
+            app_data_dir.join("recordings"),
+        ));
+        targets.push((
+            "Secure storage (API keys)".to_string(),
+            app_data_dir.join("secure.dat"),
+        ));
+    }
+
+    if let Some(override_dir) = crate::utils::data_dir::data_dir_override(app) {
+        targets.push((
+            "Transcriptions store".to_string(),
+            override_dir.join(crate::utils::data_dir::TRANSCRIPTIONS_STORE),
+        ));
+        targets.push(("Downloaded models".to_string(), override_dir.join("models")));
+        targets.push((
+            "Audio recordings".to_string(),
+            override_dir.join("recordings"),
+        ));
+        targets.push((
+            "Telemetry log".to_string(),
+            override_dir.join("telemetry.jsonl"),
+        ));
+        targets.push((
+            "History export jobs".to_string(),
+            override_dir.join("exports"),
+        ));
+    }
+
+    if let Ok(cache_dir) = app.path().app_cache_dir() {
+        targets.push(("Cache directory".to_string(), cache_dir));
+    }
+
+    #[cfg(target_os = "macos")]
+    if let Ok(home_dir) = app.path().home_dir() {
+        for path in [
+            "Library/Application Support/FluidAudio",
+            "Library/Application Support/parakeet-tdt-0.6b-v3-coreml",
+            "Library/Application Support/parakeet-tdt-0.6b-v2-coreml",
+            "Library/Caches/FluidAudio",
+        ] {
+            targets.push(("FluidAudio model cache".to_string(), home_dir.join(path)));
+        }
+        let library = home_dir.join("Library");
+        targets.push((
+            "Preferences plist".to_string(),
+            library
+                .join("Preferences")
+                .join(format!("{}.plist", app_identifier)),
+        ));
+        targets.push((
+            "Window state".to_string(),
+            library
+                .join("Saved Application State")
+                .join(format!("{}.savedState", app_identifier)),
+        ));
+        targets.push((
+            "Application logs".to_string(),
+            library.join("Logs").join(&app_identifier),
+        ));
+        targets.push((
+            "WebKit data".to_string(),
+            library.join("WebKit").join(&app_identifier),
+        ));
+        targets.push((
+            "Download cache".to_string(),
+            library
+                .join("Caches")
+                .join("com.apple.nsurlsessiond")
+                .join("Downloads")
+                .join(&app_identifier),
+        ));
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let packaging = LinuxPackaging::detect();
+        if let LinuxPackaging::Flatpak { app_id } = &packaging {
+            if let Ok(home_dir) = app.path().home_dir() {
+                targets.push((
+                    "GSettings keyfile (Flatpak)".to_string(),
+                    home_dir
+                        .join(".var/app")
+                        .join(app_id)
+                        .join("config/glib-2.0/settings/keyfile"),
+                ));
+            }
+        }
+        if packaging.is_sandboxed() {
+            if let (Ok(config_dir), Ok(data_dir)) =
+                (app.path().app_config_dir(), app.path().app_data_dir())
+            {
+                if config_dir != data_dir {
+                    targets.push(("Sandbox config directory".to_string(), config_dir));
+                }
+            }
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        if let Ok(local_data_dir) = app.path().app_local_data_dir() {
+            targets.push(("Application logs".to_string(), local_data_dir.join("logs")));
+        }
+        if let Ok(temp_dir) = app.path().temp_dir() {
+            targets.push((
+                "WebView2 cache".to_string(),
+                temp_dir.join(format!("{}.WebView2", app_identifier)),
+            ));
+        }
+    }
+
+    targets
+}
+
+/// List everything on disk that a reset would delete, without touching it,
+/// so the confirmation dialog can skip items that don't exist.
 #[tauri::command]
-pub async fn reset_app_data(app: AppHandle) -> Result<ResetResult, String> {
+pub async fn preview_reset(app: AppHandle) -> Result<Vec<ResetItemPreview>, CommandError> {
+    let targets = reset_path_targets(&app);
+
+    tokio::task::spawn_blocking(move || {
+        targets
+            .into_iter()
+            .map(|(label, path)| {
+                let exists = path.exists();
+                ResetItemPreview {
+                    label,
+                    size_bytes: if exists { path_size_bytes(&path) } else { 0 },
+                    path: path.to_string_lossy().to_string(),
+                    exists,
+                }
+            })
+            .collect()
+    })
+    .await
+    .map_err(|e| CommandError::Internal(format!("Failed to preview reset: {}", e)))
+}
+
+/// Reset app data. With `trim_older_than_days`, only recordings, history
+/// entries and logs older than that many days are deleted and everything else
+/// is kept. With `write_report`, the result is also saved to a
+/// `reset-report-*.json` in the log directory and its path returned.
+#[tauri::command]
+pub async fn reset_app_data(
+    app: AppHandle,
+    keep_personalization: bool,
+    trim_older_than_days: Option<u32>,
+    write_report: bool,
+) -> Result<ResetResult, CommandError> {
+    // Deleting models and recordings out from under an active pipeline would
+    // leave it writing into removed directories
+    let recording_state = crate::get_recording_state(&app);
+    if recording_state != crate::RecordingState::Idle
+        && recording_state != crate::RecordingState::Error
+    {
+        return Err(CommandError::Busy(
+            "Cannot reset app data while recording or transcribing".to_string(),
+        ));
+    }
+
+    if trim_older_than_days == Some(0) {
+        return Err(CommandError::InvalidInput(
+            "Trimming needs at least one day; use a full reset to delete everything".to_string(),
+        ));
+    }
+
+    let size_before = if write_report {
+        reset_targets_size(&app).await
+    } else {
+        0
+    };
+
+    let mut result = match trim_older_than_days {
+        Some(days) => trim_old_data(&app, days).await,
+        None => reset_everything(app.clone(), keep_personalization).await,
+    };
+
+    if write_report {
+        let bytes_freed = size_before.saturating_sub(reset_targets_size(&app).await);
+        let report = ResetReport {
+            created_at: chrono::Local::now().to_rfc3339(),
+            keep_personalization,
+            trim_older_than_days,
+            bytes_freed,
+            result: &result,
+        };
+        match crate::commands::logs::log_directory(&app)
+            .map_err(|e| e.to_string())
+            .and_then(|dir| write_reset_report(&dir, &report))
+        {
+            Ok(path) => {
+                log::info!("Wrote reset report to {:?}", path);
+                result.report_path = Some(path.to_string_lossy().to_string());
+            }
+            Err(e) => result.warnings.push(e),
+        }
+    }
+
+    Ok(result)
+}
+
+/// Total size of everything a full reset deletes
+async fn reset_targets_size(app: &AppHandle) -> u64 {
+    let targets = reset_path_targets(app);
+    tokio::task::spawn_blocking(move || targets.iter().map(|(_, path)| path_size_bytes(path)).sum())
+        .await
+        .unwrap_or(0)
+}
+
+/// History keys (RFC 3339 timestamps) from before `cutoff`
+pub fn transcription_keys_before(
+    keys: Vec<String>,
+    cutoff: chrono::DateTime<chrono::Utc>,
+) -> Vec<String> {
+    keys.into_iter()
+        .filter(|key| chrono::DateTime::parse_from_rfc3339(key).is_ok_and(|date| date < cutoff))
+        .collect()
+}
+
+/// Delete recordings, history entries and logs older than `days`
+async fn trim_old_data(app: &AppHandle, days: u32) -> ResetResult {
+    log::info!("Trimming app data older than {} days", days);
+
+    let mut errors = Vec::new();
+    let mut cleared_items = Vec::new();
+    let cutoff = chrono::Utc::now() - chrono::Duration::days(days as i64);
+
+    match crate::utils::data_dir::transcriptions_store(app) {
+        Ok(store) => {
+            let keys = transcription_keys_before(store.keys(), cutoff);
+            for key in &keys {
+                store.delete(key);
+            }
+            match store.save() {
+                Ok(()) => cleared_items.push(format!("{} transcription(s)", keys.len())),
+                Err(e) => errors.push(format!("Failed to save trimmed history: {}", e)),
+            }
+        }
+        Err(e) => errors.push(format!("Failed to open history: {}", e)),
+    }
+
+    let recording_cutoff = std::time::SystemTime::from(cutoff);
+    let mut recordings_deleted = 0;
+    for dir in crate::utils::data_dir::all_data_dirs(app) {
+        let recordings_dir = dir.join("recordings");
+        match tauri::async_runtime::spawn_blocking(move || {
+            crate::utils::recordings::apply_retention(&recordings_dir, Some(recording_cutoff))
+        })
+        .await
+        {
+            Ok(Ok(deleted)) => recordings_deleted += deleted,
+            Ok(Err(e)) => errors.push(e),
+            Err(e) => errors.push(format!("Recording trim task failed: {}", e)),
+        }
+    }
+    cleared_items.push(format!("{} recording(s)", recordings_deleted));
+
+    match crate::commands::logs::clear_old_logs(app.clone(), days).await {
+        Ok(deleted) => cleared_items.push(format!("{} log file(s)", deleted)),
+        Err(e) => errors.push(e.to_string()),
+    }
+
+    let _ = crate::emit_to_window(app, "main", "history-updated", ());
+
+    ResetResult {
+        success: errors.is_empty(),
+        errors,
+        warnings: Vec::new(),
+        cleared_items,
+        report_path: None,
+    }
+}
+
+/// The full reset behind `reset_app_data`, without the busy check
+async fn reset_everything(app: AppHandle, keep_personalization: bool) -> ResetResult {
     log::info!("Starting app data reset");
 
     let mut errors = Vec::new();
+    let mut warnings = Vec::new();
     let mut cleared_items = Vec::new();
 
     // Use the current bundle identifier so dev vs prod apps
     // clear their own OS-level data independently.
     let app_identifier = app.config().identifier.clone();
 
-    // 1. Clear all stores and delete the store files
-    // Clear settings store
+    // Resolve relocated data before the settings (and with them the
+    // data_dir_override) are cleared
+    let targets = reset_path_targets(&app);
+    let transcriptions_store = crate::utils::data_dir::transcriptions_store(&app);
+
+    // 1. Clear all stores (their files are deleted in step 3)
+    // Clear settings store, holding on to the personalization keys if asked
+    let mut personalization = Vec::new();
     if let Ok(store) = app.store("settings") {
+        if keep_personalization {
+            personalization = export_personalization(|key| store.get(key));
+        }
         store.clear();
         if let Err(e) = store.save() {
             errors.push(format!("Failed to save cleared settings store: {}", e));
@@ -32,7 +451,7 @@ pub async fn reset_app_data(app: AppHandle) -> Result<ResetResult, String> {
     }
 
     // Clear transcriptions store
-    if let Ok(store) = app.store("transcriptions") {
+    if let Ok(store) = transcriptions_store {
         store.clear();
         if let Err(e) = store.save() {
             errors.push(format!(
@@ -44,58 +463,7 @@ pub async fn reset_app_data(app: AppHandle) -> Result<ResetResult, String> {
         }
     }
 
-    // Delete the actual store files from disk
-    if let Ok(app_data_dir) = app.path().app_data_dir() {
-        let stores_dir = app_data_dir.join("stores");
-        if stores_dir.exists() {
-            if let Err(e) = fs::remove_dir_all(&stores_dir) {
-                errors.push(format!("Failed to delete stores directory: {}", e));
-            } else {
-                cleared_items.push("Stores directory".to_string());
-            }
-        }
-    }
-
-    // 2. Delete app data directories
-    if let Ok(app_data_dir) = app.path().app_data_dir() {
-        // Delete models directory
-        let models_dir = app_data_dir.join("models");
-        if models_dir.exists() {
-            if let Err(e) = fs::remove_dir_all(&models_dir) {
-                errors.push(format!("Failed to delete models directory: {}", e));
-            } else {
-                cleared_items.push("Downloaded models".to_string());
-            }
-        }
-
-        // Delete Parakeet model directories (for Swift sidecar)
-        // These might exist from old Python implementation or tracking
-        let parakeet_dirs = vec![
-            app_data_dir.join("parakeet-tdt-0.6b-v3"),
-            app_data_dir.join("parakeet-tdt-0.6b-v2"),
-        ];
-        for parakeet_dir in parakeet_dirs {
-            if parakeet_dir.exists() {
-                if let Err(e) = fs::remove_dir_all(&parakeet_dir) {
-                    errors.push(format!("Failed to delete Parakeet directory: {}", e));
-                } else {
-                    cleared_items.push("Parakeet model data".to_string());
-                }
-            }
-        }
-
-        // Delete recordings directory
-        let recordings_dir = app_data_dir.join("recordings");
-        if recordings_dir.exists() {
-            if let Err(e) = fs::remove_dir_all(&recordings_dir) {
-                errors.push(format!("Failed to delete recordings directory: {}", e));
-            } else {
-                cleared_items.push("Audio recordings".to_string());
-            }
-        }
-    }
-
-    // 3. Clear license data from secure store
+    // 2. Clear license data from secure store
     if let Err(e) = crate::secure_store::secure_delete(&app, "license") {
         // Only push error if it's not a "store doesn't exist" error
         if !e.contains("Store access failed") {
@@ -105,52 +473,15 @@ pub async fn reset_app_data(app: AppHandle) -> Result<ResetResult, String> {
         cleared_items.push("License data".to_string());
     }
 
-    // 3.5. Clear the secure.dat file itself
-    if let Ok(app_data_dir) = app.path().app_data_dir() {
-        let secure_store_path = app_data_dir.join("secure.dat");
-        if secure_store_path.exists() {
-            if let Err(e) = fs::remove_file(&secure_store_path) {
-                errors.push(format!("Failed to remove secure storage: {}", e));
-            } else {
-                cleared_items.push("Secure storage (API keys)".to_string());
-            }
-        }
+    // 3. Delete everything on disk the reset covers: stores, models, recordings,
+    // secure storage, caches and OS-level data (the list preview_reset shows)
+    for (label, path) in &targets {
+        remove_reset_target(label, path, &mut cleared_items, &mut errors);
     }
 
-    // 4. Clear cache data (license validation cache)
-    if let Ok(cache_dir) = app.path().cache_dir() {
-        if cache_dir.exists() {
-            if let Err(e) = fs::remove_dir_all(&cache_dir) {
-                errors.push(format!("Failed to clear cache: {}", e));
-            } else {
-                cleared_items.push("Cache directory".to_string());
-            }
-        }
-    }
-
-    // 5. Clear app preferences
+    // 4. Clear app preferences
     #[cfg(target_os = "macos")]
     {
-        // Clear FluidAudio cached models (for Swift Parakeet sidecar)
-        if let Ok(home_dir) = app.path().home_dir() {
-            let fluid_audio_paths = vec![
-                home_dir.join("Library/Application Support/FluidAudio"),
-                home_dir.join("Library/Application Support/parakeet-tdt-0.6b-v3-coreml"),
-                home_dir.join("Library/Application Support/parakeet-tdt-0.6b-v2-coreml"),
-                home_dir.join("Library/Caches/FluidAudio"),
-            ];
-
-            for fluid_path in fluid_audio_paths {
-                if fluid_path.exists() {
-                    if let Err(e) = fs::remove_dir_all(&fluid_path) {
-                        errors.push(format!("Failed to delete FluidAudio cache: {}", e));
-                    } else {
-                        cleared_items.push("FluidAudio model cache".to_string());
-                    }
-                }
-            }
-        }
-
         // macOS defaults system
         match std::process::Command::new("defaults")
             .arg("delete")
@@ -166,38 +497,53 @@ pub async fn reset_app_data(app: AppHandle) -> Result<ResetResult, String> {
                 // No defaults to clear is not an error
             }
         }
-
-        // Also remove the preferences plist file
-        if let Ok(home_dir) = app.path().home_dir() {
-            let prefs_path = home_dir
-                .join("Library")
-                .join("Preferences")
-                .join(format!("{}.plist", app_identifier));
-            if prefs_path.exists() {
-                if let Err(e) = fs::remove_file(&prefs_path) {
-                    errors.push(format!("Failed to remove preferences file: {}", e));
-                } else {
-                    cleared_items.push("Preferences plist".to_string());
-                }
-            }
-        }
     }
 
     #[cfg(target_os = "linux")]
     {
-        // On Linux, clear dconf / GSettings entries if they exist
-        // This is best-effort; failure is not critical
-        match std::process::Command::new("dconf")
-            .args(["reset", "-f", &format!("/com/ideaplexa/{}/", app_identifier)])
-            .output()
-        {
-            Ok(output) => {
-                if output.status.success() {
-                    cleared_items.push("GSettings/dconf preferences".to_string());
+        // Sandboxed installs resolve data dirs inside the sandbox and can't
+        // reach the host dconf database, so handle each packaging separately
+        let packaging = LinuxPackaging::detect();
+        cleared_items.push(format!("Linux environment: {}", packaging.describe()));
+
+        match &packaging {
+            LinuxPackaging::Native => {
+                // On Linux, clear dconf / GSettings entries if they exist
+                // This is best-effort; failure is not critical
+                match std::process::Command::new("dconf")
+                    .args([
+                        "reset",
+                        "-f",
+                        &format!("/com/ideaplexa/{}/", app_identifier),
+                    ])
+                    .output()
+                {
+                    Ok(output) => {
+                        if output.status.success() {
+                            cleared_items.push("GSettings/dconf preferences".to_string());
+                        } else {
+                            warnings.push(format!(
+                                "dconf reset failed: {}",
+                                String::from_utf8_lossy(&output.stderr).trim()
+                            ));
+                        }
+                    }
+                    Err(_) => {
+                        // dconf may not be installed; not an error
+                        warnings.push(
+                            "dconf not available; GSettings preferences were not reset".to_string(),
+                        );
+                    }
                 }
             }
-            Err(_) => {
-                // dconf may not be installed; not an error
+            LinuxPackaging::Flatpak { .. } => {
+                // Flatpak keeps GSettings in a per-app keyfile instead of host
+                // dconf; it was deleted with the other reset targets
+            }
+            LinuxPackaging::Snap { .. } => {
+                // dconf is confined under Snap; preferences live in the snap's own data dir
+                log::info!("Skipping dconf reset under Snap confinement");
+                warnings.push("dconf reset skipped under Snap confinement".to_string());
             }
         }
     }
@@ -224,92 +570,7 @@ pub async fn reset_app_data(app: AppHandle) -> Result<ResetResult, String> {
         }
     }
 
-    // 6. Clear additional system data
-    #[cfg(target_os = "macos")]
-    {
-        if let Ok(home_dir) = app.path().home_dir() {
-            // Clear saved application state (window positions, etc)
-            let saved_state_path = home_dir
-                .join("Library")
-                .join("Saved Application State")
-                .join(format!("{}.savedState", app_identifier));
-            if saved_state_path.exists() {
-                if let Err(e) = fs::remove_dir_all(&saved_state_path) {
-                    errors.push(format!("Failed to clear saved state: {}", e));
-                } else {
-                    cleared_items.push("Window state".to_string());
-                }
-            }
-
-            // Clear any logs
-            let logs_path = home_dir.join("Library").join("Logs").join(&app_identifier);
-            if logs_path.exists() {
-                if let Err(e) = fs::remove_dir_all(&logs_path) {
-                    errors.push(format!("Failed to clear logs: {}", e));
-                } else {
-                    cleared_items.push("Application logs".to_string());
-                }
-            }
-
-            // Clear WebKit data if any
-            let webkit_path = home_dir
-                .join("Library")
-                .join("WebKit")
-                .join(&app_identifier);
-            if webkit_path.exists() {
-                if let Err(e) = fs::remove_dir_all(&webkit_path) {
-                    errors.push(format!("Failed to clear WebKit data: {}", e));
-                } else {
-                    cleared_items.push("WebKit data".to_string());
-                }
-            }
-
-            // Clear NSURLSession downloads cache
-            let nsurlsession_path = home_dir
-                .join("Library")
-                .join("Caches")
-                .join("com.apple.nsurlsessiond")
-                .join("Downloads")
-                .join(&app_identifier);
-            if nsurlsession_path.exists() {
-                if let Err(e) = fs::remove_dir_all(&nsurlsession_path) {
-                    errors.push(format!("Failed to clear download cache: {}", e));
-                } else {
-                    cleared_items.push("Download cache".to_string());
-                }
-            }
-        }
-    }
-
-    #[cfg(target_os = "windows")]
-    {
-        // Clear Windows app data
-        if let Ok(local_data_dir) = app.path().app_local_data_dir() {
-            // Clear logs from AppData\Local
-            let logs_path = local_data_dir.join("logs");
-            if logs_path.exists() {
-                if let Err(e) = fs::remove_dir_all(&logs_path) {
-                    errors.push(format!("Failed to clear logs: {}", e));
-                } else {
-                    cleared_items.push("Application logs".to_string());
-                }
-            }
-        }
-
-        // Clear Windows WebView2 cache
-        if let Ok(temp_dir) = app.path().temp_dir() {
-            let webview_cache = temp_dir.join(format!("{}.WebView2", app_identifier));
-            if webview_cache.exists() {
-                if let Err(e) = fs::remove_dir_all(&webview_cache) {
-                    errors.push(format!("Failed to clear WebView2 cache: {}", e));
-                } else {
-                    cleared_items.push("WebView2 cache".to_string());
-                }
-            }
-        }
-    }
-
-    // 7. Reset system permissions
+    // 5. Reset system permissions
     #[cfg(target_os = "macos")]
     {
         let reset_script = format!(
@@ -329,10 +590,13 @@ pub async fn reset_app_data(app: AppHandle) -> Result<ResetResult, String> {
                 } else {
                     // User might have cancelled - not a critical error
                     log::info!("User cancelled permission reset");
+                    warnings.push(
+                        "System permissions were not reset (admin prompt cancelled)".to_string(),
+                    );
                 }
             }
             Err(e) => {
-                errors.push(format!("Could not reset permissions: {}", e));
+                warnings.push(format!("Could not reset permissions: {}", e));
             }
         }
     }
@@ -349,7 +613,13 @@ pub async fn reset_app_data(app: AppHandle) -> Result<ResetResult, String> {
         cleared_items.push("System permissions (N/A on Linux)".to_string());
     }
 
-    // 8. Clear any runtime state
+    // 5.5. Remove the launch-at-login entry (LaunchAgent, Run key or autostart .desktop)
+    match crate::commands::settings::apply_launch_at_login(&app, false) {
+        Ok(()) => cleared_items.push("Launch at login entry".to_string()),
+        Err(e) => errors.push(format!("Failed to remove launch at login entry: {}", e)),
+    }
+
+    // 6. Clear any runtime state
     use tauri::async_runtime::RwLock as AsyncRwLock;
     let whisper_state = app.state::<AsyncRwLock<crate::whisper::manager::WhisperManager>>();
     let mut whisper_manager = whisper_state.write().await;
@@ -357,14 +627,14 @@ pub async fn reset_app_data(app: AppHandle) -> Result<ResetResult, String> {
     drop(whisper_manager);
     cleared_items.push("Runtime state".to_string());
 
-    // 8.5. Clear API key cache
+    // 6.5. Clear API key cache
     if let Err(e) = crate::commands::ai::clear_all_api_key_cache() {
         errors.push(format!("Failed to clear API key cache: {}", e));
     } else {
         cleared_items.push("AI API key cache".to_string());
     }
 
-    // 9. Refresh preferences daemon
+    // 7. Refresh preferences daemon
     #[cfg(target_os = "macos")]
     {
         match std::process::Command::new("killall")
@@ -380,22 +650,684 @@ pub async fn reset_app_data(app: AppHandle) -> Result<ResetResult, String> {
         }
     }
 
-    // 10. Emit reset event to frontend
+    // 7.5. Re-apply personalization now that the stores directory is gone
+    if keep_personalization {
+        match app.store("settings") {
+            Ok(store) => {
+                for (key, value) in &personalization {
+                    store.set(key.as_str(), value.clone());
+                }
+                match store.save() {
+                    Ok(()) => cleared_items.push("Personalization preserved".to_string()),
+                    Err(e) => errors.push(format!("Failed to restore personalization: {}", e)),
+                }
+            }
+            Err(e) => errors.push(format!("Failed to restore personalization: {}", e)),
+        }
+    }
+
+    // 8. Re-register default shortcuts so the hotkey works without a relaunch
+    if let Err(e) = crate::commands::settings::reinit_shortcuts(app.clone()).await {
+        warnings.push(format!("Failed to re-register shortcuts: {}", e));
+    } else {
+        cleared_items.push("Shortcuts restored to defaults".to_string());
+    }
+
+    // 9. Emit reset event to frontend
     if let Err(e) = app.emit("app-reset", ()) {
-        errors.push(format!("Failed to emit reset event: {}", e));
+        warnings.push(format!("Failed to emit reset event: {}", e));
     }
 
     let success = errors.is_empty();
 
     if success {
-        log::info!("App data reset completed successfully");
+        log::info!(
+            "App data reset completed successfully ({} warnings)",
+            warnings.len()
+        );
     } else {
         log::warn!("App data reset completed with {} errors", errors.len());
     }
 
-    Ok(ResetResult {
+    ResetResult {
         success,
         errors,
+        warnings,
         cleared_items,
+        report_path: None,
+    }
+}
+
+/// Keys in `keys` that start with `prefix`
+pub fn keys_with_prefix(keys: Vec<String>, prefix: &str) -> Vec<String> {
+    keys.into_iter()
+        .filter(|key| key.starts_with(prefix))
+        .collect()
+}
+
+/// Remove only the keys of `store` that start with `prefix` (e.g. "ai_"), so a
+/// settings section can be reset on its own. Returns how many were removed.
+#[tauri::command]
+pub async fn clear_store_keys(
+    app: AppHandle,
+    store: String,
+    prefix: String,
+) -> Result<u32, String> {
+    if prefix.is_empty() {
+        return Err("A key prefix is required; use reset to clear a whole store".to_string());
+    }
+
+    let handle = match store.as_str() {
+        "settings" | "cache" => app.store(store.as_str()),
+        "transcriptions" => crate::utils::data_dir::transcriptions_store(&app),
+        // Secrets go through the keyring commands
+        _ => return Err(format!("Unknown store '{}'", store)),
+    }
+    .map_err(|e| format!("Failed to open {} store: {}", store, e))?;
+
+    let keys = keys_with_prefix(handle.keys(), &prefix);
+    for key in &keys {
+        handle.delete(key);
+    }
+    if !keys.is_empty() {
+        handle
+            .save()
+            .map_err(|e| format!("Failed to save {} store: {}", store, e))?;
+    }
+
+    if store == "settings" {
+        crate::commands::audio::invalidate_recording_config_cache(&app).await;
+    }
+    log::info!(
+        "Cleared {} key(s) with prefix '{}' from {} store",
+        keys.len(),
+        prefix,
+        store
+    );
+    Ok(keys.len() as u32)
+}
+
+/// Settings keys holding window geometry
+pub const WINDOW_STATE_KEYS: &[&str] = &["pill_position"];
+
+/// Forget saved window positions and sizes and put the windows back where
+/// they start on a fresh install. Settings and user data are untouched.
+#[tauri::command]
+pub async fn reset_window_state(app: AppHandle) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    if let Ok(home_dir) = app.path().home_dir() {
+        let saved_state_path = home_dir
+            .join("Library")
+            .join("Saved Application State")
+            .join(format!("{}.savedState", app.config().identifier));
+        if saved_state_path.exists() {
+            fs::remove_dir_all(&saved_state_path)
+                .map_err(|e| format!("Failed to clear saved window state: {}", e))?;
+        }
+    }
+
+    let store = app
+        .store("settings")
+        .map_err(|e| format!("Failed to open settings: {}", e))?;
+    for key in WINDOW_STATE_KEYS {
+        store.delete(key);
+    }
+    store
+        .save()
+        .map_err(|e| format!("Failed to save settings: {}", e))?;
+
+    if let Some(main) = app.get_webview_window("main") {
+        let _ = main.unmaximize();
+        if let Some(config) = app.config().app.windows.iter().find(|w| w.label == "main") {
+            main.set_size(tauri::LogicalSize::new(config.width, config.height))
+                .map_err(|e| format!("Failed to resize main window: {}", e))?;
+        }
+        main.center()
+            .map_err(|e| format!("Failed to center main window: {}", e))?;
+    }
+    if let Some(window_manager) = app.state::<crate::AppState>().get_window_manager() {
+        window_manager.reposition_floating_windows();
+    }
+
+    log::info!("Reset window state");
+    Ok(())
+}
+
+/// What a scheduled reset clears
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ResetCategory {
+    /// Everything `reset_app_data` clears
+    All,
+    Settings,
+    History,
+    Models,
+    Recordings,
+    /// API keys and license
+    SecureStore,
+    Cache,
+}
+
+/// Marker written next to the stores, naming what to clear on next launch
+const SCHEDULED_RESET_FILE: &str = "scheduled-reset.json";
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct ScheduledReset {
+    pub categories: Vec<ResetCategory>,
+    pub scheduled_at: String,
+}
+
+fn scheduled_reset_path(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join(SCHEDULED_RESET_FILE))
+        .map_err(|e| format!("Failed to resolve app data directory: {}", e))
+}
+
+pub fn read_scheduled_reset(path: &Path) -> Option<ScheduledReset> {
+    let contents = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Delete `path` if it exists, recording the outcome under `label`
+fn remove_reset_target(
+    label: &str,
+    path: &Path,
+    cleared_items: &mut Vec<String>,
+    errors: &mut Vec<String>,
+) {
+    if !path.exists() {
+        return;
+    }
+    let result = if !path.is_dir() {
+        fs::remove_file(path)
+    } else if path.ends_with("recordings") {
+        // Honors secure_delete_recordings
+        crate::utils::secure_delete::remove_dir_all(path)
+    } else {
+        fs::remove_dir_all(path)
+    };
+    match result {
+        Ok(()) => cleared_items.push(label.to_string()),
+        Err(e) => errors.push(format!("Failed to delete {}: {}", path.display(), e)),
+    }
+}
+
+/// Clear the selected categories. `All` runs the full reset.
+async fn reset_categories(app: &AppHandle, categories: &[ResetCategory]) -> ResetResult {
+    if categories.contains(&ResetCategory::All) {
+        return reset_everything(app.clone(), false).await;
+    }
+
+    let mut errors = Vec::new();
+    let mut warnings = Vec::new();
+    let mut cleared_items = Vec::new();
+    let data_dirs = crate::utils::data_dir::all_data_dirs(app);
+
+    for category in categories {
+        match category {
+            ResetCategory::All => {}
+            ResetCategory::Settings => match app.store("settings") {
+                Ok(store) => {
+                    store.clear();
+                    crate::whisper::params::set_active(Default::default());
+                    match store.save() {
+                        Ok(()) => cleared_items.push("Settings store".to_string()),
+                        Err(e) => errors.push(format!("Failed to save cleared settings: {}", e)),
+                    }
+                }
+                Err(e) => errors.push(format!("Failed to open settings: {}", e)),
+            },
+            ResetCategory::History => match crate::utils::data_dir::transcriptions_store(app) {
+                Ok(store) => {
+                    store.clear();
+                    match store.save() {
+                        Ok(()) => cleared_items.push("Transcriptions store".to_string()),
+                        Err(e) => errors.push(format!("Failed to save cleared history: {}", e)),
+                    }
+                }
+                Err(e) => errors.push(format!("Failed to open history: {}", e)),
+            },
+            ResetCategory::Models => {
+                for dir in &data_dirs {
+                    let models_dir = dir.join("models");
+                    remove_reset_target(
+                        "Downloaded models",
+                        &models_dir,
+                        &mut cleared_items,
+                        &mut errors,
+                    );
+                    for parakeet in ["parakeet-tdt-0.6b-v3", "parakeet-tdt-0.6b-v2"] {
+                        remove_reset_target(
+                            "Parakeet model data",
+                            &dir.join(parakeet),
+                            &mut cleared_items,
+                            &mut errors,
+                        );
+                    }
+                }
+                use tauri::async_runtime::RwLock as AsyncRwLock;
+                let whisper_state =
+                    app.state::<AsyncRwLock<crate::whisper::manager::WhisperManager>>();
+                whisper_state.write().await.refresh_downloaded_status();
+            }
+            ResetCategory::Recordings => {
+                for dir in &data_dirs {
+                    remove_reset_target(
+                        "Audio recordings",
+                        &dir.join("recordings"),
+                        &mut cleared_items,
+                        &mut errors,
+                    );
+                }
+            }
+            ResetCategory::SecureStore => {
+                if let Ok(app_data_dir) = app.path().app_data_dir() {
+                    let secure_store_path = app_data_dir.join("secure.dat");
+                    if secure_store_path.exists() {
+                        match fs::remove_file(&secure_store_path) {
+                            Ok(()) => cleared_items.push("Secure storage (API keys)".to_string()),
+                            Err(e) => {
+                                errors.push(format!("Failed to remove secure storage: {}", e))
+                            }
+                        }
+                    }
+                }
+                if let Err(e) = crate::commands::ai::clear_all_api_key_cache() {
+                    errors.push(format!("Failed to clear API key cache: {}", e));
+                }
+            }
+            ResetCategory::Cache => {
+                if let Ok(cache_dir) = app.path().app_cache_dir() {
+                    remove_reset_target(
+                        "Cache directory",
+                        &cache_dir,
+                        &mut cleared_items,
+                        &mut errors,
+                    );
+                }
+            }
+        }
+    }
+
+    if categories.contains(&ResetCategory::Settings) {
+        if let Err(e) = crate::commands::settings::reinit_shortcuts(app.clone()).await {
+            warnings.push(format!("Failed to re-register shortcuts: {}", e));
+        }
+    }
+
+    ResetResult {
+        success: errors.is_empty(),
+        errors,
+        warnings,
+        cleared_items,
+        report_path: None,
+    }
+}
+
+/// Reset the given categories the next time the app starts, before models are
+/// loaded or shortcuts registered. The stores are already open by then, so
+/// they are cleared through the store API rather than deleted.
+#[tauri::command]
+pub async fn schedule_reset_on_next_launch(
+    app: AppHandle,
+    categories: Vec<ResetCategory>,
+) -> Result<(), String> {
+    if categories.is_empty() {
+        return Err("Choose at least one category to reset".to_string());
+    }
+    let marker = ScheduledReset {
+        categories,
+        scheduled_at: chrono::Utc::now().to_rfc3339(),
+    };
+    let path = scheduled_reset_path(&app)?;
+    let contents = serde_json::to_vec_pretty(&marker)
+        .map_err(|e| format!("Failed to serialize scheduled reset: {}", e))?;
+    fs::write(&path, contents).map_err(|e| format!("Failed to schedule reset: {}", e))?;
+    log::info!("Scheduled reset of {:?} on next launch", marker.categories);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn cancel_scheduled_reset(app: AppHandle) -> Result<bool, String> {
+    let path = scheduled_reset_path(&app)?;
+    match fs::remove_file(&path) {
+        Ok(()) => {
+            log::info!("Cancelled scheduled reset");
+            Ok(true)
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(format!("Failed to cancel scheduled reset: {}", e)),
+    }
+}
+
+/// Result of the reset run at startup, kept until the frontend picks it up.
+/// The `scheduled-reset-completed` event can fire before the webview listens.
+#[derive(Default)]
+pub struct LastScheduledReset(pub Mutex<Option<ResetResult>>);
+
+/// The result of a reset run at this launch, if any. Returned once, so the
+/// frontend reports it a single time.
+#[tauri::command]
+pub async fn get_last_scheduled_reset(app: AppHandle) -> Result<Option<ResetResult>, String> {
+    Ok(app
+        .state::<LastScheduledReset>()
+        .0
+        .lock()
+        .map_err(|_| "Failed to read scheduled reset result".to_string())?
+        .take())
+}
+
+/// Run a reset scheduled by the previous session. Called during startup once
+/// app state exists but before hotkeys, windows and watchers are set up.
+pub async fn run_scheduled_reset(app: &AppHandle) -> Option<ResetResult> {
+    let path = scheduled_reset_path(app).ok()?;
+    if !path.exists() {
+        return None;
+    }
+    let marker = read_scheduled_reset(&path);
+    // Remove the marker first so a reset that crashes can't repeat every launch
+    if let Err(e) = fs::remove_file(&path) {
+        log::error!(
+            "Failed to clear scheduled reset marker, skipping reset: {}",
+            e
+        );
+        return None;
+    }
+    let Some(marker) = marker else {
+        log::warn!("Ignoring unreadable scheduled reset marker");
+        return None;
+    };
+
+    log::info!(
+        "Running reset scheduled at {}: {:?}",
+        marker.scheduled_at,
+        marker.categories
+    );
+    let result = reset_categories(app, &marker.categories).await;
+    for error in &result.errors {
+        log::warn!("Scheduled reset: {}", error);
+    }
+    Some(result)
+}
+
+/// Suffixes the OS adds to a bundle identifier in file names
+const IDENTIFIER_SUFFIXES: &[&str] = &[".plist", ".savedState", ".WebView2"];
+
+/// Bundle identifiers VoiceTypr has shipped under (release and dev builds)
+const KNOWN_IDENTIFIERS: &[&str] = &["com.ideaplexa.voicetypr", "com.ideaplexa.voicetypr.dev"];
+
+/// The identifier an entry in a per-app directory belongs to, if it is
+/// exactly one of `KNOWN_IDENTIFIERS` (plus an OS suffix). Other apps from the
+/// same vendor never match.
+pub fn legacy_identifier_of(file_name: &str) -> Option<String> {
+    let id = IDENTIFIER_SUFFIXES
+        .iter()
+        .find_map(|suffix| file_name.strip_suffix(suffix))
+        .unwrap_or(file_name);
+    KNOWN_IDENTIFIERS
+        .iter()
+        .find(|known| known.eq_ignore_ascii_case(id))
+        .map(|known| known.to_string())
+}
+
+/// Entries in `dirs` belonging to identifiers other than `current`, grouped
+/// by identifier
+pub fn scan_legacy_identifiers(
+    dirs: &[PathBuf],
+    current: &str,
+) -> std::collections::BTreeMap<String, Vec<PathBuf>> {
+    let mut found: std::collections::BTreeMap<String, Vec<PathBuf>> = Default::default();
+    for dir in dirs {
+        let Ok(entries) = fs::read_dir(dir) else {
+            continue;
+        };
+        for entry in entries.filter_map(|e| e.ok()) {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if let Some(id) = legacy_identifier_of(&name) {
+                if !id.eq_ignore_ascii_case(current) {
+                    found.entry(id).or_default().push(entry.path());
+                }
+            }
+        }
+    }
+    found
+}
+
+/// Per-app locations an older build may have written under its own identifier
+fn legacy_scan_dirs(app: &AppHandle) -> Vec<PathBuf> {
+    let path = app.path();
+    let mut dirs: Vec<PathBuf> = [
+        path.data_dir(),
+        path.local_data_dir(),
+        path.config_dir(),
+        path.cache_dir(),
+    ]
+    .into_iter()
+    .filter_map(|dir| dir.ok())
+    .collect();
+
+    #[cfg(target_os = "macos")]
+    if let Ok(home_dir) = path.home_dir() {
+        let library = home_dir.join("Library");
+        for dir in [
+            "Preferences",
+            "Saved Application State",
+            "Logs",
+            "WebKit",
+            "HTTPStorages",
+        ] {
+            dirs.push(library.join(dir));
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    if let Ok(temp_dir) = path.temp_dir() {
+        dirs.push(temp_dir);
+    }
+
+    dirs.sort();
+    dirs.dedup();
+    dirs
+}
+
+/// Identifiers of older VoiceTypr builds that still have data on disk. Reset
+/// only cleans the current identifier, so these are otherwise left behind.
+#[tauri::command]
+pub async fn find_legacy_identifiers(app: AppHandle) -> Result<Vec<String>, String> {
+    let dirs = legacy_scan_dirs(&app);
+    let current = app.config().identifier.clone();
+    let found = tokio::task::spawn_blocking(move || scan_legacy_identifiers(&dirs, &current))
+        .await
+        .map_err(|e| format!("Failed to scan for legacy data: {}", e))?;
+
+    for (id, paths) in &found {
+        log::info!(
+            "Found legacy identifier {} ({} location(s))",
+            id,
+            paths.len()
+        );
+    }
+    Ok(found.into_keys().collect())
+}
+
+/// Delete the data left by an older build under `id`. Only identifiers
+/// reported by `find_legacy_identifiers` are accepted. Returns the removed
+/// paths.
+#[tauri::command]
+pub async fn reset_legacy_identifier(app: AppHandle, id: String) -> Result<Vec<String>, String> {
+    let current = app.config().identifier.clone();
+    if id.eq_ignore_ascii_case(&current) {
+        return Err("Use reset to clear the current app's data".to_string());
+    }
+
+    let dirs = legacy_scan_dirs(&app);
+    let removed = tokio::task::spawn_blocking(move || {
+        let paths = scan_legacy_identifiers(&dirs, &current)
+            .remove(&id)
+            .ok_or_else(|| format!("No data found for identifier {}", id))?;
+
+        let mut removed = Vec::new();
+        for path in paths {
+            let result = if path.is_dir() {
+                crate::utils::secure_delete::remove_dir_all(&path)
+            } else {
+                crate::utils::secure_delete::remove_file(&path)
+            };
+            match result {
+                Ok(()) => removed.push(path.to_string_lossy().to_string()),
+                Err(e) => log::warn!("Failed to remove {:?}: {}", path, e),
+            }
+        }
+
+        #[cfg(target_os = "macos")]
+        let _ = std::process::Command::new("defaults")
+            .arg("delete")
+            .arg(&id)
+            .output();
+
+        Ok::<_, String>(removed)
     })
+    .await
+    .map_err(|e| format!("Failed to remove legacy data: {}", e))??;
+
+    log::info!("Removed {} legacy location(s)", removed.len());
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_size_bytes() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("a.bin"), vec![0u8; 100]).unwrap();
+        fs::create_dir(dir.path().join("nested")).unwrap();
+        fs::write(dir.path().join("nested/b.bin"), vec![0u8; 50]).unwrap();
+
+        assert_eq!(path_size_bytes(&dir.path().join("a.bin")), 100);
+        assert_eq!(path_size_bytes(dir.path()), 150);
+        assert_eq!(path_size_bytes(&dir.path().join("missing")), 0);
+    }
+
+    #[test]
+    fn test_write_reset_report() {
+        let dir = tempfile::tempdir().unwrap();
+        let result = ResetResult {
+            success: false,
+            errors: vec!["Failed to remove models".to_string()],
+            warnings: Vec::new(),
+            cleared_items: vec!["Settings".to_string()],
+            report_path: None,
+        };
+        let report = ResetReport {
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            keep_personalization: true,
+            trim_older_than_days: None,
+            bytes_freed: 2048,
+            result: &result,
+        };
+
+        let path = write_reset_report(&dir.path().join("logs"), &report).unwrap();
+        let name = path.file_name().unwrap().to_string_lossy().to_string();
+        assert!(name.starts_with("reset-report-") && name.ends_with(".json"));
+        let written: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(written["bytes_freed"], 2048);
+        assert_eq!(written["errors"][0], "Failed to remove models");
+        assert_eq!(written["cleared_items"][0], "Settings");
+    }
+
+    #[test]
+    fn test_export_personalization_keeps_only_set_keys() {
+        let exported = export_personalization(|key| match key {
+            "snippets" => Some(serde_json::json!({ "brb": "be right back" })),
+            "hotkey" => Some(serde_json::json!("CommandOrControl+Shift+Space")),
+            _ => None,
+        });
+        assert_eq!(
+            exported,
+            vec![(
+                "snippets".to_string(),
+                serde_json::json!({ "brb": "be right back" })
+            )]
+        );
+    }
+
+    #[test]
+    fn test_transcription_keys_before_cutoff() {
+        let cutoff = chrono::DateTime::parse_from_rfc3339("2024-06-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let keys = vec![
+            "2024-05-31T23:59:59Z".to_string(),
+            "2024-06-01T00:00:01Z".to_string(),
+            "not-a-timestamp".to_string(),
+        ];
+        assert_eq!(
+            transcription_keys_before(keys, cutoff),
+            vec!["2024-05-31T23:59:59Z"]
+        );
+    }
+
+    #[test]
+    fn test_keys_with_prefix() {
+        let keys = vec![
+            "ai_provider".to_string(),
+            "ai_enabled".to_string(),
+            "hotkey".to_string(),
+            "main_ai".to_string(),
+        ];
+        assert_eq!(
+            keys_with_prefix(keys.clone(), "ai_"),
+            vec!["ai_provider", "ai_enabled"]
+        );
+        assert!(keys_with_prefix(keys, "missing").is_empty());
+    }
+
+    #[test]
+    fn test_read_scheduled_reset_marker() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(SCHEDULED_RESET_FILE);
+        assert_eq!(read_scheduled_reset(&path), None);
+
+        fs::write(
+            &path,
+            r#"{"categories":["history","secure_store"],"scheduled_at":"2024-01-01T00:00:00Z"}"#,
+        )
+        .unwrap();
+        let marker = read_scheduled_reset(&path).unwrap();
+        assert_eq!(
+            marker.categories,
+            vec![ResetCategory::History, ResetCategory::SecureStore]
+        );
+
+        fs::write(&path, r#"{"categories":["everything"]}"#).unwrap();
+        assert_eq!(read_scheduled_reset(&path), None);
+    }
+
+    #[test]
+    fn test_scan_legacy_identifiers_skips_current_and_unrelated() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in [
+            "com.ideaplexa.voicetypr",
+            "com.ideaplexa.voicetypr.dev.plist",
+            "com.ideaplexa.othertool",
+            "com.ideaplexa.voicetypr.helper",
+            "com.voicetypr.app",
+            "com.example.other",
+            "voicetypr-notes.txt",
+        ] {
+            fs::write(dir.path().join(name), b"").unwrap();
+        }
+
+        let found = scan_legacy_identifiers(&[dir.path().to_path_buf()], "com.ideaplexa.voicetypr");
+        let ids: Vec<&String> = found.keys().collect();
+        assert_eq!(ids, vec!["com.ideaplexa.voicetypr.dev"]);
+        assert_eq!(
+            found["com.ideaplexa.voicetypr.dev"],
+            vec![dir.path().join("com.ideaplexa.voicetypr.dev.plist")]
+        );
+    }
 }
@@ -0,0 +1,364 @@
+// Editing of timed segments stored with a transcription, and caption export.
+// Segments are objects with `text` and `start`/`end` in seconds; any other
+// fields are carried along untouched.
+
+use serde_json::Value;
+use tauri::AppHandle;
+
+use crate::emit_to_window;
+
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CaptionFormat {
+    Srt,
+    Vtt,
+}
+
+fn segment_time(segment: &Value, field: &str) -> f64 {
+    segment.get(field).and_then(|v| v.as_f64()).unwrap_or(0.0)
+}
+
+fn segment_text(segment: &Value) -> &str {
+    segment.get("text").and_then(|v| v.as_str()).unwrap_or("")
+}
+
+fn segment_speaker(segment: &Value) -> Option<&str> {
+    segment.get("speaker").and_then(|v| v.as_str())
+}
+
+fn check_index(segments: &[Value], index: usize) -> Result<(), String> {
+    if index >= segments.len() {
+        return Err(format!(
+            "Segment {} does not exist (transcription has {} segments)",
+            index,
+            segments.len()
+        ));
+    }
+    Ok(())
+}
+
+/// Replace one segment's text, keeping its start/end times
+pub fn update_segment_text(
+    segments: &mut [Value],
+    index: usize,
+    new_text: &str,
+) -> Result<(), String> {
+    check_index(segments, index)?;
+    let new_text = new_text.trim();
+    if new_text.is_empty() {
+        return Err("Segment text cannot be empty".to_string());
+    }
+
+    let segment = &mut segments[index];
+    segment["text"] = serde_json::json!(new_text);
+    // Token timings no longer match the edited text
+    if let Some(object) = segment.as_object_mut() {
+        object.remove("tokens");
+    }
+    Ok(())
+}
+
+/// Split a segment at a character offset into its text. The time span is
+/// divided in proportion to the length of each half.
+pub fn split_segment(segments: &mut Vec<Value>, index: usize, at: usize) -> Result<(), String> {
+    check_index(segments, index)?;
+    let text: Vec<char> = segment_text(&segments[index]).chars().collect();
+    if at == 0 || at >= text.len() {
+        return Err("Split position must fall inside the segment text".to_string());
+    }
+
+    let first: String = text[..at].iter().collect::<String>().trim().to_string();
+    let second: String = text[at..].iter().collect::<String>().trim().to_string();
+    if first.is_empty() || second.is_empty() {
+        return Err("Both parts of a split segment need text".to_string());
+    }
+
+    let start = segment_time(&segments[index], "start");
+    let end = segment_time(&segments[index], "end");
+    let boundary = start + (end - start) * at as f64 / text.len() as f64;
+
+    let mut head = segments[index].clone();
+    if let Some(object) = head.as_object_mut() {
+        object.remove("tokens");
+    }
+    let mut tail = head.clone();
+    head["text"] = serde_json::json!(first);
+    head["end"] = serde_json::json!(boundary);
+    tail["text"] = serde_json::json!(second);
+    tail["start"] = serde_json::json!(boundary);
+
+    segments[index] = head;
+    segments.insert(index + 1, tail);
+    Ok(())
+}
+
+/// Merge a segment with the one after it, spanning both time ranges
+pub fn merge_segments(segments: &mut Vec<Value>, index: usize) -> Result<(), String> {
+    check_index(segments, index + 1)?;
+
+    let next = segments.remove(index + 1);
+    let merged_text = format!(
+        "{} {}",
+        segment_text(&segments[index]).trim(),
+        segment_text(&next).trim()
+    );
+
+    let segment = &mut segments[index];
+    segment["text"] = serde_json::json!(merged_text.trim());
+    segment["end"] = serde_json::json!(segment_time(&next, "end"));
+    if let Some(object) = segment.as_object_mut() {
+        object.remove("tokens");
+    }
+    Ok(())
+}
+
+/// Full transcription text rebuilt from its segments
+pub fn segments_text(segments: &[Value]) -> String {
+    segments
+        .iter()
+        .map(|s| segment_text(s).trim())
+        .filter(|t| !t.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Transcript with a `[Speaker N]` line per speaker turn, when the segments
+/// carry diarization labels
+pub fn speaker_labeled_text(segments: &[Value]) -> Option<String> {
+    if !segments.iter().any(|s| segment_speaker(s).is_some()) {
+        return None;
+    }
+
+    let mut turns: Vec<(Option<&str>, Vec<&str>)> = Vec::new();
+    for segment in segments {
+        let text = segment_text(segment).trim();
+        if text.is_empty() {
+            continue;
+        }
+        let speaker = segment_speaker(segment);
+        match turns.last_mut() {
+            Some((current, texts)) if *current == speaker => texts.push(text),
+            _ => turns.push((speaker, vec![text])),
+        }
+    }
+    Some(
+        turns
+            .into_iter()
+            .map(|(speaker, texts)| match speaker {
+                Some(speaker) => format!("[{}] {}", speaker, texts.join(" ")),
+                None => texts.join(" "),
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
+
+fn caption_timestamp(seconds: f64, decimal_separator: char) -> String {
+    let total_ms = (seconds.max(0.0) * 1000.0).round() as u64;
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        total_ms / 3_600_000,
+        total_ms / 60_000 % 60,
+        total_ms / 1000 % 60,
+        decimal_separator,
+        total_ms % 1000
+    )
+}
+
+/// Render segments as SRT or WebVTT captions
+pub fn format_captions(segments: &[Value], format: CaptionFormat) -> String {
+    let mut out = String::new();
+    if format == CaptionFormat::Vtt {
+        out.push_str("WEBVTT\n\n");
+    }
+
+    for (i, segment) in segments.iter().enumerate() {
+        let (separator, prefix) = match format {
+            CaptionFormat::Srt => (',', format!("{}\n", i + 1)),
+            CaptionFormat::Vtt => ('.', String::new()),
+        };
+        let speaker = segment_speaker(segment)
+            .map(|speaker| format!("[{}] ", speaker))
+            .unwrap_or_default();
+        out.push_str(&format!(
+            "{}{} --> {}\n{}{}\n\n",
+            prefix,
+            caption_timestamp(segment_time(segment, "start"), separator),
+            caption_timestamp(segment_time(segment, "end"), separator),
+            speaker,
+            segment_text(segment).trim()
+        ));
+    }
+    out
+}
+
+fn entry_segments(entry: &Value) -> Result<Vec<Value>, String> {
+    entry
+        .get("segments")
+        .and_then(|s| s.as_array())
+        .cloned()
+        .ok_or_else(|| "Transcription has no timed segments".to_string())
+}
+
+/// Apply `edit` to an entry's segments and store them. The entry's `text` is
+/// left alone: segments hold the raw engine output, while `text` is the final
+/// text after enhancement, corrections, snippets and output formatting.
+async fn edit_entry_segments<F>(app: &AppHandle, id: &str, edit: F) -> Result<Value, String>
+where
+    F: FnOnce(&mut Vec<Value>) -> Result<(), String>,
+{
+    let store = crate::utils::data_dir::transcriptions_store(app)
+        .map_err(|e| format!("Failed to get transcriptions store: {}", e))?;
+    let mut entry = store
+        .get(id)
+        .ok_or_else(|| format!("Transcription not found: {}", id))?;
+
+    let mut segments = entry_segments(&entry)?;
+    edit(&mut segments)?;
+    entry["segments"] = Value::Array(segments);
+
+    store.set(id, entry.clone());
+    store
+        .save()
+        .map_err(|e| format!("Failed to save edited transcription: {}", e))?;
+
+    let _ = emit_to_window(app, "main", "history-updated", ());
+    if let Err(e) = crate::commands::settings::update_tray_menu(app.clone()).await {
+        log::warn!("Failed to update tray menu after segment edit: {}", e);
+    }
+    Ok(entry)
+}
+
+#[tauri::command]
+pub async fn update_transcription_segment(
+    app: AppHandle,
+    id: String,
+    segment_index: usize,
+    new_text: String,
+) -> Result<Value, String> {
+    edit_entry_segments(&app, &id, |segments| {
+        update_segment_text(segments, segment_index, &new_text)
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn split_transcription_segment(
+    app: AppHandle,
+    id: String,
+    segment_index: usize,
+    at: usize,
+) -> Result<Value, String> {
+    edit_entry_segments(&app, &id, |segments| {
+        split_segment(segments, segment_index, at)
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn merge_transcription_segments(
+    app: AppHandle,
+    id: String,
+    segment_index: usize,
+) -> Result<Value, String> {
+    edit_entry_segments(&app, &id, |segments| {
+        merge_segments(segments, segment_index)
+    })
+    .await
+}
+
+/// Captions for a transcription, built from its (possibly edited) segments
+#[tauri::command]
+pub async fn export_transcription_captions(
+    app: AppHandle,
+    id: String,
+    format: CaptionFormat,
+) -> Result<String, String> {
+    let store = crate::utils::data_dir::transcriptions_store(&app)
+        .map_err(|e| format!("Failed to get transcriptions store: {}", e))?;
+    let entry = store
+        .get(&id)
+        .ok_or_else(|| format!("Transcription not found: {}", id))?;
+
+    Ok(format_captions(&entry_segments(&entry)?, format))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sample() -> Vec<Value> {
+        vec![
+            json!({"text": "hello wrld", "start": 0.0, "end": 1.0, "tokens": []}),
+            json!({"text": "second part", "start": 1.0, "end": 3.0}),
+        ]
+    }
+
+    #[test]
+    fn test_update_segment_keeps_timings() {
+        let mut segments = sample();
+        update_segment_text(&mut segments, 0, " hello world ").unwrap();
+        assert_eq!(segments[0]["text"], "hello world");
+        assert_eq!(segments[0]["start"], 0.0);
+        assert_eq!(segments[0]["end"], 1.0);
+        assert!(segments[0].get("tokens").is_none());
+
+        assert!(update_segment_text(&mut segments, 5, "x").is_err());
+        assert!(update_segment_text(&mut segments, 0, "   ").is_err());
+    }
+
+    #[test]
+    fn test_split_and_merge_round_trip() {
+        let mut segments = sample();
+        // "second part" split after "second" (6 of 11 chars)
+        split_segment(&mut segments, 1, 6).unwrap();
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments[1]["text"], "second");
+        assert_eq!(segments[2]["text"], "part");
+        let boundary = segments[1]["end"].as_f64().unwrap();
+        assert!((boundary - (1.0 + 2.0 * 6.0 / 11.0)).abs() < 1e-9);
+        assert_eq!(segments[2]["start"].as_f64().unwrap(), boundary);
+
+        merge_segments(&mut segments, 1).unwrap();
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[1]["text"], "second part");
+        assert_eq!(segments[1]["start"], 1.0);
+        assert_eq!(segments[1]["end"], 3.0);
+
+        assert!(merge_segments(&mut segments, 1).is_err());
+        assert!(split_segment(&mut segments, 0, 0).is_err());
+    }
+
+    #[test]
+    fn test_format_captions() {
+        let segments = vec![json!({"text": "Hi there", "start": 3661.5, "end": 3662.25})];
+        assert_eq!(
+            format_captions(&segments, CaptionFormat::Srt),
+            "1\n01:01:01,500 --> 01:01:02,250\nHi there\n\n"
+        );
+        assert_eq!(
+            format_captions(&segments, CaptionFormat::Vtt),
+            "WEBVTT\n\n01:01:01.500 --> 01:01:02.250\nHi there\n\n"
+        );
+        assert_eq!(segments_text(&sample()), "hello wrld second part");
+    }
+
+    #[test]
+    fn test_speaker_labels_in_text_and_captions() {
+        assert_eq!(speaker_labeled_text(&sample()), None);
+
+        let segments = vec![
+            json!({"text": "Hi.", "start": 0.0, "end": 1.0, "speaker": "Speaker 1"}),
+            json!({"text": "How are you?", "start": 1.0, "end": 2.0, "speaker": "Speaker 1"}),
+            json!({"text": "Fine.", "start": 2.0, "end": 3.0, "speaker": "Speaker 2"}),
+        ];
+        assert_eq!(
+            speaker_labeled_text(&segments).unwrap(),
+            "[Speaker 1] Hi. How are you?\n[Speaker 2] Fine."
+        );
+        assert!(
+            format_captions(&segments[2..], CaptionFormat::Srt).ends_with("[Speaker 2] Fine.\n\n")
+        );
+    }
+}
@@ -0,0 +1,284 @@
+use crate::audio::recorder::AudioRecorder;
+use crate::commands::audio::resolve_input_device;
+use crate::commands::settings::get_settings;
+use crate::parakeet::ParakeetManager;
+use crate::whisper::cache::TranscriberCache;
+use crate::whisper::manager::WhisperManager;
+use cpal::traits::{DeviceTrait, HostTrait};
+use serde::Serialize;
+use tauri::async_runtime::{Mutex as AsyncMutex, RwLock as AsyncRwLock};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SelfTestStatus {
+    Pass,
+    Fail,
+    /// Not applicable with the current settings
+    Skipped,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct SelfTestCheck {
+    pub name: String,
+    pub status: SelfTestStatus,
+    pub message: String,
+}
+
+impl SelfTestCheck {
+    fn from_result(name: &str, result: Result<String, String>) -> Self {
+        let (status, message) = match result {
+            Ok(message) => (SelfTestStatus::Pass, message),
+            Err(message) => (SelfTestStatus::Fail, message),
+        };
+        Self {
+            name: name.to_string(),
+            status,
+            message,
+        }
+    }
+
+    fn skipped(name: &str, message: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            status: SelfTestStatus::Skipped,
+            message: message.to_string(),
+        }
+    }
+}
+
+/// Result of `run_self_test`; `passed` is false if any check failed
+#[derive(Serialize, Debug, Clone)]
+pub struct SelfTestReport {
+    pub passed: bool,
+    pub checks: Vec<SelfTestCheck>,
+}
+
+impl SelfTestReport {
+    pub fn new(checks: Vec<SelfTestCheck>) -> Self {
+        Self {
+            passed: !checks.iter().any(|c| c.status == SelfTestStatus::Fail),
+            checks,
+        }
+    }
+}
+
+async fn check_model(app: &AppHandle) -> Result<String, String> {
+    let settings = get_settings(app.clone()).await?;
+    let model = settings.current_model;
+    if model.is_empty() {
+        return Err("No model is selected".to_string());
+    }
+
+    match settings.current_model_engine.as_str() {
+        "soniox" => {
+            if crate::secure_store::secure_has(app, "stt_api_key_soniox").unwrap_or(false) {
+                Ok("Soniox API key is configured".to_string())
+            } else {
+                Err("Soniox is selected but no API key is saved".to_string())
+            }
+        }
+        "parakeet" => {
+            app.state::<ParakeetManager>()
+                .load_model(app, &model)
+                .await
+                .map_err(|e| format!("Failed to load Parakeet model '{}': {:?}", model, e))?;
+            Ok(format!("Parakeet model '{}' loaded", model))
+        }
+        _ => {
+            let model_path = app
+                .state::<AsyncRwLock<WhisperManager>>()
+                .read()
+                .await
+                .get_model_path(&model)
+                .ok_or_else(|| format!("Model '{}' is not downloaded", model))?;
+            app.state::<AsyncMutex<TranscriberCache>>()
+                .lock()
+                .await
+                .get_or_create(&model_path)
+                .map_err(|e| format!("Failed to load model '{}': {}", model, e))?;
+            Ok(format!("Whisper model '{}' loaded", model))
+        }
+    }
+}
+
+async fn check_audio_device(app: &AppHandle) -> Result<String, String> {
+    let selected = get_settings(app.clone()).await?.selected_microphone;
+    tauri::async_runtime::spawn_blocking(move || {
+        let host = cpal::default_host();
+        let available = AudioRecorder::get_devices();
+        let default_device = host
+            .default_input_device()
+            .and_then(|device| device.name().ok());
+        let (name, issue) = resolve_input_device(selected.as_deref(), &available, default_device);
+        let name = name.ok_or_else(|| {
+            issue
+                .map(|i| i.message)
+                .unwrap_or_else(|| "No input device available".to_string())
+        })?;
+
+        let device = host
+            .input_devices()
+            .map_err(|e| format!("Failed to list input devices: {}", e))?
+            .find(|d| d.name().ok().as_deref() == Some(name.as_str()))
+            .ok_or_else(|| format!("Input device '{}' disappeared", name))?;
+        let config = device
+            .default_input_config()
+            .map_err(|e| format!("Failed to open '{}': {}", name, e))?;
+        Ok(format!(
+            "'{}' opened ({} Hz, {} channel(s))",
+            name,
+            config.sample_rate().0,
+            config.channels()
+        ))
+    })
+    .await
+    .map_err(|e| format!("Audio device check failed: {}", e))?
+}
+
+async fn check_permissions() -> Result<String, String> {
+    let microphone = crate::commands::permissions::check_microphone_permission().await?;
+    let accessibility = crate::commands::permissions::check_accessibility_permission().await?;
+    match (microphone, accessibility) {
+        (true, true) => Ok("Microphone and accessibility access granted".to_string()),
+        (false, true) => Err("Microphone access has not been granted".to_string()),
+        (true, false) => Err("Accessibility access has not been granted".to_string()),
+        (false, false) => {
+            Err("Microphone and accessibility access have not been granted".to_string())
+        }
+    }
+}
+
+const PROBE_KEY: &str = "self_test_probe";
+
+fn check_stores(app: &AppHandle) -> Result<String, String> {
+    let store = app
+        .store("settings")
+        .map_err(|e| format!("Failed to open settings: {}", e))?;
+    let probe = chrono::Utc::now().to_rfc3339();
+    store.set(PROBE_KEY, serde_json::json!(probe));
+    store
+        .save()
+        .map_err(|e| format!("Failed to write settings: {}", e))?;
+    let read_back = store.get(PROBE_KEY);
+    store.delete(PROBE_KEY);
+    store
+        .save()
+        .map_err(|e| format!("Failed to write settings: {}", e))?;
+    if read_back.and_then(|v| v.as_str().map(str::to_string)) != Some(probe) {
+        return Err("Settings did not read back what was written".to_string());
+    }
+
+    let history = crate::utils::data_dir::transcriptions_store(app)
+        .map_err(|e| format!("Failed to open transcription history: {}", e))?;
+    Ok(format!(
+        "Settings writable, {} history entries readable",
+        history.length()
+    ))
+}
+
+fn check_directories(app: &AppHandle) -> Result<String, String> {
+    let statuses = crate::utils::writable_paths::check_all(app);
+    let failed: Vec<String> = statuses
+        .iter()
+        .filter(|status| !status.writable)
+        .map(|status| {
+            format!(
+                "{} ({})",
+                status.name,
+                status.error.as_deref().unwrap_or("not writable")
+            )
+        })
+        .collect();
+    if failed.is_empty() {
+        Ok(format!("{} app directories writable", statuses.len()))
+    } else {
+        Err(format!("Cannot write to {}", failed.join(", ")))
+    }
+}
+
+async fn check_ai_provider(app: &AppHandle) -> Option<Result<String, String>> {
+    let settings = crate::commands::ai::get_ai_settings(app.clone())
+        .await
+        .ok()?;
+    if !settings.enabled || settings.provider.is_empty() {
+        return None;
+    }
+    Some(
+        crate::commands::ai::fetch_provider_models(app, &settings.provider)
+            .await
+            .map(|models| format!("{} responded ({} models)", settings.provider, models.len())),
+    )
+}
+
+/// Check the pieces a dictation depends on and report each one, so a single
+/// click tells the user (or support) what is broken
+#[tauri::command]
+pub async fn run_self_test(app: AppHandle) -> Result<SelfTestReport, String> {
+    log::info!("Running self-test");
+
+    let mut checks = vec![
+        SelfTestCheck::from_result("model", check_model(&app).await),
+        SelfTestCheck::from_result("audio_device", check_audio_device(&app).await),
+        SelfTestCheck::from_result("permissions", check_permissions().await),
+        SelfTestCheck::from_result("stores", check_stores(&app)),
+        SelfTestCheck::from_result("directories", check_directories(&app)),
+    ];
+    checks.push(match check_ai_provider(&app).await {
+        Some(result) => SelfTestCheck::from_result("ai_provider", result),
+        None => SelfTestCheck::skipped("ai_provider", "AI enhancement is not enabled"),
+    });
+
+    for check in &checks {
+        log::info!(
+            "Self-test {}: {:?} - {}",
+            check.name,
+            check.status,
+            check.message
+        );
+    }
+    Ok(SelfTestReport::new(checks))
+}
+
+/// Try a small write and delete in each directory the app saves into
+/// (models, recordings, stores, logs) and report which are writable
+#[tauri::command]
+pub async fn check_writable_paths(
+    app: AppHandle,
+) -> Result<Vec<crate::utils::writable_paths::PathStatus>, String> {
+    let statuses =
+        tauri::async_runtime::spawn_blocking(move || crate::utils::writable_paths::check_all(&app))
+            .await
+            .map_err(|e| format!("Path check failed: {}", e))?;
+    for status in statuses.iter().filter(|status| !status.writable) {
+        log::warn!(
+            "App directory {} ({}) is not writable: {}",
+            status.name,
+            status.path,
+            status.error.as_deref().unwrap_or("unknown error")
+        );
+    }
+    Ok(statuses)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_fails_only_on_failed_checks() {
+        let report = SelfTestReport::new(vec![
+            SelfTestCheck::from_result("model", Ok("loaded".to_string())),
+            SelfTestCheck::skipped("ai_provider", "disabled"),
+        ]);
+        assert!(report.passed);
+
+        let report = SelfTestReport::new(vec![
+            SelfTestCheck::from_result("model", Ok("loaded".to_string())),
+            SelfTestCheck::from_result("stores", Err("read-only".to_string())),
+        ]);
+        assert!(!report.passed);
+        assert_eq!(report.checks[1].status, SelfTestStatus::Fail);
+    }
+}
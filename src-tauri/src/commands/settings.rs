@@ -6,6 +6,7 @@ use crate::whisper::manager::WhisperManager;
 use crate::AppState;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::collections::HashMap;
 use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
 use tauri_plugin_store::StoreExt;
@@ -15,6 +16,13 @@ pub const MIN_INDICATOR_OFFSET: u32 = 10;
 pub const MAX_INDICATOR_OFFSET: u32 = 50;
 pub const DEFAULT_INDICATOR_OFFSET: u32 = 10;
 
+// Upper bound for the configurable paste delay (in milliseconds)
+pub const MAX_PASTE_DELAY_MS: u32 = 2000;
+// Upper bound for the per-character typing delay (in milliseconds)
+pub const MAX_TYPING_CHAR_DELAY_MS: u32 = 200;
+// Upper bound for the overlay auto-hide delay after completion (in milliseconds)
+pub const MAX_OVERLAY_TIMEOUT_MS: u32 = 10_000;
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct Settings {
     pub hotkey: String,
@@ -24,7 +32,7 @@ pub struct Settings {
     pub translate_to_english: bool,
     pub theme: String,
     pub transcription_cleanup_days: Option<u32>,
-    pub pill_position: Option<(f64, f64)>,
+    pub pill_position: Option<(f64, f64)>, // Window state (read-only here)
     pub launch_at_startup: bool,
     pub onboarding_completed: bool,
     pub check_updates_automatically: bool,
@@ -39,12 +47,129 @@ pub struct Settings {
     pub play_sound_on_recording_end: bool,
     // Pill indicator visibility mode: "never", "always", or "when_recording"
     pub pill_indicator_mode: String,
-    // Pill indicator screen position: "top", "center", or "bottom"
+    // Pill indicator screen position, e.g. "bottom-center" (read-only here)
     pub pill_indicator_position: String,
     // Pill indicator offset from screen edge in pixels (10-100)
     pub pill_indicator_offset: u32,
     // Pause system media during recording
     pub pause_media_during_recording: bool,
+    // Extra delay (ms) before and between paste steps for slow or remote apps
+    pub paste_delay_ms: u32,
+    // Delay (ms) after each character when output_mode is type
+    pub typing_char_delay_ms: u32,
+    // Emit interim transcription text while recording (local Whisper only)
+    pub partial_transcripts_enabled: bool,
+    // Maximum recording length in seconds before auto-stop (0 = no limit)
+    pub max_recording_seconds: u32,
+    // Show a native notification when transcription completes in the background
+    pub notify_on_complete: bool,
+    // Quiet hours for completion notifications ("HH:MM", local time)
+    pub quiet_hours_start: Option<String>,
+    // End of quiet hours ("HH:MM", local time)
+    pub quiet_hours_end: Option<String>,
+    // Record anonymized transcription latency metrics locally (opt-in)
+    pub telemetry_enabled: bool,
+    // Audio kept from just before the hotkey press, in ms (0 = disabled)
+    pub preroll_ms: u32,
+    // Denoise captured audio before local transcription
+    pub noise_suppression: bool,
+    // Noise suppression strength (0.0 - 1.0)
+    pub noise_suppression_strength: f32,
+    // Trigger phrase -> expansion text applied before pasting (read-only here)
+    pub snippets: HashMap<String, String>,
+    // Peak memory (MB) during transcription before the model is unloaded (0 = no cap)
+    pub max_memory_mb: u64,
+    // Output casing: "verbatim", "sentences", "lowercase" or "uppercase"
+    pub output_style: String,
+    // Trailing punctuation: "keep", "strip" or "add"
+    pub trailing_punctuation: String,
+    // Where finished transcriptions are delivered, in order (read-only here)
+    pub output_sinks: Vec<crate::output::OutputSink>,
+    // URL that receives each completed transcription as JSON
+    pub webhook_url: Option<String>,
+    // Extra HTTP headers sent with the webhook
+    pub webhook_headers: HashMap<String, String>,
+    // Webhook request timeout in seconds
+    pub webhook_timeout_secs: u64,
+    // Extra webhook attempts after a failure
+    pub webhook_retries: u32,
+    // Relocated data directory for models, recordings and history (read-only here)
+    pub data_dir_override: Option<String>,
+    // Days of log files to keep (0 = never delete)
+    pub log_retention_days: u32,
+    // Days to keep recordings (0 = never delete; orphans are always swept)
+    pub recording_retention_days: u32,
+    // Hours between automatic cleanup runs
+    pub cleanup_interval_hours: u32,
+    // Flag transcriptions whose confidence is below this (0.0 = off)
+    pub min_confidence: f32,
+    // Keep recordings after transcription, linked to their history entry
+    pub keep_recordings: bool,
+    // Input gain in dB applied while recording (read-only here; see set_recording_gain)
+    pub recording_gain_db: f32,
+    // Scale each recording so its loudest sample reaches normalize_target_peak
+    pub normalize_peak: bool,
+    // Peak targeted by normalize_peak, 0.0-1.0 of full scale
+    pub normalize_target_peak: f32,
+    // Overlay monitor: "primary", "follow_active" or a monitor name (read-only here)
+    pub overlay_monitor: String,
+    // Log line format: "text" or "json"
+    pub log_format: String,
+    // Split file transcriptions longer than this into chunks (0 = never)
+    pub chunk_seconds: u32,
+    // Audio shared by neighbouring chunks, used to stitch their text
+    pub overlap_seconds: u32,
+    // Zero-fill recordings before deleting them
+    pub secure_delete_recordings: bool,
+    // Reload settings automatically when the store file changes on disk
+    pub watch_settings_file: bool,
+    // Cut leading/trailing silence before transcription
+    pub trim_silence: bool,
+    // Paste transcriptions without saving them or their recordings (read-only here)
+    pub incognito_mode: bool,
+    // How text reaches the focused app: paste, type or type_animated
+    pub output_mode: String,
+    // Characters per second when output_mode is type_animated
+    pub typing_speed_cps: u32,
+    // output_mode overrides keyed by app name or id (as captured for history)
+    pub output_mode_by_app: HashMap<String, String>,
+    // Keep the overlay visible (with a spinner) until transcription finishes
+    pub overlay_during_transcription: bool,
+    // How long the overlay stays up after a transcription completes (0 = hide at once)
+    pub overlay_timeout_ms: u32,
+    // Label speaker turns in segments; only accepted with a -tdrz Whisper model
+    pub diarization: bool,
+    // What to do when a recording has no speech: "skip", "paste_nothing" or "notify"
+    pub on_empty_audio: String,
+    // Proxy for plain HTTP requests (model downloads, AI calls). Credentials
+    // live in the secure store (set_proxy_credentials); any typed in the URL
+    // are moved there on save and never returned
+    pub http_proxy: Option<String>,
+    // Proxy for HTTPS requests; credentials as for http_proxy
+    pub https_proxy: Option<String>,
+    // Comma-separated hosts that bypass the proxy
+    pub no_proxy: Option<String>,
+    // Recording hotkey while transcribing: "ignore", "queue_new_recording" or "cancel_and_restart"
+    pub hotkey_while_busy: String,
+    // Type finalized partial text into the focused app while recording (local
+    // Whisper, toggle mode only)
+    pub live_typing: bool,
+    // Check the model manifest for newer model files at startup
+    pub auto_check_model_updates: bool,
+    // Accessible audio cues for recording start, stop and transcription complete
+    pub play_sound_cues: bool,
+    // Sound cue volume, 0.0-1.0
+    pub sound_cue_volume: f32,
+    // WAV file played instead of the built-in start tone
+    pub sound_cue_start_file: Option<String>,
+    // WAV file played instead of the built-in stop tone
+    pub sound_cue_stop_file: Option<String>,
+    // WAV file played instead of the built-in complete tone
+    pub sound_cue_complete_file: Option<String>,
+    // Stay silent while the system is in Do Not Disturb / Focus mode
+    pub sound_cues_respect_dnd: bool,
+    // Recording hotkey presses this soon after the last accepted one are ignored
+    pub hotkey_cooldown_ms: u64,
 }
 
 impl Default for Settings {
@@ -72,6 +197,63 @@ impl Default for Settings {
             pill_indicator_position: "bottom-center".to_string(), // Default to bottom center of screen
             pill_indicator_offset: DEFAULT_INDICATOR_OFFSET,
             pause_media_during_recording: !cfg!(target_os = "macos"),
+            paste_delay_ms: 0,                  // Default to no extra delay
+            typing_char_delay_ms: 0,            // Default to typing at full speed
+            partial_transcripts_enabled: false, // Default to final transcription only
+            max_recording_seconds: 0,           // Default to no limit
+            notify_on_complete: false,          // Default to no notifications
+            quiet_hours_start: None,
+            quiet_hours_end: None,
+            telemetry_enabled: false, // Default to no telemetry
+            preroll_ms: 0,            // Default to no pre-roll (keeps the mic closed when idle)
+            noise_suppression: false, // Default to raw audio
+            noise_suppression_strength: crate::audio::denoise::DEFAULT_STRENGTH,
+            snippets: HashMap::new(),
+            max_memory_mb: 0,                     // Default to no cap
+            output_style: "verbatim".to_string(), // Default to text as transcribed
+            trailing_punctuation: "keep".to_string(),
+            output_sinks: crate::output::default_output_sinks(), // Default to pasting into the active app
+            webhook_url: None,
+            webhook_headers: HashMap::new(),
+            webhook_timeout_secs: crate::output::webhook::DEFAULT_TIMEOUT_SECS,
+            webhook_retries: 0, // Default to a single attempt
+            data_dir_override: None,
+            log_retention_days: crate::utils::auto_cleanup::DEFAULT_LOG_RETENTION_DAYS,
+            recording_retention_days: crate::utils::auto_cleanup::DEFAULT_RECORDING_RETENTION_DAYS,
+            cleanup_interval_hours: crate::utils::auto_cleanup::DEFAULT_CLEANUP_INTERVAL_HOURS,
+            min_confidence: 0.0, // Off by default
+            keep_recordings: false,
+            recording_gain_db: 0.0,
+            normalize_peak: false,
+            normalize_target_peak: crate::audio::gain::DEFAULT_TARGET_PEAK,
+            overlay_monitor: crate::window_manager::OVERLAY_MONITOR_PRIMARY.to_string(),
+            log_format: "text".to_string(),
+            chunk_seconds: crate::audio::chunking::DEFAULT_CHUNK_SECONDS,
+            overlap_seconds: crate::audio::chunking::DEFAULT_OVERLAP_SECONDS,
+            secure_delete_recordings: false,
+            watch_settings_file: false,
+            trim_silence: false,
+            incognito_mode: false,
+            output_mode: "paste".to_string(),
+            typing_speed_cps: crate::commands::text::DEFAULT_TYPING_SPEED_CPS,
+            output_mode_by_app: HashMap::new(),
+            overlay_during_transcription: true,
+            overlay_timeout_ms: 0,
+            diarization: false,
+            on_empty_audio: "notify".to_string(),
+            http_proxy: None,
+            https_proxy: None,
+            no_proxy: None,
+            hotkey_while_busy: "ignore".to_string(),
+            live_typing: false,              // Default to output after recording
+            auto_check_model_updates: false, // Default to manual checks
+            play_sound_cues: false, // Off by default; replaces the start/end sounds when on
+            sound_cue_volume: 0.6,
+            sound_cue_start_file: None,
+            sound_cue_stop_file: None,
+            sound_cue_complete_file: None,
+            sound_cues_respect_dnd: true,
+            hotkey_cooldown_ms: crate::recording::hotkeys::DEFAULT_HOTKEY_COOLDOWN_MS,
         }
     }
 }
@@ -143,7 +325,7 @@ pub(crate) fn resolve_pill_indicator_mode(
 pub async fn get_settings(app: AppHandle) -> Result<Settings, String> {
     let store = app.store("settings").map_err(|e| e.to_string())?;
 
-    let settings = Settings {
+    let mut settings = Settings {
         hotkey: store
             .get("hotkey")
             .and_then(|v| v.as_str().map(|s| s.to_string()))
@@ -243,13 +425,272 @@ pub async fn get_settings(app: AppHandle) -> Result<Settings, String> {
             .get("pause_media_during_recording")
             .and_then(|v| v.as_bool())
             .unwrap_or_else(|| Settings::default().pause_media_during_recording),
+        paste_delay_ms: store
+            .get("paste_delay_ms")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(MAX_PASTE_DELAY_MS as u64) as u32)
+            .unwrap_or_else(|| Settings::default().paste_delay_ms),
+        typing_char_delay_ms: store
+            .get("typing_char_delay_ms")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(MAX_TYPING_CHAR_DELAY_MS as u64) as u32)
+            .unwrap_or_else(|| Settings::default().typing_char_delay_ms),
+        partial_transcripts_enabled: store
+            .get("partial_transcripts_enabled")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().partial_transcripts_enabled),
+        max_recording_seconds: store
+            .get("max_recording_seconds")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(u32::MAX as u64) as u32)
+            .unwrap_or_else(|| Settings::default().max_recording_seconds),
+        notify_on_complete: store
+            .get("notify_on_complete")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().notify_on_complete),
+        quiet_hours_start: store
+            .get("quiet_hours_start")
+            .and_then(|v| v.as_str().map(|s| Some(s.to_string())))
+            .unwrap_or_else(|| Settings::default().quiet_hours_start),
+        quiet_hours_end: store
+            .get("quiet_hours_end")
+            .and_then(|v| v.as_str().map(|s| Some(s.to_string())))
+            .unwrap_or_else(|| Settings::default().quiet_hours_end),
+        telemetry_enabled: store
+            .get("telemetry_enabled")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().telemetry_enabled),
+        preroll_ms: store
+            .get("preroll_ms")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(crate::audio::preroll::MAX_PREROLL_MS as u64) as u32)
+            .unwrap_or_else(|| Settings::default().preroll_ms),
+        noise_suppression: store
+            .get("noise_suppression")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().noise_suppression),
+        noise_suppression_strength: store
+            .get("noise_suppression_strength")
+            .and_then(|v| v.as_f64())
+            .map(|v| (v as f32).clamp(0.0, 1.0))
+            .unwrap_or_else(|| Settings::default().noise_suppression_strength),
+        snippets: store
+            .get("snippets")
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_else(|| Settings::default().snippets),
+        max_memory_mb: store
+            .get("max_memory_mb")
+            .and_then(|v| v.as_u64())
+            .unwrap_or_else(|| Settings::default().max_memory_mb),
+        output_style: store
+            .get("output_style")
+            .and_then(|v| v.as_str().map(|s| s.to_string()))
+            .unwrap_or_else(|| Settings::default().output_style),
+        trailing_punctuation: store
+            .get("trailing_punctuation")
+            .and_then(|v| v.as_str().map(|s| s.to_string()))
+            .unwrap_or_else(|| Settings::default().trailing_punctuation),
+        output_sinks: store
+            .get("output_sinks")
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_else(|| Settings::default().output_sinks),
+        webhook_url: store
+            .get("webhook_url")
+            .and_then(|v| v.as_str().map(|s| Some(s.to_string())))
+            .unwrap_or_else(|| Settings::default().webhook_url),
+        webhook_headers: store
+            .get("webhook_headers")
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_else(|| Settings::default().webhook_headers),
+        webhook_timeout_secs: store
+            .get("webhook_timeout_secs")
+            .and_then(|v| v.as_u64())
+            .unwrap_or_else(|| Settings::default().webhook_timeout_secs),
+        webhook_retries: store
+            .get("webhook_retries")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as u32)
+            .unwrap_or_else(|| Settings::default().webhook_retries),
+        data_dir_override: store
+            .get("data_dir_override")
+            .and_then(|v| v.as_str().map(|s| Some(s.to_string())))
+            .unwrap_or_else(|| Settings::default().data_dir_override),
+        log_retention_days: store
+            .get("log_retention_days")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as u32)
+            .unwrap_or_else(|| Settings::default().log_retention_days),
+        recording_retention_days: store
+            .get("recording_retention_days")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as u32)
+            .unwrap_or_else(|| Settings::default().recording_retention_days),
+        cleanup_interval_hours: store
+            .get("cleanup_interval_hours")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as u32)
+            .unwrap_or_else(|| Settings::default().cleanup_interval_hours),
+        min_confidence: store
+            .get("min_confidence")
+            .and_then(|v| v.as_f64())
+            .map(|v| (v as f32).clamp(0.0, 1.0))
+            .unwrap_or_else(|| Settings::default().min_confidence),
+        keep_recordings: store
+            .get("keep_recordings")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().keep_recordings),
+        recording_gain_db: store
+            .get("recording_gain_db")
+            .and_then(|v| v.as_f64())
+            .map(|v| crate::audio::gain::clamp_gain_db(v as f32))
+            .unwrap_or_else(|| Settings::default().recording_gain_db),
+        normalize_peak: store
+            .get("normalize_peak")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().normalize_peak),
+        normalize_target_peak: store
+            .get("normalize_target_peak")
+            .and_then(|v| v.as_f64())
+            .map(|v| (v as f32).clamp(0.0, 1.0))
+            .unwrap_or_else(|| Settings::default().normalize_target_peak),
+        overlay_monitor: store
+            .get("overlay_monitor")
+            .and_then(|v| v.as_str().map(|s| s.to_string()))
+            .unwrap_or_else(|| Settings::default().overlay_monitor),
+        log_format: store
+            .get("log_format")
+            .and_then(|v| v.as_str().map(|s| s.to_string()))
+            .unwrap_or_else(|| Settings::default().log_format),
+        chunk_seconds: store
+            .get("chunk_seconds")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as u32)
+            .unwrap_or_else(|| Settings::default().chunk_seconds),
+        overlap_seconds: store
+            .get("overlap_seconds")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as u32)
+            .unwrap_or_else(|| Settings::default().overlap_seconds),
+        secure_delete_recordings: store
+            .get("secure_delete_recordings")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().secure_delete_recordings),
+        watch_settings_file: store
+            .get("watch_settings_file")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().watch_settings_file),
+        trim_silence: store
+            .get("trim_silence")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().trim_silence),
+        incognito_mode: store
+            .get("incognito_mode")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().incognito_mode),
+        output_mode: store
+            .get("output_mode")
+            .and_then(|v| v.as_str().map(|s| s.to_string()))
+            .unwrap_or_else(|| Settings::default().output_mode),
+        typing_speed_cps: store
+            .get("typing_speed_cps")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(crate::commands::text::MAX_TYPING_SPEED_CPS as u64) as u32)
+            .unwrap_or_else(|| Settings::default().typing_speed_cps),
+        output_mode_by_app: store
+            .get("output_mode_by_app")
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_else(|| Settings::default().output_mode_by_app),
+        overlay_during_transcription: store
+            .get("overlay_during_transcription")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().overlay_during_transcription),
+        overlay_timeout_ms: store
+            .get("overlay_timeout_ms")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(MAX_OVERLAY_TIMEOUT_MS as u64) as u32)
+            .unwrap_or_else(|| Settings::default().overlay_timeout_ms),
+        diarization: store
+            .get("diarization")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().diarization),
+        on_empty_audio: store
+            .get("on_empty_audio")
+            .and_then(|v| v.as_str().map(|s| s.to_string()))
+            .unwrap_or_else(|| Settings::default().on_empty_audio),
+        http_proxy: store
+            .get("http_proxy")
+            .and_then(|v| v.as_str().map(|s| Some(proxy_url_without_credentials(s))))
+            .unwrap_or_else(|| Settings::default().http_proxy),
+        https_proxy: store
+            .get("https_proxy")
+            .and_then(|v| v.as_str().map(|s| Some(proxy_url_without_credentials(s))))
+            .unwrap_or_else(|| Settings::default().https_proxy),
+        no_proxy: store
+            .get("no_proxy")
+            .and_then(|v| v.as_str().map(|s| Some(s.to_string())))
+            .unwrap_or_else(|| Settings::default().no_proxy),
+        hotkey_while_busy: store
+            .get("hotkey_while_busy")
+            .and_then(|v| v.as_str().map(|s| s.to_string()))
+            .unwrap_or_else(|| Settings::default().hotkey_while_busy),
+        live_typing: store
+            .get("live_typing")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().live_typing),
+        auto_check_model_updates: store
+            .get("auto_check_model_updates")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().auto_check_model_updates),
+        play_sound_cues: store
+            .get("play_sound_cues")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().play_sound_cues),
+        sound_cue_volume: store
+            .get("sound_cue_volume")
+            .and_then(|v| v.as_f64())
+            .map(|v| (v as f32).clamp(0.0, 1.0))
+            .unwrap_or_else(|| Settings::default().sound_cue_volume),
+        sound_cue_start_file: store
+            .get("sound_cue_start_file")
+            .and_then(|v| v.as_str().map(|s| Some(s.to_string())))
+            .unwrap_or_else(|| Settings::default().sound_cue_start_file),
+        sound_cue_stop_file: store
+            .get("sound_cue_stop_file")
+            .and_then(|v| v.as_str().map(|s| Some(s.to_string())))
+            .unwrap_or_else(|| Settings::default().sound_cue_stop_file),
+        sound_cue_complete_file: store
+            .get("sound_cue_complete_file")
+            .and_then(|v| v.as_str().map(|s| Some(s.to_string())))
+            .unwrap_or_else(|| Settings::default().sound_cue_complete_file),
+        sound_cues_respect_dnd: store
+            .get("sound_cues_respect_dnd")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_else(|| Settings::default().sound_cues_respect_dnd),
+        hotkey_cooldown_ms: store
+            .get("hotkey_cooldown_ms")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(crate::recording::hotkeys::MAX_HOTKEY_COOLDOWN_MS))
+            .unwrap_or_else(|| Settings::default().hotkey_cooldown_ms),
     };
+    // Reported off while the selected model can't diarize, so the UI hides it
+    settings.diarization &= crate::whisper::diarization::model_can_diarize(
+        &settings.current_model_engine,
+        &settings.current_model,
+    );
 
     Ok(settings)
 }
 
 #[tauri::command]
 pub async fn save_settings(app: AppHandle, settings: Settings) -> Result<(), String> {
+    if settings.diarization
+        && !crate::whisper::diarization::model_can_diarize(
+            &settings.current_model_engine,
+            &settings.current_model,
+        )
+    {
+        return Err("Speaker labels need a diarization (-tdrz) Whisper model".to_string());
+    }
     let store = app.store("settings").map_err(|e| e.to_string())?;
 
     // Check if model, recording mode, onboarding, and pill indicator mode changed
@@ -269,15 +710,12 @@ pub async fn save_settings(app: AppHandle, settings: Settings) -> Result<(), Str
         .get("pill_indicator_mode")
         .and_then(|v| v.as_str().map(|s| s.to_string()))
         .unwrap_or_else(|| Settings::default().pill_indicator_mode);
-    let old_pill_indicator_position = store
-        .get("pill_indicator_position")
-        .and_then(|v| v.as_str().map(|s| s.to_string()))
-        .unwrap_or_else(|| Settings::default().pill_indicator_position);
     let old_pill_indicator_offset = store
         .get("pill_indicator_offset")
         .and_then(|v| v.as_u64())
         .map(|v| v as u32)
         .unwrap_or_else(|| Settings::default().pill_indicator_offset);
+    let old_proxy = crate::utils::proxy::ProxyConfig::from_store(&app);
 
     store.set("hotkey", json!(settings.hotkey));
     store.set("current_model", json!(settings.current_model));
@@ -323,10 +761,7 @@ pub async fn save_settings(app: AppHandle, settings: Settings) -> Result<(), Str
         json!(settings.play_sound_on_recording_end),
     );
     store.set("pill_indicator_mode", json!(settings.pill_indicator_mode));
-    store.set(
-        "pill_indicator_position",
-        json!(settings.pill_indicator_position),
-    );
+    // pill_indicator_position and overlay_monitor only change through set_overlay_position
     store.set(
         "pill_indicator_offset",
         json!(settings
@@ -337,11 +772,134 @@ pub async fn save_settings(app: AppHandle, settings: Settings) -> Result<(), Str
         "pause_media_during_recording",
         json!(settings.pause_media_during_recording),
     );
-
-    // Save pill position if provided
-    if let Some((x, y)) = settings.pill_position {
-        store.set("pill_position", json!([x, y]));
+    store.set(
+        "paste_delay_ms",
+        json!(settings.paste_delay_ms.min(MAX_PASTE_DELAY_MS)),
+    );
+    store.set(
+        "typing_char_delay_ms",
+        json!(settings.typing_char_delay_ms.min(MAX_TYPING_CHAR_DELAY_MS)),
+    );
+    store.set(
+        "partial_transcripts_enabled",
+        json!(settings.partial_transcripts_enabled),
+    );
+    store.set(
+        "max_recording_seconds",
+        json!(settings.max_recording_seconds),
+    );
+    store.set("notify_on_complete", json!(settings.notify_on_complete));
+    store.set("quiet_hours_start", json!(settings.quiet_hours_start));
+    store.set("quiet_hours_end", json!(settings.quiet_hours_end));
+    store.set("telemetry_enabled", json!(settings.telemetry_enabled));
+    store.set("preroll_ms", json!(settings.preroll_ms));
+    store.set("noise_suppression", json!(settings.noise_suppression));
+    store.set(
+        "noise_suppression_strength",
+        json!(settings.noise_suppression_strength.clamp(0.0, 1.0)),
+    );
+    // snippets only change through the snippet and dictionary import commands
+    store.set("max_memory_mb", json!(settings.max_memory_mb));
+    store.set("output_style", json!(settings.output_style));
+    store.set("trailing_punctuation", json!(settings.trailing_punctuation));
+    // output_sinks only change through set_output_sinks
+    store.set("webhook_url", json!(settings.webhook_url));
+    store.set("webhook_headers", json!(settings.webhook_headers));
+    store.set("webhook_timeout_secs", json!(settings.webhook_timeout_secs));
+    store.set("webhook_retries", json!(settings.webhook_retries));
+    // data_dir_override only changes through migrate_data_dir, which moves the files
+    store.set("log_retention_days", json!(settings.log_retention_days));
+    store.set(
+        "recording_retention_days",
+        json!(settings.recording_retention_days),
+    );
+    store.set(
+        "cleanup_interval_hours",
+        json!(settings.cleanup_interval_hours),
+    );
+    store.set(
+        "min_confidence",
+        json!(settings.min_confidence.clamp(0.0, 1.0)),
+    );
+    store.set("keep_recordings", json!(settings.keep_recordings));
+    // recording_gain_db only changes through set_recording_gain, which applies it live
+    store.set("normalize_peak", json!(settings.normalize_peak));
+    store.set(
+        "normalize_target_peak",
+        json!(settings.normalize_target_peak.clamp(0.0, 1.0)),
+    );
+    store.set("log_format", json!(settings.log_format));
+    crate::utils::log_format::set_log_format(crate::utils::log_format::LogFormat::from_setting(
+        &settings.log_format,
+    ));
+    store.set("chunk_seconds", json!(settings.chunk_seconds));
+    store.set("overlap_seconds", json!(settings.overlap_seconds));
+    store.set(
+        "secure_delete_recordings",
+        json!(settings.secure_delete_recordings),
+    );
+    crate::utils::secure_delete::set_enabled(settings.secure_delete_recordings);
+    store.set("watch_settings_file", json!(settings.watch_settings_file));
+    store.set("trim_silence", json!(settings.trim_silence));
+    // incognito_mode only changes through set_incognito
+    store.set("output_mode", json!(settings.output_mode));
+    store.set("typing_speed_cps", json!(settings.typing_speed_cps));
+    store.set("output_mode_by_app", json!(settings.output_mode_by_app));
+    store.set(
+        "overlay_during_transcription",
+        json!(settings.overlay_during_transcription),
+    );
+    store.set(
+        "overlay_timeout_ms",
+        json!(settings.overlay_timeout_ms.min(MAX_OVERLAY_TIMEOUT_MS)),
+    );
+    store.set("diarization", json!(settings.diarization));
+    store.set("on_empty_audio", json!(settings.on_empty_audio));
+    // Credentials typed into a proxy URL go to the secure store instead
+    let mut typed_credentials = None;
+    for (key, url) in [
+        ("http_proxy", &settings.http_proxy),
+        ("https_proxy", &settings.https_proxy),
+    ] {
+        let url = url.as_deref().map(crate::utils::proxy::split_credentials);
+        if let Some((_, Some(credentials))) = &url {
+            typed_credentials = typed_credentials.or(Some(credentials.clone()));
+        }
+        store.set(key, json!(url.map(|(url, _)| url)));
+    }
+    if let Some(credentials) = &typed_credentials {
+        crate::utils::proxy::store_credentials(&app, Some(credentials))?;
     }
+    store.set("no_proxy", json!(settings.no_proxy));
+    store.set("hotkey_while_busy", json!(settings.hotkey_while_busy));
+    store.set("live_typing", json!(settings.live_typing));
+    store.set(
+        "auto_check_model_updates",
+        json!(settings.auto_check_model_updates),
+    );
+    store.set("play_sound_cues", json!(settings.play_sound_cues));
+    store.set(
+        "sound_cue_volume",
+        json!(settings.sound_cue_volume.clamp(0.0, 1.0)),
+    );
+    store.set("sound_cue_start_file", json!(settings.sound_cue_start_file));
+    store.set("sound_cue_stop_file", json!(settings.sound_cue_stop_file));
+    store.set(
+        "sound_cue_complete_file",
+        json!(settings.sound_cue_complete_file),
+    );
+    store.set(
+        "sound_cues_respect_dnd",
+        json!(settings.sound_cues_respect_dnd),
+    );
+    store.set(
+        "hotkey_cooldown_ms",
+        json!(settings
+            .hotkey_cooldown_ms
+            .min(crate::recording::hotkeys::MAX_HOTKEY_COOLDOWN_MS)),
+    );
+
+    // pill_position is window state; saving the cached copy would undo reset_window_state
 
     store.save().map_err(|e| e.to_string())?;
 
@@ -401,6 +959,12 @@ pub async fn save_settings(app: AppHandle, settings: Settings) -> Result<(), Str
 
     // Invalidate recording config cache when settings change
     crate::commands::audio::invalidate_recording_config_cache(&app).await;
+    if crate::utils::proxy::ProxyConfig::from_store(&app) != old_proxy {
+        crate::utils::proxy::refresh(&app);
+    }
+
+    // Microphone or pre-roll window may have changed
+    crate::commands::audio::apply_preroll_settings(&app);
 
     // Preload new model and update tray menu if model changed
     let is_parakeet_engine = settings.current_model_engine == "parakeet";
@@ -488,42 +1052,6 @@ pub async fn save_settings(app: AppHandle, settings: Settings) -> Result<(), Str
         }
     }
 
-    // Handle pill window position when pill_indicator_position setting changes
-    // We need to recreate the pill window at the new position since repositioning doesn't work reliably
-    if old_pill_indicator_position != settings.pill_indicator_position {
-        log::info!(
-            "Pill indicator position changed from '{}' to '{}'",
-            old_pill_indicator_position,
-            settings.pill_indicator_position
-        );
-
-        // Check if pill should be visible based on current mode
-        let should_show = match settings.pill_indicator_mode.as_str() {
-            "never" => false,
-            "always" => true,
-            "when_recording" => {
-                let app_state = app.state::<crate::AppState>();
-                let current_state = app_state.get_current_state();
-                !matches!(current_state, crate::RecordingState::Idle)
-            }
-            _ => false,
-        };
-
-        if should_show {
-            // Close and recreate the pill window at the new position
-            if let Err(e) = crate::commands::window::hide_pill_widget(app.clone()).await {
-                log::warn!("Failed to hide pill window for position change: {}", e);
-            }
-            if let Err(e) = crate::commands::window::show_pill_widget(app.clone()).await {
-                log::warn!("Failed to show pill window at new position: {}", e);
-            }
-            log::info!(
-                "Recreated pill window at new position: {}",
-                settings.pill_indicator_position
-            );
-        }
-    }
-
     // Handle pill window offset change - reposition the pill window
     if old_pill_indicator_offset != settings.pill_indicator_offset {
         log::info!(
@@ -535,8 +1063,11 @@ pub async fn save_settings(app: AppHandle, settings: Settings) -> Result<(), Str
         // Reposition the pill window if it's currently visible
         let window_manager = app.state::<crate::WindowManager>();
         if window_manager.has_pill_window() {
-            window_manager
-                .reposition_floating_windows_with_position(&settings.pill_indicator_position);
+            let position = store
+                .get("pill_indicator_position")
+                .and_then(|v| v.as_str().map(|s| s.to_string()))
+                .unwrap_or_else(|| Settings::default().pill_indicator_position);
+            window_manager.reposition_floating_windows_with_position(&position);
             log::info!(
                 "Repositioned pill window with new offset: {}",
                 settings.pill_indicator_offset
@@ -552,6 +1083,222 @@ pub async fn save_settings(app: AppHandle, settings: Settings) -> Result<(), Str
     Ok(())
 }
 
+/// A single key that differs between two settings snapshots
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct SettingChange {
+    pub key: String,
+    pub old_value: Option<serde_json::Value>,
+    pub new_value: Option<serde_json::Value>,
+}
+
+/// Setting names that are secrets on their own; others match by affix below
+const SECRET_SETTING_KEYS: &[&str] = &[
+    "api_key",
+    "apikey",
+    "secret",
+    "token",
+    "password",
+    "license_key",
+];
+
+/// Keys that must never be imported or reported in a settings diff.
+/// Matched by exact name or affix so settings like `max_tokens` stay visible.
+pub fn is_secret_setting_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    SECRET_SETTING_KEYS.contains(&key.as_str())
+        || ["ai_api_key_", "stt_api_key_"]
+            .iter()
+            .any(|prefix| key.starts_with(prefix))
+        || ["_api_key", "_token", "_secret", "_password"]
+            .iter()
+            .any(|suffix| key.ends_with(suffix))
+}
+
+/// Generic top-level JSON diff between two settings snapshots (secrets excluded)
+pub fn diff_settings_json(
+    before: &serde_json::Map<String, serde_json::Value>,
+    after: &serde_json::Map<String, serde_json::Value>,
+) -> Vec<SettingChange> {
+    let mut keys: Vec<&String> = before.keys().chain(after.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    keys.into_iter()
+        .filter(|key| !is_secret_setting_key(key))
+        .filter_map(|key| {
+            let old_value = before.get(key);
+            let new_value = after.get(key);
+            if old_value == new_value {
+                return None;
+            }
+            Some(SettingChange {
+                key: key.clone(),
+                old_value: old_value.cloned(),
+                new_value: new_value.cloned(),
+            })
+        })
+        .collect()
+}
+
+/// Merge an exported settings object into the settings store and apply it to
+/// the running app the way `reload_settings` does.
+/// Emits `settings-diff` with every key whose value actually changed.
+#[tauri::command]
+pub async fn import_settings(
+    app: AppHandle,
+    settings: serde_json::Value,
+) -> Result<Vec<SettingChange>, String> {
+    let incoming = settings
+        .as_object()
+        .ok_or_else(|| "Imported settings must be a JSON object".to_string())?;
+
+    let store = app
+        .store("settings")
+        .map_err(|e| format!("Failed to access settings store: {}", e))?;
+
+    let before: serde_json::Map<String, serde_json::Value> = store.entries().into_iter().collect();
+
+    for (key, value) in incoming {
+        if is_secret_setting_key(key) {
+            log::warn!("Skipping secret key '{}' during settings import", key);
+            continue;
+        }
+        store.set(key.as_str(), value.clone());
+    }
+
+    store
+        .save()
+        .map_err(|e| format!("Failed to save imported settings: {}", e))?;
+
+    let after: serde_json::Map<String, serde_json::Value> = store.entries().into_iter().collect();
+    let changes = diff_settings_json(&before, &after);
+
+    log::info!("Imported settings: {} keys changed", changes.len());
+
+    // save_settings emits settings-changed
+    if !changes.is_empty() {
+        apply_stored_settings(&app, &before, &after).await?;
+    }
+
+    if let Err(e) = app.emit("settings-diff", &changes) {
+        log::warn!("Failed to emit settings-diff event: {}", e);
+    }
+
+    Ok(changes)
+}
+
+/// Keys `save_settings` compares against the stored value to decide which
+/// runtime effects to re-apply
+const SAVE_SETTINGS_DIFF_KEYS: &[&str] = &[
+    "current_model",
+    "recording_mode",
+    "onboarding_completed",
+    "pill_indicator_mode",
+    "pill_indicator_position",
+    "pill_indicator_offset",
+    "overlay_monitor",
+];
+
+/// Re-read the settings store from disk, e.g. after it was edited externally,
+/// and re-apply what takes effect at runtime (hotkeys, recording config, log
+/// format, backend, indicator). Emits `settings-reloaded` with the changed keys.
+#[tauri::command]
+pub async fn reload_settings(app: AppHandle) -> Result<(), String> {
+    let store = app
+        .store("settings")
+        .map_err(|e| format!("Failed to access settings store: {}", e))?;
+
+    let before: serde_json::Map<String, serde_json::Value> = store.entries().into_iter().collect();
+    // reload() drops values set since the last save. Keys the file doesn't
+    // have yet are such values, so put them back and save them; keys in the
+    // file take its value.
+    let on_disk: serde_json::Map<String, serde_json::Value> =
+        crate::utils::data_dir::settings_store_path(&app)
+            .ok()
+            .and_then(|path| std::fs::read(path).ok())
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default();
+    store
+        .reload()
+        .map_err(|e| format!("Failed to reload settings: {}", e))?;
+    let unsaved: Vec<(&String, &serde_json::Value)> = before
+        .iter()
+        .filter(|(key, _)| !on_disk.contains_key(*key))
+        .collect();
+    if !unsaved.is_empty() {
+        for (key, value) in unsaved {
+            store.set(key.clone(), value.clone());
+        }
+        store
+            .save()
+            .map_err(|e| format!("Failed to save settings: {}", e))?;
+    }
+    let after: serde_json::Map<String, serde_json::Value> = store.entries().into_iter().collect();
+
+    let changes = diff_settings_json(&before, &after);
+    if changes.is_empty() {
+        log::debug!("Settings reloaded from disk, nothing changed");
+        return Ok(());
+    }
+    log::info!(
+        "Settings reloaded from disk: {} keys changed",
+        changes.len()
+    );
+
+    apply_stored_settings(&app, &before, &after).await?;
+
+    if let Err(e) = app.emit("settings-reloaded", &changes) {
+        log::warn!("Failed to emit settings-reloaded event: {}", e);
+    }
+    Ok(())
+}
+
+/// Apply settings already written to the store (by reload or import) to the
+/// running app. `before` is the store as it was before the write.
+async fn apply_stored_settings(
+    app: &AppHandle,
+    before: &serde_json::Map<String, serde_json::Value>,
+    after: &serde_json::Map<String, serde_json::Value>,
+) -> Result<(), String> {
+    let store = app
+        .store("settings")
+        .map_err(|e| format!("Failed to access settings store: {}", e))?;
+
+    // Run the stored values through save_settings so its runtime effects
+    // apply; it only acts on keys whose stored value differs, so put the
+    // previous values back first
+    let stored = get_settings(app.clone()).await?;
+    for key in SAVE_SETTINGS_DIFF_KEYS {
+        match before.get(*key) {
+            Some(value) => store.set(*key, value.clone()),
+            None => {
+                store.delete(*key);
+            }
+        }
+    }
+    save_settings(app.clone(), stored.clone()).await?;
+
+    let changed = |key: &str| before.get(key) != after.get(key);
+    if changed("hotkey") && !stored.hotkey.is_empty() {
+        if let Err(e) = set_global_shortcut(app.clone(), stored.hotkey.clone()).await {
+            log::warn!("Failed to apply stored hotkey: {}", e);
+        }
+    }
+    if changed("current_model_engine") {
+        let backend = match stored.current_model_engine.as_str() {
+            "whisper" => Some(crate::commands::model::Backend::Whisper),
+            "parakeet" => Some(crate::commands::model::Backend::ParakeetSidecar),
+            _ => None,
+        };
+        if let Some(backend) = backend {
+            if let Err(e) = crate::commands::model::set_backend(app.clone(), backend).await {
+                log::warn!("Failed to apply stored backend: {}", e);
+            }
+        }
+    }
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn set_global_shortcut(app: AppHandle, shortcut: String) -> Result<(), String> {
     log::info!("Updating global shortcut to: {}", shortcut);
@@ -676,16 +1423,170 @@ pub async fn set_global_shortcut(app: AppHandle, shortcut: String) -> Result<(),
     Ok(())
 }
 
+/// Re-register the recording (and PTT) shortcuts from the current settings.
+/// Used after a reset clears the settings store so the hotkey keeps working
+/// without a relaunch.
+#[tauri::command]
+pub async fn reinit_shortcuts(app: AppHandle) -> Result<(), String> {
+    let settings = get_settings(app.clone()).await?;
+    let app_state = app.state::<AppState>();
+
+    let recording_mode = match settings.recording_mode.as_str() {
+        "push_to_talk" => crate::RecordingMode::PushToTalk,
+        _ => crate::RecordingMode::Toggle,
+    };
+    if let Ok(mut mode_guard) = app_state.recording_mode.lock() {
+        *mode_guard = recording_mode;
+    }
+
+    // Drop any PTT shortcut; it's re-registered below only if still configured
+    if let Ok(mut ptt_guard) = app_state.ptt_shortcut.lock() {
+        if let Some(old_ptt) = ptt_guard.take() {
+            let _ = app.global_shortcut().unregister(old_ptt);
+        }
+    }
+
+    set_global_shortcut(app.clone(), settings.hotkey.clone()).await?;
+
+    if recording_mode == crate::RecordingMode::PushToTalk && settings.use_different_ptt_key {
+        if let Some(ptt_hotkey) = settings.ptt_hotkey.as_deref() {
+            match normalize_shortcut_keys(ptt_hotkey).parse::<Shortcut>() {
+                Ok(ptt_shortcut) => match app.global_shortcut().register(ptt_shortcut) {
+                    Ok(_) => {
+                        if let Ok(mut ptt_guard) = app_state.ptt_shortcut.lock() {
+                            *ptt_guard = Some(ptt_shortcut);
+                        }
+                    }
+                    Err(e) => log::error!("Failed to register PTT shortcut: {}", e),
+                },
+                Err(_) => log::warn!("Invalid PTT hotkey format: {}", ptt_hotkey),
+            }
+        }
+    }
+
+    log::info!("Shortcuts reinitialized (hotkey: {})", settings.hotkey);
+    if let Err(e) = app.emit("shortcuts-reinitialized", &settings.hotkey) {
+        log::warn!("Failed to emit shortcuts-reinitialized event: {}", e);
+    }
+
+    Ok(())
+}
+
 #[derive(Serialize)]
 pub struct LanguageInfo {
     pub code: String,
     pub name: String,
 }
 
+/// Where the OS keeps the launch-at-login entry, for error messages
+fn launch_at_login_mechanism() -> &'static str {
+    if cfg!(target_os = "macos") {
+        "the login item (LaunchAgent)"
+    } else if cfg!(target_os = "windows") {
+        "the registry Run key"
+    } else {
+        "the ~/.config/autostart entry"
+    }
+}
+
+/// Create or remove the OS launch-at-login entry and confirm it took effect
+pub fn apply_launch_at_login(app: &AppHandle, enabled: bool) -> Result<(), String> {
+    use tauri_plugin_autostart::ManagerExt;
+
+    // A Flatpak sandbox can't write the host's autostart directory
+    #[cfg(target_os = "linux")]
+    if enabled && std::env::var_os("FLATPAK_ID").is_some() {
+        return Err("Launch at login isn't available in the Flatpak build".to_string());
+    }
+
+    let autolaunch = app.autolaunch();
+    let currently_enabled = autolaunch.is_enabled().unwrap_or(!enabled);
+    if currently_enabled == enabled {
+        return Ok(());
+    }
+
+    let result = if enabled {
+        autolaunch.enable()
+    } else {
+        autolaunch.disable()
+    };
+    result.map_err(|e| format!("Failed to update {}: {}", launch_at_login_mechanism(), e))?;
+
+    match autolaunch.is_enabled() {
+        Ok(actual) if actual == enabled => Ok(()),
+        Ok(_) => Err(format!(
+            "The system did not accept the change to {}",
+            launch_at_login_mechanism()
+        )),
+        Err(e) => Err(format!(
+            "Failed to verify {}: {}",
+            launch_at_login_mechanism(),
+            e
+        )),
+    }
+}
+
 #[tauri::command]
-pub async fn get_supported_languages() -> Result<Vec<LanguageInfo>, String> {
+pub async fn set_launch_at_login(app: AppHandle, enabled: bool) -> Result<(), String> {
+    apply_launch_at_login(&app, enabled)?;
+
+    let store = app.store("settings").map_err(|e| e.to_string())?;
+    store.set("launch_at_startup", json!(enabled));
+    store
+        .save()
+        .map_err(|e| format!("Failed to save launch at login setting: {}", e))?;
+
+    log::info!(
+        "Launch at login {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(())
+}
+
+/// Whether the OS entry actually exists, falling back to the saved setting
+#[tauri::command]
+pub async fn get_launch_at_login(app: AppHandle) -> Result<bool, String> {
+    use tauri_plugin_autostart::ManagerExt;
+
+    match app.autolaunch().is_enabled() {
+        Ok(enabled) => Ok(enabled),
+        Err(e) => {
+            log::warn!("Failed to check launch at login state: {}", e);
+            let store = app.store("settings").map_err(|e| e.to_string())?;
+            Ok(store
+                .get("launch_at_startup")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false))
+        }
+    }
+}
+
+/// Languages Soniox accepts (per its docs)
+const SONIOX_LANGUAGES: &[&str] = &[
+    "en", "es", "fr", "de", "it", "pt", "nl", "ru", "zh", "ja", "ko", "ar", "hi", "tr", "pl", "sv",
+    "no", "da", "fi", "el", "cs", "ro", "hu", "sk", "uk", "he", "id", "vi", "th", "ms", "tl", "fa",
+    "ur", "bn", "ta", "te", "gu", "pa", "bg", "hr", "sr", "sl", "lv", "lt", "et", "is", "ca", "gl",
+];
+
+/// Languages the given engine/model can transcribe, sorted by name
+pub fn languages_for_backend(engine: &str, model: &str) -> Vec<LanguageInfo> {
+    let allowed: Option<Vec<&str>> = match engine {
+        "parakeet" => Some(
+            crate::parakeet::models::AVAILABLE_MODELS
+                .iter()
+                .find(|definition| definition.id == model)
+                .map(|definition| definition.languages.to_vec())
+                .unwrap_or_else(|| vec!["en"]),
+        ),
+        "soniox" => Some(SONIOX_LANGUAGES.to_vec()),
+        // English-only Whisper models (tiny.en, base.en, ...)
+        _ if model.ends_with(".en") => Some(vec!["en"]),
+        _ => None,
+    };
+
     let mut languages: Vec<LanguageInfo> = SUPPORTED_LANGUAGES
         .iter()
+        .filter(|(code, _)| allowed.as_ref().is_none_or(|codes| codes.contains(*code)))
         .map(|(code, lang)| LanguageInfo {
             code: code.to_string(),
             name: lang.name.to_string(),
@@ -695,7 +1596,24 @@ pub async fn get_supported_languages() -> Result<Vec<LanguageInfo>, String> {
     // Sort by name for better UX (auto-detect removed)
     languages.sort_by(|a, b| a.name.cmp(&b.name));
 
-    Ok(languages)
+    languages
+}
+
+/// Languages supported by the active backend and model, read fresh from the
+/// settings so the list follows backend switches
+#[tauri::command]
+pub async fn get_supported_languages(app: AppHandle) -> Result<Vec<LanguageInfo>, String> {
+    let store = app.store("settings").map_err(|e| e.to_string())?;
+    let engine = store
+        .get("current_model_engine")
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .unwrap_or_else(|| Settings::default().current_model_engine);
+    let model = store
+        .get("current_model")
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .unwrap_or_default();
+
+    Ok(languages_for_backend(&engine, &model))
 }
 
 #[tauri::command]
@@ -870,3 +1788,112 @@ mod tests {
         assert_eq!(resolved, "when_recording");
     }
 }
+
+fn proxy_url_without_credentials(url: &str) -> String {
+    crate::utils::proxy::split_credentials(url).0
+}
+
+/// Set (or clear with an empty username) the proxy credentials. Kept in the
+/// secure store rather than in the proxy URLs in the settings file.
+#[tauri::command]
+pub async fn set_proxy_credentials(
+    app: AppHandle,
+    username: Option<String>,
+    password: Option<String>,
+) -> Result<(), String> {
+    let credentials = username.map(|username| crate::utils::proxy::ProxyCredentials {
+        username,
+        password: password.unwrap_or_default(),
+    });
+    crate::utils::proxy::store_credentials(&app, credentials.as_ref())?;
+    crate::utils::proxy::refresh(&app);
+    log::info!("Proxy credentials updated");
+    Ok(())
+}
+
+/// Set (or clear with `None`) the shared secret used to sign webhook requests.
+/// Kept in the secure store rather than the plain settings file.
+#[tauri::command]
+pub async fn set_webhook_secret(app: AppHandle, secret: Option<String>) -> Result<(), String> {
+    use crate::output::webhook::WEBHOOK_SECRET_KEY;
+
+    match secret.filter(|s| !s.is_empty()) {
+        Some(secret) => crate::secure_store::secure_set(&app, WEBHOOK_SECRET_KEY, &secret)?,
+        None => crate::secure_store::secure_delete(&app, WEBHOOK_SECRET_KEY)?,
+    }
+    log::info!("Webhook signing secret updated");
+    Ok(())
+}
+
+/// Replace the output sinks finished transcriptions are delivered to, in order
+#[tauri::command]
+pub async fn set_output_sinks(
+    app: AppHandle,
+    sinks: Vec<crate::output::OutputSink>,
+) -> Result<(), String> {
+    use crate::output::OutputSink;
+
+    for sink in &sinks {
+        match sink {
+            OutputSink::File { path } if path.trim().is_empty() => {
+                return Err("Output file path cannot be empty".to_string());
+            }
+            OutputSink::WebhookPost { url }
+                if !(url.starts_with("http://") || url.starts_with("https://")) =>
+            {
+                return Err(format!("Invalid webhook URL: {}", url));
+            }
+            _ => {}
+        }
+    }
+
+    let store = app.store("settings").map_err(|e| e.to_string())?;
+    store.set("output_sinks", json!(sinks));
+    store
+        .save()
+        .map_err(|e| format!("Failed to save output sinks: {}", e))?;
+    log::info!(
+        "Output sinks set to: {:?}",
+        sinks.iter().map(|sink| sink.name()).collect::<Vec<_>>()
+    );
+    Ok(())
+}
+
+/// Toggle incognito mode: transcriptions are still pasted but neither they
+/// nor their recordings are kept, and no other output sink or webhook sees them
+#[tauri::command]
+pub async fn set_incognito(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let store = app.store("settings").map_err(|e| e.to_string())?;
+    store.set("incognito_mode", json!(enabled));
+    store
+        .save()
+        .map_err(|e| format!("Failed to save incognito setting: {}", e))?;
+
+    crate::commands::audio::invalidate_recording_config_cache(&app).await;
+
+    log::info!(
+        "Incognito mode {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    // The pill shows an indicator while incognito is on
+    if let Err(e) = app.emit("incognito-changed", enabled) {
+        log::warn!("Failed to emit incognito-changed event: {}", e);
+    }
+    Ok(())
+}
+
+/// Check that model downloads and AI requests can get out through the saved
+/// proxy settings
+#[tauri::command]
+pub async fn test_proxy(app: AppHandle) -> Result<crate::utils::proxy::ProxyStatus, String> {
+    let config = crate::utils::proxy::ProxyConfig::from_store(&app);
+    let status = crate::utils::proxy::check(&config).await?;
+    log::info!(
+        "Proxy test: configured={} reachable={} status={:?} error={:?}",
+        status.configured,
+        status.reachable,
+        status.status_code,
+        status.error
+    );
+    Ok(status)
+}
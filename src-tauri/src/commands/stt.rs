@@ -14,7 +14,9 @@ pub async fn validate_and_cache_soniox_key(
 
     // Best-effort validation against a public endpoint; if network fails, return error
     // We do not persist anything here; the frontend stores the key in secure store.
-    let client = reqwest::Client::new();
+    let client = crate::utils::proxy::client_builder()
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
     // Validate against an authenticated endpoint that exists across accounts
     // /v1/models lists available models; returns 200 when the key is valid.
     let url = "https://api.soniox.com/v1/models";
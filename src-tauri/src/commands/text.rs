@@ -1,10 +1,14 @@
 use arboard::Clipboard;
+use std::collections::HashMap;
 use std::panic::{self, AssertUnwindSafe};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread;
 use std::time::Duration;
 use tauri_plugin_store::StoreExt;
 
+use crate::commands::settings::{MAX_PASTE_DELAY_MS, MAX_TYPING_CHAR_DELAY_MS};
+use crate::utils::active_app::ActiveApp;
+
 // Import rdev for more reliable keyboard simulation
 use rdev::{simulate, EventType, Key as RdevKey, SimulateError};
 
@@ -18,6 +22,163 @@ use enigo::{
 // Global flag to prevent concurrent text insertions
 static IS_INSERTING: AtomicBool = AtomicBool::new(false);
 
+/// How a transcription is put into the focused app
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputMode {
+    /// Copy to the clipboard and paste (default, fastest)
+    #[default]
+    Paste,
+    /// Type the text as keystrokes, for targets that block paste, waiting
+    /// `typing_char_delay_ms` after each character
+    Type,
+    /// Type one character at a time at `typing_speed_cps`
+    TypeAnimated,
+}
+
+impl OutputMode {
+    pub fn from_setting(value: &str) -> Self {
+        serde_json::from_value(serde_json::Value::String(value.to_string())).unwrap_or_default()
+    }
+}
+
+/// Output mode for the app being typed into: an `output_mode_by_app` entry
+/// matching its name or id (case-insensitively, like `list_transcriptions_by_app`),
+/// else the global mode
+pub fn output_mode_for_app(
+    overrides: &HashMap<String, String>,
+    target: Option<&ActiveApp>,
+    global: OutputMode,
+) -> OutputMode {
+    let Some(target) = target else {
+        return global;
+    };
+    let keys = std::iter::once(target.name.as_str()).chain(target.id.as_deref());
+    keys.flat_map(|key| {
+        overrides
+            .iter()
+            .filter(move |(app, _)| app.eq_ignore_ascii_case(key))
+    })
+    .next()
+    .map(|(_, mode)| OutputMode::from_setting(mode))
+    .unwrap_or(global)
+}
+
+/// Characters per second for `OutputMode::TypeAnimated`
+pub const DEFAULT_TYPING_SPEED_CPS: u32 = 40;
+pub const MAX_TYPING_SPEED_CPS: u32 = 1000;
+
+/// Split text into units that must be typed together: a character plus any
+/// combining marks, variation selectors, skin-tone modifiers and
+/// zero-width-joined characters that follow it, so emoji sequences and
+/// accented letters never appear half-typed
+pub fn split_type_units(text: &str) -> Vec<String> {
+    fn extends(c: char) -> bool {
+        matches!(c,
+            '\u{0300}'..='\u{036F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{1F3FB}'..='\u{1F3FF}'
+            | '\u{E0020}'..='\u{E007F}'
+            | '\u{200D}')
+    }
+
+    let mut units: Vec<String> = Vec::new();
+    let mut joined = false;
+    for c in text.chars() {
+        match units.last_mut() {
+            Some(unit) if joined || extends(c) => unit.push(c),
+            _ => units.push(c.to_string()),
+        }
+        joined = c == '\u{200D}';
+    }
+    units
+}
+
+/// Typing pace settings for the keystroke output modes
+#[derive(Clone, Copy, Debug)]
+struct TypingPace {
+    /// Characters per second for `OutputMode::TypeAnimated`
+    speed_cps: u32,
+    /// Pause after each character for `OutputMode::Type`, for targets (RDP,
+    /// Electron) that drop keystrokes sent too fast
+    char_delay: Duration,
+}
+
+impl TypingPace {
+    /// Pause after each typed unit; zero types the whole text at once
+    fn unit_delay(&self, mode: OutputMode) -> Duration {
+        match mode {
+            OutputMode::TypeAnimated => {
+                Duration::from_millis(1000 / self.speed_cps.clamp(1, MAX_TYPING_SPEED_CPS) as u64)
+            }
+            OutputMode::Type => self.char_delay,
+            OutputMode::Paste => Duration::ZERO,
+        }
+    }
+}
+
+/// Type `text` as keystrokes. Enigo sends each unit as a Unicode string
+/// event, so characters outside the keyboard layout (emoji, CJK) work too.
+fn type_text(text: &str, mode: OutputMode, pace: TypingPace) -> Result<(), String> {
+    use enigo::Keyboard as _;
+
+    let mut enigo = enigo::Enigo::new(&enigo::Settings::default())
+        .map_err(|e| format!("Failed to initialize Enigo: {:?}", e))?;
+
+    let delay = pace.unit_delay(mode);
+    if delay.is_zero() {
+        return enigo
+            .text(text)
+            .map_err(|e| format!("Failed to type text: {:?}", e));
+    }
+
+    for unit in split_type_units(text) {
+        enigo
+            .text(&unit)
+            .map_err(|e| format!("Failed to type text: {:?}", e))?;
+        thread::sleep(delay);
+    }
+    Ok(())
+}
+
+/// Select the last `delete` typed units with Shift+Left and type `insert`
+/// over them, for correcting text already typed by live typing. With
+/// nothing to delete this just types `insert`.
+pub fn replace_typed_tail(delete: usize, insert: &str) -> Result<(), String> {
+    use enigo::{Direction, Key as EnigoKey, Keyboard as _};
+
+    let mut enigo = enigo::Enigo::new(&enigo::Settings::default())
+        .map_err(|e| format!("Failed to initialize Enigo: {:?}", e))?;
+
+    if delete > 0 {
+        enigo
+            .key(EnigoKey::Shift, Direction::Press)
+            .map_err(|e| format!("Failed to select typed text: {:?}", e))?;
+        let selected = (0..delete).try_for_each(|_| {
+            enigo
+                .key(EnigoKey::LeftArrow, Direction::Click)
+                .map_err(|e| format!("Failed to select typed text: {:?}", e))
+        });
+        // Always let go of Shift, even if selecting failed part way
+        let _ = enigo.key(EnigoKey::Shift, Direction::Release);
+        selected?;
+        if insert.is_empty() {
+            return enigo
+                .key(EnigoKey::Backspace, Direction::Click)
+                .map_err(|e| format!("Failed to delete typed text: {:?}", e));
+        }
+    }
+
+    if insert.is_empty() {
+        return Ok(());
+    }
+    enigo
+        .text(insert)
+        .map_err(|e| format!("Failed to type text: {:?}", e))
+}
+
 #[tauri::command]
 pub async fn insert_text(app: tauri::AppHandle, text: String) -> Result<(), String> {
     // Check if already inserting text
@@ -43,16 +204,77 @@ pub async fn insert_text(app: tauri::AppHandle, text: String) -> Result<(), Stri
     let has_accessibility_permission = true;
 
     // Move to a blocking task since clipboard operations are synchronous
-    let keep_transcription_in_clipboard = {
+    let (keep_transcription_in_clipboard, paste_delay, global_mode, overrides, typing_pace) = {
         let store = app
             .store("settings")
             .map_err(|e| format!("Failed to access settings: {}", e))?;
-        store
+        let keep = store
             .get("keep_transcription_in_clipboard")
             .and_then(|v| v.as_bool())
-            .unwrap_or(false)
+            .unwrap_or(false);
+        // Extra delay for targets (RDP, Electron) that can't keep up with instant paste
+        let delay_ms = store
+            .get("paste_delay_ms")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0)
+            .min(MAX_PASTE_DELAY_MS as u64);
+        let output_mode = store
+            .get("output_mode")
+            .and_then(|v| v.as_str().map(OutputMode::from_setting))
+            .unwrap_or_default();
+        let overrides: HashMap<String, String> = store
+            .get("output_mode_by_app")
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_default();
+        let typing_speed = store
+            .get("typing_speed_cps")
+            .and_then(|v| v.as_u64())
+            .map(|v| v.min(MAX_TYPING_SPEED_CPS as u64) as u32)
+            .unwrap_or(DEFAULT_TYPING_SPEED_CPS);
+        let char_delay_ms = store
+            .get("typing_char_delay_ms")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0)
+            .min(MAX_TYPING_CHAR_DELAY_MS as u64);
+        (
+            keep,
+            Duration::from_millis(delay_ms),
+            output_mode,
+            overrides,
+            TypingPace {
+                speed_cps: typing_speed,
+                char_delay: Duration::from_millis(char_delay_ms),
+            },
+        )
+    };
+
+    // Only look up the focused app when some app has its own mode
+    let output_mode = if overrides.is_empty() {
+        global_mode
+    } else {
+        let target = tokio::task::spawn_blocking(crate::utils::active_app::frontmost_app)
+            .await
+            .unwrap_or(None);
+        output_mode_for_app(&overrides, target.as_ref(), global_mode)
     };
 
+    if output_mode != OutputMode::Paste {
+        if !has_accessibility_permission {
+            return Err(
+                "No accessibility permission - cannot type text. Please grant accessibility permission."
+                    .to_string(),
+            );
+        }
+        return tokio::task::spawn_blocking(move || {
+            if !paste_delay.is_zero() {
+                thread::sleep(paste_delay);
+            }
+            type_text(&text, output_mode, typing_pace)
+        })
+        .await
+        .map_err(|e| format!("Task failed: {}", e))?;
+    }
+
     tokio::task::spawn_blocking(move || {
         // Always use clipboard method for reliability and to prevent duplicate insertion
         // This function handles both copying to clipboard and pasting at cursor
@@ -61,6 +283,7 @@ pub async fn insert_text(app: tauri::AppHandle, text: String) -> Result<(), Stri
             has_accessibility_permission,
             Some(app),
             keep_transcription_in_clipboard,
+            paste_delay,
         )
     })
     .await
@@ -82,11 +305,146 @@ pub async fn copy_text_to_clipboard(text: String) -> Result<(), String> {
     .map_err(|e| format!("Task failed: {}", e))?
 }
 
+/// Outcome of `test_paste` for the app that was frontmost
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PasteTestResult {
+    pub app: Option<crate::utils::active_app::ActiveApp>,
+    pub marker: String,
+    /// What was read back from the field, if selecting and copying it worked
+    pub received: Option<String>,
+    pub intact: bool,
+}
+
+/// Select the `len` characters before the cursor and copy them
+fn select_and_copy_back(len: usize) -> Result<(), String> {
+    use enigo::Keyboard as _;
+    use enigo::{Direction, Key as EnigoKey};
+
+    let mut enigo = enigo::Enigo::new(&enigo::Settings::default())
+        .map_err(|e| format!("Failed to initialize Enigo: {:?}", e))?;
+    let key = |enigo: &mut enigo::Enigo, key, direction| {
+        enigo
+            .key(key, direction)
+            .map_err(|e| format!("Failed to send key: {:?}", e))
+    };
+
+    key(&mut enigo, EnigoKey::Shift, Direction::Press)?;
+    for _ in 0..len {
+        key(&mut enigo, EnigoKey::LeftArrow, Direction::Click)?;
+    }
+    key(&mut enigo, EnigoKey::Shift, Direction::Release)?;
+
+    #[cfg(target_os = "macos")]
+    let modifier = EnigoKey::Meta;
+    #[cfg(not(target_os = "macos"))]
+    let modifier = EnigoKey::Control;
+    key(&mut enigo, modifier, Direction::Press)?;
+    key(&mut enigo, EnigoKey::Unicode('c'), Direction::Click)?;
+    key(&mut enigo, modifier, Direction::Release)
+}
+
+/// Remove the selected marker, or just collapse the selection if what was
+/// selected isn't ours
+fn finish_paste_test(delete: bool) -> Result<(), String> {
+    use enigo::Keyboard as _;
+    use enigo::{Direction, Key as EnigoKey};
+
+    let mut enigo = enigo::Enigo::new(&enigo::Settings::default())
+        .map_err(|e| format!("Failed to initialize Enigo: {:?}", e))?;
+    let key = if delete {
+        EnigoKey::Backspace
+    } else {
+        EnigoKey::RightArrow
+    };
+    enigo
+        .key(key, Direction::Click)
+        .map_err(|e| format!("Failed to send key: {:?}", e))
+}
+
+/// Paste a marker into the focused field, select it back, copy it and compare,
+/// to show whether clipboard paste works in the frontmost app. The marker is
+/// removed again when it arrived intact.
+#[tauri::command]
+pub async fn test_paste(app: tauri::AppHandle) -> Result<PasteTestResult, String> {
+    if IS_INSERTING.swap(true, Ordering::SeqCst) {
+        return Err("Text insertion already in progress".to_string());
+    }
+    let _guard = InsertionGuard;
+
+    #[cfg(target_os = "macos")]
+    {
+        use crate::commands::permissions::check_accessibility_permission;
+        if !check_accessibility_permission().await? {
+            return Err("Accessibility permission is required to test pasting".to_string());
+        }
+    }
+
+    let paste_delay = app
+        .store("settings")
+        .ok()
+        .and_then(|store| store.get("paste_delay_ms"))
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0)
+        .min(MAX_PASTE_DELAY_MS as u64);
+    let paste_delay = Duration::from_millis(paste_delay);
+    // ASCII only, so it can be selected back one arrow press per character
+    let marker = format!(
+        "voicetypr-paste-test-{}",
+        chrono::Utc::now().timestamp_millis()
+    );
+
+    let expected = marker.clone();
+    let (active_app, received) = tokio::task::spawn_blocking(move || {
+        let active_app = crate::utils::active_app::frontmost_app();
+        let mut clipboard =
+            Clipboard::new().map_err(|e| format!("Failed to initialize clipboard: {}", e))?;
+        let previous = clipboard.get_text().ok();
+
+        let result = (|| {
+            insert_via_clipboard(expected.clone(), true, None, true, paste_delay)?;
+            thread::sleep(Duration::from_millis(300));
+            // Clear first so a copy that does nothing can't report the marker
+            let _ = clipboard.set_text("");
+            select_and_copy_back(expected.len())?;
+            thread::sleep(Duration::from_millis(150));
+            let received = clipboard.get_text().ok().filter(|t| !t.is_empty());
+            finish_paste_test(received.as_deref() == Some(expected.as_str()))?;
+            Ok::<_, String>(received)
+        })();
+
+        if let Some(previous) = previous {
+            if let Err(e) = clipboard.set_text(&previous) {
+                log::error!("Failed to restore clipboard after paste test: {}", e);
+            }
+        }
+        result.map(|received| (active_app, received))
+    })
+    .await
+    .map_err(|e| format!("Task failed: {}", e))??;
+
+    let intact = received.as_deref() == Some(marker.as_str());
+    log::info!(
+        "Paste test in {}: {}",
+        active_app
+            .as_ref()
+            .map(|a| a.name.as_str())
+            .unwrap_or("unknown app"),
+        if intact { "intact" } else { "not intact" }
+    );
+    Ok(PasteTestResult {
+        app: active_app,
+        marker,
+        received,
+        intact,
+    })
+}
+
 fn insert_via_clipboard(
     text: String,
     has_accessibility_permission: bool,
     app_handle: Option<tauri::AppHandle>,
     keep_transcription_in_clipboard: bool,
+    paste_delay: Duration,
 ) -> Result<(), String> {
     // This function handles both copying text to clipboard AND pasting it at cursor
     // Initialize clipboard
@@ -136,8 +494,14 @@ fn insert_via_clipboard(
         // Try to paste using Cmd+V (macOS) with panic protection
         // Add delay since pill was just hidden
 
+        // Give slow targets time to pick up the new clipboard content
+        if !paste_delay.is_zero() {
+            log::debug!("Waiting {}ms before paste", paste_delay.as_millis());
+            thread::sleep(paste_delay);
+        }
+
         // First try with rdev, fallback to AppleScript if it fails
-        let rdev_result = try_paste_with_rdev();
+        let rdev_result = try_paste_with_rdev(paste_delay);
 
         match rdev_result {
             Ok(_) => {
@@ -289,11 +653,11 @@ impl Drop for InsertionGuard {
 
 // Helper function to send events with proper timing (only used on Windows/Linux)
 #[cfg(not(target_os = "macos"))]
-fn send_key_event(event_type: &EventType) -> Result<(), SimulateError> {
+fn send_key_event(event_type: &EventType, extra_delay: Duration) -> Result<(), SimulateError> {
     match simulate(event_type) {
         Ok(()) => {
             // Let the OS catch up - critical for proper key recognition
-            thread::sleep(Duration::from_millis(50));
+            thread::sleep(Duration::from_millis(50) + extra_delay);
             Ok(())
         }
         Err(e) => {
@@ -304,7 +668,8 @@ fn send_key_event(event_type: &EventType) -> Result<(), SimulateError> {
 }
 
 // rdev implementation for more reliable paste
-fn try_paste_with_rdev() -> Result<(), String> {
+// `step_delay` is added after each simulated key event on top of the base timing
+fn try_paste_with_rdev(step_delay: Duration) -> Result<(), String> {
     let paste_start = std::time::Instant::now();
     log::info!("=== PASTE CHAIN START ===");
     log::debug!("Platform: {}", std::env::consts::OS);
@@ -319,17 +684,17 @@ fn try_paste_with_rdev() -> Result<(), String> {
     let result = {
         #[cfg(target_os = "macos")]
         {
-            paste_mac().map_err(|e| format!("Failed to paste on macOS: {:?}", e))
+            paste_mac(step_delay).map_err(|e| format!("Failed to paste on macOS: {:?}", e))
         }
 
         #[cfg(target_os = "windows")]
         {
-            paste_windows().map_err(|e| format!("Failed to paste on Windows: {:?}", e))
+            paste_windows(step_delay).map_err(|e| format!("Failed to paste on Windows: {:?}", e))
         }
 
         #[cfg(target_os = "linux")]
         {
-            paste_linux().map_err(|e| format!("Failed to paste on Linux: {:?}", e))
+            paste_linux(step_delay).map_err(|e| format!("Failed to paste on Linux: {:?}", e))
         }
 
         #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
@@ -356,7 +721,7 @@ fn try_paste_with_rdev() -> Result<(), String> {
 }
 
 #[cfg(target_os = "macos")]
-fn paste_mac() -> Result<(), SimulateError> {
+fn paste_mac(step_delay: Duration) -> Result<(), SimulateError> {
     log::debug!("Starting macOS paste simulation with rdev");
 
     // Add initial delay to match Windows timing for better reliability
@@ -370,22 +735,22 @@ fn paste_mac() -> Result<(), SimulateError> {
             // Press Cmd (Meta) key first and hold it
             log::debug!("Pressing MetaLeft (Cmd)");
             simulate(&EventType::KeyPress(RdevKey::MetaLeft))?;
-            thread::sleep(Duration::from_millis(50)); // Give OS time to register modifier
+            thread::sleep(Duration::from_millis(50) + step_delay); // Give OS time to register modifier
 
             // While Cmd is held, press V
             log::debug!("Pressing KeyV while Cmd is held");
             simulate(&EventType::KeyPress(RdevKey::KeyV))?;
-            thread::sleep(Duration::from_millis(50));
+            thread::sleep(Duration::from_millis(50) + step_delay);
 
             // Release V first
             log::debug!("Releasing KeyV");
             simulate(&EventType::KeyRelease(RdevKey::KeyV))?;
-            thread::sleep(Duration::from_millis(50));
+            thread::sleep(Duration::from_millis(50) + step_delay);
 
             // Then release Cmd
             log::debug!("Releasing MetaLeft (Cmd)");
             simulate(&EventType::KeyRelease(RdevKey::MetaLeft))?;
-            thread::sleep(Duration::from_millis(50));
+            thread::sleep(Duration::from_millis(50) + step_delay);
 
             Ok::<(), SimulateError>(())
         })();
@@ -414,7 +779,7 @@ fn paste_mac() -> Result<(), SimulateError> {
 }
 
 #[cfg(target_os = "windows")]
-fn paste_windows() -> Result<(), SimulateError> {
+fn paste_windows(step_delay: Duration) -> Result<(), SimulateError> {
     log::debug!("Starting Windows paste simulation with rdev");
 
     // Add initial delay to match macOS timing for better reliability
@@ -425,10 +790,10 @@ fn paste_windows() -> Result<(), SimulateError> {
         log::debug!("Windows paste attempt {}/2", attempt);
 
         let result = (|| {
-            send_key_event(&EventType::KeyPress(RdevKey::ControlLeft))?;
-            send_key_event(&EventType::KeyPress(RdevKey::KeyV))?;
-            send_key_event(&EventType::KeyRelease(RdevKey::KeyV))?;
-            send_key_event(&EventType::KeyRelease(RdevKey::ControlLeft))?;
+            send_key_event(&EventType::KeyPress(RdevKey::ControlLeft), step_delay)?;
+            send_key_event(&EventType::KeyPress(RdevKey::KeyV), step_delay)?;
+            send_key_event(&EventType::KeyRelease(RdevKey::KeyV), step_delay)?;
+            send_key_event(&EventType::KeyRelease(RdevKey::ControlLeft), step_delay)?;
             Ok::<(), SimulateError>(())
         })();
 
@@ -456,12 +821,101 @@ fn paste_windows() -> Result<(), SimulateError> {
 }
 
 #[cfg(target_os = "linux")]
-fn paste_linux() -> Result<(), SimulateError> {
+fn paste_linux(step_delay: Duration) -> Result<(), SimulateError> {
     log::debug!("Starting Linux paste simulation with rdev");
-    send_key_event(&EventType::KeyPress(RdevKey::ControlLeft))?;
-    send_key_event(&EventType::KeyPress(RdevKey::KeyV))?;
-    send_key_event(&EventType::KeyRelease(RdevKey::KeyV))?;
-    send_key_event(&EventType::KeyRelease(RdevKey::ControlLeft))?;
+    send_key_event(&EventType::KeyPress(RdevKey::ControlLeft), step_delay)?;
+    send_key_event(&EventType::KeyPress(RdevKey::KeyV), step_delay)?;
+    send_key_event(&EventType::KeyRelease(RdevKey::KeyV), step_delay)?;
+    send_key_event(&EventType::KeyRelease(RdevKey::ControlLeft), step_delay)?;
     log::debug!("Linux paste simulation completed");
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_type_units_keeps_sequences_together() {
+        assert_eq!(split_type_units("héllo"), vec!["h", "é", "l", "l", "o"]);
+        // e + combining acute accent
+        assert_eq!(split_type_units("e\u{301}x"), vec!["e\u{301}", "x"]);
+        // Family emoji joined with ZWJ, and a thumbs up with skin tone
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let thumbs = "\u{1F44D}\u{1F3FD}";
+        assert_eq!(
+            split_type_units(&format!("{}{}!", family, thumbs)),
+            vec![family, thumbs, "!"]
+        );
+    }
+
+    #[test]
+    fn test_output_mode_from_setting() {
+        assert_eq!(
+            OutputMode::from_setting("type_animated"),
+            OutputMode::TypeAnimated
+        );
+        assert_eq!(OutputMode::from_setting("type"), OutputMode::Type);
+        assert_eq!(OutputMode::from_setting("bogus"), OutputMode::Paste);
+    }
+
+    #[test]
+    fn test_typing_pace_delays_per_mode() {
+        let pace = TypingPace {
+            speed_cps: 50,
+            char_delay: Duration::from_millis(15),
+        };
+        assert_eq!(
+            pace.unit_delay(OutputMode::TypeAnimated),
+            Duration::from_millis(20)
+        );
+        assert_eq!(pace.unit_delay(OutputMode::Type), Duration::from_millis(15));
+        assert_eq!(pace.unit_delay(OutputMode::Paste), Duration::ZERO);
+
+        let instant = TypingPace {
+            char_delay: Duration::ZERO,
+            ..pace
+        };
+        assert!(instant.unit_delay(OutputMode::Type).is_zero());
+    }
+
+    #[test]
+    fn test_output_mode_for_app_prefers_override() {
+        let overrides = HashMap::from([
+            ("Remote Desktop".to_string(), "type".to_string()),
+            (
+                "com.tinyspeck.slackmacgap".to_string(),
+                "type_animated".to_string(),
+            ),
+        ]);
+        let rdp = ActiveApp {
+            name: "remote desktop".to_string(),
+            id: None,
+        };
+        let slack = ActiveApp {
+            name: "Slack".to_string(),
+            id: Some("com.tinyspeck.slackmacgap".to_string()),
+        };
+        let notes = ActiveApp {
+            name: "Notes".to_string(),
+            id: Some("com.apple.Notes".to_string()),
+        };
+
+        assert_eq!(
+            output_mode_for_app(&overrides, Some(&rdp), OutputMode::Paste),
+            OutputMode::Type
+        );
+        assert_eq!(
+            output_mode_for_app(&overrides, Some(&slack), OutputMode::Paste),
+            OutputMode::TypeAnimated
+        );
+        assert_eq!(
+            output_mode_for_app(&overrides, Some(&notes), OutputMode::Paste),
+            OutputMode::Paste
+        );
+        assert_eq!(
+            output_mode_for_app(&overrides, None, OutputMode::Type),
+            OutputMode::Type
+        );
+    }
+}
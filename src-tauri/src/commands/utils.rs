@@ -8,7 +8,7 @@ pub async fn export_transcriptions(app: AppHandle) -> Result<String, String> {
     log::info!("Exporting transcriptions to JSON");
 
     // Get transcription history from the store
-    let store = app.store("transcriptions").map_err(|e| e.to_string())?;
+    let store = crate::utils::data_dir::transcriptions_store(&app).map_err(|e| e.to_string())?;
 
     let mut entries: Vec<(String, serde_json::Value)> = Vec::new();
 
@@ -71,3 +71,1147 @@ pub async fn export_transcriptions(app: AppHandle) -> Result<String, String> {
     // Return the full path as string
     Ok(file_path.to_string_lossy().to_string())
 }
+
+/// Output format for a full history export
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Markdown,
+    Json,
+}
+
+impl ExportFormat {
+    pub(crate) fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Markdown => "md",
+            ExportFormat::Json => "json",
+        }
+    }
+}
+
+/// Parse an export range bound given as RFC3339 or a plain `YYYY-MM-DD` date
+fn parse_range_bound(
+    value: &str,
+    end_of_day: bool,
+) -> Result<chrono::DateTime<chrono::Utc>, String> {
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Ok(dt.with_timezone(&chrono::Utc));
+    }
+    let date = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| format!("Invalid date '{}': expected YYYY-MM-DD or RFC3339", value))?;
+    let time = if end_of_day {
+        chrono::NaiveTime::from_hms_opt(23, 59, 59)
+    } else {
+        chrono::NaiveTime::from_hms_opt(0, 0, 0)
+    }
+    .ok_or_else(|| "Invalid time".to_string())?;
+    Ok(date.and_time(time).and_utc())
+}
+
+/// Write a single history entry as a markdown section
+pub fn write_markdown_entry<W: std::io::Write>(
+    writer: &mut W,
+    timestamp: &str,
+    entry: &serde_json::Value,
+) -> std::io::Result<()> {
+    let labeled = entry
+        .get("segments")
+        .and_then(|v| v.as_array())
+        .and_then(|segments| crate::commands::segments::speaker_labeled_text(segments));
+    let text = labeled
+        .as_deref()
+        .unwrap_or_else(|| entry.get("text").and_then(|v| v.as_str()).unwrap_or(""));
+    let model = entry.get("model").and_then(|v| v.as_str()).unwrap_or("");
+    let title = entry.get("title").and_then(|v| v.as_str());
+
+    let heading = chrono::DateTime::parse_from_rfc3339(timestamp)
+        .map(|dt| {
+            dt.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_else(|_| timestamp.to_string());
+
+    writeln!(writer, "## {}", heading)?;
+    writeln!(writer)?;
+    if let Some(title) = title {
+        writeln!(writer, "**Title:** {}  ", title)?;
+    }
+    if !model.is_empty() {
+        writeln!(writer, "**Model:** {}  ", model)?;
+    }
+    writeln!(writer)?;
+    writeln!(writer, "{}", text)?;
+    writeln!(writer)?;
+    Ok(())
+}
+
+/// Export the whole history (optionally within a date range) into a single file.
+/// Entries are written one at a time so large histories aren't held in memory as one document.
+#[tauri::command]
+pub async fn export_all_transcriptions(
+    app: AppHandle,
+    format: ExportFormat,
+    dest: String,
+    from: Option<String>,
+    to: Option<String>,
+) -> Result<String, String> {
+    use std::io::Write;
+
+    let from = from
+        .as_deref()
+        .map(|v| parse_range_bound(v, false))
+        .transpose()?;
+    let to = to
+        .as_deref()
+        .map(|v| parse_range_bound(v, true))
+        .transpose()?;
+
+    let store = crate::utils::data_dir::transcriptions_store(&app).map_err(|e| e.to_string())?;
+
+    // Keys are RFC3339 timestamps; filter by range and sort oldest first
+    let mut keys: Vec<String> = store
+        .keys()
+        .into_iter()
+        .filter(|key| {
+            match chrono::DateTime::parse_from_rfc3339(key) {
+                Ok(dt) => {
+                    let dt = dt.with_timezone(&chrono::Utc);
+                    from.map(|f| dt >= f).unwrap_or(true) && to.map(|t| dt <= t).unwrap_or(true)
+                }
+                // Keep unparseable keys only when no range was requested
+                Err(_) => from.is_none() && to.is_none(),
+            }
+        })
+        .collect();
+    keys.sort();
+
+    if keys.is_empty() {
+        return Err("No transcriptions to export".to_string());
+    }
+
+    let dest_path = std::path::PathBuf::from(&dest);
+    let file_path = if dest_path.is_dir() {
+        dest_path.join(format!(
+            "voicetypr-history-{}.{}",
+            chrono::Local::now().format("%Y-%m-%d"),
+            format.extension()
+        ))
+    } else {
+        dest_path
+    };
+
+    let file = std::fs::File::create(&file_path)
+        .map_err(|e| format!("Failed to create export file: {}", e))?;
+    let mut writer = std::io::BufWriter::new(file);
+    let write_err = |e: std::io::Error| format!("Failed to write export: {}", e);
+
+    match format {
+        ExportFormat::Markdown => {
+            writeln!(writer, "# VoiceTypr Transcriptions").map_err(write_err)?;
+            writeln!(writer).map_err(write_err)?;
+            writeln!(
+                writer,
+                "Exported {} with {} entries.",
+                chrono::Local::now().format("%Y-%m-%d %H:%M"),
+                keys.len()
+            )
+            .map_err(write_err)?;
+            writeln!(writer).map_err(write_err)?;
+
+            for key in &keys {
+                if let Some(entry) = store.get(key) {
+                    write_markdown_entry(&mut writer, key, &entry).map_err(write_err)?;
+                }
+            }
+        }
+        ExportFormat::Json => {
+            write!(
+                writer,
+                "{{\"app\":\"VoiceTypr\",\"exportDate\":{},\"totalTranscriptions\":{},\"transcriptions\":[",
+                serde_json::json!(chrono::Utc::now().to_rfc3339()),
+                keys.len()
+            )
+            .map_err(write_err)?;
+
+            let mut first = true;
+            for key in &keys {
+                if let Some(entry) = store.get(key) {
+                    if !first {
+                        writer.write_all(b",").map_err(write_err)?;
+                    }
+                    first = false;
+                    serde_json::to_writer(&mut writer, &entry)
+                        .map_err(|e| format!("Failed to serialize entry: {}", e))?;
+                }
+            }
+            writer.write_all(b"]}").map_err(write_err)?;
+        }
+    }
+
+    writer.flush().map_err(write_err)?;
+
+    log::info!(
+        "Exported {} transcriptions as {:?} to {:?}",
+        keys.len(),
+        format,
+        file_path
+    );
+
+    Ok(file_path.to_string_lossy().to_string())
+}
+
+/// Export usage analytics as CSV, one row per transcription, oldest first.
+/// Rows are written as they're read so large histories aren't held in memory.
+#[tauri::command]
+pub async fn export_usage_csv(app: AppHandle, dest: String) -> Result<String, String> {
+    use crate::utils::usage_csv::{usage_row, write_row, USAGE_CSV_HEADER};
+    use std::io::Write;
+
+    let store = crate::utils::data_dir::transcriptions_store(&app).map_err(|e| e.to_string())?;
+    let mut keys: Vec<String> = store.keys().into_iter().collect();
+    keys.sort();
+    if keys.is_empty() {
+        return Err("No transcriptions to export".to_string());
+    }
+
+    let dest_path = std::path::PathBuf::from(&dest);
+    let file_path = if dest_path.is_dir() {
+        dest_path.join(format!(
+            "voicetypr-usage-{}.csv",
+            chrono::Local::now().format("%Y-%m-%d")
+        ))
+    } else {
+        dest_path
+    };
+
+    let file = std::fs::File::create(&file_path)
+        .map_err(|e| format!("Failed to create export file: {}", e))?;
+    let mut writer = std::io::BufWriter::new(file);
+    let write_err = |e: std::io::Error| format!("Failed to write export: {}", e);
+
+    let header: Vec<String> = USAGE_CSV_HEADER.iter().map(|h| h.to_string()).collect();
+    write_row(&mut writer, &header).map_err(write_err)?;
+    let mut rows = 0;
+    for key in &keys {
+        if let Some(entry) = store.get(key) {
+            write_row(&mut writer, &usage_row(key, &entry)).map_err(write_err)?;
+            rows += 1;
+        }
+    }
+    writer.flush().map_err(write_err)?;
+
+    log::info!(
+        "Exported usage for {} transcriptions to {:?}",
+        rows,
+        file_path
+    );
+    Ok(file_path.to_string_lossy().to_string())
+}
+
+/// Start exporting the whole history to `dest` in the background, or resume
+/// the interrupted job `resume_job_id`. Returns the job id; progress is
+/// emitted as `history-export-progress` and kept in the job's manifest.
+#[tauri::command]
+pub async fn start_history_export(
+    app: AppHandle,
+    format: ExportFormat,
+    dest: String,
+    resume_job_id: Option<String>,
+) -> Result<String, String> {
+    use crate::utils::history_export::{self, ExportManifest, ExportStatus};
+    use tauri::Emitter;
+
+    let dir = history_export::manifest_dir(&app)?;
+    let manifest = match resume_job_id {
+        Some(job_id) => {
+            let manifest = history_export::load_manifest(&dir, &job_id)?;
+            if !matches!(
+                manifest.status,
+                ExportStatus::Interrupted | ExportStatus::Failed
+            ) {
+                return Err(format!(
+                    "Export job {} can't be resumed (status: {:?})",
+                    job_id, manifest.status
+                ));
+            }
+            if manifest.format != format {
+                return Err("Resumed export must use the original format".to_string());
+            }
+            manifest
+        }
+        None => {
+            let dest_path = std::path::PathBuf::from(&dest);
+            let dest = if dest_path.is_dir() {
+                dest_path.join(format!(
+                    "voicetypr-history-{}.{}",
+                    chrono::Local::now().format("%Y-%m-%d"),
+                    format.extension()
+                ))
+            } else {
+                dest_path
+            };
+            ExportManifest {
+                job_id: format!("export-{}", chrono::Utc::now().timestamp_millis()),
+                format,
+                dest,
+                status: ExportStatus::Running,
+                total: 0,
+                written: 0,
+                bytes_written: 0,
+                last_key: None,
+                sha256: None,
+                error: None,
+            }
+        }
+    };
+
+    let store = crate::utils::data_dir::transcriptions_store(&app).map_err(|e| e.to_string())?;
+    let keys = store.keys();
+    if keys.is_empty() {
+        return Err("No transcriptions to export".to_string());
+    }
+
+    let job_id = manifest.job_id.clone();
+    if !history_export::claim(&job_id) {
+        return Err(format!("Export job {} is already running", job_id));
+    }
+    let mut manifest = manifest;
+    if manifest.last_key.is_none() {
+        manifest.total = keys.len();
+    }
+    if let Err(e) = history_export::save_manifest(&dir, &manifest) {
+        history_export::release(&job_id);
+        return Err(e);
+    }
+
+    let app_for_task = app.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        let emit_progress = |manifest: &ExportManifest| {
+            let _ = app_for_task.emit("history-export-progress", manifest);
+        };
+        let job_id = manifest.job_id.clone();
+        let fallback = manifest.clone();
+        match history_export::run_export(&dir, manifest, keys, |key| store.get(key), emit_progress)
+        {
+            Ok(done) => log::info!(
+                "History export {} finished: {} entries, sha256 {:?}",
+                job_id,
+                done.written,
+                done.sha256
+            ),
+            Err(e) => {
+                log::error!("History export {} failed: {}", job_id, e);
+                // Keep the last checkpoint so the job can be resumed
+                let mut failed = history_export::load_manifest(&dir, &job_id).unwrap_or(fallback);
+                failed.status = ExportStatus::Failed;
+                failed.error = Some(e);
+                let _ = history_export::save_manifest(&dir, &failed);
+                emit_progress(&failed);
+            }
+        }
+        history_export::release(&job_id);
+    });
+
+    Ok(job_id)
+}
+
+/// Progress of a history export job, read from its manifest
+#[tauri::command]
+pub async fn get_export_progress(
+    app: AppHandle,
+    job_id: String,
+) -> Result<crate::utils::history_export::ExportManifest, String> {
+    let dir = crate::utils::history_export::manifest_dir(&app)?;
+    crate::utils::history_export::load_manifest(&dir, &job_id)
+}
+
+/// Transcription `id` with PII redacted, ready to share. `patterns` are
+/// applied in order; an empty list uses the built-in email, phone and card
+/// number rules. The stored transcription is left untouched.
+#[tauri::command]
+pub async fn export_transcription_redacted(
+    app: AppHandle,
+    id: String,
+    patterns: Vec<crate::post_processing::redaction::RedactionRule>,
+) -> Result<String, String> {
+    use crate::post_processing::redaction;
+
+    let entry = crate::utils::data_dir::transcriptions_store(&app)
+        .map_err(|e| e.to_string())?
+        .get(&id)
+        .ok_or_else(|| format!("Transcription not found: {}", id))?;
+    let text = entry
+        .get("segments")
+        .and_then(|v| v.as_array())
+        .and_then(|segments| crate::commands::segments::speaker_labeled_text(segments))
+        .or_else(|| {
+            entry
+                .get("text")
+                .and_then(|v| v.as_str())
+                .map(str::to_string)
+        })
+        .unwrap_or_default();
+
+    let rules = if patterns.is_empty() {
+        redaction::default_rules()
+    } else {
+        patterns
+    };
+    redaction::redact(&text, &rules)
+}
+
+/// Source format for `import_transcriptions`
+#[derive(serde::Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportFormat {
+    /// A VoiceTypr JSON export (or a bare array of entries)
+    Json,
+    /// SubRip captions; the whole file becomes one entry with timed segments
+    Srt,
+    /// A `.txt` file, or a folder with one transcript per `.txt` file
+    Text,
+}
+
+/// Entries from a JSON export. Anything without text is skipped.
+pub fn parse_json_import(content: &str) -> Result<Vec<serde_json::Value>, String> {
+    let document: serde_json::Value =
+        serde_json::from_str(content).map_err(|e| format!("Invalid JSON: {}", e))?;
+    let entries = match &document {
+        serde_json::Value::Array(entries) => entries,
+        other => other
+            .get("transcriptions")
+            .and_then(|t| t.as_array())
+            .ok_or_else(|| "JSON has no \"transcriptions\" list".to_string())?,
+    };
+
+    Ok(entries
+        .iter()
+        .filter(|entry| {
+            entry
+                .get("text")
+                .and_then(|t| t.as_str())
+                .is_some_and(|t| !t.trim().is_empty())
+        })
+        .cloned()
+        .collect())
+}
+
+fn parse_srt_time(value: &str) -> Option<f64> {
+    let (hms, millis) = value.trim().split_once([',', '.'])?;
+    let mut parts = hms.split(':').map(|p| p.parse::<u64>().ok());
+    let (h, m, s) = (parts.next()??, parts.next()??, parts.next()??);
+    Some((h * 3600 + m * 60 + s) as f64 + millis.parse::<u64>().ok()? as f64 / 1000.0)
+}
+
+/// Timed segments (`text`, `start`, `end`) from SubRip content
+pub fn parse_srt(content: &str) -> Result<Vec<serde_json::Value>, String> {
+    let content = content.trim_start_matches('\u{feff}').replace("\r\n", "\n");
+    let mut segments = Vec::new();
+
+    for block in content.split("\n\n").filter(|b| !b.trim().is_empty()) {
+        let mut lines = block.lines().skip_while(|l| !l.contains("-->"));
+        let Some(timing) = lines.next() else {
+            continue;
+        };
+        let (start, end) = timing
+            .split_once("-->")
+            .and_then(|(start, end)| Some((parse_srt_time(start)?, parse_srt_time(end)?)))
+            .ok_or_else(|| format!("Invalid SRT timing line: {}", timing))?;
+        let text = lines.collect::<Vec<_>>().join(" ");
+        if !text.trim().is_empty() {
+            segments.push(serde_json::json!({
+                "text": text.trim(),
+                "start": start,
+                "end": end,
+            }));
+        }
+    }
+
+    if segments.is_empty() {
+        return Err("No captions found in SRT file".to_string());
+    }
+    Ok(segments)
+}
+
+/// A store key at or just after `base` that no existing entry uses, so imports
+/// keep their place in the timeline without overwriting anything.
+pub fn unique_entry_key(
+    base: chrono::DateTime<chrono::Utc>,
+    taken: &std::collections::HashSet<String>,
+) -> String {
+    let mut candidate = base;
+    loop {
+        let key = candidate.to_rfc3339();
+        if !taken.contains(&key) {
+            return key;
+        }
+        candidate += chrono::Duration::microseconds(1);
+    }
+}
+
+fn file_modified(path: &std::path::Path) -> chrono::DateTime<chrono::Utc> {
+    std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .map(chrono::DateTime::<chrono::Utc>::from)
+        .unwrap_or_else(|_| chrono::Utc::now())
+}
+
+/// Read the files at `path` into history entries paired with their original time
+fn read_import_entries(
+    format: ImportFormat,
+    path: &std::path::Path,
+) -> Result<Vec<(chrono::DateTime<chrono::Utc>, serde_json::Value)>, String> {
+    let read = |path: &std::path::Path| {
+        std::fs::read_to_string(path).map_err(|e| format!("Failed to read {:?}: {}", path, e))
+    };
+
+    match format {
+        ImportFormat::Json => Ok(parse_json_import(&read(path)?)?
+            .into_iter()
+            .map(|entry| {
+                let time = entry
+                    .get("timestamp")
+                    .and_then(|t| t.as_str())
+                    .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+                    .map(|t| t.with_timezone(&chrono::Utc))
+                    .unwrap_or_else(|| file_modified(path));
+                (time, entry)
+            })
+            .collect()),
+        ImportFormat::Srt => {
+            let segments = parse_srt(&read(path)?)?;
+            let text = crate::commands::segments::segments_text(&segments);
+            Ok(vec![(
+                file_modified(path),
+                serde_json::json!({ "text": text, "segments": segments }),
+            )])
+        }
+        ImportFormat::Text => {
+            let files = if path.is_dir() {
+                let mut files: Vec<std::path::PathBuf> = std::fs::read_dir(path)
+                    .map_err(|e| format!("Failed to read import folder: {}", e))?
+                    .filter_map(|entry| entry.ok().map(|e| e.path()))
+                    .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("txt"))
+                    .collect();
+                files.sort();
+                files
+            } else {
+                vec![path.to_path_buf()]
+            };
+
+            let mut entries = Vec::new();
+            for file in files {
+                let text = read(&file)?;
+                if !text.trim().is_empty() {
+                    entries.push((
+                        file_modified(&file),
+                        serde_json::json!({ "text": text.trim() }),
+                    ));
+                }
+            }
+            Ok(entries)
+        }
+    }
+}
+
+/// Import transcripts from another app or an earlier export. Entries are
+/// added under fresh ids and marked `imported`; existing history is untouched.
+#[tauri::command]
+pub async fn import_transcriptions(
+    app: AppHandle,
+    format: ImportFormat,
+    path: String,
+) -> Result<u32, String> {
+    let source = std::path::PathBuf::from(&path);
+    if !source.exists() {
+        return Err(format!("Import source not found: {}", path));
+    }
+
+    let entries =
+        tauri::async_runtime::spawn_blocking(move || read_import_entries(format, &source))
+            .await
+            .map_err(|e| format!("Failed to read import: {}", e))??;
+    if entries.is_empty() {
+        return Err("No transcriptions found to import".to_string());
+    }
+
+    let store = crate::utils::data_dir::transcriptions_store(&app).map_err(|e| e.to_string())?;
+    let mut taken: std::collections::HashSet<String> =
+        store.keys().into_iter().map(|k| k.to_string()).collect();
+
+    let mut imported = 0u32;
+    for (time, mut entry) in entries {
+        let key = unique_entry_key(time, &taken);
+        entry["timestamp"] = serde_json::json!(key);
+        entry["imported"] = serde_json::json!(true);
+        crate::utils::recordings::sanitize_imported_recording(&mut entry);
+        if entry.get("model").and_then(|m| m.as_str()).is_none() {
+            entry["model"] = serde_json::json!("imported");
+        }
+        store.set(&key, entry);
+        taken.insert(key);
+        imported += 1;
+    }
+
+    store
+        .save()
+        .map_err(|e| format!("Failed to save imported transcriptions: {}", e))?;
+
+    let _ = crate::emit_to_window(&app, "main", "history-updated", ());
+    if let Err(e) = crate::commands::settings::update_tray_menu(app.clone()).await {
+        log::warn!("Failed to update tray menu after import: {}", e);
+    }
+
+    log::info!(
+        "Imported {} transcriptions as {:?} from {}",
+        imported,
+        format,
+        path
+    );
+    Ok(imported)
+}
+
+#[tauri::command]
+pub async fn get_telemetry(
+    app: AppHandle,
+) -> Result<Vec<crate::utils::telemetry::TranscriptionMetric>, String> {
+    let path = crate::utils::telemetry::telemetry_path(&app)?;
+    tauri::async_runtime::spawn_blocking(move || crate::utils::telemetry::read_metrics(&path))
+        .await
+        .map_err(|e| format!("Failed to read telemetry: {}", e))?
+}
+
+/// Whether this process holds the instance lock. A secondary instance exits
+/// during startup, so the UI only sees `false` if the lock couldn't be taken.
+#[tauri::command]
+pub async fn is_primary_instance() -> Result<bool, String> {
+    Ok(crate::utils::instance_lock::is_primary())
+}
+
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct DataDirMigration {
+    pub data_dir: String,
+    pub moved_items: Vec<String>,
+    /// Parakeet keeps its model directory until the app restarts
+    pub restart_required: bool,
+}
+
+/// Move models, recordings, history and telemetry to `new_path` and point
+/// `data_dir_override` at it. The target must be empty, except for the
+/// default app data dir, which the items are merged back into (clearing the
+/// override). Items already moved are put back if any step fails, so the
+/// setting and the files on disk never disagree.
+#[tauri::command]
+pub async fn migrate_data_dir(
+    app: AppHandle,
+    new_path: String,
+) -> Result<DataDirMigration, String> {
+    use crate::utils::data_dir::{self, DATA_DIR_OVERRIDE_KEY, RELOCATABLE_ITEMS};
+    use crate::whisper::{cache::TranscriberCache, manager::WhisperManager};
+    use std::path::PathBuf;
+    use tauri::async_runtime::{Mutex as AsyncMutex, RwLock as AsyncRwLock};
+    use tauri::Manager;
+
+    let recording_state = crate::get_recording_state(&app);
+    if recording_state != crate::RecordingState::Idle
+        && recording_state != crate::RecordingState::Error
+    {
+        return Err("Cannot move app data while recording or transcribing".to_string());
+    }
+
+    let target = PathBuf::from(new_path.trim());
+    if !target.is_absolute() {
+        return Err("Data directory must be an absolute path".to_string());
+    }
+    let current = data_dir::data_dir(&app)?;
+    if target == current {
+        return Err("App data is already stored in this directory".to_string());
+    }
+    if target.starts_with(&current) {
+        return Err("Data directory cannot be inside the current one".to_string());
+    }
+    let default_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    if target == default_dir {
+        // The default dir always holds settings, so merge into it instead
+        if let Some(conflict) = RELOCATABLE_ITEMS
+            .iter()
+            .find_map(|item| data_dir::first_conflict(&current.join(item), &target.join(item)))
+        {
+            return Err(format!(
+                "{} already exists in the default data directory",
+                conflict.display()
+            ));
+        }
+    } else {
+        let target_is_empty = match std::fs::read_dir(&target) {
+            Ok(mut entries) => entries.next().is_none(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => true,
+            Err(e) => return Err(format!("Failed to read data directory: {}", e)),
+        };
+        if !target_is_empty {
+            return Err("Data directory must be empty".to_string());
+        }
+    }
+
+    // Make sure pending history writes land in the file we're about to move
+    let old_transcriptions = data_dir::transcriptions_store(&app).ok();
+    if let Some(store) = &old_transcriptions {
+        store
+            .save()
+            .map_err(|e| format!("Failed to flush transcriptions store: {}", e))?;
+    }
+
+    log::info!("Migrating app data from {:?} to {:?}", current, target);
+    let (from_dir, to_dir) = (current.clone(), target.clone());
+    let moved_items = tauri::async_runtime::spawn_blocking(move || {
+        std::fs::create_dir_all(&to_dir)
+            .map_err(|e| format!("Failed to create data directory: {}", e))?;
+
+        let mut moved: Vec<String> = Vec::new();
+        for item in RELOCATABLE_ITEMS {
+            let from = from_dir.join(item);
+            if !from.exists() {
+                continue;
+            }
+            if let Err(e) = data_dir::merge_path(&from, &to_dir.join(item)) {
+                for done in moved.iter().rev() {
+                    if let Err(rollback) =
+                        data_dir::merge_path(&to_dir.join(done), &from_dir.join(done))
+                    {
+                        log::error!("Failed to restore {} during rollback: {}", done, rollback);
+                    }
+                }
+                return Err(e);
+            }
+            moved.push(item.to_string());
+        }
+        Ok(moved)
+    })
+    .await
+    .map_err(|e| format!("Failed to migrate data directory: {}", e))??;
+
+    // Point the setting at the new location; undo the move if that fails
+    let saved = app
+        .store("settings")
+        .map_err(|e| e.to_string())
+        .and_then(|store| {
+            if target == default_dir {
+                store.delete(DATA_DIR_OVERRIDE_KEY);
+            } else {
+                store.set(
+                    DATA_DIR_OVERRIDE_KEY,
+                    serde_json::json!(target.to_string_lossy()),
+                );
+            }
+            store.save().map_err(|e| e.to_string())
+        });
+    if let Err(e) = saved {
+        for item in moved_items.iter().rev() {
+            if let Err(rollback) = data_dir::merge_path(&target.join(item), &current.join(item)) {
+                log::error!("Failed to restore {} during rollback: {}", item, rollback);
+            }
+        }
+        return Err(format!("Failed to save data directory setting: {}", e));
+    }
+
+    // The cached history store still points at the old file; drop it so the
+    // next access loads the moved one
+    if let Some(store) = old_transcriptions {
+        store.close_resource();
+    }
+
+    // Whisper models are looked up through the manager, so rebuild it on the new dir
+    let models_dir = target.join("models");
+    if let Err(e) = std::fs::create_dir_all(&models_dir) {
+        log::warn!("Failed to create models directory: {}", e);
+    }
+    let mut whisper_manager = WhisperManager::new(models_dir);
+    whisper_manager.migrate_layout();
+    whisper_manager.set_active_quantizations(crate::commands::model::stored_quantizations(&app));
+    whisper_manager.set_updated_models(&crate::commands::model::stored_updated_models(&app));
+    *app.state::<AsyncRwLock<WhisperManager>>().write().await = whisper_manager;
+    app.state::<AsyncMutex<TranscriberCache>>()
+        .lock()
+        .await
+        .clear();
+
+    let _ = crate::commands::settings::update_tray_menu(app.clone()).await;
+    let _ = crate::emit_to_all(
+        &app,
+        "data-dir-changed",
+        target.to_string_lossy().to_string(),
+    );
+    log::info!("App data migrated: {:?}", moved_items);
+
+    Ok(DataDirMigration {
+        data_dir: target.to_string_lossy().to_string(),
+        moved_items,
+        restart_required: true,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_markdown_entry_includes_headers() {
+        let entry = serde_json::json!({
+            "text": "Hello world",
+            "model": "base.en",
+            "title": "Greeting"
+        });
+
+        let mut out = Vec::new();
+        write_markdown_entry(&mut out, "not-a-timestamp", &entry).unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        assert!(out.starts_with("## not-a-timestamp\n"));
+        assert!(out.contains("**Title:** Greeting"));
+        assert!(out.contains("**Model:** base.en"));
+        assert!(out.contains("\nHello world\n"));
+    }
+
+    #[test]
+    fn test_parse_srt() {
+        let srt = "1\r\n00:00:01,000 --> 00:00:02,500\r\nHello\r\nthere\r\n\r\n2\r\n01:00:00,000 --> 01:00:01,000\r\nBye\r\n";
+        let segments = parse_srt(srt).unwrap();
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0]["text"], "Hello there");
+        assert_eq!(segments[0]["start"], 1.0);
+        assert_eq!(segments[0]["end"], 2.5);
+        assert_eq!(segments[1]["start"], 3600.0);
+        assert!(parse_srt("not captions").is_err());
+    }
+
+    #[test]
+    fn test_parse_json_import_and_unique_keys() {
+        let export =
+            r#"{"app":"VoiceTypr","transcriptions":[{"text":"one","model":"base"},{"text":"  "}]}"#;
+        assert_eq!(parse_json_import(export).unwrap().len(), 1);
+        assert_eq!(parse_json_import(r#"[{"text":"a"}]"#).unwrap().len(), 1);
+        assert!(parse_json_import(r#"{"foo":1}"#).is_err());
+
+        let base = chrono::Utc::now();
+        let mut taken = std::collections::HashSet::new();
+        taken.insert(base.to_rfc3339());
+        let key = unique_entry_key(base, &taken);
+        assert_ne!(key, base.to_rfc3339());
+        assert!(chrono::DateTime::parse_from_rfc3339(&key).unwrap() > base);
+    }
+
+    #[test]
+    fn test_parse_range_bound_accepts_plain_dates() {
+        let start = parse_range_bound("2024-03-01", false).unwrap();
+        let end = parse_range_bound("2024-03-01", true).unwrap();
+        assert!(start < end);
+        assert!(parse_range_bound("03/01/2024", false).is_err());
+    }
+}
+
+/// Recordings (and stray sidecars) that no history entry accounts for
+#[tauri::command]
+pub async fn find_orphaned_recordings(app: AppHandle) -> Result<Vec<String>, String> {
+    let recordings_dir = crate::utils::data_dir::data_dir(&app)?.join("recordings");
+    crate::utils::recordings::reconcile_with_history(&app, &recordings_dir);
+
+    Ok(
+        crate::utils::recordings::orphaned_recordings(&app, &recordings_dir)
+            .into_iter()
+            .map(|path| path.to_string_lossy().to_string())
+            .collect(),
+    )
+}
+
+/// Clear stale recording locks left by a crash and keep the interrupted
+/// recording for transcription
+#[tauri::command]
+pub async fn recover_from_crash(
+    app: AppHandle,
+) -> Result<crate::utils::crash_recovery::RecoveryReport, String> {
+    let app_for_task = app.clone();
+    let report = tauri::async_runtime::spawn_blocking(move || {
+        crate::utils::crash_recovery::recover_app(&app_for_task)
+    })
+    .await
+    .map_err(|e| format!("Crash recovery task failed: {}", e))??;
+
+    if !report.is_empty() {
+        let _ = crate::emit_to_all(&app, "crash-recovered", report.clone());
+    }
+    Ok(report)
+}
+
+/// A recording salvaged after a crash, waiting for the user to decide
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct RecoveredRecording {
+    pub path: String,
+    pub duration_secs: f64,
+    pub created_at: String,
+}
+
+/// Recovered recordings not yet transcribed or discarded
+#[tauri::command]
+pub async fn get_recovered_recordings(app: AppHandle) -> Result<Vec<RecoveredRecording>, String> {
+    let recordings_dir = crate::utils::data_dir::data_dir(&app)?.join("recordings");
+    Ok(crate::utils::recordings::pending_recovered(&recordings_dir)
+        .into_iter()
+        .map(|(path, sidecar)| RecoveredRecording {
+            path: path.to_string_lossy().to_string(),
+            duration_secs: sidecar.duration_secs,
+            created_at: sidecar.created_at,
+        })
+        .collect())
+}
+
+/// The queued recovered recording at `path`, so only those can be acted on
+fn queued_recovered_recording(app: &AppHandle, path: &str) -> Result<std::path::PathBuf, String> {
+    let recordings_dir = crate::utils::data_dir::data_dir(app)?.join("recordings");
+    crate::utils::recordings::pending_recovered(&recordings_dir)
+        .into_iter()
+        .map(|(recording, _)| recording)
+        .find(|recording| recording == std::path::Path::new(path))
+        .ok_or_else(|| "Recovered recording not found".to_string())
+}
+
+/// Transcribe a recovered recording with the current model and save it to
+/// history, which takes it off the queue. Returns the text.
+#[tauri::command]
+pub async fn transcribe_recovered_recording(
+    app: AppHandle,
+    path: String,
+) -> Result<String, String> {
+    let recording = queued_recovered_recording(&app, &path)?;
+    let store = app.store("settings").map_err(|e| e.to_string())?;
+    let setting = |key: &str| {
+        store
+            .get(key)
+            .and_then(|v| v.as_str().map(|s| s.to_string()))
+            .unwrap_or_default()
+    };
+    let (model, engine) = (setting("current_model"), setting("current_model_engine"));
+
+    let text = crate::commands::audio::transcribe_audio_file(
+        app.clone(),
+        path,
+        model.clone(),
+        Some(engine).filter(|e| !e.is_empty()),
+    )
+    .await?;
+    let mut metadata = serde_json::Map::new();
+    metadata.insert("recovered".to_string(), true.into());
+    let id = crate::commands::audio::save_transcription_with_metadata(
+        app.clone(),
+        text.clone(),
+        model,
+        metadata,
+    )
+    .await?;
+    match id {
+        Some(id) => crate::utils::recordings::finalize_recording(&recording, &id)?,
+        // Skipped as a duplicate of the last entry, so nothing links to it
+        None => crate::utils::recordings::remove_recording(&recording),
+    }
+    Ok(text)
+}
+
+/// Delete a recovered recording without transcribing it
+#[tauri::command]
+pub async fn discard_recovered_recording(app: AppHandle, path: String) -> Result<(), String> {
+    let recording = queued_recovered_recording(&app, &path)?;
+    crate::utils::recordings::remove_recording(&recording);
+    log::info!("Discarded recovered recording {:?}", recording);
+    Ok(())
+}
+
+/// Zero-fill and delete a recording (and its sidecar) from the recordings
+/// directory, whether or not secure delete is enabled
+#[tauri::command]
+pub async fn secure_wipe_recording(app: AppHandle, path: String) -> Result<(), String> {
+    let recordings_dir = crate::utils::data_dir::data_dir(&app)?.join("recordings");
+    let recordings_dir = recordings_dir
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve recordings directory: {}", e))?;
+    let recording = std::path::Path::new(&path)
+        .canonicalize()
+        .map_err(|e| format!("Recording not found: {}", e))?;
+    if !recording.starts_with(&recordings_dir) {
+        return Err("Only files in the recordings directory can be wiped".to_string());
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        crate::utils::secure_delete::secure_wipe_recording(&recording)
+    })
+    .await
+    .map_err(|e| format!("Secure wipe task failed: {}", e))??;
+    log::info!("Securely wiped recording");
+    Ok(())
+}
+
+/// Recording kept for transcription `id` (or a recording file name), with
+/// an error explaining why it's unavailable
+pub fn kept_recording_path(app: &AppHandle, id: &str) -> Result<std::path::PathBuf, String> {
+    let recordings_dir = crate::utils::data_dir::data_dir(app)?.join("recordings");
+
+    let entry = crate::utils::data_dir::transcriptions_store(app)
+        .map_err(|e| e.to_string())?
+        .get(id);
+    let file_name = match &entry {
+        Some(entry) => crate::utils::recordings::recording_file_name(entry)
+            .ok_or_else(|| format!("No recording was kept for transcription {}", id))?,
+        None => id.to_string(),
+    };
+    // Only plain file names resolve inside the recordings directory
+    if std::path::Path::new(&file_name).file_name() != Some(std::ffi::OsStr::new(&file_name)) {
+        return Err(format!("Invalid recording id: {}", id));
+    }
+    let source_dir = entry
+        .as_ref()
+        .map(|entry| crate::utils::recordings::recording_location(&recordings_dir, entry))
+        .unwrap_or_else(|| recordings_dir.clone());
+    let source = source_dir.join(&file_name);
+    if !source.is_file() && source_dir != recordings_dir {
+        return Err(format!(
+            "Archived recording {} isn't available; is {:?} connected?",
+            file_name, source_dir
+        ));
+    }
+    if !source.is_file() {
+        return Err(format!(
+            "Recording {} no longer exists; it may have been pruned by retention cleanup",
+            file_name
+        ));
+    }
+    Ok(source)
+}
+
+/// Export the recording kept for transcription `id` (or a recording file
+/// name) to `dest` in `format`. Returns the written path.
+#[tauri::command]
+pub async fn export_recording(
+    app: AppHandle,
+    id: String,
+    format: crate::audio::encode::AudioFormat,
+    dest: String,
+) -> Result<String, String> {
+    let source = kept_recording_path(&app, &id)?;
+
+    let mut dest = std::path::PathBuf::from(dest);
+    if dest.is_dir() {
+        let stem = source
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| "recording".to_string());
+        dest = dest.join(format!("{}.{}", stem, format.extension()));
+    }
+
+    crate::audio::encode::export_audio(&app, &source, format, &dest).await?;
+
+    log::info!("Exported recording {:?} to {:?}", source, dest);
+    Ok(dest.to_string_lossy().to_string())
+}
+
+/// Move kept recordings from before `before` (local date) to `archive_dir`
+/// and point their history entries at the archive. Returns how many moved;
+/// if some couldn't be moved, the ones that did are still recorded in
+/// history and the failures are returned as the error.
+#[tauri::command]
+pub async fn archive_recordings_before(
+    app: AppHandle,
+    before: chrono::NaiveDate,
+    archive_dir: String,
+) -> Result<u32, String> {
+    let recordings_dir = crate::utils::data_dir::data_dir(&app)?.join("recordings");
+    let archive_dir = std::path::PathBuf::from(archive_dir);
+    if !archive_dir.is_absolute() {
+        return Err("Archive directory must be an absolute path".to_string());
+    }
+    if archive_dir.starts_with(&recordings_dir) {
+        return Err("Archive directory must be outside the recordings directory".to_string());
+    }
+
+    let cutoff: std::time::SystemTime = before
+        .and_hms_opt(0, 0, 0)
+        .and_then(|dt| dt.and_local_timezone(chrono::Local).earliest())
+        .ok_or_else(|| format!("Invalid date: {}", before))?
+        .into();
+
+    let archive_for_task = archive_dir.clone();
+    let outcome = tauri::async_runtime::spawn_blocking(move || {
+        crate::utils::recordings::archive_recordings(&recordings_dir, &archive_for_task, cutoff)
+    })
+    .await
+    .map_err(|e| format!("Archive task failed: {}", e))??;
+    let moved = outcome.moved;
+    let failures = || {
+        outcome
+            .failed
+            .iter()
+            .map(|(file, e)| format!("{}: {}", file, e))
+            .collect::<Vec<_>>()
+            .join("; ")
+    };
+    if moved.is_empty() {
+        if outcome.failed.is_empty() {
+            return Ok(0);
+        }
+        return Err(format!("Failed to archive recordings: {}", failures()));
+    }
+
+    let store = crate::utils::data_dir::transcriptions_store(&app).map_err(|e| e.to_string())?;
+    for (key, mut entry) in store.entries() {
+        let archived = crate::utils::recordings::recording_file_name(&entry)
+            .is_some_and(|file| moved.iter().any(|m| *m == file));
+        if let (true, Some(fields)) = (archived, entry.as_object_mut()) {
+            fields.insert(
+                crate::utils::recordings::ARCHIVE_DIR_FIELD.to_string(),
+                archive_dir.to_string_lossy().to_string().into(),
+            );
+            store.set(key, entry);
+        }
+    }
+    store
+        .save()
+        .map_err(|e| format!("Failed to save transcription history: {}", e))?;
+    let _ = crate::emit_to_window(&app, "main", "history-updated", ());
+
+    log::info!("Archived {} recordings to {:?}", moved.len(), archive_dir);
+    if !outcome.failed.is_empty() {
+        return Err(format!(
+            "Archived {} recordings, but {} could not be moved: {}",
+            moved.len(),
+            outcome.failed.len(),
+            failures()
+        ));
+    }
+    Ok(moved.len() as u32)
+}
+
+/// Rewrite recording paths stored in history to this platform's conventions,
+/// e.g. after restoring a backup made on another OS. Returns how many entries
+/// were fixed.
+#[tauri::command]
+pub async fn normalize_recording_paths(app: AppHandle) -> Result<u32, String> {
+    let store = crate::utils::data_dir::transcriptions_store(&app).map_err(|e| e.to_string())?;
+    let mut fixed = 0;
+    for (key, mut entry) in store.entries() {
+        if crate::utils::recordings::normalize_entry_paths(&mut entry) {
+            store.set(key, entry);
+            fixed += 1;
+        }
+    }
+    if fixed == 0 {
+        return Ok(0);
+    }
+    store
+        .save()
+        .map_err(|e| format!("Failed to save transcription history: {}", e))?;
+    let _ = crate::emit_to_window(&app, "main", "history-updated", ());
+
+    log::info!("Normalized {} stored recording paths", fixed);
+    Ok(fixed)
+}
@@ -1,6 +1,6 @@
 use crate::utils::logger::*;
 use crate::AppState;
-use tauri::{AppHandle, Manager};
+use tauri::{AppHandle, Emitter, Manager};
 
 #[tauri::command]
 pub async fn show_pill_widget(app: AppHandle) -> Result<(), String> {
@@ -183,3 +183,93 @@ pub async fn focus_main_window(app: AppHandle) -> Result<(), String> {
 
     Ok(())
 }
+
+/// Anchors accepted for the recording overlay (`pill_indicator_position`)
+pub const OVERLAY_ANCHORS: &[&str] = &[
+    "top-left",
+    "top-center",
+    "top-right",
+    "bottom-left",
+    "bottom-center",
+    "bottom-right",
+];
+
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct MonitorInfo {
+    /// Value to pass as `monitor_id` to `set_overlay_position`
+    pub id: String,
+    pub is_primary: bool,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Connected monitors the overlay can be pinned to
+#[tauri::command]
+pub async fn list_monitors(app: AppHandle) -> Result<Vec<MonitorInfo>, String> {
+    let primary_name = app
+        .primary_monitor()
+        .ok()
+        .flatten()
+        .and_then(|m| m.name().cloned());
+    let monitors = app
+        .available_monitors()
+        .map_err(|e| format!("Failed to list monitors: {}", e))?;
+
+    Ok(monitors
+        .into_iter()
+        .filter_map(|monitor| {
+            let id = monitor.name()?.clone();
+            Some(MonitorInfo {
+                is_primary: primary_name.as_ref() == Some(&id),
+                width: monitor.size().width,
+                height: monitor.size().height,
+                id,
+            })
+        })
+        .collect())
+}
+
+/// Choose the monitor ("primary", "follow_active" or a name from `list_monitors`)
+/// and corner the recording overlay appears in, and move it there now
+#[tauri::command]
+pub async fn set_overlay_position(
+    app: AppHandle,
+    monitor_id: String,
+    anchor: String,
+) -> Result<(), String> {
+    use tauri_plugin_store::StoreExt;
+
+    if !OVERLAY_ANCHORS.contains(&anchor.as_str()) {
+        return Err(format!("Invalid overlay position: {}", anchor));
+    }
+    let monitor = crate::window_manager::OverlayMonitor::from_setting(monitor_id.trim());
+
+    let store = app.store("settings").map_err(|e| e.to_string())?;
+    store.set("overlay_monitor", serde_json::json!(monitor.as_setting()));
+    store.set("pill_indicator_position", serde_json::json!(anchor));
+    store
+        .save()
+        .map_err(|e| format!("Failed to save overlay position: {}", e))?;
+    log::info!(
+        "Overlay position set to '{}' on monitor '{}'",
+        anchor,
+        monitor.as_setting()
+    );
+
+    // Recreate a visible pill at the new spot; moving it in place isn't reliable
+    let pill_visible = app
+        .state::<AppState>()
+        .get_window_manager()
+        .is_some_and(|window_manager| window_manager.has_pill_window());
+    if pill_visible {
+        if let Err(e) = hide_pill_widget(app.clone()).await {
+            log::warn!("Failed to hide pill window for position change: {}", e);
+        }
+        if let Err(e) = show_pill_widget(app.clone()).await {
+            log::warn!("Failed to show pill window at new position: {}", e);
+        }
+    }
+
+    let _ = app.emit("settings-changed", ());
+    Ok(())
+}
@@ -192,6 +192,30 @@ pub async fn to_wav_streaming(app: &AppHandle, input: &Path, output: &Path) -> R
     run_ffmpeg_command(app, FFMPEG_CANDIDATES, &args, "ffmpeg").await
 }
 
+pub async fn to_mp3(
+    app: &AppHandle,
+    input: &Path,
+    output: &Path,
+    bitrate_kbps: u32,
+) -> Result<(), String> {
+    // ffmpeg -y -loglevel error -i input -vn -codec:a libmp3lame -b:a <kbps>k output
+    let args: Vec<String> = vec![
+        "-y".into(),
+        "-loglevel".into(),
+        "error".into(),
+        "-hide_banner".into(),
+        "-i".into(),
+        input.to_string_lossy().to_string(),
+        "-vn".into(),
+        "-codec:a".into(),
+        "libmp3lame".into(),
+        "-b:a".into(),
+        format!("{}k", bitrate_kbps),
+        output.to_string_lossy().to_string(),
+    ];
+    run_ffmpeg_command(app, FFMPEG_CANDIDATES, &args, "ffmpeg").await
+}
+
 pub async fn normalize_streaming(
     app: &AppHandle,
     input: &Path,
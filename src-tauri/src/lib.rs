@@ -19,7 +19,9 @@ mod ffmpeg;
 mod license;
 mod media;
 mod menu;
+mod output;
 mod parakeet;
+mod post_processing;
 mod recognition;
 mod recording;
 mod secure_store;
@@ -51,30 +53,63 @@ use commands::{
     ai::{
         cache_ai_api_key, clear_ai_api_key_cache, disable_ai_enhancement, enhance_transcription,
         get_ai_settings, get_ai_settings_for_provider, get_enhancement_options, get_openai_config,
-        list_provider_models, set_openai_config, test_openai_endpoint, update_ai_settings,
-        update_enhancement_options, validate_and_cache_api_key,
+        list_cached_key_providers, list_provider_models, prune_api_key_cache, remove_ai_provider,
+        set_openai_config, test_openai_endpoint, update_ai_settings, update_enhancement_options,
+        validate_and_cache_api_key,
     },
     audio::*,
     clipboard::{copy_image_to_clipboard, save_image_to_file},
     debug::{debug_transcription_flow, test_transcription_event},
     device::get_device_id,
-    keyring::{keyring_delete, keyring_get, keyring_has, keyring_set},
+    keyring::{
+        check_secure_store, keyring_delete, keyring_get, keyring_has, keyring_list, keyring_set,
+        reset_secure_store,
+    },
+    latency::measure_latency,
     license::*,
-    logs::{clear_old_logs, get_log_directory, open_logs_folder},
+    logs::{
+        check_time_consistency, clear_old_logs, dismiss_crash_report, get_log_directory,
+        get_pending_crash_reports, open_logs_folder, read_log_filtered, read_log_since,
+        run_auto_cleanup, tail_log,
+    },
     model::{
-        cancel_download, delete_model, download_model, get_model_status, list_downloaded_models,
-        preload_model, verify_model,
+        cancel_download, check_model_updates, delete_model, download_model, get_download_queue,
+        get_loaded_models_memory, get_model_status, get_whisper_params, list_downloaded_models,
+        migrate_models_layout, pause_download, preload_model, recommend_model_for_system,
+        reorder_download, reset_whisper_params, resume_download, set_active_quantization,
+        set_backend, set_whisper_params, test_load_model, update_model, verify_model,
     },
     permissions::{
-        check_accessibility_permission, check_microphone_permission,
-        request_accessibility_permission, request_microphone_permission,
+        check_accessibility_permission, check_microphone_permission, check_permissions,
+        request_accessibility_permission, request_microphone_permission, request_permission,
         test_automation_permission,
     },
-    reset::reset_app_data,
+    post_processing::{
+        add_correction_term, add_snippet, export_dictionaries, get_correction_dictionary,
+        get_snippets, import_dictionaries, remove_correction_term, remove_snippet,
+        set_correction_max_distance,
+    },
+    reset::{
+        cancel_scheduled_reset, clear_store_keys, find_legacy_identifiers,
+        get_last_scheduled_reset, preview_reset, reset_app_data, reset_legacy_identifier,
+        reset_window_state, schedule_reset_on_next_launch,
+    },
+    segments::{
+        export_transcription_captions, merge_transcription_segments, split_transcription_segment,
+        update_transcription_segment,
+    },
+    self_test::{check_writable_paths, run_self_test},
     settings::*,
     stt::{clear_soniox_key_cache, validate_and_cache_soniox_key},
     text::*,
-    utils::export_transcriptions,
+    utils::{
+        archive_recordings_before, discard_recovered_recording, export_all_transcriptions,
+        export_recording, export_transcription_redacted, export_transcriptions, export_usage_csv,
+        find_orphaned_recordings, get_export_progress, get_recovered_recordings, get_telemetry,
+        import_transcriptions, is_primary_instance, migrate_data_dir, normalize_recording_paths,
+        recover_from_crash, secure_wipe_recording, start_history_export,
+        transcribe_recovered_recording,
+    },
     window::*,
 };
 use whisper::cache::TranscriberCache;
@@ -119,6 +154,17 @@ fn setup_logging() -> tauri_plugin_log::Builder {
         ])
         .rotation_strategy(RotationStrategy::KeepAll)
         .max_file_size(10_000_000) // 10MB per file
+        .format(|out, message, record| {
+            out.finish(format_args!(
+                "{}",
+                utils::log_format::format_line(
+                    chrono::Utc::now(),
+                    record.level(),
+                    record.target(),
+                    &message.to_string()
+                )
+            ))
+        })
         .level(if cfg!(debug_assertions) {
             log::LevelFilter::Debug
         } else {
@@ -166,6 +212,14 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let mut builder = tauri::Builder::default()
+        // Registered first so a second launch exits before other plugins touch shared state
+        .plugin(tauri_plugin_single_instance::init(|app, _argv, _cwd| {
+            // When a second instance is launched, bring the existing window to focus
+            if let Some(win) = app.get_webview_window("main") {
+                let _ = win.show();
+                let _ = win.set_focus();
+            }
+        }))
         .plugin(tauri_plugin_os::init())
         .plugin(setup_logging().build())
         // Replaced tauri-plugin-cache with simple_store-backed cache
@@ -174,13 +228,6 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_dialog::init())
-        .plugin(tauri_plugin_single_instance::init(|app, _argv, _cwd| {
-            // When a second instance is launched, bring the existing window to focus
-            if let Some(win) = app.get_webview_window("main") {
-                let _ = win.show();
-                let _ = win.set_focus();
-            }
-        }))
         .plugin({
             #[cfg(target_os = "macos")]
             let autostart = tauri_plugin_autostart::init(
@@ -230,7 +277,10 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
                 ("component", "panic_handler")
             ]);
 
-            std::panic::set_hook(Box::new(|panic_info| {
+            let crash_log_dir = app.path().app_log_dir().ok();
+            let crash_log_dir_for_startup = crash_log_dir.clone();
+            let crash_app = app.handle().clone();
+            std::panic::set_hook(Box::new(move |panic_info| {
                 let location = panic_info.location()
                     .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()))
                     .unwrap_or_else(|| "unknown location".to_string());
@@ -260,38 +310,80 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
                         location, message, panic_info, chrono::Local::now()
                     ));
                 }
+
+                // Scrubbed report offered to the user on next launch
+                if let Some(log_dir) = &crash_log_dir {
+                    let report = utils::crash_report::CrashReport {
+                        created_at: chrono::Utc::now().to_rfc3339(),
+                        app_version: env!("CARGO_PKG_VERSION").to_string(),
+                        location: location.clone(),
+                        message: message.clone(),
+                        recording_state: crash_app
+                            .try_state::<AppState>()
+                            .and_then(|state| state.recording_state.try_current())
+                            .map(|state| format!("{:?}", state)),
+                        system: utils::crash_report::SystemSummary::current(),
+                        log_tail: utils::crash_report::read_log_tail(
+                            log_dir,
+                            utils::crash_report::CRASH_LOG_LINES,
+                        ),
+                    };
+                    if let Err(e) = utils::crash_report::write_crash_report(log_dir, report) {
+                        eprintln!("{}", e);
+                    }
+                }
             }));
 
             log::info!("✅ Panic handler configured");
 
-            // Clean up old logs on startup (keep last 30 days)
-            log_start("LOG_CLEANUP");
-            log_with_context(log::Level::Debug, "Cleaning up old logs", &[
-                ("retention_days", "30")
-            ]);
+            // Backstop for launches the single-instance plugin can't see (e.g. a
+            // different build sharing the data dir): don't initialize alongside it
+            if let Ok(app_data_dir) = app.path().app_data_dir() {
+                if !utils::instance_lock::acquire_for_current_process(&app_data_dir) {
+                    log::warn!("Another VoiceTypr instance is running; exiting");
+                    std::process::exit(0);
+                }
+            }
 
-            let app_handle = app.handle().clone();
-            tauri::async_runtime::spawn(async move {
-                let cleanup_start = Instant::now();
-                match commands::logs::clear_old_logs(app_handle, 30).await {
-                    Ok(deleted) => {
-                        log_complete("LOG_CLEANUP", cleanup_start.elapsed().as_millis() as u64);
-                        log_with_context(log::Level::Debug, "Log cleanup complete", &[
-                            ("files_deleted", deleted.to_string().as_str())
-                        ]);
-                        if deleted > 0 {
-                            log::info!("🧹 Cleaned up {} old log files", deleted);
-                        }
-                    }
-                    Err(e) => {
-                        log_failed("LOG_CLEANUP", &e);
-                        log_with_context(log::Level::Debug, "Log cleanup failed", &[
-                            ("retention_days", "30")
-                        ]);
-                        log::warn!("Failed to clean up old logs: {}", e);
-                    }
+            // Apply the saved log format and deletion policy now that the store is available
+            if let Ok(store) = app.store("settings") {
+                if let Some(format) = store
+                    .get("log_format")
+                    .and_then(|v| v.as_str().map(str::to_string))
+                {
+                    utils::log_format::set_log_format(
+                        utils::log_format::LogFormat::from_setting(&format),
+                    );
                 }
-            });
+                utils::secure_delete::set_enabled(
+                    store
+                        .get("secure_delete_recordings")
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(false),
+                );
+            }
+
+            // Recover from a crash mid-recording before cleanup can sweep the
+            // interrupted recording as an orphan
+            if let Err(e) = utils::crash_recovery::recover_app(app.handle()) {
+                log::warn!("Crash recovery check failed: {}", e);
+            }
+            if let Some(log_dir) = &crash_log_dir_for_startup {
+                let pending = utils::crash_report::pending_crash_reports(log_dir).len();
+                if pending > 0 {
+                    log::info!("{} crash report(s) waiting for the user", pending);
+                }
+            }
+
+            // Pick up external edits to the settings file when enabled
+            let settings_watcher = utils::settings_watcher::SettingsWatcher::new();
+            settings_watcher.start(app.handle().clone());
+            app.manage(settings_watcher);
+
+            // Clean up old logs and leftover recordings now and on an interval
+            let auto_cleanup = utils::auto_cleanup::AutoCleanupScheduler::new();
+            auto_cleanup.start(app.handle().clone());
+            app.manage(auto_cleanup);
 
             // Set activation policy on macOS to prevent focus stealing
             #[cfg(target_os = "macos")]
@@ -314,7 +406,8 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
             }
 
             // Initialize whisper manager
-            let models_dir = app.path().app_data_dir()?.join("models");
+            // Honors the user's data_dir_override (models may live on another drive)
+            let models_dir = crate::utils::data_dir::data_dir(app.handle())?.join("models");
             log::info!("🗂️  Models directory: {:?}", models_dir);
 
             log_start("WHISPER_MANAGER_INIT");
@@ -334,10 +427,26 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
                 }
             }
 
-            let whisper_manager = whisper::manager::WhisperManager::new(models_dir.clone());
+            // Model downloads and AI clients read the proxy from here
+            utils::proxy::migrate_credentials(app.handle());
+            utils::proxy::refresh(app.handle());
+
+            let mut whisper_manager = whisper::manager::WhisperManager::new(models_dir.clone());
+            let layout_report = whisper_manager.migrate_layout();
+            if !layout_report.moved.is_empty() || !layout_report.errors.is_empty() {
+                log::info!(
+                    "🗂️  Models layout migrated: {} moved, errors: {:?}",
+                    layout_report.moved.len(),
+                    layout_report.errors
+                );
+            }
+            whisper_manager.set_active_quantizations(commands::model::stored_quantizations(app.handle()));
+            whisper_manager.set_updated_models(&commands::model::stored_updated_models(app.handle()));
+            whisper::params::set_active(commands::model::stored_whisper_params(app.handle()));
             app.manage(AsyncRwLock::new(whisper_manager));
 
             log::info!("✅ Whisper manager initialized and managed");
+            commands::model::spawn_model_update_check(app.handle().clone());
 
             // Initialize Parakeet manager and cache directory
             let parakeet_dir = models_dir.join("parakeet");
@@ -354,12 +463,16 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
 
             // Manage active downloads for cancellation
             app.manage(Arc::new(Mutex::new(HashMap::<String, Arc<AtomicBool>>::new())));
+            app.manage(utils::download_queue::DownloadQueue::new());
 
             // Initialize transcriber cache for keeping models in memory
             // Cache size is 1: only the current model (1-3GB RAM)
             // When user switches models, old one is unloaded immediately
             app.manage(AsyncMutex::new(TranscriberCache::new()));
 
+            // Cancellation flags and lock for file transcriptions
+            app.manage(FileTranscriptionState::new());
+
             // Initialize unified application state
             app.manage(AppState::new());
             log::info!("🧠 App state managed and ready");
@@ -369,6 +482,22 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
             let window_manager = WindowManager::new(app.app_handle().clone());
             app_state.set_window_manager(window_manager);
 
+            // Run a reset scheduled by the previous session before models load or
+            // shortcuts are registered (the settings store is already open). The
+            // result is kept for get_last_scheduled_reset; the event only tells a
+            // window that is already listening.
+            app.manage(commands::reset::LastScheduledReset::default());
+            if let Some(result) = tauri::async_runtime::block_on(
+                commands::reset::run_scheduled_reset(app.handle()),
+            ) {
+                if let Ok(mut last) = app.state::<commands::reset::LastScheduledReset>().0.lock() {
+                    *last = Some(result.clone());
+                }
+                if let Err(e) = app.emit("scheduled-reset-completed", result) {
+                    log::warn!("Failed to emit scheduled-reset-completed: {}", e);
+                }
+            }
+
             // Run comprehensive startup checks after state/window manager are ready
             let app_handle = app.handle().clone();
             tauri::async_runtime::spawn(async move {
@@ -423,21 +552,6 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
                 }
             });
 
-            // Clean up old logs on startup (keep only today's log)
-            let app_handle_for_logs = app.app_handle().clone();
-            tauri::async_runtime::spawn(async move {
-                match clear_old_logs(app_handle_for_logs, 1).await {
-                    Ok(deleted_count) => {
-                        if deleted_count > 0 {
-                            log::info!("Cleaned up {} old log files (keeping only today)", deleted_count);
-                        }
-                    }
-                    Err(e) => {
-                        log::warn!("Failed to clean up old logs: {}. App will continue normally.", e);
-                    }
-                }
-            });
-
             // Pill position is loaded from settings when needed, no duplicate state
 
             // Initialize recorder state (kept separate for backwards compatibility)
@@ -565,7 +679,7 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
                         let app_handle = app.app_handle().clone();
                         tauri::async_runtime::spawn(async move {
                             // Read text by timestamp and copy
-                            match app_handle.store("transcriptions") {
+                            match crate::utils::data_dir::transcriptions_store(&app_handle) {
                                 Ok(store) => {
                                     if let Some(val) = store.get(&ts_owned) {
                                         if let Some(text) = val.get("text").and_then(|v| v.as_str()) {
@@ -1035,9 +1149,16 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
         })
         .invoke_handler(tauri::generate_handler![
             start_recording,
+            can_start_recording,
             stop_recording,
             cancel_recording,
+            cancel_transcription,
+            pause_recording,
+            resume_recording,
+            set_recording_gain,
             get_current_recording_state,
+            // Path keeps it apart from the crate-level get_recording_state helper
+            commands::audio::get_recording_state,
             debug_transcription_flow,
             test_transcription_event,
             save_transcription,
@@ -1046,36 +1167,112 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
             download_model,
             get_model_status,
             preload_model,
+            set_backend,
+            set_active_quantization,
+            get_loaded_models_memory,
+            check_model_updates,
+            update_model,
+            migrate_models_layout,
+            get_whisper_params,
+            set_whisper_params,
+            reset_whisper_params,
+            recommend_model_for_system,
             verify_model,
+            test_load_model,
             transcribe_audio,
             transcribe_audio_file,
+            commands::dropped::transcribe_dropped_path,
+            retranscribe_low_confidence,
+            cancel_retranscription,
+            compare_params,
+            #[cfg(feature = "test-fixtures")]
+            commands::fixtures::seed_test_data,
+            estimate_transcription_time,
+            suggest_model_for_current_conditions,
+            get_audio_file_duration,
             get_settings,
             save_settings,
+            reload_settings,
+            import_settings,
             set_audio_device,
             validate_microphone_selection,
             set_global_shortcut,
+            reinit_shortcuts,
+            set_webhook_secret,
+            set_proxy_credentials,
+            set_output_sinks,
             get_supported_languages,
+            set_launch_at_login,
+            set_incognito,
+            test_proxy,
+            get_launch_at_login,
             set_model_from_tray,
             update_tray_menu,
             insert_text,
             delete_model,
             list_downloaded_models,
             cancel_download,
+            get_download_queue,
+            reorder_download,
+            pause_download,
+            resume_download,
             cleanup_old_transcriptions,
             get_transcription_history,
+            list_transcriptions_by_app,
+            preview_filters_on_history,
             delete_transcription_entry,
+            merge_transcriptions,
+            update_transcription_segment,
+            split_transcription_segment,
+            merge_transcription_segments,
+            export_transcription_captions,
+            find_duplicate_transcriptions,
+            dedupe_transcriptions,
+            rename_transcription,
+            compact_transcriptions_store,
             clear_all_transcriptions,
             export_transcriptions,
+            export_recording,
+            export_transcription_redacted,
+            archive_recordings_before,
+            normalize_recording_paths,
+            export_all_transcriptions,
+            export_usage_csv,
+            start_history_export,
+            get_export_progress,
+            import_transcriptions,
+            get_telemetry,
+            is_primary_instance,
+            migrate_data_dir,
+            find_orphaned_recordings,
+            recover_from_crash,
+            get_recovered_recordings,
+            transcribe_recovered_recording,
+            discard_recovered_recording,
+            secure_wipe_recording,
             show_pill_widget,
             hide_pill_widget,
             close_pill_widget,
             hide_toast_window,
             focus_main_window,
+            list_monitors,
+            set_overlay_position,
             check_accessibility_permission,
             request_accessibility_permission,
             check_microphone_permission,
             request_microphone_permission,
             test_automation_permission,
+            check_permissions,
+            request_permission,
+            get_correction_dictionary,
+            add_correction_term,
+            remove_correction_term,
+            set_correction_max_distance,
+            get_snippets,
+            add_snippet,
+            remove_snippet,
+            export_dictionaries,
+            import_dictionaries,
             check_license_status,
             restore_license,
             activate_license,
@@ -1083,6 +1280,17 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
             open_purchase_page,
             invalidate_license_cache,
             reset_app_data,
+            reset_window_state,
+            find_legacy_identifiers,
+            reset_legacy_identifier,
+            schedule_reset_on_next_launch,
+            cancel_scheduled_reset,
+            get_last_scheduled_reset,
+            clear_store_keys,
+            run_self_test,
+            check_writable_paths,
+            measure_latency,
+            preview_reset,
             copy_image_to_clipboard,
             save_image_to_file,
             copy_text_to_clipboard,
@@ -1094,6 +1302,9 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
             get_openai_config,
             test_openai_endpoint,
             clear_ai_api_key_cache,
+            list_cached_key_providers,
+            prune_api_key_cache,
+            remove_ai_provider,
             update_ai_settings,
             enhance_transcription,
             disable_ai_enhancement,
@@ -1101,16 +1312,31 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
             update_enhancement_options,
             list_provider_models,
             keyring_set,
+            check_secure_store,
+            reset_secure_store,
             keyring_get,
             keyring_delete,
             keyring_has,
+            keyring_list,
             validate_and_cache_soniox_key,
             clear_soniox_key_cache,
             get_log_directory,
+            get_pending_crash_reports,
+            dismiss_crash_report,
             open_logs_folder,
+            run_auto_cleanup,
+            read_log_filtered,
+            tail_log,
+            read_log_since,
+            check_time_consistency,
             get_device_id,
         ])
         .on_window_event(|window, event| {
+            if let tauri::WindowEvent::DragDrop(tauri::DragDropEvent::Drop { paths, .. }) = event {
+                if window.label() == "main" && !paths.is_empty() {
+                    commands::dropped::handle_dropped_paths(window.app_handle(), paths.clone());
+                }
+            }
             if let tauri::WindowEvent::CloseRequested { api, .. } = event {
                 // Only hide the window instead of closing it (except for pill)
                 if window.label() == "main" {
@@ -1137,6 +1363,28 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
             Box::new(e)
         })?
         .run(|app_handle, event| {
+            // Finish recording/transcription and flush stores before the process exits
+            if let tauri::RunEvent::ExitRequested { api, code, .. } = &event {
+                if !commands::audio::is_shutdown_prepared() {
+                    api.prevent_exit();
+                    let app = app_handle.clone();
+                    let exit_code = code.unwrap_or(0);
+                    tauri::async_runtime::spawn(async move {
+                        if let Err(e) = prepare_shutdown(app.clone()).await {
+                            log::error!("Shutdown preparation failed: {}", e);
+                        }
+                        app.exit(exit_code);
+                    });
+                    return;
+                }
+            }
+
+            if let tauri::RunEvent::Exit = event {
+                if let Ok(app_data_dir) = app_handle.path().app_data_dir() {
+                    utils::instance_lock::release_for_current_process(&app_data_dir);
+                }
+            }
+
             #[cfg(target_os = "macos")]
             if let tauri::RunEvent::Reopen { has_visible_windows, .. } = event {
                 if !has_visible_windows {
@@ -1166,6 +1414,23 @@ async fn perform_startup_checks(app: tauri::AppHandle) {
         &[("stage", "comprehensive_validation")],
     );
 
+    // Catch read-only or restricted data directories before anything fails to save
+    let unwritable: Vec<_> = crate::utils::writable_paths::check_all(&app)
+        .into_iter()
+        .filter(|status| !status.writable)
+        .collect();
+    if !unwritable.is_empty() {
+        for status in &unwritable {
+            log::warn!(
+                "⚠️  App directory {} ({}) is not writable: {}",
+                status.name,
+                status.path,
+                status.error.as_deref().unwrap_or("unknown error")
+            );
+        }
+        let _ = emit_to_window(&app, "main", "unwritable-paths", unwritable);
+    }
+
     let availability = recognition_availability_snapshot(&app).await;
     log_model_operation(
         "AVAILABILITY_CHECK",
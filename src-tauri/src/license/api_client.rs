@@ -31,7 +31,7 @@ pub struct LicenseApiClient {
 
 impl LicenseApiClient {
     pub fn new() -> Result<Self, String> {
-        let client = reqwest::Client::builder()
+        let client = crate::utils::proxy::client_builder()
             .timeout(API_TIMEOUT)
             .build()
             .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
@@ -251,7 +251,9 @@ impl Default for LicenseApiClient {
             Err(e) => {
                 log::error!("Failed to create default API client: {}", e);
                 // Create a client with minimal configuration as fallback
-                let client = reqwest::Client::new();
+                let client = crate::utils::proxy::client_builder()
+                    .build()
+                    .unwrap_or_else(|_| reqwest::Client::new());
                 Self { client }
             }
         }
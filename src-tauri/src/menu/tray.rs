@@ -208,7 +208,7 @@ pub async fn build_tray_menu<R: tauri::Runtime>(
 
     let mut recent_owned: Vec<tauri::menu::MenuItem<R>> = Vec::new();
     {
-        if let Ok(store) = app.store("transcriptions") {
+        if let Ok(store) = crate::utils::data_dir::transcriptions_store(app) {
             let mut entries: Vec<(String, serde_json::Value)> = Vec::new();
             for key in store.keys() {
                 if let Some(value) = store.get(&key) {
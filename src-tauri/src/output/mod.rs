@@ -0,0 +1,183 @@
+// Output sinks: where a finished transcription goes after post-processing.
+// Sinks run in order and each one reports its own result, so a failing
+// webhook never prevents the paste (or vice versa).
+
+pub mod webhook;
+
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::Path;
+use tauri::AppHandle;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OutputSink {
+    /// Paste into the focused application (the default behaviour)
+    ActiveAppPaste,
+    /// Leave the text on the clipboard without pasting
+    Clipboard,
+    /// Append the text to a file, one timestamped entry per line
+    File { path: String },
+    /// POST the text as JSON to a URL
+    WebhookPost { url: String },
+}
+
+impl OutputSink {
+    pub fn name(&self) -> &'static str {
+        match self {
+            OutputSink::ActiveAppPaste => "active_app_paste",
+            OutputSink::Clipboard => "clipboard",
+            OutputSink::File { .. } => "file",
+            OutputSink::WebhookPost { .. } => "webhook_post",
+        }
+    }
+}
+
+pub fn default_output_sinks() -> Vec<OutputSink> {
+    vec![OutputSink::ActiveAppPaste]
+}
+
+/// Outcome of delivering a transcription to one sink
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct SinkResult {
+    pub sink: String,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Append one timestamped line to a transcription log file
+pub fn append_to_file(path: &Path, text: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create output directory: {}", e))?;
+        }
+    }
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| format!("Failed to open output file: {}", e))?;
+    writeln!(file, "[{}] {}", chrono::Local::now().to_rfc3339(), text)
+        .map_err(|e| format!("Failed to write output file: {}", e))
+}
+
+/// POST to the sink's URL, reusing the webhook headers, timeout, retries and signing
+async fn post_webhook(app: &AppHandle, url: &str, text: &str) -> Result<(), String> {
+    let config = webhook::WebhookConfig {
+        url: url.to_string(),
+        ..webhook::WebhookConfig::load(app).unwrap_or_else(|| webhook::WebhookConfig {
+            timeout: std::time::Duration::from_secs(webhook::DEFAULT_TIMEOUT_SECS),
+            ..Default::default()
+        })
+    };
+    webhook::post_json(
+        &config,
+        &serde_json::json!({
+            "text": text,
+            "timestamp": chrono::Utc::now().to_rfc3339(),
+        }),
+    )
+    .await
+}
+
+async fn deliver(app: &AppHandle, sink: &OutputSink, text: &str) -> Result<(), String> {
+    match sink {
+        OutputSink::ActiveAppPaste => match crate::recording::live_typing::finish() {
+            // Most of the text is already in the app; only correct the tail
+            Some(typed) => {
+                let result =
+                    crate::recording::live_typing::apply_final(typed, text.to_string()).await;
+                if result.is_err() {
+                    let _ = crate::commands::text::copy_text_to_clipboard(text.to_string()).await;
+                }
+                result
+            }
+            None => crate::commands::text::insert_text(app.clone(), text.to_string()).await,
+        },
+        OutputSink::Clipboard => {
+            crate::commands::text::copy_text_to_clipboard(text.to_string()).await
+        }
+        OutputSink::File { path } => {
+            let path = std::path::PathBuf::from(path);
+            let text = text.to_string();
+            tokio::task::spawn_blocking(move || append_to_file(&path, &text))
+                .await
+                .map_err(|e| format!("Task failed: {}", e))?
+        }
+        OutputSink::WebhookPost { url } => post_webhook(app, url, text).await,
+    }
+}
+
+/// Deliver the text to every sink in order; a failure is recorded and the rest still run
+pub async fn run_output_sinks(
+    app: &AppHandle,
+    sinks: &[OutputSink],
+    text: &str,
+) -> Vec<SinkResult> {
+    let mut results = Vec::with_capacity(sinks.len());
+    for sink in sinks {
+        let result = deliver(app, sink, text).await;
+        if let Err(e) = &result {
+            log::warn!("Output sink '{}' failed: {}", sink.name(), e);
+        }
+        results.push(SinkResult {
+            sink: sink.name().to_string(),
+            success: result.is_ok(),
+            error: result.err(),
+        });
+    }
+    results
+}
+
+/// Configured sinks from settings, falling back to paste-only
+pub fn load_output_sinks(app: &AppHandle) -> Vec<OutputSink> {
+    use tauri_plugin_store::StoreExt;
+
+    app.store("settings")
+        .ok()
+        .and_then(|store| store.get("output_sinks"))
+        .and_then(|v| serde_json::from_value::<Vec<OutputSink>>(v).ok())
+        .unwrap_or_else(default_output_sinks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_sink_serialization() {
+        let sinks = vec![
+            OutputSink::ActiveAppPaste,
+            OutputSink::File {
+                path: "/tmp/log.txt".to_string(),
+            },
+        ];
+        let json = serde_json::to_value(&sinks).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                { "type": "active_app_paste" },
+                { "type": "file", "path": "/tmp/log.txt" }
+            ])
+        );
+        let parsed: Vec<OutputSink> = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, sinks);
+    }
+
+    #[test]
+    fn test_append_to_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested/transcripts.log");
+
+        append_to_file(&path, "first").unwrap();
+        append_to_file(&path, "second").unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].ends_with("] first"));
+        assert!(lines[1].ends_with("] second"));
+    }
+}
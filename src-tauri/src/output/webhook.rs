@@ -0,0 +1,209 @@
+use hmac::{Hmac, Mac};
+use serde::Serialize;
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::time::Duration;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+/// Header carrying the HMAC-SHA256 of `<timestamp>.<body>`, as `sha256=<hex>`
+pub const SIGNATURE_HEADER: &str = "X-VoiceTypr-Signature";
+/// Header carrying the Unix time the request was signed at. Receivers should
+/// reject stale timestamps so a captured request can't be replayed.
+pub const TIMESTAMP_HEADER: &str = "X-VoiceTypr-Timestamp";
+/// Secure-store key of the shared secret used to sign webhook requests
+pub const WEBHOOK_SECRET_KEY: &str = "webhook_hmac_secret";
+
+pub const DEFAULT_TIMEOUT_SECS: u64 = 10;
+const MAX_RETRIES: u32 = 5;
+const RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// JSON body sent for each completed transcription
+#[derive(Serialize, Debug, Clone)]
+pub struct WebhookPayload {
+    pub text: String,
+    pub timestamp: String,
+    /// Length of the recorded audio in seconds, when known
+    pub duration: Option<f64>,
+    pub model: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct WebhookConfig {
+    pub url: String,
+    pub headers: HashMap<String, String>,
+    pub timeout: Duration,
+    pub retries: u32,
+    pub secret: Option<String>,
+}
+
+impl WebhookConfig {
+    /// Read the webhook settings; `None` when no URL is configured
+    pub fn load(app: &AppHandle) -> Option<Self> {
+        let store = app.store("settings").ok()?;
+        let url = store
+            .get("webhook_url")
+            .and_then(|v| v.as_str().map(|s| s.trim().to_string()))
+            .filter(|url| !url.is_empty())?;
+
+        Some(Self {
+            url,
+            headers: store
+                .get("webhook_headers")
+                .and_then(|v| serde_json::from_value(v).ok())
+                .unwrap_or_default(),
+            timeout: Duration::from_secs(
+                store
+                    .get("webhook_timeout_secs")
+                    .and_then(|v| v.as_u64())
+                    .filter(|&secs| secs > 0)
+                    .unwrap_or(DEFAULT_TIMEOUT_SECS),
+            ),
+            retries: store
+                .get("webhook_retries")
+                .and_then(|v| v.as_u64())
+                .map(|v| (v as u32).min(MAX_RETRIES))
+                .unwrap_or(0),
+            secret: crate::secure_store::secure_get(app, WEBHOOK_SECRET_KEY)
+                .ok()
+                .flatten()
+                .filter(|s| !s.is_empty()),
+        })
+    }
+}
+
+/// Hex HMAC-SHA256 of `body` keyed with `secret`
+pub fn sign_payload(secret: &str, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Signature sent in `SIGNATURE_HEADER`: the body is bound to the timestamp
+/// header, so neither can be swapped without the secret
+pub fn sign_request(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut signed = format!("{}.", timestamp).into_bytes();
+    signed.extend_from_slice(body);
+    sign_payload(secret, &signed)
+}
+
+async fn post_once(
+    client: &reqwest::Client,
+    config: &WebhookConfig,
+    body: &[u8],
+) -> Result<(), String> {
+    let mut request = client
+        .post(&config.url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(body.to_vec());
+    for (name, value) in &config.headers {
+        request = request.header(name.as_str(), value.as_str());
+    }
+    if let Some(secret) = &config.secret {
+        let timestamp = chrono::Utc::now().timestamp();
+        request = request
+            .header(TIMESTAMP_HEADER, timestamp.to_string())
+            .header(
+                SIGNATURE_HEADER,
+                format!("sha256={}", sign_request(secret, timestamp, body)),
+            );
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("Webhook request failed: {}", e))?;
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(format!("Webhook returned status {}", response.status()))
+    }
+}
+
+/// POST a JSON body, retrying failed attempts up to `config.retries` times
+pub async fn post_json(config: &WebhookConfig, payload: &impl Serialize) -> Result<(), String> {
+    let body = serde_json::to_vec(payload)
+        .map_err(|e| format!("Failed to serialize webhook payload: {}", e))?;
+    let client = crate::utils::proxy::client_builder()
+        .timeout(config.timeout)
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let mut attempt = 0;
+    loop {
+        match post_once(&client, config, &body).await {
+            Ok(()) => return Ok(()),
+            Err(e) if attempt < config.retries => {
+                attempt += 1;
+                log::warn!("Webhook attempt {} failed: {}. Retrying...", attempt, e);
+                tokio::time::sleep(RETRY_DELAY).await;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Fire the configured webhook for a completed transcription in the background.
+/// Skipped when an output sink already posted to the same URL. Never blocks
+/// the caller; failures are only logged.
+pub fn spawn_transcription_webhook(app: &AppHandle, payload: WebhookPayload, posted: &[String]) {
+    let Some(config) = WebhookConfig::load(app) else {
+        return;
+    };
+    if posted.iter().any(|url| url.trim() == config.url) {
+        log::debug!("Transcription webhook already posted by an output sink");
+        return;
+    }
+
+    tauri::async_runtime::spawn(async move {
+        match post_json(&config, &payload).await {
+            Ok(()) => log::debug!("Transcription webhook delivered"),
+            Err(e) => log::warn!("Transcription webhook failed: {}", e),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_payload_matches_rfc4231() {
+        // RFC 4231 test case 2
+        assert_eq!(
+            sign_payload("Jefe", b"what do ya want for nothing?"),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_sign_request_binds_timestamp() {
+        let body = br#"{"text":"hi"}"#;
+        let signature = sign_request("secret", 1_700_000_000, body);
+        assert_eq!(
+            signature,
+            sign_payload("secret", br#"1700000000.{"text":"hi"}"#)
+        );
+        assert_ne!(signature, sign_request("secret", 1_700_000_001, body));
+    }
+
+    #[test]
+    fn test_payload_shape() {
+        let payload = WebhookPayload {
+            text: "hello".to_string(),
+            timestamp: "2024-01-01T00:00:00Z".to_string(),
+            duration: Some(1.5),
+            model: "base.en".to_string(),
+        };
+        assert_eq!(
+            serde_json::to_value(&payload).unwrap(),
+            serde_json::json!({
+                "text": "hello",
+                "timestamp": "2024-01-01T00:00:00Z",
+                "duration": 1.5,
+                "model": "base.en"
+            })
+        );
+    }
+}
@@ -40,7 +40,9 @@ impl ParakeetManager {
         Self {
             client: ParakeetClient::new("parakeet-sidecar"),
             root_dir,
-            http: Client::new(),
+            http: crate::utils::proxy::client_builder()
+                .build()
+                .unwrap_or_default(),
         }
     }
 
@@ -320,6 +322,19 @@ impl ParakeetManager {
         self.send_command(app, &command).await
     }
 
+    /// Ask the sidecar to release the loaded model; the next transcription reloads it
+    pub async fn unload_model(&self, app: &AppHandle) -> Result<(), ParakeetError> {
+        match self
+            .send_command(app, &ParakeetCommand::UnloadModel {})
+            .await?
+        {
+            ParakeetResponse::Error { code, message, .. } => {
+                Err(ParakeetError::SidecarError { code, message })
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Check if the Parakeet sidecar is healthy and can respond to commands
     #[allow(dead_code)]
     pub async fn health_check(&self, app: &AppHandle) -> Result<bool, ParakeetError> {
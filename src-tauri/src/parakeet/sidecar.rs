@@ -20,10 +20,15 @@ impl ParakeetSidecar {
     pub async fn spawn(app: &AppHandle, binary_name: &str) -> Result<Self, ParakeetError> {
         // In Tauri v2, use the shell plugin and pass just the filename.
         // The externalBin entry in tauri.conf.json must include this binary.
-        let (rx, child) = app
+        let mut command = app
             .shell()
             .sidecar(binary_name)
-            .map_err(|e| ParakeetError::SpawnError(e.to_string()))?
+            .map_err(|e| ParakeetError::SpawnError(e.to_string()))?;
+        // The sidecar downloads its own models, so hand it the configured proxy
+        for (key, value) in crate::utils::proxy::current().env_vars() {
+            command = command.env(key, value);
+        }
+        let (rx, child) = command
             .spawn()
             .map_err(|e| ParakeetError::SpawnError(e.to_string()))?;
 
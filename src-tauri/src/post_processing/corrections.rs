@@ -0,0 +1,114 @@
+/// Words shorter than this are never fuzzy-corrected; short words are too
+/// likely to be within a small edit distance of an unrelated term
+const MIN_FUZZY_WORD_LEN: usize = 4;
+
+/// Default edit distance allowed between a transcribed word and a dictionary term
+pub const DEFAULT_MAX_DISTANCE: usize = 2;
+
+/// Levenshtein distance between two strings, compared by character
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.is_empty() {
+        return b.len();
+    }
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == cb { 0 } else { 1 };
+            curr[j + 1] = (prev[j + 1] + 1).min(curr[j] + 1).min(prev[j] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+/// Find the dictionary term closest to `word`, if any is within `max_distance`
+fn closest_term<'a>(word: &str, dict: &'a [String], max_distance: usize) -> Option<&'a str> {
+    let lower = word.to_lowercase();
+    let word_len = lower.chars().count();
+
+    // Exact (case-insensitive) matches always normalize casing
+    if let Some(term) = dict.iter().find(|t| t.to_lowercase() == lower) {
+        return Some(term);
+    }
+    if word_len < MIN_FUZZY_WORD_LEN {
+        return None;
+    }
+
+    // Never allow more edits than a third of the word, so short words stay intact
+    let limit = max_distance.min(word_len / 3);
+    dict.iter()
+        .filter(|term| !term.contains(char::is_whitespace))
+        .map(|term| (term, edit_distance(&lower, &term.to_lowercase())))
+        .filter(|(_, distance)| *distance <= limit)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(term, _)| term.as_str())
+}
+
+/// Replace words that are "close enough" to a dictionary term with that term.
+/// Matching is token by token and case-insensitive; surrounding punctuation and
+/// whitespace are preserved.
+pub fn apply_fuzzy_corrections(text: &str, dict: &[String], max_distance: usize) -> String {
+    if dict.is_empty() || text.is_empty() {
+        return text.to_string();
+    }
+
+    let mut result = String::with_capacity(text.len());
+    for piece in text.split_inclusive(char::is_whitespace) {
+        let token = piece.trim_end_matches(char::is_whitespace);
+        let trailing_ws = &piece[token.len()..];
+
+        let core_start = token
+            .find(|c: char| c.is_alphanumeric())
+            .unwrap_or(token.len());
+        let core_end = token
+            .rfind(|c: char| c.is_alphanumeric())
+            .map(|i| i + token[i..].chars().next().map_or(1, |c| c.len_utf8()))
+            .unwrap_or(core_start);
+
+        let core = &token[core_start..core_end.max(core_start)];
+        result.push_str(&token[..core_start]);
+        match closest_term(core, dict, max_distance).filter(|_| !core.is_empty()) {
+            Some(term) => result.push_str(term),
+            None => result.push_str(core),
+        }
+        result.push_str(&token[core_end.max(core_start)..]);
+        result.push_str(trailing_ws);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dict() -> Vec<String> {
+        vec!["Kubernetes".to_string(), "PostgreSQL".to_string()]
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("same", "same"), 0);
+    }
+
+    #[test]
+    fn test_fuzzy_corrections_fix_close_matches() {
+        assert_eq!(
+            apply_fuzzy_corrections("deploy to kubernetis, then postgresql.", &dict(), 2),
+            "deploy to Kubernetes, then PostgreSQL."
+        );
+    }
+
+    #[test]
+    fn test_fuzzy_corrections_leave_unrelated_words() {
+        let text = "the cat sat on the mat  twice";
+        assert_eq!(apply_fuzzy_corrections(text, &dict(), 2), text);
+        assert_eq!(apply_fuzzy_corrections(text, &[], 2), text);
+    }
+}
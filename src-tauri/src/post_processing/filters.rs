@@ -0,0 +1,107 @@
+use super::{corrections, output_style, snippets};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// One step of a candidate post-processing pipeline, mirroring the steps the
+/// configured pipeline runs
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TextFilter {
+    Corrections {
+        dictionary: Vec<String>,
+        #[serde(default = "default_max_distance")]
+        max_distance: usize,
+    },
+    Snippets {
+        snippets: HashMap<String, String>,
+    },
+    /// Casing, using the `output_style` setting values
+    OutputStyle {
+        style: String,
+    },
+    /// Using the `trailing_punctuation` setting values
+    TrailingPunctuation {
+        mode: String,
+    },
+}
+
+fn default_max_distance() -> usize {
+    corrections::DEFAULT_MAX_DISTANCE
+}
+
+impl TextFilter {
+    pub fn apply(&self, text: &str) -> String {
+        match self {
+            TextFilter::Corrections {
+                dictionary,
+                max_distance,
+            } => corrections::apply_fuzzy_corrections(text, dictionary, *max_distance),
+            TextFilter::Snippets { snippets } => snippets::apply_snippets(text, snippets),
+            TextFilter::OutputStyle { style } => output_style::apply_output_style(
+                text,
+                output_style::OutputStyle::from_setting(style),
+            ),
+            TextFilter::TrailingPunctuation { mode } => output_style::apply_trailing_punctuation(
+                text,
+                output_style::TrailingPunctuation::from_setting(mode),
+            ),
+        }
+    }
+}
+
+/// Run `text` through each filter in order
+pub fn apply_filters(text: &str, filters: &[TextFilter]) -> String {
+    filters
+        .iter()
+        .fold(text.to_string(), |result, filter| filter.apply(&result))
+}
+
+/// A stored transcription before and after a candidate pipeline
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct FilterPreview {
+    /// History key (RFC 3339 timestamp) of the transcription
+    pub timestamp: String,
+    pub before: String,
+    pub after: String,
+    pub changed: bool,
+}
+
+impl FilterPreview {
+    pub fn new(timestamp: String, before: String, filters: &[TextFilter]) -> Self {
+        let after = apply_filters(&before, filters);
+        let changed = after != before;
+        Self {
+            timestamp,
+            before,
+            after,
+            changed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filters_apply_in_order() {
+        let filters: Vec<TextFilter> = serde_json::from_value(serde_json::json!([
+            { "type": "corrections", "dictionary": ["Kubernetes"] },
+            { "type": "snippets", "snippets": { "my sig": "Best, Sam" } },
+            { "type": "output_style", "style": "uppercase" },
+            { "type": "trailing_punctuation", "mode": "strip" }
+        ]))
+        .unwrap();
+
+        let preview = FilterPreview::new(
+            "2024-01-01T00:00:00Z".to_string(),
+            "deploy to kubernetis. my sig.".to_string(),
+            &filters,
+        );
+        assert_eq!(preview.after, "DEPLOY TO KUBERNETES. BEST, SAM");
+        assert!(preview.changed);
+
+        let unchanged = FilterPreview::new(String::new(), "hello".to_string(), &[]);
+        assert!(!unchanged.changed);
+    }
+}
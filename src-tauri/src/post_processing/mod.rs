@@ -0,0 +1,75 @@
+// Text post-processing applied to a transcription before AI enhancement and pasting
+pub mod corrections;
+pub mod filters;
+pub mod output_style;
+pub mod redaction;
+pub mod snippets;
+
+use std::collections::HashMap;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+/// Apply the user's configured post-processing steps to a raw transcription:
+/// fuzzy dictionary corrections first, then snippet expansion
+pub fn apply_post_processing(app: &AppHandle, text: &str) -> String {
+    let Ok(store) = app.store("settings") else {
+        return text.to_string();
+    };
+
+    let mut result = text.to_string();
+
+    let dictionary: Vec<String> = store
+        .get("correction_dictionary")
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default();
+    if !dictionary.is_empty() {
+        let max_distance = store
+            .get("fuzzy_correction_max_distance")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as usize)
+            .unwrap_or(corrections::DEFAULT_MAX_DISTANCE);
+
+        let corrected = corrections::apply_fuzzy_corrections(&result, &dictionary, max_distance);
+        if corrected != result {
+            log::debug!("Applied fuzzy corrections to transcription");
+        }
+        result = corrected;
+    }
+
+    let snippet_map: HashMap<String, String> = store
+        .get("snippets")
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default();
+    if !snippet_map.is_empty() {
+        let expanded = snippets::apply_snippets(&result, &snippet_map);
+        if expanded != result {
+            log::debug!("Expanded snippets in transcription");
+        }
+        result = expanded;
+    }
+
+    result
+}
+
+/// Apply the configured casing and trailing punctuation. Runs last, after AI
+/// enhancement, so the pasted text always matches the chosen style.
+pub fn apply_output_formatting(app: &AppHandle, text: &str) -> String {
+    let Ok(store) = app.store("settings") else {
+        return text.to_string();
+    };
+
+    let style = store
+        .get("output_style")
+        .and_then(|v| v.as_str().map(output_style::OutputStyle::from_setting))
+        .unwrap_or(output_style::OutputStyle::Verbatim);
+    let trailing = store
+        .get("trailing_punctuation")
+        .and_then(|v| {
+            v.as_str()
+                .map(output_style::TrailingPunctuation::from_setting)
+        })
+        .unwrap_or(output_style::TrailingPunctuation::Keep);
+
+    let styled = output_style::apply_output_style(text, style);
+    output_style::apply_trailing_punctuation(&styled, trailing)
+}
@@ -0,0 +1,177 @@
+/// How transcribed text is cased before it is pasted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputStyle {
+    /// Leave the text exactly as transcribed
+    Verbatim,
+    /// Capitalize the first letter of every sentence
+    Sentences,
+    Lowercase,
+    Uppercase,
+}
+
+impl OutputStyle {
+    /// Parse the stored setting value; unknown values fall back to verbatim
+    pub fn from_setting(value: &str) -> Self {
+        match value {
+            "sentences" => OutputStyle::Sentences,
+            "lowercase" => OutputStyle::Lowercase,
+            "uppercase" => OutputStyle::Uppercase,
+            _ => OutputStyle::Verbatim,
+        }
+    }
+}
+
+/// What to do with the punctuation at the very end of the text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrailingPunctuation {
+    Keep,
+    /// Remove a trailing period/comma (handy when dictating into code or chat)
+    Strip,
+    /// Make sure the text ends with sentence punctuation
+    Add,
+}
+
+impl TrailingPunctuation {
+    pub fn from_setting(value: &str) -> Self {
+        match value {
+            "strip" => TrailingPunctuation::Strip,
+            "add" => TrailingPunctuation::Add,
+            _ => TrailingPunctuation::Keep,
+        }
+    }
+}
+
+fn is_sentence_end(c: char) -> bool {
+    matches!(c, '.' | '!' | '?')
+}
+
+fn capitalize_sentences(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut at_sentence_start = true;
+    let mut after_terminator = false;
+
+    for c in text.chars() {
+        if at_sentence_start && c.is_alphabetic() {
+            result.extend(c.to_uppercase());
+            at_sentence_start = false;
+            after_terminator = false;
+            continue;
+        }
+
+        if is_sentence_end(c) {
+            after_terminator = true;
+        } else if c.is_whitespace() {
+            // "end. next" starts a new sentence, "3.5" doesn't
+            if after_terminator {
+                at_sentence_start = true;
+            }
+        } else if !at_sentence_start {
+            after_terminator = false;
+        }
+        result.push(c);
+    }
+    result
+}
+
+/// Apply the casing style to the whole text
+pub fn apply_output_style(text: &str, style: OutputStyle) -> String {
+    match style {
+        OutputStyle::Verbatim => text.to_string(),
+        OutputStyle::Sentences => capitalize_sentences(text),
+        OutputStyle::Lowercase => text.to_lowercase(),
+        OutputStyle::Uppercase => text.to_uppercase(),
+    }
+}
+
+/// Strip or add punctuation at the end of the text; trailing whitespace is preserved
+pub fn apply_trailing_punctuation(text: &str, mode: TrailingPunctuation) -> String {
+    let body = text.trim_end();
+    let trailing_ws = &text[body.len()..];
+    if body.is_empty() {
+        return text.to_string();
+    }
+
+    match mode {
+        TrailingPunctuation::Keep => text.to_string(),
+        TrailingPunctuation::Strip => {
+            let stripped = body.trim_end_matches(['.', ',', ';', ':']);
+            format!("{}{}", stripped, trailing_ws)
+        }
+        TrailingPunctuation::Add => {
+            let ends_with_punctuation = body
+                .chars()
+                .last()
+                .map(|c| is_sentence_end(c) || matches!(c, '"' | '\'' | ')' | '…'))
+                .unwrap_or(false);
+            if ends_with_punctuation {
+                text.to_string()
+            } else {
+                let body = body.trim_end_matches([',', ';', ':']);
+                format!("{}.{}", body, trailing_ws)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verbatim_is_unchanged() {
+        let text = "hello World. this is e.g. 3.5 Things";
+        assert_eq!(apply_output_style(text, OutputStyle::Verbatim), text);
+    }
+
+    #[test]
+    fn test_sentences_capitalizes_sentence_starts() {
+        assert_eq!(
+            apply_output_style(
+                "hello world. this costs 3.5 dollars! really? yes",
+                OutputStyle::Sentences
+            ),
+            "Hello world. This costs 3.5 dollars! Really? Yes"
+        );
+        assert_eq!(
+            apply_output_style("  \"quoted\" start", OutputStyle::Sentences),
+            "  \"Quoted\" start"
+        );
+    }
+
+    #[test]
+    fn test_lowercase_and_uppercase() {
+        assert_eq!(
+            apply_output_style("Hello World", OutputStyle::Lowercase),
+            "hello world"
+        );
+        assert_eq!(
+            apply_output_style("Hello World", OutputStyle::Uppercase),
+            "HELLO WORLD"
+        );
+    }
+
+    #[test]
+    fn test_trailing_punctuation() {
+        use TrailingPunctuation::*;
+        assert_eq!(apply_trailing_punctuation("done.", Keep), "done.");
+        assert_eq!(apply_trailing_punctuation("my_var.", Strip), "my_var");
+        assert_eq!(apply_trailing_punctuation("wait? ", Strip), "wait? ");
+        assert_eq!(apply_trailing_punctuation("done", Add), "done.");
+        assert_eq!(apply_trailing_punctuation("done,", Add), "done.");
+        assert_eq!(apply_trailing_punctuation("done!", Add), "done!");
+        assert_eq!(apply_trailing_punctuation("", Add), "");
+    }
+
+    #[test]
+    fn test_style_from_setting() {
+        assert_eq!(
+            OutputStyle::from_setting("lowercase"),
+            OutputStyle::Lowercase
+        );
+        assert_eq!(OutputStyle::from_setting("bogus"), OutputStyle::Verbatim);
+        assert_eq!(
+            TrailingPunctuation::from_setting("strip"),
+            TrailingPunctuation::Strip
+        );
+    }
+}
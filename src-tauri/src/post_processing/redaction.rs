@@ -0,0 +1,81 @@
+use regex::{NoExpand, Regex};
+use serde::Deserialize;
+
+/// Replacement used by custom rules that don't set one
+pub const DEFAULT_REPLACEMENT: &str = "[REDACTED]";
+
+/// A regex and what to replace its matches with
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct RedactionRule {
+    pub pattern: String,
+    #[serde(default)]
+    pub replacement: Option<String>,
+}
+
+impl RedactionRule {
+    fn builtin(pattern: &str, replacement: &str) -> Self {
+        Self {
+            pattern: pattern.to_string(),
+            replacement: Some(replacement.to_string()),
+        }
+    }
+}
+
+/// Card numbers, emails and phone numbers, in that order so a card number
+/// isn't half-eaten by the phone pattern
+pub fn default_rules() -> Vec<RedactionRule> {
+    vec![
+        RedactionRule::builtin(r"\b(?:\d[ -]?){12,18}\d\b", "[CARD]"),
+        RedactionRule::builtin(r"\b[\w.+-]+@[\w-]+(?:\.[\w-]+)+\b", "[EMAIL]"),
+        RedactionRule::builtin(
+            r"(?:\+\d{1,3}[\s.-]?)?(?:\(\d{3}\)\s?|\b\d{3}[\s.-]?)\d{3}[\s.-]?\d{4}\b",
+            "[PHONE]",
+        ),
+    ]
+}
+
+/// Apply each rule in order. An invalid pattern fails the whole redaction
+/// rather than silently leaving text unredacted.
+pub fn redact(text: &str, rules: &[RedactionRule]) -> Result<String, String> {
+    let mut result = text.to_string();
+    for rule in rules {
+        let re = Regex::new(&rule.pattern)
+            .map_err(|e| format!("Invalid redaction pattern '{}': {}", rule.pattern, e))?;
+        let replacement = rule.replacement.as_deref().unwrap_or(DEFAULT_REPLACEMENT);
+        result = re.replace_all(&result, NoExpand(replacement)).into_owned();
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_rules_redact_common_pii() {
+        let text = "Mail jane.doe+work@example.co.uk or call (555) 123-4567 / +1 555.987.6543. \
+                    Card 4111 1111 1111 1111, order 12345.";
+        assert_eq!(
+            redact(text, &default_rules()).unwrap(),
+            "Mail [EMAIL] or call [PHONE] / [PHONE]. Card [CARD], order 12345."
+        );
+    }
+
+    #[test]
+    fn test_custom_rules_and_invalid_patterns() {
+        let rules = vec![RedactionRule {
+            pattern: r"(?i)project \w+".to_string(),
+            replacement: None,
+        }];
+        assert_eq!(
+            redact("Ship Project Falcon Monday", &rules).unwrap(),
+            "Ship [REDACTED] Monday"
+        );
+
+        let invalid = vec![RedactionRule {
+            pattern: "(".to_string(),
+            replacement: None,
+        }];
+        assert!(redact("text", &invalid).is_err());
+    }
+}
@@ -0,0 +1,85 @@
+use regex::{NoExpand, RegexBuilder};
+use std::collections::HashMap;
+
+/// Build a case-insensitive pattern for a trigger phrase. Words may be separated
+/// by any whitespace, and punctuation the transcriber adds right after the
+/// phrase ("Insert signature.") is consumed along with it.
+fn trigger_pattern(trigger: &str) -> Option<String> {
+    let words: Vec<String> = trigger.split_whitespace().map(regex::escape).collect();
+    if words.is_empty() {
+        return None;
+    }
+    Some(format!(r"\b{}\b[.,!?]?", words.join(r"\s+")))
+}
+
+/// Replace every trigger phrase in `text` with its expansion.
+/// Longer triggers are applied first so "insert full signature" wins over
+/// "insert signature" when both are defined.
+pub fn apply_snippets(text: &str, snippets: &HashMap<String, String>) -> String {
+    if snippets.is_empty() || text.is_empty() {
+        return text.to_string();
+    }
+
+    let mut triggers: Vec<(&String, &String)> = snippets.iter().collect();
+    triggers.sort_by(|a, b| b.0.len().cmp(&a.0.len()).then_with(|| a.0.cmp(b.0)));
+
+    let mut result = text.to_string();
+    for (trigger, expansion) in triggers {
+        let Some(pattern) = trigger_pattern(trigger) else {
+            continue;
+        };
+        match RegexBuilder::new(&pattern).case_insensitive(true).build() {
+            Ok(re) => {
+                result = re.replace_all(&result, NoExpand(expansion)).into_owned();
+            }
+            Err(e) => log::warn!("Skipping snippet '{}': {}", trigger, e),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snippets() -> HashMap<String, String> {
+        HashMap::from([
+            (
+                "insert signature".to_string(),
+                "Best regards,\nJane".to_string(),
+            ),
+            (
+                "insert full signature".to_string(),
+                "Best regards,\nJane Doe\nACME Inc.".to_string(),
+            ),
+            ("my email".to_string(), "jane@example.com".to_string()),
+        ])
+    }
+
+    #[test]
+    fn test_apply_snippets_expands_triggers() {
+        assert_eq!(
+            apply_snippets("Thanks again. Insert signature.", &snippets()),
+            "Thanks again. Best regards,\nJane"
+        );
+        assert_eq!(
+            apply_snippets("Send it to my  email please", &snippets()),
+            "Send it to jane@example.com please"
+        );
+    }
+
+    #[test]
+    fn test_apply_snippets_prefers_longest_trigger() {
+        assert_eq!(
+            apply_snippets("insert full signature", &snippets()),
+            "Best regards,\nJane Doe\nACME Inc."
+        );
+    }
+
+    #[test]
+    fn test_apply_snippets_requires_word_boundaries() {
+        let text = "my emails are filtered";
+        assert_eq!(apply_snippets(text, &snippets()), text);
+        assert_eq!(apply_snippets(text, &HashMap::new()), text);
+    }
+}
@@ -3,3 +3,4 @@ mod model_selection;
 pub use model_selection::{
     auto_select_model_if_needed, recognition_availability_snapshot, RecognitionAvailabilitySnapshot,
 };
+pub(crate) use model_selection::{pick_best_parakeet_model, pick_best_whisper_model};
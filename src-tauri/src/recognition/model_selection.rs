@@ -69,7 +69,9 @@ pub async fn recognition_availability_snapshot(
     }
 }
 
-fn pick_best_parakeet_model(models: Vec<parakeet::ParakeetModelStatus>) -> Option<String> {
+pub(crate) fn pick_best_parakeet_model(
+    models: Vec<parakeet::ParakeetModelStatus>,
+) -> Option<String> {
     let mut downloaded: Vec<_> = models.into_iter().filter(|m| m.downloaded).collect();
     downloaded.sort_by(|a, b| {
         b.recommended
@@ -80,7 +82,7 @@ fn pick_best_parakeet_model(models: Vec<parakeet::ParakeetModelStatus>) -> Optio
     downloaded.first().map(|m| m.name.clone())
 }
 
-async fn pick_best_whisper_model(
+pub(crate) async fn pick_best_whisper_model(
     manager: &AsyncRwLock<whisper::manager::WhisperManager>,
 ) -> Option<String> {
     let manager = manager.read().await;
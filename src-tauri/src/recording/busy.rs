@@ -0,0 +1,220 @@
+use crate::{AppState, RecordingState};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::Ordering;
+
+/// What the recording hotkey does while the previous recording is still being
+/// stopped or transcribed (the `hotkey_while_busy` setting)
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum HotkeyWhileBusy {
+    #[default]
+    Ignore,
+    /// Start a new recording as soon as the transcription finishes
+    QueueNewRecording,
+    /// Abandon the transcription and start recording again
+    CancelAndRestart,
+}
+
+impl HotkeyWhileBusy {
+    pub fn from_setting(value: Option<&str>) -> Self {
+        value
+            .and_then(|v| serde_json::from_value(serde_json::json!(v)).ok())
+            .unwrap_or_default()
+    }
+}
+
+/// How a hotkey press while busy was handled, sent with `hotkey-while-busy`
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BusyAction {
+    Ignored,
+    /// A recording starts once the state returns to idle
+    Queued,
+    /// The transcription was cancelled; a recording starts once it has stopped
+    CancelledAndRestarting,
+}
+
+/// States in which a press can't start or stop a recording directly
+pub fn is_busy(state: RecordingState) -> bool {
+    matches!(
+        state,
+        RecordingState::Stopping | RecordingState::Transcribing
+    )
+}
+
+/// What a hotkey press in `state` does under `policy`; `None` when not busy
+pub fn busy_action(policy: HotkeyWhileBusy, state: RecordingState) -> Option<BusyAction> {
+    if !is_busy(state) {
+        return None;
+    }
+    Some(match policy {
+        HotkeyWhileBusy::Ignore => BusyAction::Ignored,
+        HotkeyWhileBusy::QueueNewRecording => BusyAction::Queued,
+        HotkeyWhileBusy::CancelAndRestart => BusyAction::CancelledAndRestarting,
+    })
+}
+
+/// Handle a hotkey press in `state` under `policy`: queue a new recording, or
+/// also cancel the running transcription. Returns what was done, `None` when
+/// not busy.
+pub fn apply_busy_press(
+    app_state: &AppState,
+    policy: HotkeyWhileBusy,
+    state: RecordingState,
+) -> Option<BusyAction> {
+    let action = busy_action(policy, state)?;
+    match action {
+        BusyAction::Ignored => {}
+        BusyAction::Queued => {
+            app_state
+                .start_after_transcription
+                .store(true, Ordering::SeqCst);
+        }
+        BusyAction::CancelledAndRestarting => {
+            // The cancelled flow returns to idle, which starts the new recording
+            app_state
+                .start_after_transcription
+                .store(true, Ordering::SeqCst);
+            app_state.request_cancellation();
+        }
+    }
+    Some(action)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state_machine::RecordingStateMachine;
+
+    /// A state machine that has stopped a recording and is transcribing it
+    fn transcribing() -> RecordingStateMachine {
+        let mut sm = RecordingStateMachine::new();
+        for state in [
+            RecordingState::Starting,
+            RecordingState::Recording,
+            RecordingState::Stopping,
+            RecordingState::Transcribing,
+        ] {
+            sm.transition_to(state).unwrap();
+        }
+        sm
+    }
+
+    #[test]
+    fn test_ignore_leaves_transcription_running() {
+        assert_eq!(HotkeyWhileBusy::from_setting(None), HotkeyWhileBusy::Ignore);
+        assert_eq!(
+            HotkeyWhileBusy::from_setting(Some("bogus")),
+            HotkeyWhileBusy::Ignore
+        );
+
+        let sm = transcribing();
+        assert_eq!(
+            busy_action(HotkeyWhileBusy::Ignore, sm.current()),
+            Some(BusyAction::Ignored)
+        );
+        assert_eq!(sm.current(), RecordingState::Transcribing);
+        // Presses outside the busy states are handled by the normal toggle flow
+        for state in [
+            RecordingState::Idle,
+            RecordingState::Starting,
+            RecordingState::Recording,
+            RecordingState::Error,
+        ] {
+            assert_eq!(busy_action(HotkeyWhileBusy::Ignore, state), None);
+        }
+    }
+
+    #[test]
+    fn test_queue_starts_after_transcription_finishes() {
+        let policy = HotkeyWhileBusy::from_setting(Some("queue_new_recording"));
+        assert_eq!(policy, HotkeyWhileBusy::QueueNewRecording);
+
+        let mut sm = transcribing();
+        assert_eq!(busy_action(policy, sm.current()), Some(BusyAction::Queued));
+        // The transcription completes, then the queued recording starts
+        assert!(sm.transition_to(RecordingState::Idle).is_ok());
+        assert!(sm.transition_to(RecordingState::Starting).is_ok());
+    }
+
+    #[test]
+    fn test_cancel_and_restart_from_stopping_and_transcribing() {
+        let policy = HotkeyWhileBusy::from_setting(Some("cancel_and_restart"));
+        assert_eq!(policy, HotkeyWhileBusy::CancelAndRestart);
+
+        let mut sm = transcribing();
+        assert_eq!(
+            busy_action(policy, sm.current()),
+            Some(BusyAction::CancelledAndRestarting)
+        );
+        // A cancelled transcription returns to idle before recording again
+        assert!(sm.transition_to(RecordingState::Starting).is_err());
+        assert!(sm.transition_to(RecordingState::Idle).is_ok());
+        assert!(sm.transition_to(RecordingState::Starting).is_ok());
+
+        let mut sm = RecordingStateMachine::new();
+        sm.transition_to(RecordingState::Starting).unwrap();
+        sm.transition_to(RecordingState::Recording).unwrap();
+        sm.transition_to(RecordingState::Stopping).unwrap();
+        assert_eq!(
+            busy_action(policy, sm.current()),
+            Some(BusyAction::CancelledAndRestarting)
+        );
+        assert!(sm.transition_to(RecordingState::Idle).is_ok());
+        assert!(sm.transition_to(RecordingState::Starting).is_ok());
+    }
+
+    #[test]
+    fn test_apply_busy_press_queues_and_cancels() {
+        let queued = |state: &AppState| state.start_after_transcription.load(Ordering::SeqCst);
+
+        let app_state = AppState::new();
+        assert_eq!(
+            apply_busy_press(
+                &app_state,
+                HotkeyWhileBusy::Ignore,
+                RecordingState::Transcribing
+            ),
+            Some(BusyAction::Ignored)
+        );
+        assert!(!queued(&app_state));
+        assert!(!app_state.is_cancellation_requested());
+
+        let app_state = AppState::new();
+        assert_eq!(
+            apply_busy_press(
+                &app_state,
+                HotkeyWhileBusy::QueueNewRecording,
+                RecordingState::Transcribing
+            ),
+            Some(BusyAction::Queued)
+        );
+        assert!(queued(&app_state));
+        assert!(!app_state.is_cancellation_requested());
+
+        let app_state = AppState::new();
+        assert_eq!(
+            apply_busy_press(
+                &app_state,
+                HotkeyWhileBusy::CancelAndRestart,
+                RecordingState::Stopping
+            ),
+            Some(BusyAction::CancelledAndRestarting)
+        );
+        assert!(queued(&app_state));
+        assert!(app_state.is_cancellation_requested());
+
+        // Not busy: nothing changes
+        let app_state = AppState::new();
+        assert_eq!(
+            apply_busy_press(
+                &app_state,
+                HotkeyWhileBusy::CancelAndRestart,
+                RecordingState::Recording
+            ),
+            None
+        );
+        assert!(!queued(&app_state));
+        assert!(!app_state.is_cancellation_requested());
+    }
+}
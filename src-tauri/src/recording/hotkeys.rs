@@ -1,9 +1,29 @@
 use crate::commands::audio::{start_recording, stop_recording, RecorderState};
+use crate::recording::busy::{apply_busy_press, BusyAction, HotkeyWhileBusy};
 use crate::recording::escape_handler::handle_escape_key_press;
 use crate::{get_recording_state, update_recording_state, AppState, RecordingMode, RecordingState};
 use std::sync::atomic::Ordering;
-use tauri::Manager;
+use std::time::{Duration, Instant};
+use tauri::{Emitter, Manager};
 use tauri_plugin_global_shortcut::{Shortcut, ShortcutState};
+use tauri_plugin_store::StoreExt;
+
+/// Cool-down used when `hotkey_cooldown_ms` isn't set
+pub const DEFAULT_HOTKEY_COOLDOWN_MS: u64 = 300;
+/// Longest allowed cool-down; anything longer would make the hotkey feel broken
+pub const MAX_HOTKEY_COOLDOWN_MS: u64 = 5000;
+
+/// Accept a press at `now` unless it's within `cooldown_ms` of the last
+/// accepted press. Accepted presses become the new `last_press`.
+pub fn accept_press(last_press: &mut Option<Instant>, now: Instant, cooldown_ms: u64) -> bool {
+    if let Some(last) = *last_press {
+        if now.saturating_duration_since(last) < Duration::from_millis(cooldown_ms) {
+            return false;
+        }
+    }
+    *last_press = Some(now);
+    true
+}
 
 /// Handle global shortcut events for recording
 ///
@@ -98,33 +118,7 @@ fn handle_toggle_mode(
     if event_state != ShortcutState::Pressed {
         return;
     }
-
-    let should_throttle = {
-        let now = std::time::Instant::now();
-        match app_state.last_toggle_press.lock() {
-            Ok(mut last_press) => {
-                if let Some(last) = *last_press {
-                    if now.duration_since(last).as_millis() < 300 {
-                        log::debug!("Toggle: Throttling hotkey press (too fast)");
-                        true
-                    } else {
-                        *last_press = Some(now);
-                        false
-                    }
-                } else {
-                    *last_press = Some(now);
-                    false
-                }
-            }
-            Err(e) => {
-                log::error!("Failed to lock last_toggle_press: {}", e);
-                false
-            }
-        }
-    };
-
-    if should_throttle {
-        crate::commands::audio::pill_toast(app, "Hold on...", 1000);
+    if !accept_hotkey_press(app, app_state, "Toggle") {
         return;
     }
 
@@ -160,10 +154,41 @@ fn handle_toggle_mode(
                 }
             });
         }
-        _ => log::debug!("Toggle: Ignoring hotkey in state {:?}", current_state),
+        RecordingState::Stopping | RecordingState::Transcribing => {
+            handle_busy_press(app, app_state, current_state);
+        }
     }
 }
 
+/// Apply the `hotkey_cooldown_ms` cool-down to a recording hotkey press.
+/// A rejected press shows a toast and returns false.
+fn accept_hotkey_press(app: &tauri::AppHandle, app_state: &AppState, mode: &str) -> bool {
+    let cooldown_ms = app
+        .store("settings")
+        .ok()
+        .and_then(|store| store.get("hotkey_cooldown_ms"))
+        .and_then(|v| v.as_u64())
+        .unwrap_or(DEFAULT_HOTKEY_COOLDOWN_MS)
+        .min(MAX_HOTKEY_COOLDOWN_MS);
+    let accepted = match app_state.last_hotkey_press.lock() {
+        Ok(mut last_press) => accept_press(&mut last_press, Instant::now(), cooldown_ms),
+        Err(e) => {
+            log::error!("Failed to lock last_hotkey_press: {}", e);
+            true
+        }
+    };
+
+    if !accepted {
+        log::debug!(
+            "{}: Ignoring hotkey press within the {} ms cool-down",
+            mode,
+            cooldown_ms
+        );
+        crate::commands::audio::pill_toast(app, "Hold on...", 1000);
+    }
+    accepted
+}
+
 /// Handle push-to-talk mode recording (hold to record, release to stop)
 fn handle_ptt_mode(
     app: &tauri::AppHandle,
@@ -173,10 +198,23 @@ fn handle_ptt_mode(
 ) {
     match event_state {
         ShortcutState::Pressed => {
+            // Key repeat while held isn't a new press. When idle, a held flag means
+            // a release was missed, so the press goes through.
+            if app_state.ptt_key_held.load(Ordering::SeqCst)
+                && !matches!(current_state, RecordingState::Idle | RecordingState::Error)
+            {
+                return;
+            }
+            // A rejected press leaves the key marked released, so its release is ignored too
+            if !accept_hotkey_press(app, app_state, "PTT") {
+                return;
+            }
             log::info!("PTT: Key pressed");
             app_state.ptt_key_held.store(true, Ordering::Relaxed);
 
-            if matches!(current_state, RecordingState::Idle | RecordingState::Error) {
+            if crate::recording::busy::is_busy(current_state) {
+                handle_busy_press(app, app_state, current_state);
+            } else if matches!(current_state, RecordingState::Idle | RecordingState::Error) {
                 log::info!("PTT: Starting recording");
                 let app_handle = app.clone();
                 tauri::async_runtime::spawn(async move {
@@ -219,6 +257,68 @@ fn handle_ptt_mode(
     }
 }
 
+/// Apply the `hotkey_while_busy` setting to a press while the previous
+/// recording is being stopped or transcribed
+fn handle_busy_press(app: &tauri::AppHandle, app_state: &AppState, current_state: RecordingState) {
+    let policy = HotkeyWhileBusy::from_setting(
+        app.store("settings")
+            .ok()
+            .and_then(|store| store.get("hotkey_while_busy"))
+            .and_then(|v| v.as_str().map(|s| s.to_string()))
+            .as_deref(),
+    );
+    let Some(action) = apply_busy_press(app_state, policy, current_state) else {
+        return;
+    };
+
+    match action {
+        BusyAction::Ignored => {
+            log::debug!("Ignoring hotkey in state {:?}", current_state);
+        }
+        BusyAction::Queued => {
+            log::info!("Hotkey while {:?}: queueing a new recording", current_state);
+            crate::commands::audio::pill_toast(app, "Next recording queued", 1000);
+        }
+        BusyAction::CancelledAndRestarting => {
+            log::info!(
+                "Hotkey while {:?}: cancelling to record again",
+                current_state
+            );
+        }
+    }
+
+    let _ = app.emit(
+        "hotkey-while-busy",
+        serde_json::json!({ "action": action, "state": current_state }),
+    );
+}
+
+/// Start the recording queued by `handle_busy_press` now that the previous
+/// one has finished. In push-to-talk mode the key must still be held.
+pub fn start_queued_recording(app: &tauri::AppHandle) {
+    let app_state = app.state::<AppState>();
+    let is_ptt = app_state
+        .recording_mode
+        .lock()
+        .map(|mode| *mode == RecordingMode::PushToTalk)
+        .unwrap_or(false);
+    if is_ptt && !app_state.ptt_key_held.load(Ordering::SeqCst) {
+        log::info!("PTT key released before the queued recording could start");
+        return;
+    }
+
+    log::info!("Starting queued recording");
+    let _ = app.emit("queued-recording-started", ());
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let recorder_state = app_handle.state::<RecorderState>();
+        if let Err(e) = start_recording(app_handle.clone(), recorder_state).await {
+            log::error!("Error starting queued recording: {}", e);
+            update_recording_state(&app_handle, RecordingState::Error, Some(e));
+        }
+    });
+}
+
 /// Handle non-recording shortcuts (e.g., ESC key)
 fn handle_non_recording_shortcut(
     app: &tauri::AppHandle,
@@ -252,3 +352,32 @@ fn handle_non_recording_shortcut(
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presses_within_cooldown_are_ignored() {
+        let mut last_press = None;
+        let first = Instant::now();
+        assert!(accept_press(&mut last_press, first, 300));
+        // A bounce 40 ms later is dropped and doesn't extend the window
+        assert!(!accept_press(
+            &mut last_press,
+            first + Duration::from_millis(40),
+            300
+        ));
+        assert_eq!(last_press, Some(first));
+        assert!(accept_press(
+            &mut last_press,
+            first + Duration::from_millis(300),
+            300
+        ));
+
+        // A zero cool-down accepts everything
+        let mut last_press = None;
+        assert!(accept_press(&mut last_press, first, 0));
+        assert!(accept_press(&mut last_press, first, 0));
+    }
+}
@@ -0,0 +1,155 @@
+// Live typing: partial transcription chunks are typed into the focused app as
+// soon as they're transcribed, instead of pasting everything at the end. The
+// final pass over the whole recording is usually more accurate (and may be AI
+// enhanced), so when it finishes, the typed text is compared with it and only
+// the differing tail is selected with Shift+Left and typed over.
+//
+// Tradeoffs, which is why it's opt-in:
+// - Typed text can't be taken back: cancelling the recording leaves it there.
+// - Text goes to whatever has focus, so switching windows mid-dictation types
+//   into the new one, and the final correction lands there too.
+// - The correction relies on Shift+Left selecting one character at a time;
+//   terminals and some editors don't support it. A large change (e.g. from AI
+//   enhancement) means selecting back a long way.
+// - Keystrokes bypass the clipboard, so it's slower than paste for long text.
+// - Toggle mode only. In push-to-talk the hotkey's modifiers are held while
+//   chunks arrive, so typed characters would become shortcuts (Ctrl+S, ...);
+//   push-to-talk recordings are output normally when they finish.
+
+use std::sync::Mutex;
+
+use crate::commands::text::{replace_typed_tail, split_type_units};
+
+/// Text typed during the current recording; `None` when live typing is off
+static SESSION: Mutex<Option<LiveTypingSession>> = Mutex::new(None);
+
+#[derive(Default)]
+struct LiveTypingSession {
+    typed: String,
+    /// Typing failed; later chunks are skipped and the final pass corrects
+    /// what was typed before the failure
+    failed: bool,
+}
+
+/// Start (or, with `enabled` false, turn off) live typing for a new recording
+pub fn begin(enabled: bool) {
+    if let Ok(mut session) = SESSION.lock() {
+        *session = enabled.then(LiveTypingSession::default);
+    }
+}
+
+/// Type a finalized chunk after what's already been typed. Blocking; does
+/// nothing once the session has finished.
+pub fn type_chunk(chunk: &str) {
+    let chunk = chunk.trim();
+    let Ok(mut guard) = SESSION.lock() else {
+        return;
+    };
+    let Some(session) = guard.as_mut() else {
+        return;
+    };
+    if chunk.is_empty() || session.failed {
+        return;
+    }
+
+    let text = if session.typed.is_empty() {
+        chunk.to_string()
+    } else {
+        format!(" {}", chunk)
+    };
+    match replace_typed_tail(0, &text) {
+        Ok(()) => session.typed.push_str(&text),
+        Err(e) => {
+            log::warn!("Live typing stopped: {}", e);
+            session.failed = true;
+        }
+    }
+}
+
+/// End the session, returning what was typed. `None` when live typing was off
+/// or nothing was typed, in which case the text is output normally.
+pub fn finish() -> Option<String> {
+    SESSION
+        .lock()
+        .ok()?
+        .take()
+        .map(|session| session.typed)
+        .filter(|typed| !typed.is_empty())
+}
+
+/// Edit that turns the typed text into the final text
+#[derive(Debug, PartialEq, Eq)]
+pub struct TailEdit {
+    /// Typed units to select and replace, counted from the end
+    pub delete: usize,
+    pub insert: String,
+}
+
+/// Keep the longest common prefix of `typed` and `target` and replace the
+/// rest. Units match what one Shift+Left selects, so emoji and accented
+/// letters aren't split.
+pub fn tail_edit(typed: &str, target: &str) -> TailEdit {
+    let typed_units = split_type_units(typed);
+    let target_units = split_type_units(target);
+    let common = typed_units
+        .iter()
+        .zip(&target_units)
+        .take_while(|(a, b)| a == b)
+        .count();
+    TailEdit {
+        delete: typed_units.len() - common,
+        insert: target_units[common..].concat(),
+    }
+}
+
+/// Correct the typed text to `final_text`
+pub async fn apply_final(typed: String, final_text: String) -> Result<(), String> {
+    let edit = tail_edit(&typed, final_text.trim());
+    if edit.delete == 0 && edit.insert.is_empty() {
+        return Ok(());
+    }
+    log::debug!(
+        "Live typing correction: replacing {} typed characters",
+        edit.delete
+    );
+    tokio::task::spawn_blocking(move || replace_typed_tail(edit.delete, &edit.insert))
+        .await
+        .map_err(|e| format!("Task failed: {}", e))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tail_edit_replaces_only_the_differing_tail() {
+        assert_eq!(
+            tail_edit("Send the report to fin", "Send the report to finance."),
+            TailEdit {
+                delete: 0,
+                insert: "ance.".to_string()
+            }
+        );
+        assert_eq!(
+            tail_edit("Meet at ten thirty tomorow", "Meet at 10:30 tomorrow."),
+            TailEdit {
+                delete: 18,
+                insert: "10:30 tomorrow.".to_string()
+            }
+        );
+        assert_eq!(
+            tail_edit("Hello 👋🏽 there", "Hello 👋🏽."),
+            TailEdit {
+                delete: 6,
+                insert: ".".to_string()
+            }
+        );
+        assert_eq!(
+            tail_edit("same", "same"),
+            TailEdit {
+                delete: 0,
+                insert: String::new()
+            }
+        );
+    }
+}
@@ -1,4 +1,7 @@
+pub mod busy;
 pub mod escape_handler;
 mod hotkeys;
+pub mod live_typing;
+pub mod partial;
 
-pub use hotkeys::handle_global_shortcut;
+pub use hotkeys::{handle_global_shortcut, start_queued_recording};
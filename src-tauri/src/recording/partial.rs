@@ -0,0 +1,188 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use tauri::async_runtime::Mutex as AsyncMutex;
+use tauri::{AppHandle, Manager};
+
+use crate::commands::audio::RecorderState;
+use crate::whisper::cache::TranscriberCache;
+use crate::{emit_to_all, get_recording_state, RecordingState};
+
+/// How often the capture buffer is polled for new audio
+const POLL_INTERVAL: Duration = Duration::from_millis(1000);
+
+/// Amount of new audio collected before an interim pass is run
+const CHUNK_SECONDS: usize = 5;
+
+/// Bumped for every recording that starts a partial loop. A loop stops as soon
+/// as it no longer holds the latest generation, so a loop still finishing a
+/// chunk from the previous recording can't run alongside the new one.
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Interim transcription emitted while recording is still in progress.
+/// Partials are never persisted or pasted; the final transcription replaces them.
+#[derive(Clone, serde::Serialize)]
+pub struct PartialTranscriptPayload {
+    /// Accumulated interim text for the current recording
+    pub text: String,
+    /// Number of chunks processed so far
+    pub chunk_index: u32,
+    /// Always false for partials
+    pub is_final: bool,
+}
+
+/// Start emitting `partial-transcript` events for the active Whisper recording.
+///
+/// Audio is transcribed in fixed-size chunks as it arrives and the accumulated
+/// text is emitted after each chunk (when `emit_events` is set) and handed to
+/// live typing. The loop ends as soon as the recording leaves the `Recording`
+/// state or a newer recording starts its own loop. Chunks are written to the
+/// temp dir so they never show up among the recordings.
+pub fn spawn_partial_transcription(
+    app: AppHandle,
+    model_path: PathBuf,
+    language: Option<String>,
+    translate: bool,
+    emit_events: bool,
+) {
+    let generation = GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let is_current = move || GENERATION.load(Ordering::SeqCst) == generation;
+
+    if let Ok(recorder) = app.state::<RecorderState>().0.lock() {
+        recorder.set_partial_capture(true);
+    }
+
+    tauri::async_runtime::spawn(async move {
+        log::info!("Partial transcription started");
+        let chunk_path = std::env::temp_dir().join(format!(
+            "voicetypr-partial-{}-{}.wav",
+            std::process::id(),
+            generation
+        ));
+        let mut chunk: Vec<i16> = Vec::new();
+        let mut accumulated = String::new();
+        let mut chunk_index: u32 = 0;
+
+        loop {
+            tokio::time::sleep(POLL_INTERVAL).await;
+
+            if !is_current() || !matches!(get_recording_state(&app), RecordingState::Recording) {
+                break;
+            }
+
+            let (samples, format) = match app.state::<RecorderState>().0.lock() {
+                Ok(recorder) => (recorder.take_pending_samples(), recorder.input_format()),
+                Err(_) => break,
+            };
+            let Some((sample_rate, channels)) = format else {
+                continue;
+            };
+
+            chunk.extend_from_slice(&samples);
+            if chunk.len() < CHUNK_SECONDS * sample_rate as usize * channels as usize {
+                continue;
+            }
+
+            if let Err(e) = write_chunk(&chunk_path, &chunk, sample_rate, channels) {
+                log::warn!("Failed to write partial audio chunk: {}", e);
+                chunk.clear();
+                continue;
+            }
+            chunk.clear();
+
+            let transcriber = {
+                let cache_state = app.state::<AsyncMutex<TranscriberCache>>();
+                let mut cache = cache_state.lock().await;
+                match cache.get_or_create(&model_path) {
+                    Ok(t) => t,
+                    Err(e) => {
+                        log::warn!("Partial transcription unavailable: {}", e);
+                        break;
+                    }
+                }
+            };
+
+            let app_for_cancel = app.clone();
+            let path_for_task = chunk_path.clone();
+            let language_for_task = language.clone();
+            let result = tokio::task::spawn_blocking(move || {
+                transcriber.transcribe_with_cancellation(
+                    &path_for_task,
+                    language_for_task.as_deref(),
+                    translate,
+                    move || {
+                        !is_current()
+                            || !matches!(
+                                get_recording_state(&app_for_cancel),
+                                RecordingState::Recording
+                            )
+                    },
+                )
+            })
+            .await;
+
+            if !is_current() {
+                break;
+            }
+
+            match result {
+                Ok(Ok(text)) if !text.trim().is_empty() && text.trim() != "[SOUND]" => {
+                    if !accumulated.is_empty() {
+                        accumulated.push(' ');
+                    }
+                    accumulated.push_str(text.trim());
+                    chunk_index += 1;
+
+                    if emit_events {
+                        let _ = emit_to_all(
+                            &app,
+                            "partial-transcript",
+                            PartialTranscriptPayload {
+                                text: accumulated.clone(),
+                                chunk_index,
+                                is_final: false,
+                            },
+                        );
+                    }
+                    // Chunks don't overlap, so each one is final once transcribed
+                    let _ = tokio::task::spawn_blocking(move || {
+                        crate::recording::live_typing::type_chunk(&text)
+                    })
+                    .await;
+                }
+                Ok(Ok(_)) => {}
+                Ok(Err(e)) => log::debug!("Partial chunk skipped: {}", e),
+                Err(e) => log::warn!("Partial transcription task failed: {}", e),
+            }
+        }
+
+        // A newer loop owns partial capture now; leave it on for that one
+        if is_current() {
+            if let Ok(recorder) = app.state::<RecorderState>().0.lock() {
+                recorder.set_partial_capture(false);
+            }
+        }
+        let _ = crate::utils::secure_delete::remove_file(&chunk_path);
+        log::info!("Partial transcription stopped after {} chunks", chunk_index);
+    });
+}
+
+fn write_chunk(
+    path: &Path,
+    samples: &[i16],
+    sample_rate: u32,
+    channels: u16,
+) -> Result<(), String> {
+    let spec = hound::WavSpec {
+        channels,
+        sample_rate,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut writer = hound::WavWriter::create(path, spec).map_err(|e| e.to_string())?;
+    for &sample in samples {
+        writer.write_sample(sample).map_err(|e| e.to_string())?;
+    }
+    writer.finalize().map_err(|e| e.to_string())
+}
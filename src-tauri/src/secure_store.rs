@@ -232,6 +232,177 @@ pub fn secure_has<R: Runtime>(app: &AppHandle<R>, key: &str) -> Result<bool, Str
     })
 }
 
+/// Separator between namespace and key in the secure store.
+/// Flat keys never contain it (keyring commands reject '/'), so namespaced
+/// entries cannot collide with existing ones.
+const NAMESPACE_SEPARATOR: char = '/';
+
+/// Build the store key for a namespaced entry, e.g. ("ai", "openai") -> "ai/openai"
+fn namespaced_key(namespace: &str, key: &str) -> Result<String, String> {
+    if namespace.is_empty() || key.is_empty() {
+        return Err("Namespace and key cannot be empty".to_string());
+    }
+    if namespace.contains(NAMESPACE_SEPARATOR) || key.contains(NAMESPACE_SEPARATOR) {
+        return Err(format!(
+            "Namespace and key cannot contain '{}'",
+            NAMESPACE_SEPARATOR
+        ));
+    }
+    Ok(format!("{}{}{}", namespace, NAMESPACE_SEPARATOR, key))
+}
+
+/// Extract the key part of a store key if it belongs to `namespace`
+fn key_in_namespace<'a>(store_key: &'a str, namespace: &str) -> Option<&'a str> {
+    store_key
+        .strip_prefix(namespace)?
+        .strip_prefix(NAMESPACE_SEPARATOR)
+        .filter(|key| !key.is_empty())
+}
+
+/// Set an encrypted value under a namespace (e.g. "ai" / "openai")
+pub fn secure_set_ns<R: Runtime>(
+    app: &AppHandle<R>,
+    namespace: &str,
+    key: &str,
+    value: &str,
+) -> Result<(), String> {
+    secure_set(app, &namespaced_key(namespace, key)?, value)
+}
+
+/// Get and decrypt a namespaced value
+pub fn secure_get_ns<R: Runtime>(
+    app: &AppHandle<R>,
+    namespace: &str,
+    key: &str,
+) -> Result<Option<String>, String> {
+    secure_get(app, &namespaced_key(namespace, key)?)
+}
+
+/// Delete a namespaced value
+pub fn secure_delete_ns<R: Runtime>(
+    app: &AppHandle<R>,
+    namespace: &str,
+    key: &str,
+) -> Result<(), String> {
+    secure_delete(app, &namespaced_key(namespace, key)?)
+}
+
+/// List the keys stored under a namespace (without the namespace prefix)
+pub fn secure_list<R: Runtime>(app: &AppHandle<R>, namespace: &str) -> Result<Vec<String>, String> {
+    let store = match app.store("secure.dat") {
+        Ok(store) => store,
+        Err(_) => return Ok(Vec::new()),
+    };
+
+    let mut keys: Vec<String> = store
+        .keys()
+        .iter()
+        .filter_map(|k| key_in_namespace(k, namespace).map(|s| s.to_string()))
+        .collect();
+    keys.sort();
+    Ok(keys)
+}
+
+#[derive(serde::Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SecureStoreStatus {
+    Healthy,
+    /// The file can't be parsed or some entries can't be decrypted
+    Corrupted,
+    Missing,
+}
+
+/// Result of `check_secure_store`
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+pub struct SecureStoreHealth {
+    pub status: SecureStoreStatus,
+    pub key_count: usize,
+    pub corrupted_keys: Vec<String>,
+}
+
+/// Check that `contents` of secure.dat parses and every entry decrypts
+pub fn assess_store_contents(contents: &str) -> SecureStoreHealth {
+    let entries = match serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(contents)
+    {
+        Ok(entries) => entries,
+        Err(e) => {
+            log::error!("Secure store is not valid JSON: {}", e);
+            return SecureStoreHealth {
+                status: SecureStoreStatus::Corrupted,
+                key_count: 0,
+                corrupted_keys: Vec::new(),
+            };
+        }
+    };
+
+    let mut corrupted_keys: Vec<String> = entries
+        .iter()
+        .filter(|(_, value)| !value.as_str().is_some_and(|v| decrypt_value(v).is_ok()))
+        .map(|(key, _)| key.clone())
+        .collect();
+    corrupted_keys.sort();
+
+    SecureStoreHealth {
+        status: if corrupted_keys.is_empty() {
+            SecureStoreStatus::Healthy
+        } else {
+            SecureStoreStatus::Corrupted
+        },
+        key_count: entries.len(),
+        corrupted_keys,
+    }
+}
+
+fn secure_store_path<R: Runtime>(app: &AppHandle<R>) -> Result<std::path::PathBuf, String> {
+    use tauri::Manager;
+
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join("secure.dat"))
+        .map_err(|e| format!("Failed to resolve app data directory: {}", e))
+}
+
+/// Read secure.dat from disk and try to decrypt every entry
+pub fn check_health<R: Runtime>(app: &AppHandle<R>) -> Result<SecureStoreHealth, String> {
+    let path = secure_store_path(app)?;
+    if !path.exists() {
+        return Ok(SecureStoreHealth {
+            status: SecureStoreStatus::Missing,
+            key_count: 0,
+            corrupted_keys: Vec::new(),
+        });
+    }
+    let contents =
+        std::fs::read(&path).map_err(|e| format!("Failed to read secure store: {}", e))?;
+    Ok(assess_store_contents(&String::from_utf8_lossy(&contents)))
+}
+
+/// Move the current secure.dat aside and start an empty store. Returns the
+/// backup path, if there was a file to keep.
+pub fn reset<R: Runtime>(app: &AppHandle<R>) -> Result<Option<std::path::PathBuf>, String> {
+    let path = secure_store_path(app)?;
+    let backup = if path.exists() {
+        let backup = path.with_extension(format!(
+            "dat.corrupt-{}",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ));
+        std::fs::copy(&path, &backup)
+            .map_err(|e| format!("Failed to back up secure store: {}", e))?;
+        Some(backup)
+    } else {
+        None
+    };
+
+    let store = app
+        .store("secure.dat")
+        .map_err(|e| format!("Failed to access store: {}", e))?;
+    store.clear();
+    store
+        .save()
+        .map_err(|e| format!("Failed to save store: {}", e))?;
+    Ok(backup)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -277,4 +448,39 @@ mod tests {
         let result = decrypt_value("dGVzdA=="); // Just "test" in base64
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_namespaced_keys() {
+        assert_eq!(namespaced_key("ai", "openai").unwrap(), "ai/openai");
+        assert!(namespaced_key("", "openai").is_err());
+        assert!(namespaced_key("ai", "").is_err());
+        assert!(namespaced_key("ai/x", "openai").is_err());
+
+        assert_eq!(key_in_namespace("ai/openai", "ai"), Some("openai"));
+        assert_eq!(key_in_namespace("aix/openai", "ai"), None);
+        assert_eq!(key_in_namespace("license", "ai"), None);
+        assert_eq!(key_in_namespace("ai/", "ai"), None);
+    }
+
+    #[test]
+    fn test_assess_store_contents() {
+        initialize_encryption_key().unwrap();
+
+        let good = encrypt_value("sk-test").unwrap();
+        let healthy =
+            assess_store_contents(&serde_json::json!({ "ai_api_key_openai": good }).to_string());
+        assert_eq!(healthy.status, SecureStoreStatus::Healthy);
+        assert_eq!(healthy.key_count, 1);
+
+        let damaged = assess_store_contents(
+            &serde_json::json!({ "license": good, "ai_api_key_gemini": "dGVzdA==", "n": 1 })
+                .to_string(),
+        );
+        assert_eq!(damaged.status, SecureStoreStatus::Corrupted);
+        assert_eq!(damaged.key_count, 3);
+        assert_eq!(damaged.corrupted_keys, vec!["ai_api_key_gemini", "n"]);
+
+        let unreadable = assess_store_contents("{\"license\": ");
+        assert_eq!(unreadable.status, SecureStoreStatus::Corrupted);
+    }
 }
@@ -45,6 +45,8 @@ pub struct AppState {
     pub ptt_shortcut: Arc<Mutex<Option<tauri_plugin_global_shortcut::Shortcut>>>,
     pub should_cancel_recording: Arc<AtomicBool>,
     pub pending_stop_after_start: Arc<AtomicBool>,
+    /// Set by a hotkey press while busy; the next recording starts once idle
+    pub start_after_transcription: Arc<AtomicBool>,
     pub esc_pressed_once: Arc<AtomicBool>,
     pub esc_timeout_handle: Arc<Mutex<Option<tauri::async_runtime::JoinHandle<()>>>>,
     pub window_manager: Arc<Mutex<Option<WindowManager>>>,
@@ -52,7 +54,12 @@ pub struct AppState {
         Arc<tokio::sync::RwLock<Option<crate::commands::audio::RecordingConfig>>>,
     pub license_cache: Arc<tokio::sync::RwLock<Option<crate::commands::license::CachedLicense>>>,
     pub pill_event_queue: Arc<Mutex<Vec<QueuedPillEvent>>>,
-    pub last_toggle_press: Arc<Mutex<Option<Instant>>>,
+    /// Last accepted recording hotkey press, for the cool-down (both modes)
+    pub last_hotkey_press: Arc<Mutex<Option<Instant>>>,
+    /// When the current recording began; cleared once back to idle
+    pub recording_started_at: Arc<Mutex<Option<Instant>>>,
+    /// Frontmost app when the current recording began, saved with its history entry
+    pub recording_app: Arc<Mutex<Option<crate::utils::active_app::ActiveApp>>>,
 }
 
 impl Default for AppState {
@@ -73,13 +80,16 @@ impl AppState {
             ptt_shortcut: Arc::new(Mutex::new(None)),
             should_cancel_recording: Arc::new(AtomicBool::new(false)),
             pending_stop_after_start: Arc::new(AtomicBool::new(false)),
+            start_after_transcription: Arc::new(AtomicBool::new(false)),
             esc_pressed_once: Arc::new(AtomicBool::new(false)),
             esc_timeout_handle: Arc::new(Mutex::new(None)),
             window_manager: Arc::new(Mutex::new(None)),
             recording_config_cache: Arc::new(tokio::sync::RwLock::new(None)),
             license_cache: Arc::new(tokio::sync::RwLock::new(None)),
             pill_event_queue: Arc::new(Mutex::new(Vec::new())),
-            last_toggle_press: Arc::new(Mutex::new(None)),
+            last_hotkey_press: Arc::new(Mutex::new(None)),
+            recording_started_at: Arc::new(Mutex::new(None)),
+            recording_app: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -227,6 +237,16 @@ pub fn update_recording_state(
             }
         };
 
+    if let Ok(mut started_at) = app_state.recording_started_at.lock() {
+        match final_state {
+            RecordingState::Recording => {
+                started_at.get_or_insert_with(Instant::now);
+            }
+            RecordingState::Idle | RecordingState::Error => *started_at = None,
+            _ => {}
+        }
+    }
+
     let payload = serde_json::json!({
         "state": match final_state {
             RecordingState::Idle => "idle",
@@ -244,6 +264,27 @@ pub fn update_recording_state(
     if let Some(pill_window) = app.get_webview_window("pill") {
         let _ = pill_window.emit("recording-state-changed", payload);
     }
+
+    // A hotkey press while busy (hotkey_while_busy) may have queued a recording
+    match final_state {
+        RecordingState::Idle => {
+            if app_state
+                .start_after_transcription
+                .swap(false, Ordering::SeqCst)
+            {
+                crate::recording::start_queued_recording(app);
+            }
+        }
+        RecordingState::Error => {
+            if app_state
+                .start_after_transcription
+                .swap(false, Ordering::SeqCst)
+            {
+                log::info!("Dropping queued recording after an error");
+            }
+        }
+        _ => {}
+    }
 }
 
 /// Helper function to get current recording state
@@ -58,6 +58,16 @@ impl UnifiedRecordingState {
         }
     }
 
+    /// Current state without blocking, for the panic hook, where the lock may
+    /// be held by the panicking thread
+    pub fn try_current(&self) -> Option<RecordingState> {
+        match self.inner.try_lock() {
+            Ok(guard) => Some(guard.current),
+            Err(std::sync::TryLockError::Poisoned(poisoned)) => Some(poisoned.into_inner().current),
+            Err(std::sync::TryLockError::WouldBlock) => None,
+        }
+    }
+
     /// Reset to initial state
     pub fn reset(&self) -> Result<(), String> {
         let mut guard = self.lock_or_recover()?;
@@ -283,4 +283,69 @@ mod tests {
             assert!(task_guard.is_none());
         }
     }
+
+    #[test]
+    fn test_recording_limit_triggers_at_max_length() {
+        use crate::audio::recorder::RecordingLimit;
+
+        // 2 second limit at 16kHz, fed in 100ms buffers
+        let mut limit = RecordingLimit::new(Some(2), 16_000);
+        let mut buffers_until_stop = 0;
+        for i in 1..=100 {
+            if limit.advance(1_600) {
+                buffers_until_stop = i;
+                break;
+            }
+        }
+        assert_eq!(buffers_until_stop, 20);
+    }
+
+    #[test]
+    fn test_recording_limit_zero_or_unset_is_unlimited() {
+        use crate::audio::recorder::RecordingLimit;
+
+        let mut unset = RecordingLimit::new(None, 48_000);
+        let mut zero = RecordingLimit::new(Some(0), 48_000);
+        for _ in 0..1_000 {
+            assert!(!unset.advance(48_000));
+            assert!(!zero.advance(48_000));
+        }
+    }
+
+    #[test]
+    fn test_resolve_input_device_falls_back_to_default() {
+        use crate::commands::audio::{resolve_input_device, ReadinessIssueKind, ReadinessReport};
+
+        let available = vec!["Built-in Mic".to_string(), "USB Mic".to_string()];
+
+        let (device, issue) =
+            resolve_input_device(Some("USB Mic"), &available, Some("Built-in Mic".into()));
+        assert_eq!(device.as_deref(), Some("USB Mic"));
+        assert!(issue.is_none());
+
+        // Unplugged selection: recording still works on the default device
+        let (device, issue) =
+            resolve_input_device(Some("AirPods"), &available, Some("Built-in Mic".into()));
+        assert_eq!(device.as_deref(), Some("Built-in Mic"));
+        let issue = issue.unwrap();
+        assert_eq!(issue.kind, ReadinessIssueKind::SelectedDeviceMissing);
+        assert!(!issue.blocking);
+
+        // Nothing to fall back to
+        let (device, issue) = resolve_input_device(Some("AirPods"), &[], None);
+        assert!(device.is_none());
+        let report = ReadinessReport::new(device, None, issue.into_iter().collect());
+        assert!(!report.ready);
+        assert_eq!(report.issues[0].kind, ReadinessIssueKind::NoInputDevice);
+    }
+
+    #[test]
+    fn test_pause_resume_require_active_recording() {
+        use crate::audio::recorder::AudioRecorder;
+
+        let recorder = AudioRecorder::new();
+        assert!(recorder.pause().is_err());
+        assert!(recorder.resume().is_err());
+        assert!(!recorder.is_paused());
+    }
 }
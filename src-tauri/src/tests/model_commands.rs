@@ -180,6 +180,63 @@ mod tests {
         assert!(path.is_none());
     }
 
+    #[test]
+    fn test_quantization_variants() {
+        let temp_dir = TempDir::new().unwrap();
+        let models_dir = temp_dir.path().join("models");
+        std::fs::create_dir_all(&models_dir).unwrap();
+        std::fs::write(models_dir.join("base.en.bin"), vec![0u8; 1024]).unwrap();
+        std::fs::write(models_dir.join("base.en-q5_0.bin"), vec![0u8; 512]).unwrap();
+
+        let mut manager = WhisperManager::new_for_test(models_dir.clone());
+        assert_eq!(
+            manager.installed_quantizations("base.en"),
+            vec!["f16".to_string(), "q5_0".to_string()]
+        );
+        assert_eq!(
+            manager.get_model_path("base.en").unwrap(),
+            models_dir.join("base.en.bin")
+        );
+
+        manager.set_active_quantization("base.en", "q5_0").unwrap();
+        let path = manager.get_model_path("base.en").unwrap();
+        assert_eq!(path, models_dir.join("base.en-q5_0.bin"));
+        assert_eq!(crate::whisper::manager::quantization_of(&path), "q5_0");
+
+        assert!(manager.set_active_quantization("base.en", "q8_0").is_err());
+
+        // Falls back to the default file once the chosen variant is removed
+        std::fs::remove_file(models_dir.join("base.en-q5_0.bin")).unwrap();
+        assert_eq!(
+            manager.get_model_path("base.en").unwrap(),
+            models_dir.join("base.en.bin")
+        );
+    }
+
+    #[test]
+    fn test_model_for_loaded_path() {
+        let temp_dir = TempDir::new().unwrap();
+        let models_dir = temp_dir.path().join("models");
+        let manager = WhisperManager::new_for_test(models_dir.clone());
+
+        assert_eq!(
+            manager.model_for_path(&models_dir.join("base.en.bin")),
+            Some(("base.en".to_string(), "f16".to_string()))
+        );
+        assert_eq!(
+            manager.model_for_path(&models_dir.join("base.en-q5_0.bin")),
+            Some(("base.en".to_string(), "q5_0".to_string()))
+        );
+        assert_eq!(
+            manager.model_for_path(&temp_dir.path().join("base.en.bin")),
+            None
+        );
+        assert_eq!(
+            crate::commands::model::estimated_model_memory(200 * 1024 * 1024),
+            300 * 1024 * 1024
+        );
+    }
+
     #[test]
     fn test_delete_model_file() {
         let temp_dir = TempDir::new().unwrap();
@@ -247,6 +304,34 @@ mod tests {
         assert!(!status.get("large-v3").unwrap().downloaded);
     }
 
+    #[test]
+    fn test_updated_models_replace_size_and_checksum() {
+        let temp_dir = TempDir::new().unwrap();
+        let models_dir = temp_dir.path().join("models");
+        std::fs::create_dir_all(&models_dir).unwrap();
+        let mut manager = WhisperManager::new_for_test(models_dir.clone());
+
+        // An updated file much larger than the built-in size is still found,
+        // and a truncated one is not
+        let updated = std::collections::HashMap::from([(
+            "base.en".to_string(),
+            crate::whisper::updates::InstalledModel {
+                sha256: "updated_hash".to_string(),
+                size: 4096,
+            },
+        )]);
+        std::fs::write(models_dir.join("base.en.bin"), vec![0u8; 1024]).unwrap();
+        manager.set_updated_models(&updated);
+        let info = manager.get_models_status().remove("base.en").unwrap();
+        assert_eq!(info.sha256, "updated_hash");
+        assert_eq!(info.size, 4096);
+        assert!(!info.downloaded);
+
+        std::fs::write(models_dir.join("base.en.bin"), vec![0u8; 4096]).unwrap();
+        manager.refresh_downloaded_status();
+        assert!(manager.get_models_status()["base.en"].downloaded);
+    }
+
     #[test]
     fn test_model_scores() {
         let temp_dir = TempDir::new().unwrap();
@@ -303,4 +388,34 @@ mod tests {
             assert_eq!(model.sha256.len(), 40);
         }
     }
+
+    #[test]
+    fn test_pick_model_for_memory() {
+        use crate::commands::model::pick_model_for_memory;
+
+        const MB: u64 = 1024 * 1024;
+        let models = vec![
+            ("base.en".to_string(), 142 * MB),
+            ("small.en".to_string(), 466 * MB),
+            ("large-v3-turbo".to_string(), 1536 * MB),
+            ("large-v3".to_string(), 2970 * MB),
+        ];
+
+        // Plenty of memory picks the largest model
+        assert_eq!(
+            pick_model_for_memory(&models, 16 * 1024 * MB).as_deref(),
+            Some("large-v3")
+        );
+        // 4 GB available leaves room for large-v3-turbo but not large-v3
+        assert_eq!(
+            pick_model_for_memory(&models, 4 * 1024 * MB).as_deref(),
+            Some("large-v3-turbo")
+        );
+        // Nothing fits: fall back to the smallest model
+        assert_eq!(
+            pick_model_for_memory(&models, 512 * MB).as_deref(),
+            Some("base.en")
+        );
+        assert_eq!(pick_model_for_memory(&[], 16 * 1024 * MB), None);
+    }
 }
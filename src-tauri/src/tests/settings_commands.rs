@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod tests {
-    use crate::commands::settings::{get_supported_languages, Settings};
+    use crate::commands::settings::{languages_for_backend, Settings};
     use serde_json::json;
+    use std::collections::HashMap;
 
     #[test]
     fn test_settings_default() {
@@ -42,6 +43,63 @@ mod tests {
             pill_indicator_position: "bottom-center".to_string(),
             pill_indicator_offset: 10,
             pause_media_during_recording: true,
+            paste_delay_ms: 0,
+            typing_char_delay_ms: 0,
+            partial_transcripts_enabled: false,
+            max_recording_seconds: 0,
+            notify_on_complete: false,
+            quiet_hours_start: None,
+            quiet_hours_end: None,
+            telemetry_enabled: false,
+            preroll_ms: 300,
+            noise_suppression: true,
+            noise_suppression_strength: 0.7,
+            snippets: HashMap::new(),
+            max_memory_mb: 4096,
+            output_style: "sentences".to_string(),
+            trailing_punctuation: "strip".to_string(),
+            output_sinks: crate::output::default_output_sinks(),
+            webhook_url: Some("https://example.com/hook".to_string()),
+            webhook_headers: HashMap::new(),
+            webhook_timeout_secs: 10,
+            webhook_retries: 2,
+            data_dir_override: None,
+            log_retention_days: 30,
+            recording_retention_days: 7,
+            cleanup_interval_hours: 24,
+            min_confidence: 0.0,
+            keep_recordings: true,
+            recording_gain_db: 0.0,
+            normalize_peak: false,
+            normalize_target_peak: 0.9,
+            overlay_monitor: "primary".to_string(),
+            log_format: "text".to_string(),
+            chunk_seconds: 300,
+            overlap_seconds: 5,
+            secure_delete_recordings: false,
+            watch_settings_file: false,
+            trim_silence: false,
+            incognito_mode: false,
+            output_mode: "type_animated".to_string(),
+            typing_speed_cps: 40,
+            output_mode_by_app: HashMap::new(),
+            overlay_during_transcription: false,
+            overlay_timeout_ms: 1500,
+            diarization: false,
+            on_empty_audio: "skip".to_string(),
+            http_proxy: Some("http://proxy.example.com:8080".to_string()),
+            https_proxy: Some("http://proxy.example.com:8080".to_string()),
+            no_proxy: Some("localhost,127.0.0.1".to_string()),
+            hotkey_while_busy: "queue_new_recording".to_string(),
+            live_typing: false,
+            auto_check_model_updates: false,
+            play_sound_cues: true,
+            sound_cue_volume: 0.8,
+            sound_cue_start_file: None,
+            sound_cue_stop_file: None,
+            sound_cue_complete_file: None,
+            sound_cues_respect_dnd: true,
+            hotkey_cooldown_ms: 500,
         };
 
         // Test serialization
@@ -105,6 +163,63 @@ mod tests {
             pill_indicator_position: "top-center".to_string(),
             pill_indicator_offset: 25,
             pause_media_during_recording: true,
+            paste_delay_ms: 0,
+            typing_char_delay_ms: 0,
+            partial_transcripts_enabled: false,
+            max_recording_seconds: 0,
+            notify_on_complete: false,
+            quiet_hours_start: None,
+            quiet_hours_end: None,
+            telemetry_enabled: false,
+            preroll_ms: 300,
+            noise_suppression: true,
+            noise_suppression_strength: 0.7,
+            snippets: HashMap::new(),
+            max_memory_mb: 4096,
+            output_style: "sentences".to_string(),
+            trailing_punctuation: "strip".to_string(),
+            output_sinks: crate::output::default_output_sinks(),
+            webhook_url: Some("https://example.com/hook".to_string()),
+            webhook_headers: HashMap::new(),
+            webhook_timeout_secs: 10,
+            webhook_retries: 2,
+            data_dir_override: None,
+            log_retention_days: 30,
+            recording_retention_days: 7,
+            cleanup_interval_hours: 24,
+            min_confidence: 0.0,
+            keep_recordings: true,
+            recording_gain_db: 0.0,
+            normalize_peak: false,
+            normalize_target_peak: 0.9,
+            overlay_monitor: "primary".to_string(),
+            log_format: "text".to_string(),
+            chunk_seconds: 300,
+            overlap_seconds: 5,
+            secure_delete_recordings: false,
+            watch_settings_file: false,
+            trim_silence: false,
+            incognito_mode: false,
+            output_mode: "type_animated".to_string(),
+            typing_speed_cps: 40,
+            output_mode_by_app: HashMap::new(),
+            overlay_during_transcription: false,
+            overlay_timeout_ms: 1500,
+            diarization: false,
+            on_empty_audio: "skip".to_string(),
+            http_proxy: Some("http://proxy.example.com:8080".to_string()),
+            https_proxy: Some("http://proxy.example.com:8080".to_string()),
+            no_proxy: Some("localhost,127.0.0.1".to_string()),
+            hotkey_while_busy: "queue_new_recording".to_string(),
+            live_typing: false,
+            auto_check_model_updates: false,
+            play_sound_cues: true,
+            sound_cue_volume: 0.8,
+            sound_cue_start_file: None,
+            sound_cue_stop_file: None,
+            sound_cue_complete_file: None,
+            sound_cues_respect_dnd: true,
+            hotkey_cooldown_ms: 500,
         };
 
         let cloned = settings.clone();
@@ -222,9 +337,9 @@ mod tests {
         assert!(normal_hotkey.len() <= 100);
     }
 
-    #[tokio::test]
-    async fn test_get_supported_languages() {
-        let languages = get_supported_languages().await.unwrap();
+    #[test]
+    fn test_get_supported_languages() {
+        let languages = languages_for_backend("whisper", "large-v3");
 
         // Should have multiple languages
         assert!(languages.len() > 50);
@@ -249,4 +364,67 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_supported_languages_follow_backend() {
+        let english_only = languages_for_backend("whisper", "base.en");
+        assert_eq!(english_only.len(), 1);
+        assert_eq!(english_only[0].code, "en");
+
+        let parakeet: Vec<String> = languages_for_backend("parakeet", "parakeet-tdt-0.6b-v3")
+            .into_iter()
+            .map(|l| l.code)
+            .collect();
+        assert_eq!(parakeet.len(), 25);
+        assert!(parakeet.contains(&"de".to_string()));
+        assert!(!parakeet.contains(&"zh".to_string()));
+
+        let soniox = languages_for_backend("soniox", "soniox");
+        assert!(soniox.iter().any(|l| l.code == "ja"));
+        assert!(soniox.len() < languages_for_backend("whisper", "large-v3").len());
+    }
+
+    #[test]
+    fn test_diff_settings_json_reports_changes_without_secrets() {
+        use crate::commands::settings::diff_settings_json;
+
+        let before = json!({
+            "theme": "light",
+            "language": "en",
+            "ai_api_key_openai": "old-secret"
+        });
+        let after = json!({
+            "theme": "dark",
+            "language": "en",
+            "hotkey": "Alt+Space",
+            "ai_api_key_openai": "new-secret"
+        });
+
+        let changes = diff_settings_json(before.as_object().unwrap(), after.as_object().unwrap());
+
+        let keys: Vec<&str> = changes.iter().map(|c| c.key.as_str()).collect();
+        assert_eq!(keys, vec!["hotkey", "theme"]);
+        assert_eq!(changes[0].old_value, None);
+        assert_eq!(changes[1].old_value, Some(json!("light")));
+        assert_eq!(changes[1].new_value, Some(json!("dark")));
+    }
+
+    #[test]
+    fn test_is_secret_setting_key_matches_names_not_substrings() {
+        use crate::commands::settings::is_secret_setting_key;
+
+        for key in [
+            "ai_api_key_openai",
+            "stt_api_key_soniox",
+            "webhook_hmac_secret",
+            "proxy_password",
+            "license_key",
+            "refresh_token",
+        ] {
+            assert!(is_secret_setting_key(key), "{} should be secret", key);
+        }
+        for key in ["max_tokens", "hotkey", "use_different_ptt_key", "theme"] {
+            assert!(!is_secret_setting_key(key), "{} should not be secret", key);
+        }
+    }
 }
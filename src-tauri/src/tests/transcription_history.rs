@@ -191,4 +191,116 @@ mod tests {
         assert_eq!(sorted[0], timestamps[0]); // Most recent should be first
         assert_eq!(sorted[sorted.len() - 1], timestamps[timestamps.len() - 1]); // Oldest should be last
     }
+
+    #[test]
+    fn test_merge_transcription_entries() {
+        use crate::commands::audio::merge_transcription_entries;
+
+        let first = json!({
+            "text": "First part.",
+            "model": "base.en",
+            "timestamp": "2024-01-01T10:00:00+00:00",
+            "segments": [{"start": 0.0, "end": 2.5, "text": "First part."}]
+        });
+        let second = json!({
+            "text": "Second part.",
+            "model": "base.en",
+            "timestamp": "2024-01-01T10:05:00+00:00",
+            "segments": [{"start": 0.0, "end": 1.0, "text": "Second part."}]
+        });
+
+        let merged =
+            merge_transcription_entries(&[first, second], " ", "2024-01-01T11:00:00+00:00");
+
+        assert_eq!(merged["text"], "First part. Second part.");
+        assert_eq!(merged["model"], "base.en");
+        assert_eq!(merged["timestamp"], "2024-01-01T11:00:00+00:00");
+        assert_eq!(merged["merged_from"].as_array().unwrap().len(), 2);
+
+        // Second entry's segments are offset by the end of the first
+        let segments = merged["segments"].as_array().unwrap();
+        assert_eq!(segments[1]["start"], 2.5);
+        assert_eq!(segments[1]["end"], 3.5);
+    }
+
+    #[test]
+    fn test_write_json_compact_atomic() {
+        use crate::commands::audio::write_json_compact_atomic;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("transcriptions");
+        let history = json!({
+            "2024-01-01T00:00:00Z": { "text": "Hello world", "model": "base" }
+        });
+        std::fs::write(&path, serde_json::to_vec_pretty(&history).unwrap()).unwrap();
+        let before = std::fs::metadata(&path).unwrap().len();
+
+        let written = write_json_compact_atomic(&path, &history).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(written, contents.len() as u64);
+        assert!(written < before);
+        assert!(!contents.contains('\n'));
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&contents).unwrap(),
+            history
+        );
+        assert!(!path.with_extension("compact.tmp").exists());
+    }
+
+    #[test]
+    fn test_find_duplicate_groups() {
+        use crate::commands::audio::find_duplicate_groups;
+
+        let entries = vec![
+            (
+                "2024-01-01T10:00:00+00:00".to_string(),
+                "test test test".to_string(),
+            ),
+            (
+                "2024-01-01T10:01:00+00:00".to_string(),
+                "Test, test test.".to_string(),
+            ),
+            (
+                "2024-01-01T10:02:00+00:00".to_string(),
+                "test test tests".to_string(),
+            ),
+            (
+                "2024-01-01T10:03:00+00:00".to_string(),
+                "Something else entirely".to_string(),
+            ),
+        ];
+
+        // Exact match after normalization
+        let exact = find_duplicate_groups(&entries, 1.0);
+        assert_eq!(exact.len(), 1);
+        assert_eq!(
+            exact[0].timestamps,
+            vec!["2024-01-01T10:01:00+00:00", "2024-01-01T10:00:00+00:00"]
+        );
+        assert_eq!(exact[0].text, "Test, test test.");
+
+        // Near duplicates join the group, unrelated text never does
+        let near = find_duplicate_groups(&entries, 0.9);
+        assert_eq!(near.len(), 1);
+        assert_eq!(near[0].timestamps.len(), 3);
+        assert_eq!(near[0].timestamps[0], "2024-01-01T10:02:00+00:00");
+    }
+
+    #[test]
+    fn test_entry_matches_app_by_name_or_bundle_id() {
+        use crate::commands::audio::entry_matches_app;
+
+        let entry = json!({
+            "text": "Hello",
+            "app": { "name": "Slack", "id": "com.tinyspeck.slackmacgap" }
+        });
+        assert!(entry_matches_app(&entry, "slack"));
+        assert!(entry_matches_app(&entry, "com.tinyspeck.slackmacgap"));
+        assert!(!entry_matches_app(&entry, "Mail"));
+
+        let unknown = json!({ "text": "Hi", "app": { "name": "unknown", "id": null } });
+        assert!(entry_matches_app(&unknown, "unknown"));
+        assert!(!entry_matches_app(&json!({ "text": "Old" }), "Slack"));
+    }
 }
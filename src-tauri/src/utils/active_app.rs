@@ -0,0 +1,160 @@
+// Detection of the frontmost application. Best effort: on macOS this goes
+// through System Events (needs accessibility access), on Windows through the
+// foreground window's process, and on Linux through xdotool when installed.
+// Anything that fails yields `None` and callers show it as unknown, as does
+// VoiceTypr itself being frontmost (e.g. recording started from its window).
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ActiveApp {
+    /// Display name, e.g. "Slack"
+    pub name: String,
+    /// Bundle id on macOS, executable name on Windows
+    pub id: Option<String>,
+}
+
+/// The frontmost app, unless it is this process. Dictation never targets
+/// VoiceTypr's own windows, so recording it would only mislabel history.
+pub fn frontmost_app() -> Option<ActiveApp> {
+    let (app, pid) = frontmost()?;
+    if pid == Some(std::process::id()) {
+        log::debug!("VoiceTypr is frontmost; recording the app as unknown");
+        return None;
+    }
+    Some(app)
+}
+
+/// Parse `osascript` output of the form "name\nbundle id\npid"
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_macos_output(output: &str) -> Option<(ActiveApp, Option<u32>)> {
+    let mut lines = output.lines().map(str::trim).filter(|l| !l.is_empty());
+    let name = lines.next()?.to_string();
+    let id = lines
+        .next()
+        .filter(|id| *id != "missing value")
+        .map(str::to_string);
+    let pid = lines.next().and_then(|pid| pid.parse().ok());
+    Some((ActiveApp { name, id }, pid))
+}
+
+#[cfg(target_os = "macos")]
+fn frontmost() -> Option<(ActiveApp, Option<u32>)> {
+    let script = r#"
+        tell application "System Events"
+            set frontApp to first application process whose frontmost is true
+            return (name of frontApp) & linefeed & (bundle identifier of frontApp) & linefeed & (unix id of frontApp)
+        end tell
+    "#;
+    let output = std::process::Command::new("osascript")
+        .arg("-e")
+        .arg(script)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        log::debug!(
+            "Could not read frontmost app: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+        return None;
+    }
+    parse_macos_output(&String::from_utf8_lossy(&output.stdout))
+}
+
+#[cfg(target_os = "windows")]
+fn frontmost() -> Option<(ActiveApp, Option<u32>)> {
+    use windows::core::PWSTR;
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::Threading::{
+        OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32,
+        PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+    use windows::Win32::UI::WindowsAndMessaging::{
+        GetForegroundWindow, GetWindowTextW, GetWindowThreadProcessId,
+    };
+
+    unsafe {
+        let hwnd = GetForegroundWindow();
+        if hwnd.0.is_null() {
+            return None;
+        }
+        let mut title = [0u16; 512];
+        let title_len = GetWindowTextW(hwnd, &mut title) as usize;
+        let title = String::from_utf16_lossy(&title[..title_len]);
+
+        let mut pid = 0u32;
+        GetWindowThreadProcessId(hwnd, Some(&mut pid));
+        let exe = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid)
+            .ok()
+            .and_then(|process| {
+                let mut buf = [0u16; 1024];
+                let mut len = buf.len() as u32;
+                let result = QueryFullProcessImageNameW(
+                    process,
+                    PROCESS_NAME_WIN32,
+                    PWSTR(buf.as_mut_ptr()),
+                    &mut len,
+                );
+                let _ = CloseHandle(process);
+                result.ok()?;
+                let path = String::from_utf16_lossy(&buf[..len as usize]);
+                std::path::Path::new(&path)
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+            });
+
+        let name = exe
+            .as_deref()
+            .and_then(|e| e.strip_suffix(".exe").or(Some(e)))
+            .map(str::to_string)
+            .or_else(|| (!title.is_empty()).then_some(title))?;
+        Some((ActiveApp { name, id: exe }, Some(pid)))
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn frontmost() -> Option<(ActiveApp, Option<u32>)> {
+    let xdotool = |command: &str| {
+        let output = std::process::Command::new("xdotool")
+            .args(["getactivewindow", command])
+            .output()
+            .ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    let name = xdotool("getwindowclassname").filter(|name| !name.is_empty())?;
+    // Not every window sets _NET_WM_PID
+    let pid = xdotool("getwindowpid").and_then(|pid| pid.parse().ok());
+    Some((ActiveApp { name, id: None }, pid))
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+fn frontmost() -> Option<(ActiveApp, Option<u32>)> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_macos_output() {
+        assert_eq!(
+            parse_macos_output("Slack\ncom.tinyspeck.slackmacgap\n812\n"),
+            Some((
+                ActiveApp {
+                    name: "Slack".to_string(),
+                    id: Some("com.tinyspeck.slackmacgap".to_string()),
+                },
+                Some(812)
+            ))
+        );
+        assert_eq!(
+            parse_macos_output("Terminal\nmissing value\n").and_then(|(a, _)| a.id),
+            None
+        );
+        assert_eq!(parse_macos_output("\n"), None);
+    }
+}
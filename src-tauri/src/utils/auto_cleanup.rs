@@ -0,0 +1,197 @@
+// Hands-off cleanup of old log files and leftover recordings.
+// Runs once at startup and then every `cleanup_interval_hours`, reading the
+// retention settings fresh on every run.
+
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+use tokio::sync::Notify;
+
+/// Matches the previous startup behavior of keeping only today's log
+pub const DEFAULT_LOG_RETENTION_DAYS: u32 = 1;
+/// Kept recordings are the user's data; only delete them once a retention is chosen
+pub const DEFAULT_RECORDING_RETENTION_DAYS: u32 = 0;
+pub const DEFAULT_CLEANUP_INTERVAL_HOURS: u32 = 24;
+
+/// Outcome of one cleanup run, sent with the `auto-cleanup-ran` event
+#[derive(serde::Serialize, Debug, Clone, Default)]
+pub struct AutoCleanupReport {
+    pub logs_deleted: u32,
+    pub recordings_deleted: u32,
+    pub errors: Vec<String>,
+}
+
+/// Delete recordings in `dir` last modified before `cutoff`, plus orphaned
+/// recordings (no finalized sidecar) past their grace period
+pub fn delete_recordings_older_than(dir: &Path, cutoff: SystemTime) -> Result<u32, String> {
+    crate::utils::recordings::apply_retention(dir, Some(cutoff))
+}
+
+fn setting_u32(app: &AppHandle, key: &str, default: u32) -> u32 {
+    app.store("settings")
+        .ok()
+        .and_then(|store| store.get(key))
+        .and_then(|v| v.as_u64())
+        .map(|v| v as u32)
+        .unwrap_or(default)
+}
+
+/// Periodic cleanup task. Runs never overlap, and `stop` lets an in-flight
+/// run finish before the loop exits.
+pub struct AutoCleanupScheduler {
+    running: Arc<AtomicBool>,
+    stopped: Arc<AtomicBool>,
+    wake: Arc<Notify>,
+    started: Mutex<bool>,
+}
+
+impl Default for AutoCleanupScheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AutoCleanupScheduler {
+    pub fn new() -> Self {
+        Self {
+            running: Arc::new(AtomicBool::new(false)),
+            stopped: Arc::new(AtomicBool::new(false)),
+            wake: Arc::new(Notify::new()),
+            started: Mutex::new(false),
+        }
+    }
+
+    pub fn start(&self, app: AppHandle) {
+        match self.started.lock() {
+            Ok(mut started) if !*started => *started = true,
+            _ => return,
+        }
+
+        let running = self.running.clone();
+        let stopped = self.stopped.clone();
+        let wake = self.wake.clone();
+        tauri::async_runtime::spawn(async move {
+            log::info!("Auto-cleanup scheduler started");
+            while !stopped.load(Ordering::SeqCst) {
+                run_cleanup(&app, &running).await;
+
+                let hours = setting_u32(
+                    &app,
+                    "cleanup_interval_hours",
+                    DEFAULT_CLEANUP_INTERVAL_HOURS,
+                )
+                .max(1);
+                tokio::select! {
+                    _ = tokio::time::sleep(Duration::from_secs(hours as u64 * 3600)) => {}
+                    _ = wake.notified() => {}
+                }
+            }
+            log::info!("Auto-cleanup scheduler stopped");
+        });
+    }
+
+    /// Stop scheduling further runs
+    pub fn stop(&self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        self.wake.notify_one();
+    }
+
+    /// Run a cleanup now, unless one is already in progress
+    pub async fn run_now(&self, app: &AppHandle) -> Option<AutoCleanupReport> {
+        run_cleanup(app, &self.running).await
+    }
+}
+
+async fn run_cleanup(app: &AppHandle, running: &AtomicBool) -> Option<AutoCleanupReport> {
+    if running.swap(true, Ordering::SeqCst) {
+        log::debug!("Auto-cleanup already running, skipping");
+        return None;
+    }
+
+    let mut report = AutoCleanupReport::default();
+
+    let log_days = setting_u32(app, "log_retention_days", DEFAULT_LOG_RETENTION_DAYS);
+    if log_days > 0 {
+        match crate::commands::logs::clear_old_logs(app.clone(), log_days).await {
+            Ok(deleted) => report.logs_deleted = deleted,
+            Err(e) => report.errors.push(e.to_string()),
+        }
+    }
+
+    let recording_days = setting_u32(
+        app,
+        "recording_retention_days",
+        DEFAULT_RECORDING_RETENTION_DAYS,
+    );
+    // Orphans are swept even when recordings are kept forever
+    let cutoff = (recording_days > 0)
+        .then(|| SystemTime::now() - Duration::from_secs(recording_days as u64 * 24 * 60 * 60));
+    match crate::utils::data_dir::data_dir(app) {
+        Ok(dir) => {
+            let recordings_dir = dir.join("recordings");
+            crate::utils::recordings::reconcile_with_history(app, &recordings_dir);
+            let retention_dir = recordings_dir.clone();
+            match tauri::async_runtime::spawn_blocking(move || {
+                crate::utils::recordings::apply_retention(&retention_dir, cutoff)
+            })
+            .await
+            {
+                Ok(Ok(deleted)) => report.recordings_deleted = deleted,
+                Ok(Err(e)) => report.errors.push(e),
+                Err(e) => report
+                    .errors
+                    .push(format!("Recording cleanup failed: {}", e)),
+            }
+            // History must not point at recordings retention just removed
+            if report.recordings_deleted > 0
+                && crate::utils::recordings::unlink_missing_recordings(app, &recordings_dir) > 0
+            {
+                let _ = crate::emit_to_window(app, "main", "history-updated", ());
+            }
+        }
+        Err(e) => report.errors.push(e),
+    }
+
+    running.store(false, Ordering::SeqCst);
+
+    if report.logs_deleted > 0 || report.recordings_deleted > 0 {
+        log::info!(
+            "🧹 Auto-cleanup removed {} log files and {} recordings",
+            report.logs_deleted,
+            report.recordings_deleted
+        );
+    }
+    for error in &report.errors {
+        log::warn!("Auto-cleanup: {}", error);
+    }
+    let _ = crate::emit_to_all(app, "auto-cleanup-ran", report.clone());
+
+    Some(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delete_recordings_older_than() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("recording_1.wav"), b"RIFF").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), b"keep").unwrap();
+
+        // Nothing is older than a cutoff in the past
+        let past = SystemTime::now() - Duration::from_secs(3600);
+        assert_eq!(delete_recordings_older_than(dir.path(), past).unwrap(), 0);
+
+        let future = SystemTime::now() + Duration::from_secs(3600);
+        assert_eq!(delete_recordings_older_than(dir.path(), future).unwrap(), 1);
+        assert!(!dir.path().join("recording_1.wav").exists());
+        assert!(dir.path().join("notes.txt").exists());
+
+        let missing = dir.path().join("missing");
+        assert_eq!(delete_recordings_older_than(&missing, future).unwrap(), 0);
+    }
+}
@@ -0,0 +1,245 @@
+// Crash recovery for interrupted recordings. While recording, a lock file in
+// the recordings directory names the owning process and the WAV being
+// written. A lock whose process is gone means the app died mid-recording:
+// the lock is cleared and the partial WAV is repaired and kept as a
+// recovered recording instead of being swept as an orphan. Recovered
+// recordings stay queued (`pending_recovered`) until the user transcribes or
+// discards them from the prompt the main window shows.
+
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+pub const LOCK_FILE_NAME: &str = "recording.lock";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RecordingLock {
+    pub pid: u32,
+    pub recording: PathBuf,
+    pub started_at: String,
+}
+
+/// What `recover_from_crash` cleaned up, sent with the `crash-recovered` event
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct RecoveryReport {
+    pub stale_locks_cleared: u32,
+    pub recovered_recordings: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+impl RecoveryReport {
+    pub fn is_empty(&self) -> bool {
+        self.stale_locks_cleared == 0 && self.recovered_recordings.is_empty()
+    }
+}
+
+pub fn lock_path(recordings_dir: &Path) -> PathBuf {
+    recordings_dir.join(LOCK_FILE_NAME)
+}
+
+/// Record that this process is writing `recording`
+pub fn acquire_recording_lock(recordings_dir: &Path, recording: &Path) -> Result<(), String> {
+    let lock = RecordingLock {
+        pid: std::process::id(),
+        recording: recording.to_path_buf(),
+        started_at: chrono::Utc::now().to_rfc3339(),
+    };
+    let contents = serde_json::to_vec(&lock)
+        .map_err(|e| format!("Failed to serialize recording lock: {}", e))?;
+    std::fs::write(lock_path(recordings_dir), contents)
+        .map_err(|e| format!("Failed to write recording lock: {}", e))
+}
+
+pub fn release_recording_lock(recordings_dir: &Path) {
+    if let Err(e) = std::fs::remove_file(lock_path(recordings_dir)) {
+        if e.kind() != std::io::ErrorKind::NotFound {
+            log::warn!("Failed to remove recording lock: {}", e);
+        }
+    }
+}
+
+pub fn read_recording_lock(recordings_dir: &Path) -> Option<RecordingLock> {
+    let contents = std::fs::read_to_string(lock_path(recordings_dir)).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+pub fn is_process_alive(pid: u32) -> bool {
+    let pid = sysinfo::Pid::from_u32(pid);
+    let mut system = sysinfo::System::new();
+    system.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid]), true);
+    system.process(pid).is_some()
+}
+
+/// A lock is stale when it belongs to neither this process nor a running one
+pub fn is_stale(lock: &RecordingLock, is_alive: impl Fn(u32) -> bool) -> bool {
+    lock.pid != std::process::id() && !is_alive(lock.pid)
+}
+
+/// Fix the RIFF and data chunk sizes of a WAV whose writer never finalized,
+/// so the samples written before the crash can be read. Returns the data size.
+pub fn repair_wav_header(path: &Path) -> Result<u32, String> {
+    let mut file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(path)
+        .map_err(|e| format!("Failed to open recording: {}", e))?;
+    let len = file
+        .metadata()
+        .map_err(|e| format!("Failed to read recording metadata: {}", e))?
+        .len();
+
+    let mut header = [0u8; 12];
+    file.read_exact(&mut header)
+        .map_err(|e| format!("Failed to read recording header: {}", e))?;
+    if &header[0..4] != b"RIFF" || &header[8..12] != b"WAVE" {
+        return Err("Recording is not a WAV file".to_string());
+    }
+
+    // Walk chunks until the data chunk; everything after its header is audio
+    let mut offset = 12u64;
+    while offset + 8 <= len {
+        let mut chunk = [0u8; 8];
+        file.seek(SeekFrom::Start(offset))
+            .and_then(|_| file.read_exact(&mut chunk))
+            .map_err(|e| format!("Failed to read recording chunk: {}", e))?;
+        let size = u32::from_le_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]) as u64;
+
+        if &chunk[0..4] == b"data" {
+            // Keep whole 16-bit samples only
+            let data_len = ((len - offset - 8) & !1).min(u32::MAX as u64) as u32;
+            let riff_len = (offset + data_len as u64).min(u32::MAX as u64) as u32;
+            let write = |file: &mut std::fs::File, at: u64, value: u32| {
+                file.seek(SeekFrom::Start(at))
+                    .and_then(|_| file.write_all(&value.to_le_bytes()))
+            };
+            write(&mut file, 4, riff_len)
+                .and_then(|_| write(&mut file, offset + 4, data_len))
+                .and_then(|_| file.set_len(offset + 8 + data_len as u64))
+                .map_err(|e| format!("Failed to repair recording header: {}", e))?;
+            return Ok(data_len);
+        }
+        offset += 8 + size + (size & 1);
+    }
+    Err("Recording has no audio data".to_string())
+}
+
+/// Clear a stale lock in `recordings_dir` and keep the recording it pointed at
+pub fn recover_recordings_dir(
+    recordings_dir: &Path,
+    is_alive: impl Fn(u32) -> bool,
+) -> RecoveryReport {
+    let mut report = RecoveryReport::default();
+    let Some(lock) = read_recording_lock(recordings_dir) else {
+        // An unreadable lock can't be owned by anyone; drop it
+        if lock_path(recordings_dir).exists() {
+            release_recording_lock(recordings_dir);
+            report.stale_locks_cleared += 1;
+        }
+        return report;
+    };
+    if !is_stale(&lock, is_alive) {
+        return report;
+    }
+
+    log::warn!(
+        "Found stale recording lock from PID {} (started {})",
+        lock.pid,
+        lock.started_at
+    );
+    release_recording_lock(recordings_dir);
+    report.stale_locks_cleared += 1;
+
+    if lock.recording.exists() {
+        let recovered = repair_wav_header(&lock.recording)
+            .and_then(|_| crate::utils::recordings::write_recovered_sidecar(&lock.recording));
+        match recovered {
+            Ok(()) => report
+                .recovered_recordings
+                .push(lock.recording.to_string_lossy().to_string()),
+            Err(e) => report.errors.push(format!(
+                "Failed to recover {}: {}",
+                lock.recording.display(),
+                e
+            )),
+        }
+    }
+    report
+}
+
+fn recordings_dir(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    Ok(crate::utils::data_dir::data_dir(app)?.join("recordings"))
+}
+
+/// Drop this app's recording lock once the recorder has stopped
+pub fn release_app_recording_lock(app: &tauri::AppHandle) {
+    if let Ok(dir) = recordings_dir(app) {
+        release_recording_lock(&dir);
+    }
+}
+
+/// Recover from a crash in the app's recordings directory
+pub fn recover_app(app: &tauri::AppHandle) -> Result<RecoveryReport, String> {
+    let report = recover_recordings_dir(&recordings_dir(app)?, is_process_alive);
+    if !report.is_empty() {
+        log::info!(
+            "🩹 Crash recovery cleared {} stale lock(s) and recovered {} recording(s)",
+            report.stale_locks_cleared,
+            report.recovered_recordings.len()
+        );
+    }
+    for error in &report.errors {
+        log::warn!("Crash recovery: {}", error);
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recover_repairs_unfinalized_wav_and_clears_lock() {
+        let dir = tempfile::tempdir().unwrap();
+        let recording = dir.path().join("recording_1.wav");
+
+        // Samples appended past a header that still claims no data
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: 16000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let writer = hound::WavWriter::create(&recording, spec).unwrap();
+        writer.finalize().unwrap();
+        let mut bytes = std::fs::read(&recording).unwrap();
+        bytes.extend(std::iter::repeat_n(0u8, 3200));
+        std::fs::write(&recording, bytes).unwrap();
+
+        acquire_recording_lock(dir.path(), &recording).unwrap();
+        // Owned by this process, so not stale
+        assert!(recover_recordings_dir(dir.path(), |_| false).is_empty());
+
+        let mut lock = read_recording_lock(dir.path()).unwrap();
+        lock.pid = u32::MAX;
+        std::fs::write(lock_path(dir.path()), serde_json::to_vec(&lock).unwrap()).unwrap();
+        assert!(recover_recordings_dir(dir.path(), |_| true).is_empty());
+
+        let report = recover_recordings_dir(dir.path(), |_| false);
+        assert_eq!(report.stale_locks_cleared, 1);
+        assert_eq!(report.recovered_recordings.len(), 1);
+        assert!(!lock_path(dir.path()).exists());
+
+        let reader = hound::WavReader::open(&recording).unwrap();
+        assert_eq!(reader.duration(), 1600);
+        let sidecar = crate::utils::recordings::read_sidecar(&recording).unwrap();
+        assert!(sidecar.recovered);
+
+        // Queued until it is transcribed
+        let pending = crate::utils::recordings::pending_recovered(dir.path());
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].0, recording);
+        crate::utils::recordings::finalize_recording(&recording, "1").unwrap();
+        assert!(crate::utils::recordings::pending_recovered(dir.path()).is_empty());
+    }
+}
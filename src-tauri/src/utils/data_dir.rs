@@ -0,0 +1,231 @@
+// Resolution of the directory holding models, recordings and history.
+// The settings store (and the secure store) always stay in the OS default
+// app data dir, since the override itself is read from settings.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, Runtime};
+use tauri_plugin_store::{Store, StoreExt};
+
+pub const DATA_DIR_OVERRIDE_KEY: &str = "data_dir_override";
+pub const TRANSCRIPTIONS_STORE: &str = "transcriptions";
+
+/// Entries under the data dir that follow the override (and get migrated)
+pub const RELOCATABLE_ITEMS: &[&str] = &[
+    "models",
+    "recordings",
+    TRANSCRIPTIONS_STORE,
+    "telemetry.jsonl",
+    "exports",
+];
+
+/// Pick the override when it is a usable absolute path, else the default dir
+pub fn resolve_data_dir(override_dir: Option<&str>, default_dir: PathBuf) -> PathBuf {
+    override_dir
+        .map(str::trim)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .unwrap_or(default_dir)
+}
+
+/// The configured override, if any
+pub fn data_dir_override<R: Runtime>(app: &AppHandle<R>) -> Option<PathBuf> {
+    let default_dir = app.path().app_data_dir().ok()?;
+    let configured = app
+        .store("settings")
+        .ok()?
+        .get(DATA_DIR_OVERRIDE_KEY)
+        .and_then(|v| v.as_str().map(|s| s.to_string()));
+    let resolved = resolve_data_dir(configured.as_deref(), default_dir.clone());
+    (resolved != default_dir).then_some(resolved)
+}
+
+/// Directory for app data, honoring `data_dir_override`
+pub fn data_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    if let Some(dir) = data_dir_override(app) {
+        return Ok(dir);
+    }
+    app.path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))
+}
+
+/// Every directory that may hold app data: the default one plus the override
+pub fn all_data_dirs(app: &AppHandle) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = app.path().app_data_dir().ok().into_iter().collect();
+    if let Some(dir) = data_dir_override(app) {
+        dirs.push(dir);
+    }
+    dirs
+}
+
+/// The settings store always lives in the default app data dir
+pub fn settings_store_path(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join("settings"))
+        .map_err(|e| format!("Failed to get app data dir: {}", e))
+}
+
+pub fn transcriptions_store_path(app: &AppHandle) -> Result<PathBuf, String> {
+    data_dir(app).map(|dir| dir.join(TRANSCRIPTIONS_STORE))
+}
+
+/// Minified JSON for the history store. The plugin's default is pretty JSON,
+/// which would undo `compact_transcriptions_store` on the next save.
+fn serialize_compact(
+    cache: &HashMap<String, serde_json::Value>,
+) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+    Ok(serde_json::to_vec(cache)?)
+}
+
+/// The transcription history store, located in the (possibly overridden) data dir
+pub fn transcriptions_store<R: Runtime>(
+    app: &AppHandle<R>,
+) -> tauri_plugin_store::Result<Arc<Store<R>>> {
+    let path = match data_dir_override(app) {
+        Some(dir) => dir.join(TRANSCRIPTIONS_STORE),
+        None => PathBuf::from(TRANSCRIPTIONS_STORE),
+    };
+    if let Some(store) = app.get_store(&path) {
+        return Ok(store);
+    }
+    app.store_builder(path).serialize(serialize_compact).build()
+}
+
+/// Move a file or directory, falling back to copy + delete across filesystems
+pub fn move_path(from: &Path, to: &Path) -> Result<(), String> {
+    if std::fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+
+    copy_recursive(from, to).map_err(|e| {
+        let _ = remove_path(to);
+        format!("Failed to copy {}: {}", from.display(), e)
+    })?;
+    remove_path(from).map_err(|e| format!("Failed to remove {}: {}", from.display(), e))
+}
+
+/// Move `from` into `to`, merging directories that already exist there.
+/// Files present on both sides are an error; check `first_conflict` first so
+/// nothing is moved when the merge can't complete.
+pub fn merge_path(from: &Path, to: &Path) -> Result<(), String> {
+    if !to.exists() {
+        return move_path(from, to);
+    }
+    if !(from.is_dir() && to.is_dir()) {
+        return Err(format!("{} already exists", to.display()));
+    }
+    let entries =
+        std::fs::read_dir(from).map_err(|e| format!("Failed to read {}: {}", from.display(), e))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Failed to read {}: {}", from.display(), e))?;
+        merge_path(&entry.path(), &to.join(entry.file_name()))?;
+    }
+    std::fs::remove_dir(from).map_err(|e| format!("Failed to remove {}: {}", from.display(), e))
+}
+
+/// The first path under `to` that `merge_path(from, to)` would refuse to overwrite
+pub fn first_conflict(from: &Path, to: &Path) -> Option<PathBuf> {
+    if !to.exists() {
+        return None;
+    }
+    if !(from.is_dir() && to.is_dir()) {
+        return Some(to.to_path_buf());
+    }
+    std::fs::read_dir(from)
+        .ok()?
+        .flatten()
+        .find_map(|entry| first_conflict(&entry.path(), &to.join(entry.file_name())))
+}
+
+fn copy_recursive(from: &Path, to: &Path) -> std::io::Result<()> {
+    if from.is_dir() {
+        std::fs::create_dir_all(to)?;
+        for entry in std::fs::read_dir(from)? {
+            let entry = entry?;
+            copy_recursive(&entry.path(), &to.join(entry.file_name()))?;
+        }
+        Ok(())
+    } else {
+        std::fs::copy(from, to).map(|_| ())
+    }
+}
+
+fn remove_path(path: &Path) -> std::io::Result<()> {
+    if path.is_dir() {
+        std::fs::remove_dir_all(path)
+    } else {
+        std::fs::remove_file(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_data_dir() {
+        let default_dir = PathBuf::from("/default/data");
+        let absolute = if cfg!(windows) {
+            "D:\\voicetypr"
+        } else {
+            "/mnt/data/voicetypr"
+        };
+
+        assert_eq!(resolve_data_dir(None, default_dir.clone()), default_dir);
+        assert_eq!(
+            resolve_data_dir(Some("  "), default_dir.clone()),
+            default_dir
+        );
+        assert_eq!(
+            resolve_data_dir(Some("relative/dir"), default_dir.clone()),
+            default_dir
+        );
+        assert_eq!(
+            resolve_data_dir(Some(absolute), default_dir),
+            PathBuf::from(absolute)
+        );
+    }
+
+    #[test]
+    fn test_move_path_moves_directories() {
+        let dir = tempfile::tempdir().unwrap();
+        let from = dir.path().join("models");
+        std::fs::create_dir_all(from.join("parakeet")).unwrap();
+        std::fs::write(from.join("base.bin"), b"weights").unwrap();
+
+        let to = dir.path().join("elsewhere/models");
+        std::fs::create_dir_all(to.parent().unwrap()).unwrap();
+        move_path(&from, &to).unwrap();
+
+        assert!(!from.exists());
+        assert_eq!(std::fs::read(to.join("base.bin")).unwrap(), b"weights");
+        assert!(to.join("parakeet").is_dir());
+    }
+
+    #[test]
+    fn test_merge_path_merges_into_existing_directories() {
+        let dir = tempfile::tempdir().unwrap();
+        let from = dir.path().join("override/models");
+        std::fs::create_dir_all(&from).unwrap();
+        std::fs::write(from.join("base.bin"), b"weights").unwrap();
+        let to = dir.path().join("default/models");
+        std::fs::create_dir_all(to.join("parakeet")).unwrap();
+
+        assert_eq!(first_conflict(&from, &to), None);
+        merge_path(&from, &to).unwrap();
+        assert!(!from.exists());
+        assert_eq!(std::fs::read(to.join("base.bin")).unwrap(), b"weights");
+        assert!(to.join("parakeet").is_dir());
+
+        // A file on both sides is never overwritten
+        std::fs::create_dir_all(&from).unwrap();
+        std::fs::write(from.join("base.bin"), b"newer").unwrap();
+        assert_eq!(first_conflict(&from, &to), Some(to.join("base.bin")));
+        assert!(merge_path(&from, &to).is_err());
+        assert_eq!(std::fs::read(to.join("base.bin")).unwrap(), b"weights");
+    }
+}
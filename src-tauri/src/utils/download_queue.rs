@@ -0,0 +1,317 @@
+// Model download queue. Downloads run one at a time in queue order; each
+// change to the queue is emitted as `download-queue-updated` with the full
+// list so the UI never has to piece it together from per-download events.
+//
+// Each item carries a generation that is bumped whenever a new worker takes
+// it over (enqueue or resume). Workers pass their generation back on every
+// update, so a paused worker that is still winding down can't touch the item
+// after a quick resume handed it to a new worker.
+
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use tokio::sync::Notify;
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DownloadStatus {
+    Queued,
+    Downloading,
+    /// Stopped by the user; resuming starts the download over
+    Paused,
+    Done,
+    Failed,
+}
+
+impl DownloadStatus {
+    fn is_pending(&self) -> bool {
+        matches!(self, DownloadStatus::Queued | DownloadStatus::Downloading)
+    }
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct DownloadQueueItem {
+    pub model: String,
+    pub engine: String,
+    pub status: DownloadStatus,
+    pub downloaded: u64,
+    pub total: u64,
+    pub progress: f64,
+    pub error: Option<String>,
+    #[serde(skip)]
+    generation: u64,
+    /// A worker is still downloading this item, possibly a replaced one
+    #[serde(skip)]
+    running: bool,
+}
+
+#[derive(Default)]
+pub struct DownloadQueue {
+    items: Mutex<Vec<DownloadQueueItem>>,
+    changed: Notify,
+    next_generation: AtomicU64,
+}
+
+impl DownloadQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn update<T>(&self, f: impl FnOnce(&mut Vec<DownloadQueueItem>) -> T) -> T {
+        let result = match self.items.lock() {
+            Ok(mut items) => f(&mut items),
+            Err(poisoned) => f(&mut poisoned.into_inner()),
+        };
+        self.changed.notify_waiters();
+        result
+    }
+
+    pub fn snapshot(&self) -> Vec<DownloadQueueItem> {
+        self.items
+            .lock()
+            .map(|items| items.clone())
+            .unwrap_or_default()
+    }
+
+    fn bump_generation(&self) -> u64 {
+        self.next_generation.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    /// Status of `model` as seen by the worker holding `generation`; `None`
+    /// once another worker has taken the item over
+    pub fn status_for(&self, model: &str, generation: u64) -> Option<DownloadStatus> {
+        self.items
+            .lock()
+            .ok()?
+            .iter()
+            .find(|item| item.model == model && item.generation == generation)
+            .map(|item| item.status)
+    }
+
+    /// Queue `model`, keeping its place if it is already listed (e.g. resumed).
+    /// Returns the generation the new worker must pass back.
+    pub fn enqueue(&self, model: &str, engine: &str) -> u64 {
+        self.update(|items| {
+            let generation = self.bump_generation();
+            let running = items.iter().any(|i| i.model == model && i.running);
+            let item = DownloadQueueItem {
+                model: model.to_string(),
+                engine: engine.to_string(),
+                status: DownloadStatus::Queued,
+                downloaded: 0,
+                total: 0,
+                progress: 0.0,
+                error: None,
+                generation,
+                running,
+            };
+            match items.iter_mut().find(|i| i.model == model) {
+                Some(existing) => *existing = item,
+                None => items.push(item),
+            }
+            generation
+        })
+    }
+
+    /// Move a paused item back to queued and retire its old worker. Returns
+    /// false if it isn't paused, so two resumes can't both start a worker.
+    pub fn resume(&self, model: &str) -> bool {
+        self.update(|items| match items.iter_mut().find(|i| i.model == model) {
+            Some(item) if item.status == DownloadStatus::Paused => {
+                item.status = DownloadStatus::Queued;
+                item.generation = self.bump_generation();
+                true
+            }
+            _ => false,
+        })
+    }
+
+    /// Whether `model` may start: nothing else is downloading, no earlier
+    /// worker is still winding down on it and it is the first queued item
+    pub fn is_next(&self, model: &str, generation: u64) -> bool {
+        let Ok(items) = self.items.lock() else {
+            return true;
+        };
+        items
+            .iter()
+            .any(|i| i.model == model && i.generation == generation && !i.running)
+            && !items
+                .iter()
+                .any(|i| i.status == DownloadStatus::Downloading && i.model != model)
+            && items
+                .iter()
+                .find(|i| i.status == DownloadStatus::Queued)
+                .is_some_and(|i| i.model == model)
+    }
+
+    /// Wait until `model` is next in line or `stop` returns true
+    pub async fn wait_for_turn(&self, model: &str, generation: u64, stop: impl Fn() -> bool) {
+        loop {
+            // Registered before checking, so a change in between isn't missed
+            let notified = self.changed.notified();
+            if stop() || self.is_next(model, generation) {
+                return;
+            }
+            notified.await;
+        }
+    }
+
+    /// Wake waiters without changing the queue (e.g. after a cancel flag is set)
+    pub fn notify(&self) {
+        self.changed.notify_waiters();
+    }
+
+    /// Mark a queued item as downloading. Returns false if the worker holding
+    /// `generation` has been replaced or the item is no longer queued.
+    pub fn start(&self, model: &str, generation: u64) -> bool {
+        self.update(|items| {
+            match items
+                .iter_mut()
+                .find(|i| i.model == model && i.generation == generation)
+            {
+                Some(item) if item.status == DownloadStatus::Queued && !item.running => {
+                    item.status = DownloadStatus::Downloading;
+                    item.running = true;
+                    true
+                }
+                _ => false,
+            }
+        })
+    }
+
+    /// Record that the worker that started `model` has exited, letting a
+    /// worker that replaced it begin
+    pub fn finish(&self, model: &str) {
+        self.update(|items| {
+            if let Some(item) = items.iter_mut().find(|i| i.model == model) {
+                item.running = false;
+            }
+        })
+    }
+
+    pub fn set_status(
+        &self,
+        model: &str,
+        generation: u64,
+        status: DownloadStatus,
+        error: Option<String>,
+    ) {
+        self.update(|items| {
+            if let Some(item) = items
+                .iter_mut()
+                .find(|i| i.model == model && i.generation == generation)
+            {
+                item.status = status;
+                item.error = error;
+            }
+        })
+    }
+
+    /// Pause a queued or downloading item. Returns false if it isn't pending.
+    pub fn pause(&self, model: &str) -> bool {
+        self.update(|items| match items.iter_mut().find(|i| i.model == model) {
+            Some(item) if item.status.is_pending() => {
+                item.status = DownloadStatus::Paused;
+                true
+            }
+            _ => false,
+        })
+    }
+
+    pub fn set_progress(&self, model: &str, generation: u64, downloaded: u64, total: u64) {
+        if let Ok(mut items) = self.items.lock() {
+            if let Some(item) = items
+                .iter_mut()
+                .find(|i| i.model == model && i.generation == generation)
+            {
+                item.downloaded = downloaded;
+                item.total = total;
+                item.progress = if total > 0 {
+                    downloaded as f64 / total as f64 * 100.0
+                } else {
+                    0.0
+                };
+            }
+        }
+    }
+
+    pub fn remove(&self, model: &str, generation: u64) {
+        self.update(|items| items.retain(|i| i.model != model || i.generation != generation))
+    }
+
+    /// Move `model` to `position` (clamped to the end of the queue)
+    pub fn reorder(&self, model: &str, position: usize) -> Result<(), String> {
+        self.update(|items| {
+            let from = items
+                .iter()
+                .position(|i| i.model == model)
+                .ok_or_else(|| format!("Model '{}' is not in the download queue", model))?;
+            let item = items.remove(from);
+            let to = position.min(items.len());
+            items.insert(to, item);
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queue_runs_in_order_and_reorders() {
+        let queue = DownloadQueue::new();
+        let base = queue.enqueue("base", "whisper");
+        let small = queue.enqueue("small", "whisper");
+        let tiny = queue.enqueue("tiny", "whisper");
+        assert!(queue.is_next("base", base));
+        assert!(!queue.is_next("small", small));
+
+        assert!(queue.start("base", base));
+        // Nothing else starts while one is downloading
+        queue.reorder("tiny", 0).unwrap();
+        assert!(!queue.is_next("tiny", tiny));
+
+        queue.set_status("base", base, DownloadStatus::Done, None);
+        queue.finish("base");
+        assert!(queue.is_next("tiny", tiny));
+        let order: Vec<String> = queue.snapshot().into_iter().map(|i| i.model).collect();
+        assert_eq!(order, vec!["tiny", "base", "small"]);
+
+        // A paused item is skipped until it is queued again
+        assert!(queue.pause("tiny"));
+        assert!(queue.is_next("small", small));
+        let tiny = queue.enqueue("tiny", "whisper");
+        assert!(queue.is_next("tiny", tiny));
+        assert_eq!(queue.snapshot()[0].model, "tiny");
+
+        assert!(queue.reorder("missing", 0).is_err());
+    }
+
+    #[test]
+    fn test_quick_resume_retires_paused_worker() {
+        let queue = DownloadQueue::new();
+        let old = queue.enqueue("base", "whisper");
+        assert!(queue.start("base", old));
+
+        // Pause and resume before the old worker has noticed
+        assert!(queue.pause("base"));
+        assert!(queue.resume("base"));
+        assert!(!queue.resume("base"));
+        let new = queue.enqueue("base", "whisper");
+
+        // The old worker can no longer change the item
+        assert_eq!(queue.status_for("base", old), None);
+        queue.set_status("base", old, DownloadStatus::Failed, None);
+        queue.remove("base", old);
+        assert_eq!(queue.status_for("base", new), Some(DownloadStatus::Queued));
+
+        // The new worker waits for the old one to exit, then runs alone
+        assert!(!queue.is_next("base", new));
+        assert!(!queue.start("base", new));
+        queue.finish("base");
+        assert!(queue.is_next("base", new));
+        assert!(queue.start("base", new));
+        assert!(!queue.start("base", old));
+    }
+}
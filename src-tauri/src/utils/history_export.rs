@@ -0,0 +1,338 @@
+// Chunked, resumable history export. Entries are appended to the destination
+// in chunks; after each chunk the file is synced and a manifest in
+// `<data dir>/exports/<job id>.json` records the byte length and the last key
+// written. An interrupted job resumes by truncating the file back to that
+// length and continuing after that key. The finished file's SHA-256 is stored
+// in the manifest.
+
+use crate::commands::utils::{write_markdown_entry, ExportFormat};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::AppHandle;
+
+/// Entries written between manifest checkpoints
+pub const EXPORT_CHUNK_SIZE: usize = 500;
+
+/// Jobs running in this process, so a job isn't resumed twice at once
+static RUNNING_JOBS: once_cell::sync::Lazy<Mutex<HashSet<String>>> =
+    once_cell::sync::Lazy::new(|| Mutex::new(HashSet::new()));
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportStatus {
+    Running,
+    /// Stopped before finishing (e.g. the app quit); can be resumed
+    Interrupted,
+    Done,
+    /// Stopped by an error; can be resumed from the last checkpoint
+    Failed,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ExportManifest {
+    pub job_id: String,
+    pub format: ExportFormat,
+    pub dest: PathBuf,
+    pub status: ExportStatus,
+    /// Entries to export when the job started
+    pub total: usize,
+    pub written: usize,
+    /// File length at the last checkpoint
+    pub bytes_written: u64,
+    /// Key of the last entry included at the last checkpoint
+    pub last_key: Option<String>,
+    /// SHA-256 of the finished file
+    pub sha256: Option<String>,
+    pub error: Option<String>,
+}
+
+pub fn manifest_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    crate::utils::data_dir::data_dir(app).map(|dir| dir.join("exports"))
+}
+
+fn manifest_path(dir: &Path, job_id: &str) -> Result<PathBuf, String> {
+    if job_id.is_empty()
+        || !job_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-')
+    {
+        return Err(format!("Invalid export job id: {}", job_id));
+    }
+    Ok(dir.join(format!("{}.json", job_id)))
+}
+
+pub fn load_manifest(dir: &Path, job_id: &str) -> Result<ExportManifest, String> {
+    let path = manifest_path(dir, job_id)?;
+    let contents =
+        fs::read_to_string(&path).map_err(|e| format!("Export job {} not found: {}", job_id, e))?;
+    let mut manifest: ExportManifest = serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to read export manifest: {}", e))?;
+    // A running job that isn't running in this process was cut off
+    if manifest.status == ExportStatus::Running && !is_running(job_id) {
+        manifest.status = ExportStatus::Interrupted;
+    }
+    Ok(manifest)
+}
+
+pub fn save_manifest(dir: &Path, manifest: &ExportManifest) -> Result<(), String> {
+    fs::create_dir_all(dir).map_err(|e| format!("Failed to create exports directory: {}", e))?;
+    let path = manifest_path(dir, &manifest.job_id)?;
+    let tmp = path.with_extension("json.tmp");
+    let contents = serde_json::to_vec_pretty(manifest)
+        .map_err(|e| format!("Failed to serialize export manifest: {}", e))?;
+    fs::write(&tmp, contents).map_err(|e| format!("Failed to write export manifest: {}", e))?;
+    fs::rename(&tmp, &path).map_err(|e| format!("Failed to write export manifest: {}", e))
+}
+
+pub fn is_running(job_id: &str) -> bool {
+    RUNNING_JOBS
+        .lock()
+        .map(|jobs| jobs.contains(job_id))
+        .unwrap_or(false)
+}
+
+/// Mark `job_id` as running; false if it already is
+pub fn claim(job_id: &str) -> bool {
+    RUNNING_JOBS
+        .lock()
+        .map(|mut jobs| jobs.insert(job_id.to_string()))
+        .unwrap_or(false)
+}
+
+pub fn release(job_id: &str) {
+    if let Ok(mut jobs) = RUNNING_JOBS.lock() {
+        jobs.remove(job_id);
+    }
+}
+
+/// Keys still to export after `last_key`, oldest first. Keys are RFC 3339
+/// timestamps, so they sort chronologically.
+pub fn pending_keys(mut keys: Vec<String>, last_key: Option<&str>) -> Vec<String> {
+    keys.sort();
+    match last_key {
+        Some(last) => keys.into_iter().filter(|key| key.as_str() > last).collect(),
+        None => keys,
+    }
+}
+
+pub fn sha256_file(path: &Path) -> Result<String, String> {
+    let mut file =
+        File::open(path).map_err(|e| format!("Failed to open export for checksum: {}", e))?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let read = file
+            .read(&mut buffer)
+            .map_err(|e| format!("Failed to read export for checksum: {}", e))?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+fn write_header<W: Write>(
+    writer: &mut W,
+    format: ExportFormat,
+    total: usize,
+) -> std::io::Result<()> {
+    match format {
+        ExportFormat::Markdown => {
+            writeln!(writer, "# VoiceTypr Transcriptions")?;
+            writeln!(writer)?;
+            writeln!(
+                writer,
+                "Exported {} with {} entries.",
+                chrono::Local::now().format("%Y-%m-%d %H:%M"),
+                total
+            )?;
+            writeln!(writer)
+        }
+        ExportFormat::Json => write!(
+            writer,
+            "{{\"app\":\"VoiceTypr\",\"exportDate\":{},\"totalTranscriptions\":{},\"transcriptions\":[",
+            serde_json::json!(chrono::Utc::now().to_rfc3339()),
+            total
+        ),
+    }
+}
+
+fn write_entry<W: Write>(
+    writer: &mut W,
+    format: ExportFormat,
+    first: bool,
+    key: &str,
+    entry: &serde_json::Value,
+) -> std::io::Result<()> {
+    match format {
+        ExportFormat::Markdown => write_markdown_entry(writer, key, entry),
+        ExportFormat::Json => {
+            if !first {
+                writer.write_all(b",")?;
+            }
+            serde_json::to_writer(&mut *writer, entry).map_err(std::io::Error::other)
+        }
+    }
+}
+
+/// Run (or resume) the export described by `manifest` until it finishes.
+/// `get` reads an entry, `on_progress` is called after every checkpoint.
+pub fn run_export(
+    dir: &Path,
+    mut manifest: ExportManifest,
+    keys: Vec<String>,
+    get: impl Fn(&str) -> Option<serde_json::Value>,
+    on_progress: impl Fn(&ExportManifest),
+) -> Result<ExportManifest, String> {
+    let write_err = |e: std::io::Error| format!("Failed to write export: {}", e);
+
+    let file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&manifest.dest)
+        .map_err(|e| format!("Failed to open export file: {}", e))?;
+    let existing = file.metadata().map_err(write_err)?.len();
+    if existing < manifest.bytes_written {
+        // The partial file is gone or was cut short; start over
+        log::warn!(
+            "Export file for job {} is shorter than its checkpoint; restarting",
+            manifest.job_id
+        );
+        manifest.bytes_written = 0;
+        manifest.written = 0;
+        manifest.last_key = None;
+    }
+    let keys = pending_keys(keys, manifest.last_key.as_deref());
+    // Drop anything written after the last checkpoint
+    file.set_len(manifest.bytes_written).map_err(write_err)?;
+    let mut writer = BufWriter::new(file);
+    std::io::Seek::seek(&mut writer, std::io::SeekFrom::End(0)).map_err(write_err)?;
+
+    if manifest.bytes_written == 0 {
+        write_header(&mut writer, manifest.format, manifest.total).map_err(write_err)?;
+    }
+
+    manifest.status = ExportStatus::Running;
+    manifest.error = None;
+    for chunk in keys.chunks(EXPORT_CHUNK_SIZE) {
+        for key in chunk {
+            if let Some(entry) = get(key) {
+                write_entry(
+                    &mut writer,
+                    manifest.format,
+                    manifest.written == 0,
+                    key,
+                    &entry,
+                )
+                .map_err(write_err)?;
+                manifest.written += 1;
+            }
+            manifest.last_key = Some(key.clone());
+        }
+        writer.flush().map_err(write_err)?;
+        writer.get_ref().sync_all().map_err(write_err)?;
+        manifest.bytes_written = writer.get_ref().metadata().map_err(write_err)?.len();
+        manifest.total = manifest.total.max(manifest.written);
+        save_manifest(dir, &manifest)?;
+        on_progress(&manifest);
+    }
+
+    if manifest.format == ExportFormat::Json {
+        writer.write_all(b"]}").map_err(write_err)?;
+    }
+    writer.flush().map_err(write_err)?;
+    writer.get_ref().sync_all().map_err(write_err)?;
+    drop(writer);
+
+    manifest.bytes_written = fs::metadata(&manifest.dest).map_err(write_err)?.len();
+    manifest.sha256 = Some(sha256_file(&manifest.dest)?);
+    manifest.status = ExportStatus::Done;
+    save_manifest(dir, &manifest)?;
+    on_progress(&manifest);
+    Ok(manifest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(dest: PathBuf) -> ExportManifest {
+        ExportManifest {
+            job_id: "job-1".to_string(),
+            format: ExportFormat::Json,
+            dest,
+            status: ExportStatus::Running,
+            total: 3,
+            written: 0,
+            bytes_written: 0,
+            last_key: None,
+            sha256: None,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_resumed_export_matches_uninterrupted_export() {
+        let dir = tempfile::tempdir().unwrap();
+        let keys: Vec<String> = (1..=3)
+            .map(|i| format!("2024-01-0{}T00:00:00Z", i))
+            .collect();
+        let get = |key: &str| Some(serde_json::json!({ "text": key }));
+
+        let full = run_export(
+            dir.path(),
+            manifest(dir.path().join("full.json")),
+            keys.clone(),
+            get,
+            |_| {},
+        )
+        .unwrap();
+        let full_contents = fs::read_to_string(&full.dest).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&full_contents).unwrap();
+        assert_eq!(parsed["transcriptions"].as_array().unwrap().len(), 3);
+
+        // Simulate a job cut off after the first entry, with a torn write
+        // past the checkpoint
+        let partial_dest = dir.path().join("partial.json");
+        let mut partial = manifest(partial_dest.clone());
+        partial.job_id = "job-2".to_string();
+        partial = run_export(dir.path(), partial, keys[..1].to_vec(), get, |_| {}).unwrap();
+        let header_and_first = fs::read_to_string(&partial_dest).unwrap();
+        let checkpoint = header_and_first.len() as u64 - 2; // before "]}"
+        fs::write(
+            &partial_dest,
+            format!("{}GARBAGE", &header_and_first[..checkpoint as usize]),
+        )
+        .unwrap();
+        partial.status = ExportStatus::Interrupted;
+        partial.bytes_written = checkpoint;
+        partial.sha256 = None;
+
+        let resumed = run_export(dir.path(), partial, keys.clone(), get, |_| {}).unwrap();
+        assert_eq!(resumed.written, 3);
+        assert_eq!(resumed.status, ExportStatus::Done);
+        let resumed_contents = fs::read_to_string(&partial_dest).unwrap();
+        // Only the export date differs between the two files
+        let strip_date = |s: &str| {
+            s.split_once(",\"totalTranscriptions\"")
+                .unwrap()
+                .1
+                .to_string()
+        };
+        assert_eq!(strip_date(&resumed_contents), strip_date(&full_contents));
+        assert_eq!(resumed.sha256, Some(sha256_file(&partial_dest).unwrap()));
+
+        assert_eq!(
+            load_manifest(dir.path(), "job-2").unwrap().status,
+            ExportStatus::Done
+        );
+        assert!(load_manifest(dir.path(), "../etc").is_err());
+    }
+}
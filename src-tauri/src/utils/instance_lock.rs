@@ -0,0 +1,205 @@
+// Cross-process instance lock. The single-instance plugin already hands a
+// second launch of the same build over to the running one; this lock file in
+// the app data dir also catches launches it can't see (another build of the
+// app sharing the data dir), so two processes never write the stores at once.
+// The lock records the holder's pid and start time, so it is only honored
+// while that exact process is running.
+
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System};
+
+const LOCK_FILE: &str = "instance.lock";
+
+static PRIMARY: AtomicBool = AtomicBool::new(true);
+
+/// Whether this process holds the instance lock
+pub fn is_primary() -> bool {
+    PRIMARY.load(Ordering::SeqCst)
+}
+
+/// Who holds the lock: a pid plus the process start time, so a recycled pid
+/// (any process, even another copy of the app) never passes for the holder
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LockOwner {
+    pub pid: u32,
+    /// Seconds since the epoch; `None` in locks written before it was recorded
+    pub start_time: Option<u64>,
+}
+
+impl LockOwner {
+    fn parse(contents: &str) -> Option<Self> {
+        let mut parts = contents.trim().splitn(2, ':');
+        let pid = parts.next()?.parse().ok()?;
+        let start_time = match parts.next() {
+            Some(start) => Some(start.parse().ok()?),
+            None => None,
+        };
+        Some(Self { pid, start_time })
+    }
+
+    fn token(&self) -> String {
+        match self.start_time {
+            Some(start) => format!("{}:{}", self.pid, start),
+            None => self.pid.to_string(),
+        }
+    }
+
+    /// This process, with the start time the OS reports for it. Looked up
+    /// once so acquire and release always agree.
+    pub fn current() -> Self {
+        static CURRENT: OnceLock<LockOwner> = OnceLock::new();
+        *CURRENT.get_or_init(|| {
+            let pid = std::process::id();
+            Self {
+                pid,
+                start_time: process_start_time(pid),
+            }
+        })
+    }
+}
+
+/// Try to take the lock in `dir` for `owner`. A lock left by an owner that is
+/// no longer running (per `is_alive`) is taken over.
+pub fn try_acquire(
+    dir: &Path,
+    owner: LockOwner,
+    is_alive: impl Fn(LockOwner) -> bool,
+) -> Result<bool, String> {
+    fs::create_dir_all(dir).map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    let path = dir.join(LOCK_FILE);
+
+    match fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)
+    {
+        Ok(mut file) => {
+            write!(file, "{}", owner.token())
+                .map_err(|e| format!("Failed to write instance lock: {}", e))?;
+            return Ok(true);
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {}
+        Err(e) => return Err(format!("Failed to create instance lock: {}", e)),
+    }
+
+    let holder = fs::read_to_string(&path)
+        .ok()
+        .and_then(|contents| LockOwner::parse(&contents));
+    if let Some(holder) = holder {
+        if holder != owner && is_alive(holder) {
+            return Ok(false);
+        }
+    }
+    if holder != Some(owner) {
+        log::info!("Taking over stale instance lock (held by {:?})", holder);
+    }
+    fs::write(&path, owner.token()).map_err(|e| format!("Failed to write instance lock: {}", e))?;
+    Ok(true)
+}
+
+/// Remove the lock if `owner` holds it
+pub fn release(dir: &Path, owner: LockOwner) {
+    let path = dir.join(LOCK_FILE);
+    let held = fs::read_to_string(&path)
+        .ok()
+        .is_some_and(|contents| LockOwner::parse(&contents) == Some(owner));
+    if held {
+        let _ = fs::remove_file(&path);
+    }
+}
+
+fn process_start_time(pid: u32) -> Option<u64> {
+    let pid = sysinfo::Pid::from_u32(pid);
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        true,
+        ProcessRefreshKind::nothing(),
+    );
+    system.process(pid).map(|process| process.start_time())
+}
+
+/// Whether the lock holder is still running: its pid exists and started when
+/// the lock says it did
+fn is_running_owner(owner: LockOwner) -> bool {
+    match (process_start_time(owner.pid), owner.start_time) {
+        (Some(started), Some(expected)) => started == expected,
+        (Some(_), None) => true,
+        (None, _) => false,
+    }
+}
+
+/// Take the lock for this process and record the outcome for `is_primary`
+pub fn acquire_for_current_process(dir: &Path) -> bool {
+    let primary = match try_acquire(dir, LockOwner::current(), is_running_owner) {
+        Ok(primary) => primary,
+        Err(e) => {
+            // Never refuse to start because the lock itself is broken
+            log::warn!("{}", e);
+            true
+        }
+    };
+    PRIMARY.store(primary, Ordering::SeqCst);
+    primary
+}
+
+/// Remove the lock if this process holds it
+pub fn release_for_current_process(dir: &Path) {
+    release(dir, LockOwner::current());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lock_is_exclusive_until_released_or_stale() {
+        let owner = |pid, start| LockOwner {
+            pid,
+            start_time: Some(start),
+        };
+        let dir = tempfile::tempdir().unwrap();
+        assert!(try_acquire(dir.path(), owner(100, 1), |_| true).unwrap());
+        // Held by a live process
+        assert!(!try_acquire(dir.path(), owner(200, 2), |_| true).unwrap());
+        // The holder re-acquiring is fine
+        assert!(try_acquire(dir.path(), owner(100, 1), |_| true).unwrap());
+
+        // Only the holder can release it; the same pid started later isn't it
+        release(dir.path(), owner(200, 2));
+        release(dir.path(), owner(100, 5));
+        assert!(!try_acquire(dir.path(), owner(200, 2), |_| true).unwrap());
+        release(dir.path(), owner(100, 1));
+        assert!(try_acquire(dir.path(), owner(200, 2), |_| true).unwrap());
+
+        // A dead holder's lock is taken over, and liveness sees the start time
+        assert!(
+            try_acquire(dir.path(), owner(300, 3), |holder| holder.start_time
+                != Some(2))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_lock_owner_parses_old_and_new_tokens() {
+        assert_eq!(
+            LockOwner::parse("42:1700000000"),
+            Some(LockOwner {
+                pid: 42,
+                start_time: Some(1_700_000_000)
+            })
+        );
+        assert_eq!(
+            LockOwner::parse("42\n"),
+            Some(LockOwner {
+                pid: 42,
+                start_time: None
+            })
+        );
+        assert_eq!(LockOwner::parse("42:soon"), None);
+    }
+}
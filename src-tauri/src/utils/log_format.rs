@@ -0,0 +1,171 @@
+// Log line formats. Text matches tauri-plugin-log's default layout
+// (`[date][time][LEVEL][target] message`); Json writes one object per line
+// with `ts`, `level`, `target` and `msg` for observability tools. The format
+// can change at runtime, so a single file may contain both.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static JSON_FORMAT: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    Text,
+    Json,
+}
+
+impl LogFormat {
+    /// Parse the stored setting value; unknown values fall back to text
+    pub fn from_setting(value: &str) -> Self {
+        if value.eq_ignore_ascii_case("json") {
+            LogFormat::Json
+        } else {
+            LogFormat::Text
+        }
+    }
+}
+
+pub fn set_log_format(format: LogFormat) {
+    JSON_FORMAT.store(format == LogFormat::Json, Ordering::Relaxed);
+}
+
+pub fn current_log_format() -> LogFormat {
+    if JSON_FORMAT.load(Ordering::Relaxed) {
+        LogFormat::Json
+    } else {
+        LogFormat::Text
+    }
+}
+
+/// Render one record in the current format
+pub fn format_line(
+    now: chrono::DateTime<chrono::Utc>,
+    level: log::Level,
+    target: &str,
+    message: &str,
+) -> String {
+    format_line_as(current_log_format(), now, level, target, message)
+}
+
+/// Render one record in `format`
+pub fn format_line_as(
+    format: LogFormat,
+    now: chrono::DateTime<chrono::Utc>,
+    level: log::Level,
+    target: &str,
+    message: &str,
+) -> String {
+    match format {
+        LogFormat::Json => serde_json::json!({
+            "ts": now.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+            "level": level.as_str(),
+            "target": target,
+            "msg": message,
+        })
+        .to_string(),
+        LogFormat::Text => format!(
+            "{}[{}][{}] {}",
+            now.format("[%Y-%m-%d][%H:%M:%S]"),
+            level,
+            target,
+            message
+        ),
+    }
+}
+
+/// A log line parsed from either format
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    pub ts: String,
+    pub level: String,
+    pub target: String,
+    pub msg: String,
+}
+
+/// Parse a line written in either format. Lines that match neither (e.g. the
+/// continuation of a multi-line message) come back as `None`.
+pub fn parse_log_line(line: &str) -> Option<LogEntry> {
+    let line = line.trim_end();
+    if line.starts_with('{') {
+        let value: serde_json::Value = serde_json::from_str(line).ok()?;
+        let field = |name: &str| value.get(name).and_then(|v| v.as_str()).map(str::to_string);
+        return Some(LogEntry {
+            ts: field("ts")?,
+            level: field("level")?,
+            target: field("target").unwrap_or_default(),
+            msg: field("msg")?,
+        });
+    }
+
+    // [2024-01-01][12:00:00][INFO][target] message
+    let mut rest = line;
+    let mut fields = Vec::with_capacity(4);
+    for _ in 0..4 {
+        let inner = rest.strip_prefix('[')?;
+        let end = inner.find(']')?;
+        fields.push(&inner[..end]);
+        rest = &inner[end + 1..];
+    }
+    // Older lines may carry the target before the level
+    let (level, target) = if fields[2].parse::<log::Level>().is_ok() {
+        (fields[2], fields[3])
+    } else {
+        (fields[3], fields[2])
+    };
+    Some(LogEntry {
+        ts: format!("{} {}", fields[0], fields[1]),
+        level: level.to_string(),
+        target: target.to_string(),
+        msg: rest.strip_prefix(' ').unwrap_or(rest).to_string(),
+    })
+}
+
+/// Whether `entry` is at `min_level` or more severe (e.g. "warn" keeps WARN and ERROR)
+pub fn meets_level(entry: &LogEntry, min_level: &str) -> bool {
+    match (
+        entry.level.parse::<log::Level>(),
+        min_level.parse::<log::Level>(),
+    ) {
+        (Ok(level), Ok(min)) => level <= min,
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_log_line_reads_both_formats() {
+        let text =
+            parse_log_line("[2024-01-01][12:00:00][INFO][voicetypr_lib::audio] Recording started")
+                .unwrap();
+        assert_eq!(text.ts, "2024-01-01 12:00:00");
+        assert_eq!(text.level, "INFO");
+        assert_eq!(text.target, "voicetypr_lib::audio");
+        assert_eq!(text.msg, "Recording started");
+
+        let json = parse_log_line(
+            r#"{"ts":"2024-01-01T12:00:00.000Z","level":"WARN","target":"app","msg":"Low disk [space]"}"#,
+        )
+        .unwrap();
+        assert_eq!(json.level, "WARN");
+        assert_eq!(json.msg, "Low disk [space]");
+
+        assert_eq!(parse_log_line("  continuation of a message"), None);
+    }
+
+    #[test]
+    fn test_format_line_round_trips() {
+        let now = chrono::Utc::now();
+        for format in [LogFormat::Json, LogFormat::Text] {
+            let line = format_line_as(format, now, log::Level::Error, "voicetypr_lib", "Boom [1]");
+            let entry = parse_log_line(&line).unwrap();
+            assert_eq!(entry.level, "ERROR");
+            assert_eq!(entry.msg, "Boom [1]");
+        }
+
+        let entry = parse_log_line("[2024-01-01][12:00:00][DEBUG][app] x").unwrap();
+        assert!(!meets_level(&entry, "info"));
+        assert!(meets_level(&entry, "trace"));
+    }
+}
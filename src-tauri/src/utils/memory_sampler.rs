@@ -0,0 +1,118 @@
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System};
+
+/// How often the sampler reads the process RSS while a transcription runs
+pub const DEFAULT_SAMPLE_INTERVAL: Duration = Duration::from_millis(100);
+
+const BYTES_PER_MB: u64 = 1_048_576;
+
+/// Resident set size of the current process in bytes
+fn sample_rss(system: &mut System, pid: sysinfo::Pid) -> Option<u64> {
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        true,
+        ProcessRefreshKind::nothing().with_memory(),
+    );
+    system.process(pid).map(|p| p.memory())
+}
+
+/// Samples this process's memory on a background thread and keeps the peak.
+/// Lightweight enough to run around every transcription.
+pub struct MemorySampler {
+    stop: Arc<AtomicBool>,
+    peak_bytes: Arc<AtomicU64>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MemorySampler {
+    pub fn start(interval: Duration) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let peak_bytes = Arc::new(AtomicU64::new(0));
+
+        let handle = match sysinfo::get_current_pid() {
+            Ok(pid) => {
+                let stop = stop.clone();
+                let peak_bytes = peak_bytes.clone();
+                Some(thread::spawn(move || {
+                    let mut system = System::new();
+                    loop {
+                        if let Some(rss) = sample_rss(&mut system, pid) {
+                            peak_bytes.fetch_max(rss, Ordering::Relaxed);
+                        }
+                        if stop.load(Ordering::Relaxed) {
+                            break;
+                        }
+                        thread::park_timeout(interval);
+                    }
+                }))
+            }
+            Err(e) => {
+                log::warn!("Memory sampling unavailable: {}", e);
+                None
+            }
+        };
+
+        Self {
+            stop,
+            peak_bytes,
+            handle,
+        }
+    }
+
+    /// Stop sampling and return the peak RSS in MB, if any sample was taken
+    pub fn finish(mut self) -> Option<u64> {
+        self.stop_thread();
+        match self.peak_bytes.load(Ordering::Relaxed) {
+            0 => None,
+            bytes => Some(bytes_to_mb(bytes)),
+        }
+    }
+
+    fn stop_thread(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            handle.thread().unpark();
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for MemorySampler {
+    fn drop(&mut self) {
+        self.stop_thread();
+    }
+}
+
+pub fn bytes_to_mb(bytes: u64) -> u64 {
+    bytes / BYTES_PER_MB
+}
+
+/// Whether a measured peak is over the configured cap (0 disables the cap)
+pub fn exceeds_memory_cap(peak_mb: u64, max_memory_mb: u64) -> bool {
+    max_memory_mb > 0 && peak_mb > max_memory_mb
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exceeds_memory_cap() {
+        assert!(!exceeds_memory_cap(4096, 0));
+        assert!(!exceeds_memory_cap(1024, 1024));
+        assert!(exceeds_memory_cap(1025, 1024));
+    }
+
+    #[test]
+    fn test_sampler_reports_peak() {
+        let sampler = MemorySampler::start(Duration::from_millis(5));
+        thread::sleep(Duration::from_millis(20));
+        // Sampling can be unavailable in restricted sandboxes
+        if let Some(peak_mb) = sampler.finish() {
+            assert!(peak_mb > 0);
+        }
+    }
+}
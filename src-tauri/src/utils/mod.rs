@@ -1,7 +1,27 @@
 // Utility modules
+pub mod active_app;
+pub mod auto_cleanup;
+pub mod crash_recovery;
+pub mod crash_report;
+pub mod data_dir;
 pub mod diagnostics;
 pub mod display_watcher;
+pub mod download_queue;
+pub mod history_export;
+pub mod instance_lock;
+pub mod log_format;
 pub mod logger;
+pub mod memory_sampler;
+pub mod model_suggestions;
 pub mod network_diagnostics;
+pub mod notifications;
 pub mod onboarding_logger;
+pub mod proxy;
+pub mod recordings;
+pub mod secure_delete;
+pub mod settings_watcher;
 pub mod system_monitor;
+pub mod telemetry;
+pub mod transcription_eta;
+pub mod usage_csv;
+pub mod writable_paths;
@@ -0,0 +1,285 @@
+// Learns which model transcribes best under the user's typical conditions.
+// Each recording is bucketed by duration and background noise; per bucket we
+// keep a running mean of each model's confidence. The suggestion is the model
+// with the best mean, with the measured realtime factor breaking near-ties.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tauri::AppHandle;
+
+use crate::simple_cache;
+
+const SUGGESTIONS_CACHE_KEY: &str = "model_suggestions";
+const LAST_CONDITIONS_CACHE_KEY: &str = "model_suggestions_last_conditions";
+
+/// Results needed before a model is trusted for a bucket
+pub const MIN_SAMPLES: u32 = 3;
+
+/// Confidence gap under which the faster model wins
+const CONFIDENCE_TIE: f32 = 0.02;
+
+/// Frame used to measure the noise floor (30 ms at 16 kHz)
+const NOISE_FRAME: usize = 480;
+/// Share of the quietest frames taken as background noise
+const NOISE_FRAME_FRACTION: f32 = 0.1;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum DurationBucket {
+    Short,
+    Medium,
+    Long,
+}
+
+impl DurationBucket {
+    pub fn from_secs(secs: f64) -> Self {
+        if secs < 10.0 {
+            DurationBucket::Short
+        } else if secs < 60.0 {
+            DurationBucket::Medium
+        } else {
+            DurationBucket::Long
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum NoiseLevel {
+    Quiet,
+    Moderate,
+    Noisy,
+}
+
+impl NoiseLevel {
+    /// Classify a noise floor given in dBFS
+    pub fn from_dbfs(dbfs: f32) -> Self {
+        if dbfs < -50.0 {
+            NoiseLevel::Quiet
+        } else if dbfs < -35.0 {
+            NoiseLevel::Moderate
+        } else {
+            NoiseLevel::Noisy
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AudioConditions {
+    pub duration: DurationBucket,
+    pub noise: NoiseLevel,
+}
+
+impl AudioConditions {
+    pub fn key(&self) -> String {
+        format!("{:?}:{:?}", self.duration, self.noise).to_lowercase()
+    }
+
+    /// Conditions from a duration and a noise floor measured elsewhere (the
+    /// recorder tracks it while capturing)
+    pub fn new(secs: f64, noise_floor_dbfs: f32) -> Self {
+        Self {
+            duration: DurationBucket::from_secs(secs),
+            noise: NoiseLevel::from_dbfs(noise_floor_dbfs),
+        }
+    }
+
+    pub fn from_samples(samples: &[f32], sample_rate: u32) -> Self {
+        let secs = samples.len() as f64 / sample_rate.max(1) as f64;
+        Self::new(secs, noise_floor_dbfs(samples))
+    }
+}
+
+/// RMS level of the quietest frames in dBFS, i.e. the background noise
+pub fn noise_floor_dbfs(samples: &[f32]) -> f32 {
+    noise_floor_from_levels(
+        samples
+            .chunks(NOISE_FRAME)
+            .filter(|frame| frame.len() == NOISE_FRAME)
+            .map(|frame| (frame.iter().map(|s| s * s).sum::<f32>() / frame.len() as f32).sqrt())
+            .collect(),
+    )
+}
+
+/// `noise_floor_dbfs` from per-frame RMS levels
+pub fn noise_floor_from_levels(mut levels: Vec<f32>) -> f32 {
+    if levels.is_empty() {
+        return f32::NEG_INFINITY;
+    }
+    levels.sort_by(|a, b| a.total_cmp(b));
+    let count = ((levels.len() as f32 * NOISE_FRAME_FRACTION).ceil() as usize).max(1);
+    let floor = levels[..count].iter().sum::<f32>() / count as f32;
+    20.0 * floor.max(1e-9).log10()
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct ModelScore {
+    pub mean_confidence: f32,
+    pub samples: u32,
+}
+
+impl ModelScore {
+    pub fn add(self, confidence: f32) -> Self {
+        let samples = self.samples.saturating_add(1);
+        Self {
+            mean_confidence: self.mean_confidence
+                + (confidence - self.mean_confidence) / samples as f32,
+            samples,
+        }
+    }
+}
+
+/// Conditions bucket -> model -> score
+pub type SuggestionTable = HashMap<String, HashMap<String, ModelScore>>;
+
+/// Best model for a bucket among `candidates`. `realtime_factor` breaks ties
+/// in favor of the faster model.
+pub fn best_model(
+    scores: &HashMap<String, ModelScore>,
+    candidates: &[String],
+    realtime_factor: impl Fn(&str) -> f64,
+) -> Option<String> {
+    let mut ranked: Vec<(&String, &ModelScore)> = scores
+        .iter()
+        .filter(|(model, score)| score.samples >= MIN_SAMPLES && candidates.contains(model))
+        .collect();
+    ranked.sort_by(|a, b| b.1.mean_confidence.total_cmp(&a.1.mean_confidence));
+    let (_, best) = ranked.first()?;
+
+    ranked
+        .iter()
+        .filter(|(_, score)| best.mean_confidence - score.mean_confidence <= CONFIDENCE_TIE)
+        .min_by(|a, b| realtime_factor(a.0).total_cmp(&realtime_factor(b.0)))
+        .map(|(model, _)| model.to_string())
+}
+
+fn load_table(app: &AppHandle) -> SuggestionTable {
+    simple_cache::get(app, SUGGESTIONS_CACHE_KEY)
+        .ok()
+        .flatten()
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default()
+}
+
+fn save_json(app: &AppHandle, key: &str, value: impl Serialize) {
+    match serde_json::to_value(value) {
+        Ok(value) => {
+            if let Err(e) = simple_cache::set(app, key, value, None) {
+                log::warn!("Failed to save model suggestion data: {}", e);
+            }
+        }
+        Err(e) => log::warn!("Failed to serialize model suggestion data: {}", e),
+    }
+}
+
+/// Remember the conditions of the latest recording as the "current" ones
+pub fn record_conditions(app: &AppHandle, conditions: AudioConditions) {
+    save_json(app, LAST_CONDITIONS_CACHE_KEY, conditions);
+}
+
+pub fn last_conditions(app: &AppHandle) -> Option<AudioConditions> {
+    simple_cache::get(app, LAST_CONDITIONS_CACHE_KEY)
+        .ok()
+        .flatten()
+        .and_then(|value| serde_json::from_value(value).ok())
+}
+
+/// Fold a transcription's confidence into the model's score for its bucket
+pub fn record_result(app: &AppHandle, conditions: AudioConditions, model: &str, confidence: f32) {
+    if !confidence.is_finite() {
+        return;
+    }
+    let mut table = load_table(app);
+    let scores = table.entry(conditions.key()).or_default();
+    let updated = match scores.get(model) {
+        Some(score) => score.add(confidence),
+        None => ModelScore {
+            mean_confidence: confidence,
+            samples: 1,
+        },
+    };
+    scores.insert(model.to_string(), updated);
+    save_json(app, SUGGESTIONS_CACHE_KEY, &table);
+}
+
+/// Suggested model for the latest recording's conditions, among `candidates`
+pub fn suggest_model(app: &AppHandle, candidates: &[String]) -> Option<String> {
+    let conditions = last_conditions(app)?;
+    let table = load_table(app);
+    let scores = table.get(&conditions.key())?;
+    best_model(scores, candidates, |model| {
+        crate::utils::transcription_eta::realtime_factor_for(app, "whisper", model)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(mean_confidence: f32, samples: u32) -> ModelScore {
+        ModelScore {
+            mean_confidence,
+            samples,
+        }
+    }
+
+    #[test]
+    fn test_conditions_bucket_duration_and_noise() {
+        let quiet = vec![0.0005f32; 16000 * 5];
+        let conditions = AudioConditions::from_samples(&quiet, 16000);
+        assert_eq!(conditions.duration, DurationBucket::Short);
+        assert_eq!(conditions.noise, NoiseLevel::Quiet);
+        assert_eq!(conditions.key(), "short:quiet");
+
+        let noisy = vec![0.1f32; 16000 * 30];
+        let conditions = AudioConditions::from_samples(&noisy, 16000);
+        assert_eq!(conditions.duration, DurationBucket::Medium);
+        assert_eq!(conditions.noise, NoiseLevel::Noisy);
+
+        // Levels tracked by the recorder: the quietest tenth sets the floor
+        let mut levels = vec![0.2f32; 90];
+        levels.extend(vec![0.0001f32; 10]);
+        let floor = noise_floor_from_levels(levels);
+        assert!((floor + 80.0).abs() < 0.01);
+        assert_eq!(AudioConditions::new(75.0, floor).key(), "long:quiet");
+    }
+
+    #[test]
+    fn test_best_model_prefers_confidence_then_speed() {
+        let candidates = vec![
+            "base.en".to_string(),
+            "small.en".to_string(),
+            "large-v3".to_string(),
+        ];
+        let mut scores = HashMap::new();
+        scores.insert("base.en".to_string(), score(0.80, 5));
+        scores.insert("small.en".to_string(), score(0.91, 5));
+        scores.insert("large-v3".to_string(), score(0.92, 5));
+        let speed = |model: &str| if model == "large-v3" { 1.0 } else { 0.2 };
+
+        // small.en is within the tie margin of large-v3 and faster
+        assert_eq!(
+            best_model(&scores, &candidates, speed).as_deref(),
+            Some("small.en")
+        );
+
+        // Too few samples, or not installed, and the model isn't considered
+        scores.insert("small.en".to_string(), score(0.91, 1));
+        assert_eq!(
+            best_model(&scores, &candidates, speed).as_deref(),
+            Some("large-v3")
+        );
+        assert_eq!(
+            best_model(&scores, &candidates[..1], speed).as_deref(),
+            Some("base.en")
+        );
+        assert_eq!(best_model(&HashMap::new(), &candidates, speed), None);
+    }
+
+    #[test]
+    fn test_model_score_keeps_running_mean() {
+        let updated = score(0.5, 1).add(0.9);
+        assert!((updated.mean_confidence - 0.7).abs() < 1e-6);
+        assert_eq!(updated.samples, 2);
+    }
+}
@@ -0,0 +1,118 @@
+use chrono::NaiveTime;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_notification::NotificationExt;
+use tauri_plugin_store::StoreExt;
+
+/// Maximum characters of the transcription shown in the notification body
+const SNIPPET_MAX_CHARS: usize = 120;
+
+/// Check whether `now` falls inside a quiet-hours window given as "HH:MM" strings.
+/// Windows that wrap past midnight (e.g. 22:00-07:00) are supported.
+pub fn is_within_quiet_hours(now: NaiveTime, start: &str, end: &str) -> bool {
+    let (Ok(start), Ok(end)) = (
+        NaiveTime::parse_from_str(start, "%H:%M"),
+        NaiveTime::parse_from_str(end, "%H:%M"),
+    ) else {
+        return false;
+    };
+
+    if start <= end {
+        now >= start && now < end
+    } else {
+        now >= start || now < end
+    }
+}
+
+/// Shorten text for display in a notification body
+pub fn notification_snippet(text: &str) -> String {
+    let trimmed = text.trim();
+    if trimmed.chars().count() <= SNIPPET_MAX_CHARS {
+        return trimmed.to_string();
+    }
+    let cut: String = trimmed.chars().take(SNIPPET_MAX_CHARS).collect();
+    format!("{}…", cut.trim_end())
+}
+
+/// Show a native notification for a finished transcription when enabled,
+/// the main window isn't focused, and we're outside quiet hours.
+pub fn notify_transcription_complete(app: &AppHandle, text: &str) {
+    let Ok(store) = app.store("settings") else {
+        return;
+    };
+
+    let enabled = store
+        .get("notify_on_complete")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    if !enabled || text.trim().is_empty() {
+        return;
+    }
+
+    let app_focused = app
+        .get_webview_window("main")
+        .and_then(|w| w.is_focused().ok())
+        .unwrap_or(false);
+    if app_focused {
+        return;
+    }
+
+    let quiet_start = store
+        .get("quiet_hours_start")
+        .and_then(|v| v.as_str().map(|s| s.to_string()));
+    let quiet_end = store
+        .get("quiet_hours_end")
+        .and_then(|v| v.as_str().map(|s| s.to_string()));
+    if let (Some(start), Some(end)) = (quiet_start, quiet_end) {
+        if is_within_quiet_hours(chrono::Local::now().time(), &start, &end) {
+            log::debug!("Skipping completion notification during quiet hours");
+            return;
+        }
+    }
+
+    if let Err(e) = app
+        .notification()
+        .builder()
+        .title("Transcription complete")
+        .body(notification_snippet(text))
+        .show()
+    {
+        log::warn!("Failed to show completion notification: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn t(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    #[test]
+    fn test_quiet_hours_same_day_window() {
+        assert!(is_within_quiet_hours(t(13, 0), "12:00", "14:00"));
+        assert!(!is_within_quiet_hours(t(14, 0), "12:00", "14:00"));
+        assert!(!is_within_quiet_hours(t(9, 0), "12:00", "14:00"));
+    }
+
+    #[test]
+    fn test_quiet_hours_wrapping_midnight() {
+        assert!(is_within_quiet_hours(t(23, 30), "22:00", "07:00"));
+        assert!(is_within_quiet_hours(t(3, 0), "22:00", "07:00"));
+        assert!(!is_within_quiet_hours(t(12, 0), "22:00", "07:00"));
+    }
+
+    #[test]
+    fn test_quiet_hours_invalid_values_never_match() {
+        assert!(!is_within_quiet_hours(t(3, 0), "late", "07:00"));
+    }
+
+    #[test]
+    fn test_notification_snippet_truncates_long_text() {
+        let long = "word ".repeat(100);
+        let snippet = notification_snippet(&long);
+        assert!(snippet.ends_with('…'));
+        assert!(snippet.chars().count() <= SNIPPET_MAX_CHARS + 1);
+        assert_eq!(notification_snippet("  short  "), "short");
+    }
+}
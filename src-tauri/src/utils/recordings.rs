@@ -0,0 +1,656 @@
+// Recordings directory bookkeeping. Every recording gets a JSON sidecar
+// (`<name>.json`) written next to it; a recording only counts as finalized
+// once its sidecar names the transcription it belongs to. Anything else left
+// behind (a crash mid-transcription, a sidecar without audio) is an orphan.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Unfinalized recordings younger than this may still be mid-transcription
+pub const ORPHAN_GRACE_PERIOD: Duration = Duration::from_secs(60 * 60);
+
+/// History entry field naming the directory a recording was archived to
+pub const ARCHIVE_DIR_FIELD: &str = "recording_archive_dir";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RecordingSidecar {
+    pub duration_secs: f64,
+    pub sample_rate: u32,
+    /// History entry id; `None` until the transcription has been saved
+    #[serde(default)]
+    pub transcription_id: Option<String>,
+    pub created_at: String,
+    /// Salvaged after a crash; kept for transcription instead of swept as an orphan
+    #[serde(default)]
+    pub recovered: bool,
+}
+
+impl RecordingSidecar {
+    pub fn is_finalized(&self) -> bool {
+        self.transcription_id.is_some()
+    }
+}
+
+pub fn sidecar_path(recording: &Path) -> PathBuf {
+    recording.with_extension("json")
+}
+
+fn is_recording(path: &Path) -> bool {
+    path.is_file() && path.extension().and_then(|e| e.to_str()) == Some("wav")
+}
+
+pub fn read_sidecar(recording: &Path) -> Option<RecordingSidecar> {
+    let contents = std::fs::read_to_string(sidecar_path(recording)).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Write through a temp file and rename so a crash never leaves a half-written sidecar
+pub fn write_sidecar(recording: &Path, sidecar: &RecordingSidecar) -> Result<(), String> {
+    let path = sidecar_path(recording);
+    let tmp_path = path.with_extension("json.tmp");
+    let contents = serde_json::to_vec_pretty(sidecar)
+        .map_err(|e| format!("Failed to serialize recording metadata: {}", e))?;
+    std::fs::write(&tmp_path, contents)
+        .map_err(|e| format!("Failed to write recording metadata: {}", e))?;
+    std::fs::rename(&tmp_path, &path)
+        .map_err(|e| format!("Failed to save recording metadata: {}", e))
+}
+
+/// Describe a freshly captured recording; it stays unfinalized until linked
+pub fn write_pending_sidecar(recording: &Path) -> Result<RecordingSidecar, String> {
+    let reader = hound::WavReader::open(recording)
+        .map_err(|e| format!("Failed to read recording header: {}", e))?;
+    let spec = reader.spec();
+    let duration_secs = if spec.sample_rate > 0 {
+        reader.duration() as f64 / spec.sample_rate as f64
+    } else {
+        0.0
+    };
+
+    let sidecar = RecordingSidecar {
+        duration_secs,
+        sample_rate: spec.sample_rate,
+        transcription_id: None,
+        created_at: chrono::Utc::now().to_rfc3339(),
+        recovered: false,
+    };
+    write_sidecar(recording, &sidecar)?;
+    Ok(sidecar)
+}
+
+/// Mark a recording salvaged after a crash so orphan cleanup leaves it alone
+pub fn write_recovered_sidecar(recording: &Path) -> Result<(), String> {
+    let mut sidecar = write_pending_sidecar(recording)?;
+    sidecar.recovered = true;
+    write_sidecar(recording, &sidecar)
+}
+
+/// Link a recording to its saved history entry, marking it finalized
+pub fn finalize_recording(recording: &Path, transcription_id: &str) -> Result<(), String> {
+    let mut sidecar = match read_sidecar(recording) {
+        Some(sidecar) => sidecar,
+        None => write_pending_sidecar(recording)?,
+    };
+    sidecar.transcription_id = Some(transcription_id.to_string());
+    write_sidecar(recording, &sidecar)
+}
+
+/// Recordings salvaged after a crash that are still waiting to be transcribed
+/// or discarded, oldest first
+pub fn pending_recovered(dir: &Path) -> Vec<(PathBuf, RecordingSidecar)> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut pending: Vec<(PathBuf, RecordingSidecar)> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| is_recording(path))
+        .filter_map(|path| {
+            let sidecar = read_sidecar(&path)?;
+            (sidecar.recovered && !sidecar.is_finalized()).then_some((path, sidecar))
+        })
+        .collect();
+    pending.sort_by(|a, b| a.1.created_at.cmp(&b.1.created_at));
+    pending
+}
+
+/// Delete a recording together with its sidecar, wiping them first when
+/// secure delete is enabled
+pub fn remove_recording(recording: &Path) {
+    for path in [recording.to_path_buf(), sidecar_path(recording)] {
+        if let Err(e) = crate::utils::secure_delete::remove_file(&path) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                log::warn!("Failed to remove {:?}: {}", path, e);
+            }
+        }
+    }
+}
+
+/// `path` with either separator rewritten to this platform's, for paths
+/// stored on another OS (e.g. a macOS backup restored on Windows)
+pub fn normalize_separators(path: &str) -> String {
+    let foreign = if std::path::MAIN_SEPARATOR == '/' {
+        '\\'
+    } else {
+        '/'
+    };
+    path.replace(foreign, std::path::MAIN_SEPARATOR_STR)
+}
+
+/// File name of the recording kept for a history entry. Older or restored
+/// entries may hold a full path; only its last component is used, since
+/// recordings always live directly in the recordings or archive directory.
+pub fn recording_file_name(entry: &serde_json::Value) -> Option<String> {
+    let stored = entry.get("recording_file")?.as_str()?;
+    stored
+        .rsplit(['/', '\\'])
+        .next()
+        .filter(|name| is_plain_file_name(name))
+        .map(str::to_string)
+}
+
+/// A single path component that can't climb out of its directory
+pub fn is_plain_file_name(name: &str) -> bool {
+    !name.is_empty() && name != "." && name != ".." && !name.contains(['/', '\\'])
+}
+
+/// Drop the recording links from an imported entry. Its recording lives on
+/// the machine it came from; a matching name here belongs to another entry,
+/// and deleting the imported one would remove that entry's audio.
+pub fn sanitize_imported_recording(entry: &mut serde_json::Value) {
+    if let Some(fields) = entry.as_object_mut() {
+        fields.remove(ARCHIVE_DIR_FIELD);
+        fields.remove("recording_file");
+    }
+}
+
+/// Directory holding the recording kept for a history entry: the archive
+/// directory it was moved to, or the recordings directory
+pub fn recording_location(recordings_dir: &Path, entry: &serde_json::Value) -> PathBuf {
+    entry
+        .get(ARCHIVE_DIR_FIELD)
+        .and_then(|v| v.as_str())
+        .map(|dir| PathBuf::from(normalize_separators(dir)))
+        .unwrap_or_else(|| recordings_dir.to_path_buf())
+}
+
+/// Delete the recording kept for a history entry, if any, with its sidecar
+pub fn remove_entry_recording(recordings_dir: &Path, entry: &serde_json::Value) {
+    if let Some(file) = recording_file_name(entry) {
+        remove_recording(&recording_location(recordings_dir, entry).join(file));
+    }
+}
+
+/// Drop the recording fields from history entries whose recording no longer
+/// exists (e.g. removed by retention). Returns how many entries changed.
+pub fn unlink_missing_recordings(app: &tauri::AppHandle, dir: &Path) -> u32 {
+    let Ok(store) = crate::utils::data_dir::transcriptions_store(app) else {
+        return 0;
+    };
+
+    let mut unlinked = 0;
+    for (id, mut entry) in store.entries() {
+        let Some(file) = recording_file_name(&entry) else {
+            continue;
+        };
+        if recording_location(dir, &entry).join(&file).exists() {
+            continue;
+        }
+        if let Some(fields) = entry.as_object_mut() {
+            fields.remove("recording_file");
+            fields.remove(ARCHIVE_DIR_FIELD);
+        }
+        store.set(id, entry);
+        unlinked += 1;
+    }
+    if unlinked > 0 {
+        if let Err(e) = store.save() {
+            log::warn!("Failed to save history after unlinking recordings: {}", e);
+        }
+    }
+    unlinked
+}
+
+/// Rewrite an entry's stored recording paths to what `recording_file_name`
+/// and `recording_location` resolve. Returns whether anything changed.
+pub fn normalize_entry_paths(entry: &mut serde_json::Value) -> bool {
+    let file_name = recording_file_name(entry);
+    let archive_dir = entry
+        .get(ARCHIVE_DIR_FIELD)
+        .and_then(|v| v.as_str())
+        .map(normalize_separators);
+    let Some(fields) = entry.as_object_mut() else {
+        return false;
+    };
+
+    let mut changed = false;
+    for (field, normalized) in [
+        ("recording_file", file_name),
+        (ARCHIVE_DIR_FIELD, archive_dir),
+    ] {
+        if let Some(normalized) = normalized {
+            if fields.get(field).and_then(|v| v.as_str()) != Some(normalized.as_str()) {
+                fields.insert(field.to_string(), normalized.into());
+                changed = true;
+            }
+        }
+    }
+    changed
+}
+
+/// Rename, falling back to copy and delete when `to` is on another volume
+fn move_file(from: &Path, to: &Path) -> std::io::Result<()> {
+    if std::fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    std::fs::copy(from, to)?;
+    std::fs::File::open(to)?.sync_all()?;
+    crate::utils::secure_delete::remove_file(from)
+}
+
+/// Result of `archive_recordings`. A failed file doesn't stop the rest, so
+/// the caller can still update history for everything that moved.
+#[derive(Debug, Default, PartialEq)]
+pub struct ArchiveOutcome {
+    /// File names moved to the archive
+    pub moved: Vec<String>,
+    /// File names that couldn't be moved, with the error
+    pub failed: Vec<(String, String)>,
+}
+
+/// Move finalized recordings last modified before `cutoff`, with their
+/// sidecars, from `dir` to `archive_dir`. Files already present in the
+/// archive are left alone.
+pub fn archive_recordings(
+    dir: &Path,
+    archive_dir: &Path,
+    cutoff: SystemTime,
+) -> Result<ArchiveOutcome, String> {
+    let mut outcome = ArchiveOutcome::default();
+    if !dir.exists() {
+        return Ok(outcome);
+    }
+    std::fs::create_dir_all(archive_dir)
+        .map_err(|e| format!("Failed to create archive directory: {}", e))?;
+
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| format!("Failed to read recordings directory: {}", e))?;
+    for path in entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+    {
+        let eligible = is_recording(&path)
+            && modified(&path).is_some_and(|m| m < cutoff)
+            && read_sidecar(&path).is_some_and(|s| s.is_finalized());
+        let Some(file_name) = path.file_name().filter(|_| eligible) else {
+            continue;
+        };
+        let target = archive_dir.join(file_name);
+        if target.exists() {
+            log::warn!("Not archiving {:?}: already in the archive", file_name);
+            continue;
+        }
+        let name = file_name.to_string_lossy().to_string();
+        if let Err(e) = move_file(&path, &target) {
+            log::warn!("Failed to archive {:?}: {}", file_name, e);
+            outcome.failed.push((name, e.to_string()));
+            continue;
+        }
+        if let Err(e) = move_file(&sidecar_path(&path), &sidecar_path(&target)) {
+            log::warn!("Failed to archive metadata for {:?}: {}", file_name, e);
+        }
+        outcome.moved.push(name);
+    }
+    outcome.moved.sort();
+    Ok(outcome)
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Recordings without a finalized (or recovered) sidecar, and sidecars whose
+/// recording is gone.
+/// Recordings modified after `newer_than_cutoff` are skipped as possibly in flight.
+pub fn find_orphans(dir: &Path, newer_than_cutoff: SystemTime) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut orphans = Vec::new();
+    for path in entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+    {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("wav") if path.is_file() => {
+                let kept = read_sidecar(&path).is_some_and(|s| s.is_finalized() || s.recovered);
+                let settled = modified(&path).is_some_and(|m| m < newer_than_cutoff);
+                if !kept && settled {
+                    orphans.push(path);
+                }
+            }
+            Some("json") if !path.with_extension("wav").exists() => orphans.push(path),
+            _ => {}
+        }
+    }
+    orphans.sort();
+    orphans
+}
+
+/// Apply retention to a recordings directory: finalized recordings older than
+/// `cutoff` and orphans past the grace period are deleted with their sidecars.
+/// Archived recordings live outside `dir` and are kept.
+/// Returns how many recordings were removed.
+pub fn apply_retention(dir: &Path, cutoff: Option<SystemTime>) -> Result<u32, String> {
+    if !dir.exists() {
+        return Ok(0);
+    }
+
+    let grace_cutoff = SystemTime::now()
+        .checked_sub(ORPHAN_GRACE_PERIOD)
+        .unwrap_or(SystemTime::UNIX_EPOCH);
+    let mut deleted = 0;
+
+    for orphan in find_orphans(dir, grace_cutoff) {
+        if is_recording(&orphan) {
+            log::info!("Removing orphaned recording {:?}", orphan.file_name());
+            remove_recording(&orphan);
+            deleted += 1;
+        } else {
+            let _ = crate::utils::secure_delete::remove_file(&orphan);
+        }
+    }
+
+    if let Some(cutoff) = cutoff {
+        let entries = std::fs::read_dir(dir)
+            .map_err(|e| format!("Failed to read recordings directory: {}", e))?;
+        for path in entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+        {
+            if is_recording(&path) && modified(&path).is_some_and(|m| m < cutoff) {
+                remove_recording(&path);
+                deleted += 1;
+            }
+        }
+    }
+
+    Ok(deleted)
+}
+
+/// Finalize recordings whose history entry was saved but whose sidecar wasn't
+/// updated (e.g. the app quit in between). Returns how many were repaired.
+pub fn reconcile_with_history(app: &tauri::AppHandle, dir: &Path) -> u32 {
+    let Ok(store) = crate::utils::data_dir::transcriptions_store(app) else {
+        return 0;
+    };
+
+    let mut repaired = 0;
+    for (id, entry) in store.entries() {
+        let Some(file) = recording_file_name(&entry) else {
+            continue;
+        };
+        let recording = recording_location(dir, &entry).join(&file);
+        if !recording.exists() || read_sidecar(&recording).is_some_and(|s| s.is_finalized()) {
+            continue;
+        }
+        match finalize_recording(&recording, &id) {
+            Ok(()) => repaired += 1,
+            Err(e) => log::warn!("Failed to finalize recording {}: {}", file, e),
+        }
+    }
+    repaired
+}
+
+/// Orphans in the recordings directory, including finalized recordings whose
+/// history entry has since been deleted
+pub fn orphaned_recordings(app: &tauri::AppHandle, dir: &Path) -> Vec<PathBuf> {
+    let grace_cutoff = SystemTime::now()
+        .checked_sub(ORPHAN_GRACE_PERIOD)
+        .unwrap_or(SystemTime::UNIX_EPOCH);
+    let mut orphans = find_orphans(dir, grace_cutoff);
+
+    if let (Ok(store), Ok(entries)) = (
+        crate::utils::data_dir::transcriptions_store(app),
+        std::fs::read_dir(dir),
+    ) {
+        for path in entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+        {
+            let unlinked = is_recording(&path)
+                && read_sidecar(&path)
+                    .and_then(|s| s.transcription_id)
+                    .is_some_and(|id| !store.has(&id));
+            if unlinked {
+                orphans.push(path);
+            }
+        }
+    }
+    orphans.sort();
+    orphans
+}
+
+/// History entries scored below `threshold` whose kept recording still
+/// exists, oldest first, with the recording's path
+pub fn low_confidence_recordings(
+    dir: &Path,
+    entries: Vec<(String, serde_json::Value)>,
+    threshold: f32,
+) -> Vec<(String, PathBuf)> {
+    let mut found: Vec<(String, PathBuf)> = entries
+        .into_iter()
+        .filter(|(_, entry)| {
+            entry
+                .get("confidence")
+                .and_then(|v| v.as_f64())
+                .is_some_and(|confidence| confidence < threshold as f64)
+        })
+        .filter_map(|(key, entry)| {
+            let file = recording_file_name(&entry)?;
+            let recording = recording_location(dir, &entry).join(file);
+            recording.exists().then_some((key, recording))
+        })
+        .collect();
+    found.sort();
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_wav(path: &Path) {
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: 16000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(path, spec).unwrap();
+        for _ in 0..8000 {
+            writer.write_sample(0i16).unwrap();
+        }
+        writer.finalize().unwrap();
+    }
+
+    #[test]
+    fn test_recording_is_finalized_only_once_linked() {
+        let dir = tempfile::tempdir().unwrap();
+        let recording = dir.path().join("normalized_1.wav");
+        write_wav(&recording);
+
+        let pending = write_pending_sidecar(&recording).unwrap();
+        assert_eq!(pending.sample_rate, 16000);
+        assert!((pending.duration_secs - 0.5).abs() < 1e-9);
+        assert!(!pending.is_finalized());
+
+        let future = SystemTime::now() + Duration::from_secs(60);
+        assert_eq!(find_orphans(dir.path(), future), vec![recording.clone()]);
+
+        finalize_recording(&recording, "2024-01-01T00:00:00Z").unwrap();
+        let sidecar = read_sidecar(&recording).unwrap();
+        assert_eq!(
+            sidecar.transcription_id.as_deref(),
+            Some("2024-01-01T00:00:00Z")
+        );
+        assert!(find_orphans(dir.path(), future).is_empty());
+    }
+
+    #[test]
+    fn test_find_orphans_reports_dangling_sidecars_and_skips_fresh_audio() {
+        let dir = tempfile::tempdir().unwrap();
+        let recording = dir.path().join("recording_2.wav");
+        write_wav(&recording);
+        std::fs::write(dir.path().join("recording_3.json"), "{}").unwrap();
+
+        let past = SystemTime::now() - Duration::from_secs(60);
+        assert_eq!(
+            find_orphans(dir.path(), past),
+            vec![dir.path().join("recording_3.json")]
+        );
+
+        remove_recording(&recording);
+        assert!(!recording.exists());
+    }
+
+    #[test]
+    fn test_archive_moves_only_old_finalized_recordings() {
+        let dir = tempfile::tempdir().unwrap();
+        let archive = tempfile::tempdir().unwrap();
+        let kept = dir.path().join("recording_1.wav");
+        let pending = dir.path().join("recording_2.wav");
+        write_wav(&kept);
+        write_wav(&pending);
+        finalize_recording(&kept, "2024-01-01T00:00:00Z").unwrap();
+        write_pending_sidecar(&pending).unwrap();
+
+        let past = SystemTime::now() - Duration::from_secs(60);
+        assert_eq!(
+            archive_recordings(dir.path(), archive.path(), past).unwrap(),
+            ArchiveOutcome::default()
+        );
+
+        let future = SystemTime::now() + Duration::from_secs(60);
+        let outcome = archive_recordings(dir.path(), archive.path(), future).unwrap();
+        assert_eq!(outcome.moved, vec!["recording_1.wav".to_string()]);
+        assert!(outcome.failed.is_empty());
+        assert!(!kept.exists());
+        assert!(pending.exists());
+        let archived = archive.path().join("recording_1.wav");
+        assert!(read_sidecar(&archived).is_some_and(|s| s.is_finalized()));
+
+        let entry = serde_json::json!({
+            "recording_file": "recording_1.wav",
+            ARCHIVE_DIR_FIELD: archive.path(),
+        });
+        assert_eq!(recording_location(dir.path(), &entry), archive.path());
+        assert_eq!(
+            recording_location(dir.path(), &serde_json::json!({})),
+            dir.path()
+        );
+    }
+
+    #[test]
+    fn test_low_confidence_recordings() {
+        let dir = tempfile::tempdir().unwrap();
+        write_wav(&dir.path().join("low.wav"));
+        write_wav(&dir.path().join("high.wav"));
+        let entries = vec![
+            (
+                "2024-01-02T00:00:00Z".to_string(),
+                serde_json::json!({ "confidence": 0.4, "recording_file": "low.wav" }),
+            ),
+            (
+                "2024-01-01T00:00:00Z".to_string(),
+                serde_json::json!({ "confidence": 0.3, "recording_file": "gone.wav" }),
+            ),
+            (
+                "2024-01-03T00:00:00Z".to_string(),
+                serde_json::json!({ "confidence": 0.9, "recording_file": "high.wav" }),
+            ),
+            (
+                "2024-01-04T00:00:00Z".to_string(),
+                serde_json::json!({ "recording_file": "high.wav" }),
+            ),
+        ];
+
+        assert_eq!(
+            low_confidence_recordings(dir.path(), entries, 0.6),
+            vec![(
+                "2024-01-02T00:00:00Z".to_string(),
+                dir.path().join("low.wav")
+            )]
+        );
+    }
+
+    #[test]
+    fn test_remove_entry_recording_deletes_audio_and_sidecar() {
+        let dir = tempfile::tempdir().unwrap();
+        let recording = dir.path().join("recording_4.wav");
+        write_wav(&recording);
+        finalize_recording(&recording, "2024-01-01T00:00:00Z").unwrap();
+
+        remove_entry_recording(
+            dir.path(),
+            &serde_json::json!({ "recording_file": "recording_4.wav" }),
+        );
+        assert!(!recording.exists());
+        assert!(!sidecar_path(&recording).exists());
+
+        // Entries without a recording are a no-op
+        remove_entry_recording(dir.path(), &serde_json::json!({ "text": "hi" }));
+    }
+
+    #[test]
+    fn test_sanitize_imported_recording() {
+        let mut entry = serde_json::json!({
+            "text": "hi",
+            "recording_file": "../../Documents/notes.txt",
+            ARCHIVE_DIR_FIELD: "/home/me",
+        });
+        sanitize_imported_recording(&mut entry);
+        assert_eq!(entry, serde_json::json!({ "text": "hi" }));
+
+        // Even a plain name may match an unrelated local recording
+        let mut entry = serde_json::json!({ "text": "hi", "recording_file": "recording_1.wav" });
+        sanitize_imported_recording(&mut entry);
+        assert_eq!(entry, serde_json::json!({ "text": "hi" }));
+
+        assert_eq!(
+            recording_file_name(&serde_json::json!({ "recording_file": "a/.." })),
+            None
+        );
+    }
+
+    #[test]
+    fn test_normalize_entry_paths_from_another_os() {
+        let mut entry = serde_json::json!({
+            "recording_file": "C:\\Users\\me\\AppData\\Roaming\\recordings\\recording_1.wav",
+            ARCHIVE_DIR_FIELD: "/Volumes/Archive/voicetypr",
+        });
+        assert_eq!(
+            recording_file_name(&entry).as_deref(),
+            Some("recording_1.wav")
+        );
+        assert!(normalize_entry_paths(&mut entry));
+        assert_eq!(entry["recording_file"], "recording_1.wav");
+        let archive_dir =
+            ["", "Volumes", "Archive", "voicetypr"].join(std::path::MAIN_SEPARATOR_STR);
+        assert_eq!(entry[ARCHIVE_DIR_FIELD], archive_dir.as_str());
+        assert_eq!(
+            recording_location(Path::new("recordings"), &entry),
+            PathBuf::from(archive_dir)
+        );
+
+        // Already normalized entries are left alone
+        assert!(!normalize_entry_paths(&mut entry));
+        let mut plain = serde_json::json!({ "recording_file": "recording_2.wav" });
+        assert!(!normalize_entry_paths(&mut plain));
+        assert!(!normalize_entry_paths(
+            &mut serde_json::json!({ "text": "hi" })
+        ));
+    }
+}
@@ -0,0 +1,118 @@
+// Secure deletion of recordings. When `secure_delete_recordings` is on, files
+// are overwritten with zeros and synced before being unlinked. This only
+// defeats casual recovery: SSD wear leveling, copy-on-write filesystems and
+// backups or snapshots can still hold earlier copies of the data.
+
+use std::io::Write;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+
+static SECURE_DELETE: AtomicBool = AtomicBool::new(false);
+static CAVEAT_LOGGED: Once = Once::new();
+
+const WIPE_CHUNK: usize = 64 * 1024;
+
+pub fn set_enabled(enabled: bool) {
+    SECURE_DELETE.store(enabled, Ordering::Relaxed);
+    if enabled {
+        CAVEAT_LOGGED.call_once(|| {
+            log::info!(
+                "Secure delete enabled: recordings are zero-filled before removal. \
+                 SSDs, copy-on-write filesystems (APFS, Btrfs) and backups may still retain copies."
+            );
+        });
+    }
+}
+
+pub fn is_enabled() -> bool {
+    SECURE_DELETE.load(Ordering::Relaxed)
+}
+
+/// Overwrite a file with zeros, flush it to disk, then remove it
+pub fn wipe_file(path: &Path) -> std::io::Result<()> {
+    let len = std::fs::metadata(path)?.len();
+    {
+        let mut file = std::fs::OpenOptions::new().write(true).open(path)?;
+        let zeros = vec![0u8; WIPE_CHUNK];
+        let mut remaining = len;
+        while remaining > 0 {
+            let n = remaining.min(WIPE_CHUNK as u64) as usize;
+            file.write_all(&zeros[..n])?;
+            remaining -= n as u64;
+        }
+        file.sync_all()?;
+    }
+    std::fs::remove_file(path)
+}
+
+/// Remove a file, wiping it first when secure delete is enabled
+pub fn remove_file(path: &Path) -> std::io::Result<()> {
+    if is_enabled() {
+        wipe_file(path)
+    } else {
+        std::fs::remove_file(path)
+    }
+}
+
+/// Rename `from` over `to`, wiping the replaced file first when secure delete
+/// is enabled (a plain rename would free its blocks without overwriting them)
+pub fn replace_file(from: &Path, to: &Path) -> std::io::Result<()> {
+    if is_enabled() && to.exists() {
+        wipe_file(to)?;
+    }
+    std::fs::rename(from, to)
+}
+
+/// Wipe a recording and its sidecar regardless of the setting
+pub fn secure_wipe_recording(path: &Path) -> Result<(), String> {
+    for file in [
+        path.to_path_buf(),
+        crate::utils::recordings::sidecar_path(path),
+    ] {
+        match wipe_file(&file) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(format!("Failed to wipe {}: {}", file.display(), e)),
+        }
+    }
+    Ok(())
+}
+
+/// Remove a directory tree, wiping every file in it first when secure delete
+/// is enabled
+pub fn remove_dir_all(dir: &Path) -> std::io::Result<()> {
+    if is_enabled() {
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                remove_dir_all(&path)?;
+            } else {
+                wipe_file(&path)?;
+            }
+        }
+    }
+    std::fs::remove_dir_all(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wipe_file_zero_fills_before_removing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("recording_1.wav");
+        std::fs::write(&path, vec![0xAB; WIPE_CHUNK + 10]).unwrap();
+
+        // Keep a second link to observe the contents after the unlink
+        let link = dir.path().join("observer");
+        std::fs::hard_link(&path, &link).unwrap();
+
+        wipe_file(&path).unwrap();
+        assert!(!path.exists());
+        let contents = std::fs::read(&link).unwrap();
+        assert_eq!(contents.len(), WIPE_CHUNK + 10);
+        assert!(contents.iter().all(|b| *b == 0));
+    }
+}
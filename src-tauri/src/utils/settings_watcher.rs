@@ -0,0 +1,56 @@
+// Optional watcher that reloads settings when the store file changes on disk
+// (edited by hand or written by another instance). Polls the file's
+// modification time; enabled by the `watch_settings_file` setting, which is
+// read on every tick so it can be toggled without a restart.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime};
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Default)]
+pub struct SettingsWatcher {
+    started: AtomicBool,
+}
+
+impl SettingsWatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn start(&self, app: AppHandle) {
+        if self.started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        tauri::async_runtime::spawn(async move {
+            let mut last_modified: Option<SystemTime> = None;
+            loop {
+                tokio::time::sleep(POLL_INTERVAL).await;
+
+                let enabled = app
+                    .store("settings")
+                    .ok()
+                    .and_then(|store| store.get("watch_settings_file"))
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                let modified = crate::utils::data_dir::settings_store_path(&app)
+                    .ok()
+                    .and_then(|path| std::fs::metadata(path).ok())
+                    .and_then(|meta| meta.modified().ok());
+
+                // Our own saves also bump the mtime; reloading them finds no changes
+                let changed = last_modified.is_some() && modified != last_modified;
+                last_modified = modified;
+                if enabled && changed {
+                    log::info!("Settings file changed on disk, reloading");
+                    if let Err(e) = crate::commands::settings::reload_settings(app.clone()).await {
+                        log::warn!("Failed to reload settings: {}", e);
+                    }
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,170 @@
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+
+/// Maximum number of metrics kept in the local ring buffer
+pub const MAX_TELEMETRY_EVENTS: usize = 500;
+
+const TELEMETRY_FILE: &str = "telemetry.jsonl";
+
+/// Serializes writers so concurrent transcriptions don't interleave rewrites
+static TELEMETRY_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+/// Anonymized latency metric for a single transcription. Never contains text.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TranscriptionMetric {
+    pub timestamp: String,
+    pub model: String,
+    pub backend: String,
+    pub audio_duration_secs: f64,
+    pub processing_ms: u64,
+    /// Processing time divided by audio duration (lower is faster)
+    pub realtime_factor: f64,
+    /// Peak process memory while transcribing, when it could be sampled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub peak_memory_mb: Option<u64>,
+}
+
+impl TranscriptionMetric {
+    pub fn new(model: &str, backend: &str, audio_duration_secs: f64, processing: Duration) -> Self {
+        let processing_ms = processing.as_millis() as u64;
+        Self {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            model: model.to_string(),
+            backend: backend.to_string(),
+            audio_duration_secs,
+            processing_ms,
+            realtime_factor: realtime_factor(processing_ms, audio_duration_secs),
+            peak_memory_mb: None,
+        }
+    }
+
+    pub fn with_peak_memory(mut self, peak_memory_mb: Option<u64>) -> Self {
+        self.peak_memory_mb = peak_memory_mb;
+        self
+    }
+}
+
+pub fn realtime_factor(processing_ms: u64, audio_duration_secs: f64) -> f64 {
+    if audio_duration_secs <= 0.0 {
+        return 0.0;
+    }
+    (processing_ms as f64 / 1000.0) / audio_duration_secs
+}
+
+/// Duration of a WAV file in seconds, read from its header
+pub fn wav_duration_secs(path: &Path) -> Option<f64> {
+    let reader = hound::WavReader::open(path).ok()?;
+    let spec = reader.spec();
+    if spec.sample_rate == 0 || spec.channels == 0 {
+        return None;
+    }
+    let frames = reader.duration() as f64;
+    Some(frames / spec.sample_rate as f64)
+}
+
+/// Read all metrics from a telemetry file, skipping malformed lines
+pub fn read_metrics(path: &Path) -> Result<Vec<TranscriptionMetric>, String> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read telemetry file: {}", e))?;
+    Ok(contents
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+/// Append a metric, keeping only the newest `max_events` entries
+pub fn append_metric(
+    path: &Path,
+    metric: &TranscriptionMetric,
+    max_events: usize,
+) -> Result<(), String> {
+    let _guard = TELEMETRY_LOCK.lock().map_err(|e| e.to_string())?;
+
+    let mut metrics = read_metrics(path)?;
+    metrics.push(metric.clone());
+    let overflow = metrics.len().saturating_sub(max_events);
+    metrics.drain(..overflow);
+
+    let tmp_path = path.with_extension("jsonl.tmp");
+    {
+        let mut file = std::fs::File::create(&tmp_path)
+            .map_err(|e| format!("Failed to create telemetry file: {}", e))?;
+        for m in &metrics {
+            let line = serde_json::to_string(m)
+                .map_err(|e| format!("Failed to serialize telemetry: {}", e))?;
+            writeln!(file, "{}", line)
+                .map_err(|e| format!("Failed to write telemetry file: {}", e))?;
+        }
+    }
+    std::fs::rename(&tmp_path, path).map_err(|e| format!("Failed to save telemetry file: {}", e))
+}
+
+pub fn telemetry_path(app: &AppHandle) -> Result<PathBuf, String> {
+    crate::utils::data_dir::data_dir(app).map(|dir| dir.join(TELEMETRY_FILE))
+}
+
+pub fn is_telemetry_enabled(app: &AppHandle) -> bool {
+    app.store("settings")
+        .ok()
+        .and_then(|store| store.get("telemetry_enabled"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+/// Record a transcription metric locally if the user opted in
+pub fn record_transcription_metric(app: &AppHandle, metric: TranscriptionMetric) {
+    if !is_telemetry_enabled(app) {
+        return;
+    }
+
+    let path = match telemetry_path(app) {
+        Ok(path) => path,
+        Err(e) => {
+            log::warn!("Telemetry disabled for this event: {}", e);
+            return;
+        }
+    };
+
+    tauri::async_runtime::spawn_blocking(move || {
+        if let Err(e) = append_metric(&path, &metric, MAX_TELEMETRY_EVENTS) {
+            log::warn!("Failed to record telemetry: {}", e);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_realtime_factor() {
+        assert_eq!(realtime_factor(5000, 10.0), 0.5);
+        assert_eq!(realtime_factor(1000, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_append_metric_keeps_newest_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(TELEMETRY_FILE);
+
+        for i in 0..5 {
+            let metric =
+                TranscriptionMetric::new("base.en", "whisper", 2.0, Duration::from_millis(i * 100));
+            append_metric(&path, &metric, 3).unwrap();
+        }
+
+        let metrics = read_metrics(&path).unwrap();
+        assert_eq!(metrics.len(), 3);
+        assert_eq!(metrics[0].processing_ms, 200);
+        assert_eq!(metrics[2].processing_ms, 400);
+    }
+}
@@ -0,0 +1,199 @@
+// Wall-clock estimates for transcriptions, from a per model/backend realtime
+// factor (processing time / audio duration). The factor is seeded from local
+// telemetry when available and refined after every real transcription.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+use tauri::AppHandle;
+
+use crate::simple_cache;
+use crate::utils::telemetry::{self, TranscriptionMetric};
+
+const CALIBRATION_CACHE_KEY: &str = "transcription_calibration";
+
+/// Weight of the newest measurement in the moving average
+const SMOOTHING: f64 = 0.3;
+
+/// Clips this short are dominated by model load and setup time and would skew the factor
+const MIN_CALIBRATION_AUDIO_SECS: f64 = 2.0;
+
+/// Conservative factors used until a model has been measured
+const DEFAULT_WHISPER_FACTOR: f64 = 0.5;
+const DEFAULT_PARAKEET_FACTOR: f64 = 0.1;
+const DEFAULT_CLOUD_FACTOR: f64 = 0.3;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct Calibration {
+    pub realtime_factor: f64,
+    pub samples: u32,
+}
+
+impl Calibration {
+    /// Fold a new measurement into the running average
+    pub fn update(self, realtime_factor: f64) -> Self {
+        Self {
+            realtime_factor: self.realtime_factor * (1.0 - SMOOTHING) + realtime_factor * SMOOTHING,
+            samples: self.samples.saturating_add(1),
+        }
+    }
+}
+
+pub fn calibration_key(backend: &str, model: &str) -> String {
+    format!("{}:{}", backend, model)
+}
+
+pub fn default_realtime_factor(backend: &str) -> f64 {
+    match backend {
+        "parakeet" => DEFAULT_PARAKEET_FACTOR,
+        "soniox" => DEFAULT_CLOUD_FACTOR,
+        _ => DEFAULT_WHISPER_FACTOR,
+    }
+}
+
+/// Median factor of past runs of the same model, so a single outlier doesn't
+/// dominate the initial calibration
+pub fn seed_from_metrics(
+    metrics: &[TranscriptionMetric],
+    backend: &str,
+    model: &str,
+) -> Option<Calibration> {
+    let mut factors: Vec<f64> = metrics
+        .iter()
+        .filter(|m| m.backend == backend && m.model == model)
+        .filter(|m| m.audio_duration_secs >= MIN_CALIBRATION_AUDIO_SECS)
+        .map(|m| m.realtime_factor)
+        .filter(|f| f.is_finite() && *f > 0.0)
+        .collect();
+    if factors.is_empty() {
+        return None;
+    }
+    factors.sort_by(|a, b| a.total_cmp(b));
+    Some(Calibration {
+        realtime_factor: factors[factors.len() / 2],
+        samples: factors.len() as u32,
+    })
+}
+
+pub fn estimate_seconds(audio_seconds: f64, realtime_factor: f64) -> f64 {
+    (audio_seconds.max(0.0) * realtime_factor).max(0.0)
+}
+
+fn load_calibrations(app: &AppHandle) -> HashMap<String, Calibration> {
+    simple_cache::get(app, CALIBRATION_CACHE_KEY)
+        .ok()
+        .flatten()
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default()
+}
+
+fn save_calibrations(app: &AppHandle, calibrations: &HashMap<String, Calibration>) {
+    let value = match serde_json::to_value(calibrations) {
+        Ok(value) => value,
+        Err(e) => {
+            log::warn!("Failed to serialize transcription calibration: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = simple_cache::set(app, CALIBRATION_CACHE_KEY, value, None) {
+        log::warn!("Failed to save transcription calibration: {}", e);
+    }
+}
+
+/// Realtime factor for a model, measuring it once from telemetry and caching
+/// the result when there is no calibration yet
+pub fn realtime_factor_for(app: &AppHandle, backend: &str, model: &str) -> f64 {
+    let key = calibration_key(backend, model);
+    let mut calibrations = load_calibrations(app);
+    if let Some(calibration) = calibrations.get(&key) {
+        return calibration.realtime_factor;
+    }
+
+    let seeded = telemetry::telemetry_path(app)
+        .and_then(|path| telemetry::read_metrics(&path))
+        .ok()
+        .and_then(|metrics| seed_from_metrics(&metrics, backend, model));
+    match seeded {
+        Some(calibration) => {
+            calibrations.insert(key, calibration);
+            save_calibrations(app, &calibrations);
+            calibration.realtime_factor
+        }
+        None => default_realtime_factor(backend),
+    }
+}
+
+/// Refine the calibration after a completed transcription
+pub fn record_transcription_time(
+    app: &AppHandle,
+    backend: &str,
+    model: &str,
+    audio_seconds: f64,
+    processing: Duration,
+) {
+    if audio_seconds < MIN_CALIBRATION_AUDIO_SECS {
+        return;
+    }
+    let factor = telemetry::realtime_factor(processing.as_millis() as u64, audio_seconds);
+    if !factor.is_finite() || factor <= 0.0 {
+        return;
+    }
+
+    let key = calibration_key(backend, model);
+    let mut calibrations = load_calibrations(app);
+    let updated = match calibrations.get(&key) {
+        Some(calibration) => calibration.update(factor),
+        None => Calibration {
+            realtime_factor: factor,
+            samples: 1,
+        },
+    };
+    log::debug!(
+        "Transcription calibration for {}: {:.3}x realtime ({} samples)",
+        key,
+        updated.realtime_factor,
+        updated.samples
+    );
+    calibrations.insert(key, updated);
+    save_calibrations(app, &calibrations);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metric(model: &str, audio: f64, factor: f64) -> TranscriptionMetric {
+        let mut metric = TranscriptionMetric::new(model, "whisper", audio, Duration::ZERO);
+        metric.realtime_factor = factor;
+        metric
+    }
+
+    #[test]
+    fn test_calibration_moves_toward_new_measurements() {
+        let calibration = Calibration {
+            realtime_factor: 1.0,
+            samples: 1,
+        }
+        .update(0.0);
+        assert!((calibration.realtime_factor - 0.7).abs() < 1e-9);
+        assert_eq!(calibration.samples, 2);
+        assert_eq!(estimate_seconds(90.0, 0.5), 45.0);
+    }
+
+    #[test]
+    fn test_seed_from_metrics_uses_median_of_matching_runs() {
+        let metrics = vec![
+            metric("base.en", 10.0, 0.2),
+            metric("base.en", 10.0, 5.0),
+            metric("base.en", 10.0, 0.3),
+            // Too short to count, and a different model
+            metric("base.en", 0.5, 9.0),
+            metric("large-v3", 10.0, 2.0),
+        ];
+        let seeded = seed_from_metrics(&metrics, "whisper", "base.en").unwrap();
+        assert_eq!(seeded.realtime_factor, 0.3);
+        assert_eq!(seeded.samples, 3);
+
+        assert!(seed_from_metrics(&metrics, "parakeet", "base.en").is_none());
+    }
+}
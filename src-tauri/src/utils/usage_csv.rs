@@ -0,0 +1,129 @@
+// Usage analytics export: one CSV row per history entry, for spreadsheets.
+// Columns that older entries didn't record are left empty.
+
+use std::io::Write;
+
+pub const USAGE_CSV_HEADER: &[&str] = &[
+    "date",
+    "word_count",
+    "audio_seconds",
+    "model",
+    "language",
+    "app_name",
+    "confidence",
+];
+
+/// Quote a field when it contains a delimiter, quote or line break. Fields a
+/// spreadsheet would run as a formula (e.g. an app name starting with `=`)
+/// get a leading `'` so they stay text.
+pub fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+/// Recorded audio length, or the end of the last timed segment for entries
+/// saved before it was recorded
+fn audio_seconds(entry: &serde_json::Value) -> Option<f64> {
+    entry
+        .get("audio_seconds")
+        .and_then(|v| v.as_f64())
+        .or_else(|| {
+            entry
+                .get("segments")?
+                .as_array()?
+                .iter()
+                .filter_map(|segment| segment.get("end")?.as_f64())
+                .reduce(f64::max)
+        })
+}
+
+/// Fields of the CSV row for history entry `timestamp`, in header order
+pub fn usage_row(timestamp: &str, entry: &serde_json::Value) -> Vec<String> {
+    let str_field = |value: &serde_json::Value| value.as_str().unwrap_or("").to_string();
+    let word_count = entry
+        .get("text")
+        .and_then(|v| v.as_str())
+        .map(|text| text.split_whitespace().count())
+        .unwrap_or(0);
+    vec![
+        timestamp.to_string(),
+        word_count.to_string(),
+        audio_seconds(entry)
+            .map(|secs| format!("{:.2}", secs))
+            .unwrap_or_default(),
+        str_field(&entry["model"]),
+        str_field(&entry["language"]),
+        str_field(&entry["app"]["name"]),
+        entry
+            .get("confidence")
+            .and_then(|v| v.as_f64())
+            .map(|confidence| format!("{:.3}", confidence))
+            .unwrap_or_default(),
+    ]
+}
+
+pub fn write_row<W: Write>(writer: &mut W, fields: &[String]) -> std::io::Result<()> {
+    let line: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+    writeln!(writer, "{}", line.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usage_row_and_escaping() {
+        let entry = serde_json::json!({
+            "text": "  Send the report,\nplease ",
+            "model": "base.en",
+            "language": "en",
+            "app": { "name": "Mail, \"Work\"", "id": "com.apple.mail" },
+            "confidence": 0.91234,
+            "segments": [{ "text": "Send", "start": 0.0, "end": 1.5 }, { "text": "please", "start": 1.5, "end": 3.25 }],
+        });
+        let row = usage_row("2024-01-01T09:00:00+00:00", &entry);
+        assert_eq!(
+            row,
+            vec![
+                "2024-01-01T09:00:00+00:00",
+                "4",
+                "3.25",
+                "base.en",
+                "en",
+                "Mail, \"Work\"",
+                "0.912"
+            ]
+        );
+
+        let mut out = Vec::new();
+        write_row(&mut out, &row).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "2024-01-01T09:00:00+00:00,4,3.25,base.en,en,\"Mail, \"\"Work\"\"\",0.912\n"
+        );
+
+        // Formula-like fields are neutralized
+        assert_eq!(csv_field("=HYPERLINK(\"x\")"), "\"'=HYPERLINK(\"\"x\"\")\"");
+        assert_eq!(csv_field("@SUM(A1)"), "'@SUM(A1)");
+        assert_eq!(csv_field("-2+3"), "'-2+3");
+        assert_eq!(csv_field("3.25"), "3.25");
+
+        // Older entries leave unknown columns empty
+        let row = usage_row(
+            "2023-05-01T10:00:00+00:00",
+            &serde_json::json!({ "text": "hi" }),
+        );
+        assert_eq!(
+            row,
+            vec!["2023-05-01T10:00:00+00:00", "1", "", "", "", "", ""]
+        );
+    }
+}
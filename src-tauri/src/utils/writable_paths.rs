@@ -0,0 +1,107 @@
+// Write checks for the directories the app saves into. A data directory on a
+// read-only or permission-restricted volume otherwise only shows up later as
+// recordings, models or history silently failing to save.
+
+use serde::Serialize;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct PathStatus {
+    /// "models", "recordings", "stores", "history" or "logs"
+    pub name: String,
+    pub path: String,
+    pub writable: bool,
+    pub error: Option<String>,
+}
+
+/// Create `dir` if needed, then write, sync and delete a tiny probe file in it
+pub fn probe_writable(dir: &Path) -> Result<(), String> {
+    std::fs::create_dir_all(dir).map_err(|e| format!("Failed to create directory: {}", e))?;
+    let probe = dir.join(format!(".voicetypr-write-test-{}", std::process::id()));
+    let written = std::fs::File::create(&probe)
+        .and_then(|mut file| file.write_all(b"ok").and_then(|_| file.sync_all()))
+        .map_err(|e| format!("Failed to write: {}", e));
+    let removed = std::fs::remove_file(&probe).map_err(|e| format!("Failed to delete: {}", e));
+    written.and(removed)
+}
+
+/// Directories the app writes to, by name. The history store lives in the
+/// data directory, which may be overridden; settings always stay in the
+/// default app data directory.
+pub fn key_directories(app: &AppHandle) -> Vec<(&'static str, Result<PathBuf, String>)> {
+    let data_dir = crate::utils::data_dir::data_dir(app);
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e));
+    let mut dirs = vec![
+        ("models", data_dir.clone().map(|dir| dir.join("models"))),
+        (
+            "recordings",
+            data_dir.clone().map(|dir| dir.join("recordings")),
+        ),
+        ("stores", app_data_dir.clone()),
+    ];
+    if crate::utils::data_dir::data_dir_override(app).is_some() {
+        dirs.push(("history", data_dir));
+    }
+    dirs.push((
+        "logs",
+        app.path()
+            .app_log_dir()
+            .map_err(|e| format!("Failed to get log dir: {}", e)),
+    ));
+    dirs
+}
+
+pub fn check_path(name: &str, dir: Result<PathBuf, String>) -> PathStatus {
+    let (path, result) = match dir {
+        Ok(dir) => (dir.to_string_lossy().to_string(), probe_writable(&dir)),
+        Err(e) => (String::new(), Err(e)),
+    };
+    PathStatus {
+        name: name.to_string(),
+        path,
+        writable: result.is_ok(),
+        error: result.err(),
+    }
+}
+
+/// Probe every key directory
+pub fn check_all(app: &AppHandle) -> Vec<PathStatus> {
+    key_directories(app)
+        .into_iter()
+        .map(|(name, dir)| check_path(name, dir))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_path_reports_writable_and_blocked_dirs() {
+        let dir = tempfile::tempdir().unwrap();
+        let status = check_path("models", Ok(dir.path().join("models")));
+        assert!(status.writable, "{:?}", status.error);
+        // Created, and the probe file cleaned up
+        assert_eq!(
+            std::fs::read_dir(dir.path().join("models"))
+                .unwrap()
+                .count(),
+            0
+        );
+
+        // A file where the directory should be can't be written into
+        std::fs::write(dir.path().join("blocked"), b"").unwrap();
+        let status = check_path("recordings", Ok(dir.path().join("blocked")));
+        assert!(!status.writable);
+        assert!(status.error.is_some());
+
+        let status = check_path("logs", Err("no log dir".to_string()));
+        assert!(!status.writable);
+        assert_eq!(status.error.as_deref(), Some("no log dir"));
+    }
+}
@@ -1,5 +1,5 @@
 use std::collections::{HashMap, VecDeque};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use super::transcriber::Transcriber;
@@ -172,8 +172,12 @@ impl TranscriberCache {
         }
     }
 
+    /// Files of the models currently loaded, least recently used first
+    pub fn loaded_paths(&self) -> Vec<PathBuf> {
+        self.lru_order.iter().map(PathBuf::from).collect()
+    }
+
     /// Manually clear the cache (e.g. to free RAM or after a model upgrade).
-    #[cfg(test)]
     pub fn clear(&mut self) {
         self.map.clear();
         self.lru_order.clear();
@@ -0,0 +1,117 @@
+// Confidence scores derived from Whisper's per-token probabilities.
+
+/// One timed segment of a Whisper transcription
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+pub struct TranscribedSegment {
+    pub text: String,
+    /// Seconds from the start of the audio
+    pub start: f64,
+    pub end: f64,
+    /// Mean probability of the segment's text tokens, 0.0..=1.0
+    pub confidence: Option<f32>,
+    /// "Speaker 1", "Speaker 2", ... when diarization labeled the segment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>,
+}
+
+/// Text plus the timed segments it was built from
+#[derive(Debug, Clone)]
+pub struct WhisperTranscription {
+    pub text: String,
+    pub segments: Vec<TranscribedSegment>,
+    /// Language the audio was decoded as (auto falls back to English)
+    pub language: Option<String>,
+}
+
+impl WhisperTranscription {
+    pub fn confidence(&self) -> Option<f32> {
+        overall_confidence(&self.segments)
+    }
+}
+
+/// Timestamp and control tokens (`[_BEG_]`, `[_TT_150]`, `<|en|>`) carry no
+/// information about how sure the model was of the words
+pub fn is_special_token(token: &str) -> bool {
+    let token = token.trim();
+    (token.starts_with("[_") && token.ends_with(']'))
+        || (token.starts_with("<|") && token.ends_with("|>"))
+}
+
+pub fn mean_probability(probabilities: &[f32]) -> Option<f32> {
+    if probabilities.is_empty() {
+        return None;
+    }
+    let mean = probabilities.iter().sum::<f32>() / probabilities.len() as f32;
+    Some(mean.clamp(0.0, 1.0))
+}
+
+/// Duration-weighted mean of the segment confidences, so a long mumbled
+/// stretch counts for more than a short clear word
+pub fn overall_confidence(segments: &[TranscribedSegment]) -> Option<f32> {
+    let scored: Vec<(f32, f64)> = segments
+        .iter()
+        .filter_map(|s| s.confidence.map(|c| (c, (s.end - s.start).max(0.0))))
+        .collect();
+    if scored.is_empty() {
+        return None;
+    }
+
+    let total_duration: f64 = scored.iter().map(|(_, d)| d).sum();
+    if total_duration <= 0.0 {
+        return mean_probability(&scored.iter().map(|(c, _)| *c).collect::<Vec<_>>());
+    }
+    let weighted: f64 = scored.iter().map(|(c, d)| *c as f64 * d).sum();
+    Some((weighted / total_duration) as f32)
+}
+
+/// Whether a transcription falls below the user's `min_confidence` (0 disables the check)
+pub fn is_low_confidence(confidence: Option<f32>, min_confidence: f32) -> bool {
+    min_confidence > 0.0 && confidence.is_some_and(|c| c < min_confidence)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(start: f64, end: f64, confidence: Option<f32>) -> TranscribedSegment {
+        TranscribedSegment {
+            text: "x".to_string(),
+            start,
+            end,
+            confidence,
+            speaker: None,
+        }
+    }
+
+    #[test]
+    fn test_special_tokens_are_skipped() {
+        assert!(is_special_token("[_BEG_]"));
+        assert!(is_special_token("[_TT_150]"));
+        assert!(is_special_token("<|en|>"));
+        assert!(!is_special_token(" hello"));
+        assert!(!is_special_token("[laughs"));
+    }
+
+    #[test]
+    fn test_overall_confidence_is_duration_weighted() {
+        let segments = vec![
+            segment(0.0, 3.0, Some(0.9)),
+            segment(3.0, 4.0, Some(0.5)),
+            segment(4.0, 5.0, None),
+        ];
+        let overall = overall_confidence(&segments).unwrap();
+        assert!((overall - 0.8).abs() < 1e-6);
+
+        assert_eq!(overall_confidence(&[segment(0.0, 1.0, None)]), None);
+        assert_eq!(mean_probability(&[]), None);
+    }
+
+    #[test]
+    fn test_is_low_confidence() {
+        assert!(is_low_confidence(Some(0.4), 0.6));
+        assert!(!is_low_confidence(Some(0.7), 0.6));
+        // Disabled, or nothing to judge by
+        assert!(!is_low_confidence(Some(0.1), 0.0));
+        assert!(!is_low_confidence(None, 0.6));
+    }
+}
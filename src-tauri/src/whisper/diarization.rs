@@ -0,0 +1,72 @@
+// Speaker labels from whisper.cpp's tinydiarize turn detection. Models
+// fine-tuned for it (the `-tdrz` variants) mark where the speaker changes
+// after a segment; they don't identify who is speaking, so turns alternate
+// between two labels, which fits the common one-on-one meeting. Other models
+// never mark a turn, and the Parakeet sidecar doesn't diarize, so the
+// `diarization` setting is only accepted while a `-tdrz` model is selected.
+
+use super::confidence::TranscribedSegment;
+
+/// Whether `model` on `engine` can mark speaker turns
+pub fn model_can_diarize(engine: &str, model: &str) -> bool {
+    engine == "whisper" && model.ends_with("-tdrz")
+}
+
+/// Label for the `index`th speaker, as shown in exports
+pub fn speaker_label(index: usize) -> String {
+    format!("Speaker {}", index + 1)
+}
+
+/// Label `segments` from per-segment "speaker changes after this segment"
+/// flags. Returns whether any labels were applied.
+pub fn label_speaker_turns(segments: &mut [TranscribedSegment], turn_after: &[bool]) -> bool {
+    if !turn_after.iter().any(|turn| *turn) {
+        return false;
+    }
+    let mut speaker = 0;
+    for (i, segment) in segments.iter_mut().enumerate() {
+        segment.speaker = Some(speaker_label(speaker));
+        if turn_after.get(i).copied().unwrap_or(false) {
+            speaker = 1 - speaker;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(text: &str) -> TranscribedSegment {
+        TranscribedSegment {
+            text: text.to_string(),
+            start: 0.0,
+            end: 1.0,
+            confidence: None,
+            speaker: None,
+        }
+    }
+
+    #[test]
+    fn test_turns_alternate_labels_and_no_turns_leave_none() {
+        let mut segments = vec![segment("a"), segment("b"), segment("c")];
+        assert!(label_speaker_turns(&mut segments, &[false, true, false]));
+        let labels: Vec<_> = segments.iter().map(|s| s.speaker.as_deref()).collect();
+        assert_eq!(
+            labels,
+            vec![Some("Speaker 1"), Some("Speaker 1"), Some("Speaker 2")]
+        );
+
+        let mut segments = vec![segment("a"), segment("b")];
+        assert!(!label_speaker_turns(&mut segments, &[false, false]));
+        assert!(segments.iter().all(|s| s.speaker.is_none()));
+    }
+
+    #[test]
+    fn test_only_tdrz_whisper_models_diarize() {
+        assert!(model_can_diarize("whisper", "small.en-tdrz"));
+        assert!(!model_can_diarize("whisper", "base.en"));
+        assert!(!model_can_diarize("parakeet", "parakeet-tdt-0.6b-v3"));
+        assert!(!model_can_diarize("soniox", "small.en-tdrz"));
+    }
+}
@@ -0,0 +1,197 @@
+// Versioned layout of the Whisper models directory. A `layout.json` marker
+// records the version the directory was last organized for; at startup (and
+// through `migrate_models_layout`) anything in an older shape is moved into
+// the current one so downloaded models are always detected.
+//
+// Version 1: every file sits directly in the models directory as
+// `<model>.bin` or `<model>-<quant>.bin`. Unversioned directories may also
+// hold files named like whisper.cpp's downloads (`ggml-<model>.bin`) or
+// per-model folders (`<model>/<model>.bin`, `<model>/ggml-<model>.bin`).
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use super::manager::{DEFAULT_QUANTIZATION, QUANTIZATIONS};
+
+pub const MODELS_LAYOUT_VERSION: u32 = 1;
+pub const LAYOUT_MARKER: &str = "layout.json";
+
+/// Path of a model file in the current layout
+pub fn model_file(models_dir: &Path, model_name: &str, quantization: &str) -> PathBuf {
+    if quantization == DEFAULT_QUANTIZATION {
+        models_dir.join(format!("{}.bin", model_name))
+    } else {
+        models_dir.join(format!("{}-{}.bin", model_name, quantization))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct LayoutMarker {
+    version: u32,
+}
+
+/// Layout version recorded in `models_dir`; `None` for unversioned directories
+pub fn layout_version(models_dir: &Path) -> Option<u32> {
+    let contents = std::fs::read(models_dir.join(LAYOUT_MARKER)).ok()?;
+    serde_json::from_slice::<LayoutMarker>(&contents)
+        .ok()
+        .map(|marker| marker.version)
+}
+
+fn write_layout_version(models_dir: &Path, version: u32) -> Result<(), String> {
+    let contents = serde_json::to_vec(&LayoutMarker { version })
+        .map_err(|e| format!("Failed to serialize layout marker: {}", e))?;
+    std::fs::write(models_dir.join(LAYOUT_MARKER), contents)
+        .map_err(|e| format!("Failed to write layout marker: {}", e))
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct MigrationReport {
+    /// 0 for a directory without a marker
+    pub from_version: u32,
+    pub to_version: u32,
+    /// Files moved, as `old -> new` relative to the models directory
+    pub moved: Vec<String>,
+    /// Files left in place because the current-layout file already exists
+    pub skipped: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+/// Where an old-layout file for one of `known_models` belongs, if anywhere
+fn current_name(file_name: &str, known_models: &[String]) -> Option<String> {
+    let stem = file_name.strip_suffix(".bin")?;
+    let target = format!("{}.bin", stem.strip_prefix("ggml-").unwrap_or(stem));
+    let known = known_models.iter().any(|model| {
+        std::iter::once(DEFAULT_QUANTIZATION)
+            .chain(QUANTIZATIONS.iter().copied())
+            .any(|quant| model_file(Path::new(""), model, quant) == Path::new(&target))
+    });
+    known.then_some(target)
+}
+
+/// Move files for `known_models` into the current layout and record the
+/// version. Directories marked with a newer version are left untouched.
+pub fn migrate(models_dir: &Path, known_models: &[String]) -> MigrationReport {
+    let from_version = layout_version(models_dir).unwrap_or(0);
+    let mut report = MigrationReport {
+        from_version,
+        to_version: MODELS_LAYOUT_VERSION,
+        ..Default::default()
+    };
+    if from_version > MODELS_LAYOUT_VERSION {
+        report.to_version = from_version;
+        report.errors.push(format!(
+            "Models directory uses layout version {}, newer than this app supports ({})",
+            from_version, MODELS_LAYOUT_VERSION
+        ));
+        return report;
+    }
+    if !models_dir.is_dir() {
+        return report;
+    }
+
+    // Old-layout files at the top level and inside per-model folders
+    let mut candidates: Vec<(PathBuf, String)> = Vec::new();
+    let top_level = std::fs::read_dir(models_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path());
+    for path in top_level {
+        let Some(name) = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .map(str::to_string)
+        else {
+            continue;
+        };
+        if path.is_dir() && known_models.contains(&name) {
+            for nested in std::fs::read_dir(&path)
+                .into_iter()
+                .flatten()
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+            {
+                if let Some(file) = nested.file_name().and_then(|n| n.to_str()) {
+                    candidates.push((nested.clone(), format!("{}/{}", name, file)));
+                }
+            }
+        } else if path.is_file() && name.starts_with("ggml-") {
+            candidates.push((path.clone(), name));
+        }
+    }
+
+    for (path, label) in candidates {
+        let Some(target_name) = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .and_then(|n| current_name(n, known_models))
+        else {
+            continue;
+        };
+        let target = models_dir.join(&target_name);
+        if target.exists() {
+            report.skipped.push(label);
+            continue;
+        }
+        match std::fs::rename(&path, &target) {
+            Ok(()) => report.moved.push(format!("{} -> {}", label, target_name)),
+            Err(e) => report
+                .errors
+                .push(format!("Failed to move {}: {}", label, e)),
+        }
+        // Drop per-model folders once they're empty
+        if let Some(parent) = path.parent().filter(|parent| *parent != models_dir) {
+            let _ = std::fs::remove_dir(parent);
+        }
+    }
+
+    if report.errors.is_empty() && from_version < MODELS_LAYOUT_VERSION {
+        if let Err(e) = write_layout_version(models_dir, MODELS_LAYOUT_VERSION) {
+            report.errors.push(e);
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrate_flattens_old_layouts_and_writes_marker() {
+        let dir = tempfile::tempdir().unwrap();
+        let models = vec!["base.en".to_string(), "large-v3".to_string()];
+        std::fs::write(dir.path().join("ggml-base.en-q5_0.bin"), b"q5").unwrap();
+        std::fs::create_dir(dir.path().join("large-v3")).unwrap();
+        std::fs::write(
+            dir.path().join("large-v3").join("ggml-large-v3.bin"),
+            b"f16",
+        )
+        .unwrap();
+        // Already in place, so the old copy is left alone
+        std::fs::write(dir.path().join("base.en.bin"), b"f16").unwrap();
+        std::fs::write(dir.path().join("ggml-base.en.bin"), b"old").unwrap();
+        std::fs::write(dir.path().join("ggml-unknown.bin"), b"?").unwrap();
+
+        let report = migrate(dir.path(), &models);
+        assert_eq!(report.from_version, 0);
+        assert_eq!(report.to_version, MODELS_LAYOUT_VERSION);
+        assert!(report.errors.is_empty());
+        assert_eq!(report.skipped, vec!["ggml-base.en.bin".to_string()]);
+        assert_eq!(report.moved.len(), 2);
+        assert!(dir.path().join("base.en-q5_0.bin").exists());
+        assert!(dir.path().join("large-v3.bin").exists());
+        assert!(!dir.path().join("large-v3").exists());
+        assert!(dir.path().join("ggml-unknown.bin").exists());
+        assert_eq!(layout_version(dir.path()), Some(MODELS_LAYOUT_VERSION));
+
+        // A second run has nothing to do
+        let report = migrate(dir.path(), &models);
+        assert_eq!(report.from_version, MODELS_LAYOUT_VERSION);
+        assert!(report.moved.is_empty());
+
+        write_layout_version(dir.path(), MODELS_LAYOUT_VERSION + 1).unwrap();
+        assert!(!migrate(dir.path(), &models).errors.is_empty());
+    }
+}
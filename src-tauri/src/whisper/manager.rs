@@ -3,13 +3,15 @@ use reqwest;
 use sha1::Sha1;
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tokio::fs;
 use tokio::io::AsyncReadExt;
 use tokio::io::AsyncWriteExt;
 
+use super::layout::model_file;
+
 // Type-safe size validation
 #[derive(Debug, Clone, Copy)]
 #[allow(dead_code)] // Field accessed through as_bytes() in tests
@@ -63,9 +65,32 @@ impl ModelInfo {
     }
 }
 
+/// Quantization of the standard `<model>.bin` files
+pub const DEFAULT_QUANTIZATION: &str = "f16";
+
+/// Variants that can sit next to a model as `<model>-<quant>.bin`
+pub const QUANTIZATIONS: &[&str] = &["q4_0", "q4_1", "q5_0", "q5_1", "q8_0"];
+
+/// Quantization of a model file, from its name
+pub fn quantization_of(model_path: &Path) -> String {
+    model_path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .and_then(|stem| {
+            QUANTIZATIONS
+                .iter()
+                .find(|quant| stem.ends_with(&format!("-{}", quant)))
+        })
+        .copied()
+        .unwrap_or(DEFAULT_QUANTIZATION)
+        .to_string()
+}
+
 pub struct WhisperManager {
     models_dir: PathBuf,
     models: HashMap<String, ModelInfo>,
+    /// Quantization picked per model; models without an entry use the default
+    active_quantizations: HashMap<String, String>,
 }
 
 impl WhisperManager {
@@ -165,7 +190,11 @@ impl WhisperManager {
 
         // Removed: large-v3-turbo-q8_0 to simplify model list
 
-        let mut manager = Self { models_dir, models };
+        let mut manager = Self {
+            models_dir,
+            models,
+            active_quantizations: HashMap::new(),
+        };
         manager.check_downloaded_models();
         manager
     }
@@ -178,7 +207,7 @@ impl WhisperManager {
 
         // Check each known model directly instead of scanning directory
         for (model_name, model_info) in self.models.iter_mut() {
-            let model_path = self.models_dir.join(format!("{}.bin", model_name));
+            let model_path = model_file(&self.models_dir, model_name, DEFAULT_QUANTIZATION);
             let exists = model_path.exists();
 
             if exists {
@@ -221,6 +250,15 @@ impl WhisperManager {
                 );
                 model_info.downloaded = false;
             }
+
+            // A quantized variant on its own is enough to use the model
+            if !model_info.downloaded {
+                model_info.downloaded = QUANTIZATIONS.iter().any(|quant| {
+                    std::fs::metadata(model_file(&self.models_dir, model_name, quant))
+                        .map(|metadata| metadata.len() > 0)
+                        .unwrap_or(false)
+                });
+            }
         }
 
         // Log final status
@@ -266,7 +304,7 @@ impl WhisperManager {
         // Validate model size before downloading
         let _ = model.validated_size()?;
 
-        let output_path = self.models_dir.join(format!("{}.bin", model_name));
+        let output_path = model_file(&self.models_dir, model_name, DEFAULT_QUANTIZATION);
 
         Ok((model.clone(), output_path))
     }
@@ -328,7 +366,9 @@ impl WhisperManager {
         );
 
         // Download the model
-        let client = reqwest::Client::new();
+        let client = crate::utils::proxy::client_builder()
+            .build()
+            .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
         let response = client
             .get(&model_info.url)
             .send()
@@ -563,12 +603,106 @@ impl WhisperManager {
         }
 
         if self.models.get(model_name)?.downloaded {
-            Some(self.models_dir.join(format!("{}.bin", model_name)))
+            let quantization = self.active_quantization(model_name)?;
+            Some(model_file(&self.models_dir, model_name, &quantization))
         } else {
             None
         }
     }
 
+    /// Quantizations of `model_name` present in the models directory
+    pub fn installed_quantizations(&self, model_name: &str) -> Vec<String> {
+        if !self.is_valid_model_name(model_name) {
+            return Vec::new();
+        }
+        std::iter::once(DEFAULT_QUANTIZATION)
+            .chain(QUANTIZATIONS.iter().copied())
+            .filter(|quant| model_file(&self.models_dir, model_name, quant).exists())
+            .map(|quant| quant.to_string())
+            .collect()
+    }
+
+    /// The quantization loads will use: the one picked for the model if it's
+    /// still installed, otherwise the default, otherwise any installed variant
+    pub fn active_quantization(&self, model_name: &str) -> Option<String> {
+        let installed = self.installed_quantizations(model_name);
+        self.active_quantizations
+            .get(model_name)
+            .filter(|quant| installed.contains(quant))
+            .cloned()
+            .or_else(|| {
+                installed
+                    .iter()
+                    .find(|quant| quant.as_str() == DEFAULT_QUANTIZATION)
+                    .cloned()
+            })
+            .or_else(|| installed.into_iter().next())
+    }
+
+    /// Pick the installed variant of `model_name` to load
+    pub fn set_active_quantization(
+        &mut self,
+        model_name: &str,
+        quantization: &str,
+    ) -> Result<(), String> {
+        if !self.is_valid_model_name(model_name) {
+            return Err(format!("Invalid model name: '{}'", model_name));
+        }
+        if !self
+            .installed_quantizations(model_name)
+            .iter()
+            .any(|quant| quant == quantization)
+        {
+            return Err(format!(
+                "Quantization '{}' of model '{}' is not installed",
+                quantization, model_name
+            ));
+        }
+        self.active_quantizations
+            .insert(model_name.to_string(), quantization.to_string());
+        Ok(())
+    }
+
+    /// Move files in an older models directory layout into the current one
+    /// and pick up any models that become visible
+    pub fn migrate_layout(&mut self) -> super::layout::MigrationReport {
+        let known: Vec<String> = self.models.keys().cloned().collect();
+        let report = super::layout::migrate(&self.models_dir, &known);
+        if !report.moved.is_empty() {
+            self.refresh_downloaded_status();
+        }
+        report
+    }
+
+    /// Known model and quantization a model file belongs to
+    pub fn model_for_path(&self, model_path: &Path) -> Option<(String, String)> {
+        let quant = quantization_of(model_path);
+        self.models
+            .keys()
+            .find(|name| model_file(&self.models_dir, name, &quant) == model_path)
+            .map(|name| (name.clone(), quant))
+    }
+
+    /// Restore the per-model choices saved in settings
+    pub fn set_active_quantizations(&mut self, quantizations: HashMap<String, String>) {
+        self.active_quantizations = quantizations;
+    }
+
+    /// Use the checksum and size of updated model files instead of the
+    /// built-in ones, then recheck which models are downloaded
+    pub fn set_updated_models(
+        &mut self,
+        updated: &HashMap<String, super::updates::InstalledModel>,
+    ) {
+        for (name, installed) in updated {
+            if let Some(info) = self.models.get_mut(name) {
+                info.sha256 = installed.sha256.clone();
+                info.size = installed.size;
+            }
+        }
+        self.refresh_downloaded_status();
+    }
+
     pub fn get_models_status(&self) -> HashMap<String, ModelInfo> {
         self.models.clone()
     }
@@ -620,7 +754,7 @@ impl WhisperManager {
         self.models
             .iter()
             .filter(|(name, _)| {
-                let path = self.models_dir.join(format!("{}.bin", name));
+                let path = model_file(&self.models_dir, name, DEFAULT_QUANTIZATION);
                 path.exists()
             })
             .map(|(name, _)| name.clone())
@@ -634,7 +768,7 @@ impl WhisperManager {
             return Err(format!("Invalid model name: '{}'", model_name));
         }
 
-        let path = self.models_dir.join(format!("{}.bin", model_name));
+        let path = model_file(&self.models_dir, model_name, DEFAULT_QUANTIZATION);
         if !path.exists() {
             return Err("Model file not found".to_string());
         }
@@ -768,7 +902,11 @@ impl WhisperManager {
             },
         );
 
-        let mut manager = Self { models, models_dir };
+        let mut manager = Self {
+            models,
+            models_dir,
+            active_quantizations: HashMap::new(),
+        };
         manager.check_downloaded_models();
         manager
     }
@@ -1,4 +1,9 @@
 pub mod cache;
+pub mod confidence;
+pub mod diarization;
 pub mod languages;
+pub mod layout;
 pub mod manager;
+pub mod params;
 pub mod transcriber;
+pub mod updates;
@@ -0,0 +1,160 @@
+// Whisper decoding parameters. The active set is persisted in settings under
+// `whisper_params` and used by every transcription; `Transcriber` also takes
+// an explicit set so alternatives can be compared on the same audio.
+//
+// Sensible ranges (values outside them are clamped with a warning):
+// - temperature 0.0-1.0: 0.0 is deterministic; higher values add randomness,
+//   which can break repetition loops but also invent words
+// - beam_size 1-10: 1 switches to greedy decoding; larger beams are slower
+//   and rarely help past 5
+// - best_of 1-10: candidates sampled per segment with greedy decoding
+//   (ignored while beam_size > 1)
+// - no_speech_threshold 0.0-1.0: segments whose no-speech probability is
+//   above it are dropped; higher keeps more quiet speech
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::sync::RwLock;
+use whisper_rs::SamplingStrategy;
+
+/// Settings key holding the user's decoding parameters
+pub const WHISPER_PARAMS_KEY: &str = "whisper_params";
+
+pub const TEMPERATURE_RANGE: (f32, f32) = (0.0, 1.0);
+pub const BEAM_SIZE_RANGE: (i32, i32) = (1, 10);
+pub const BEST_OF_RANGE: (i32, i32) = (1, 10);
+pub const NO_SPEECH_THRESHOLD_RANGE: (f32, f32) = (0.0, 1.0);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct WhisperParams {
+    pub temperature: f32,
+    pub beam_size: i32,
+    pub best_of: i32,
+    pub no_speech_threshold: f32,
+}
+
+impl Default for WhisperParams {
+    fn default() -> Self {
+        Self {
+            temperature: 0.2,
+            beam_size: 5,
+            best_of: 5,
+            no_speech_threshold: 0.6,
+        }
+    }
+}
+
+fn clamp_field<T: PartialOrd + Copy + std::fmt::Display>(
+    name: &str,
+    value: T,
+    (min, max): (T, T),
+    warnings: &mut Vec<String>,
+) -> T {
+    if value >= min && value <= max {
+        return value;
+    }
+    // NaN compares false both ways, so it becomes the minimum
+    let clamped = if value > max { max } else { min };
+    warnings.push(format!(
+        "{} {} is outside {}-{}, using {}",
+        name, value, min, max, clamped
+    ));
+    clamped
+}
+
+impl WhisperParams {
+    /// These parameters with every field in range, plus a warning for each
+    /// field that had to be clamped
+    pub fn clamped(self) -> (Self, Vec<String>) {
+        let mut warnings = Vec::new();
+        let params = Self {
+            temperature: clamp_field(
+                "temperature",
+                self.temperature,
+                TEMPERATURE_RANGE,
+                &mut warnings,
+            ),
+            beam_size: clamp_field("beam_size", self.beam_size, BEAM_SIZE_RANGE, &mut warnings),
+            best_of: clamp_field("best_of", self.best_of, BEST_OF_RANGE, &mut warnings),
+            no_speech_threshold: clamp_field(
+                "no_speech_threshold",
+                self.no_speech_threshold,
+                NO_SPEECH_THRESHOLD_RANGE,
+                &mut warnings,
+            ),
+        };
+        (params, warnings)
+    }
+
+    /// `clamped`, logging a warning for each out-of-range field
+    pub fn validated(self) -> Self {
+        let (params, warnings) = self.clamped();
+        for warning in warnings {
+            log::warn!("Whisper params: {}", warning);
+        }
+        params
+    }
+
+    pub fn sampling_strategy(&self) -> SamplingStrategy {
+        if self.beam_size > 1 {
+            SamplingStrategy::BeamSearch {
+                beam_size: self.beam_size,
+                patience: -1.0,
+            }
+        } else {
+            SamplingStrategy::Greedy {
+                best_of: self.best_of,
+            }
+        }
+    }
+}
+
+static ACTIVE: Lazy<RwLock<WhisperParams>> = Lazy::new(|| RwLock::new(WhisperParams::default()));
+
+/// Parameters used by transcriptions that don't pass their own
+pub fn active() -> WhisperParams {
+    ACTIVE.read().map(|params| *params).unwrap_or_default()
+}
+
+pub fn set_active(params: WhisperParams) {
+    if let Ok(mut active) = ACTIVE.write() {
+        *active = params;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamped_keeps_valid_values_and_clamps_others() {
+        let (params, warnings) = WhisperParams::default().clamped();
+        assert_eq!(params, WhisperParams::default());
+        assert!(warnings.is_empty());
+
+        let (params, warnings) = WhisperParams {
+            temperature: 1.5,
+            beam_size: 0,
+            best_of: 3,
+            no_speech_threshold: f32::NAN,
+        }
+        .clamped();
+        assert_eq!(
+            params,
+            WhisperParams {
+                temperature: 1.0,
+                beam_size: 1,
+                best_of: 3,
+                no_speech_threshold: 0.0,
+            }
+        );
+        assert_eq!(warnings.len(), 3);
+
+        // Missing fields fall back to the defaults
+        let partial: WhisperParams =
+            serde_json::from_value(serde_json::json!({ "beam_size": 1 })).unwrap();
+        assert_eq!(partial.beam_size, 1);
+        assert_eq!(partial.temperature, WhisperParams::default().temperature);
+    }
+}
@@ -1,10 +1,15 @@
 use std::path::Path;
 use std::time::Instant;
 use whisper_rs::{
-    convert_integer_to_float_audio, convert_stereo_to_mono_audio, FullParams, SamplingStrategy,
-    WhisperContext, WhisperContextParameters,
+    convert_integer_to_float_audio, convert_stereo_to_mono_audio, FullParams, WhisperContext,
+    WhisperContextParameters,
 };
 
+use super::confidence::{
+    is_special_token, mean_probability, TranscribedSegment, WhisperTranscription,
+};
+use super::diarization::label_speaker_turns;
+use super::params::WhisperParams;
 use crate::utils::logger::*;
 #[cfg(debug_assertions)]
 use crate::utils::system_monitor;
@@ -355,8 +360,66 @@ impl Transcriber {
         should_cancel: F,
     ) -> Result<String, String>
     where
-        F: Fn() -> bool,
+        F: Fn() -> bool + 'static,
     {
+        self.transcribe_detailed_with_cancellation(audio_path, language, translate, should_cancel)
+            .map(|transcription| transcription.text)
+    }
+
+    /// Like `transcribe_with_cancellation`, but also returns the timed segments
+    /// with a confidence score from the token probabilities
+    pub fn transcribe_detailed_with_cancellation<F>(
+        &self,
+        audio_path: &Path,
+        language: Option<&str>,
+        translate: bool,
+        should_cancel: F,
+    ) -> Result<WhisperTranscription, String>
+    where
+        F: Fn() -> bool + 'static,
+    {
+        self.transcribe_segments(audio_path, language, translate, false, should_cancel)
+    }
+
+    /// `transcribe_detailed_with_cancellation` with optional speaker-turn
+    /// detection. Only `-tdrz` models detect turns; with any other model the
+    /// segments come back unlabeled.
+    pub fn transcribe_segments<F>(
+        &self,
+        audio_path: &Path,
+        language: Option<&str>,
+        translate: bool,
+        diarize: bool,
+        should_cancel: F,
+    ) -> Result<WhisperTranscription, String>
+    where
+        F: Fn() -> bool + 'static,
+    {
+        self.transcribe_segments_with_params(
+            audio_path,
+            language,
+            translate,
+            diarize,
+            super::params::active(),
+            should_cancel,
+        )
+    }
+
+    /// `transcribe_segments` with explicit decoding parameters instead of the
+    /// active ones
+    pub fn transcribe_segments_with_params<F>(
+        &self,
+        audio_path: &Path,
+        language: Option<&str>,
+        translate: bool,
+        diarize: bool,
+        decoding: WhisperParams,
+        should_cancel: F,
+    ) -> Result<WhisperTranscription, String>
+    where
+        F: Fn() -> bool + 'static,
+    {
+        let should_cancel = std::sync::Arc::new(should_cancel);
         let transcription_start = Instant::now();
         let audio_path_str = format!("{:?}", audio_path);
 
@@ -543,11 +606,9 @@ impl Transcriber {
             resampled_audio.len() as f32 / 16_000_f32
         );
 
-        // Create transcription parameters - use BeamSearch for better accuracy
-        let mut params = FullParams::new(SamplingStrategy::BeamSearch {
-            beam_size: 5,
-            patience: -1.0,
-        });
+        // Create transcription parameters - BeamSearch by default for better accuracy
+        log::debug!("Decoding with {:?}", decoding);
+        let mut params = FullParams::new(decoding.sampling_strategy());
 
         // Set language - use centralized validation
         log::info!("[LANGUAGE] Received language: {:?}", language);
@@ -622,7 +683,7 @@ impl Transcriber {
         params.set_suppress_nst(true);
 
         // Adjust speech detection threshold
-        params.set_no_speech_thold(0.6); // Default value, as higher values can cause issues
+        params.set_no_speech_thold(decoding.no_speech_threshold); // 0.6 unless tuned
 
         // Quality thresholds - use more lenient values to avoid rejecting valid speech
         // Default entropy threshold is 2.4, we'll keep it default to avoid over-filtering
@@ -635,7 +696,7 @@ impl Transcriber {
         params.set_initial_prompt(""); // Empty prompt to avoid biasing the model
 
         // Temperature settings - slight randomness helps avoid repetitive loops
-        params.set_temperature(0.2); // Small amount of randomness instead of deterministic
+        params.set_temperature(decoding.temperature); // 0.2 unless tuned: a little randomness
         params.set_temperature_inc(0.2); // Increase by 0.2 on fallback (default)
         params.set_max_initial_ts(1.0); // Limit initial timestamp search
 
@@ -643,6 +704,12 @@ impl Transcriber {
         params.set_max_len(0); // 0 means no limit
         params.set_length_penalty(-1.0); // Default penalty
 
+        params.set_tdrz_enable(diarize);
+
+        // Polled by whisper.cpp during decoding so a cancel doesn't wait for the whole file
+        let abort_check = should_cancel.clone();
+        params.set_abort_callback_safe(move || abort_check());
+
         // Run transcription
         log::info!("[TRANSCRIPTION_DEBUG] Creating Whisper state...");
         let mut state = self.context.create_state().map_err(|e| {
@@ -699,6 +766,11 @@ impl Transcriber {
                     inference_time.as_secs_f32()
                 );
             }
+            Err(_) if should_cancel() => {
+                // The state is per-call, so the cached context stays usable
+                log::info!("[TRANSCRIPTION_DEBUG] Transcription cancelled during inference");
+                return Err("Transcription cancelled".to_string());
+            }
             Err(e) => {
                 let error = format!("Whisper inference failed: {}", e);
                 log_failed("WHISPER_INFERENCE", &error);
@@ -732,11 +804,36 @@ impl Transcriber {
         );
 
         let mut text = String::new();
+        let mut segments = Vec::new();
+        let mut turn_after = Vec::new();
         for (i, segment) in state.as_iter().enumerate() {
+            turn_after.push(diarize && segment.next_segment_speaker_turn());
             let segment_text = segment.to_string();
             log::info!("[TRANSCRIPTION_DEBUG] Segment {}: '{}'", i, segment_text);
             text.push_str(&segment_text);
             text.push(' ');
+
+            let probabilities: Vec<f32> = (0..segment.n_tokens())
+                .filter_map(|t| segment.get_token(t))
+                .filter(|token| {
+                    token
+                        .to_str_lossy()
+                        .map(|t| !is_special_token(&t))
+                        .unwrap_or(false)
+                })
+                .map(|token| token.token_probability())
+                .collect();
+            // Whisper timestamps are in centiseconds
+            segments.push(TranscribedSegment {
+                text: segment_text.trim().to_string(),
+                start: segment.start_timestamp() as f64 / 100.0,
+                end: segment.end_timestamp() as f64 / 100.0,
+                confidence: mean_probability(&probabilities),
+                speaker: None,
+            });
+        }
+        if diarize && !label_speaker_turns(&mut segments, &turn_after) {
+            log::info!("Diarization requested but the model marked no speaker turns");
         }
 
         let result = text.trim().to_string();
@@ -805,7 +902,11 @@ impl Transcriber {
             );
         }
 
-        Ok(result)
+        Ok(WhisperTranscription {
+            text: result,
+            segments,
+            language: final_lang.map(str::to_string),
+        })
     }
 }
 
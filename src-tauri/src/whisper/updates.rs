@@ -0,0 +1,142 @@
+// Model updates. A JSON manifest lists the current download for each Whisper
+// model; a model has an update when the manifest's checksum differs from the
+// one it was installed with. The checksum and size of an applied update are
+// recorded in settings and replace the built-in ones, so later update checks
+// and download verification compare against the file actually installed.
+// Checking never hashes model files.
+// Only the default `<model>.bin` file is updated; quantized variants are left
+// alone.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use super::manager::ModelInfo;
+
+/// Where the model manifest is published
+pub const MODEL_MANIFEST_URL: &str =
+    "https://raw.githubusercontent.com/sfsajid91/voicetypr/main/models/manifest.json";
+const MANIFEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Settings key holding the file each updated model was installed with
+pub const UPDATED_MODELS_KEY: &str = "updated_models";
+
+/// Checksum and size of a model file installed by an update
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InstalledModel {
+    pub sha256: String,
+    pub size: u64,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ManifestEntry {
+    pub url: String,
+    /// SHA1 (40 chars) or SHA256 (64 chars), as in `ModelInfo::sha256`
+    pub sha256: String,
+    pub size: u64,
+    #[serde(default)]
+    pub version: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ModelManifest {
+    pub models: HashMap<String, ManifestEntry>,
+}
+
+/// A downloaded model with a newer file available
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ModelUpdate {
+    pub name: String,
+    pub current_checksum: String,
+    pub latest_checksum: String,
+    pub latest_version: Option<String>,
+    pub size: u64,
+}
+
+impl ModelManifest {
+    /// `info` pointing at the manifest's download for it
+    pub fn updated_info(&self, info: &ModelInfo) -> Option<ModelInfo> {
+        let entry = self.models.get(&info.name)?;
+        Some(ModelInfo {
+            url: entry.url.clone(),
+            sha256: entry.sha256.clone(),
+            size: entry.size,
+            ..info.clone()
+        })
+    }
+}
+
+/// Updates for `installed` (model name and installed checksum), by name
+pub fn available_updates(
+    manifest: &ModelManifest,
+    installed: &[(String, String)],
+) -> Vec<ModelUpdate> {
+    let mut updates: Vec<ModelUpdate> = installed
+        .iter()
+        .filter_map(|(name, checksum)| {
+            let entry = manifest.models.get(name)?;
+            (!entry.sha256.is_empty() && !entry.sha256.eq_ignore_ascii_case(checksum)).then(|| {
+                ModelUpdate {
+                    name: name.clone(),
+                    current_checksum: checksum.clone(),
+                    latest_checksum: entry.sha256.clone(),
+                    latest_version: entry.version.clone(),
+                    size: entry.size,
+                }
+            })
+        })
+        .collect();
+    updates.sort_by(|a, b| a.name.cmp(&b.name));
+    updates
+}
+
+pub async fn fetch_manifest() -> Result<ModelManifest, String> {
+    let client = crate::utils::proxy::client_builder()
+        .timeout(MANIFEST_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+    client
+        .get(MODEL_MANIFEST_URL)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| format!("Failed to fetch model manifest: {}", e))?
+        .json()
+        .await
+        .map_err(|e| format!("Invalid model manifest: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_available_updates_compares_checksums() {
+        let manifest: ModelManifest = serde_json::from_value(serde_json::json!({
+            "models": {
+                "base.en": { "url": "https://example.com/base.en.bin", "sha256": "BBB", "size": 100, "version": "2" },
+                "large-v3": { "url": "https://example.com/large-v3.bin", "sha256": "ccc", "size": 300 },
+            }
+        }))
+        .unwrap();
+        let installed = vec![
+            ("large-v3".to_string(), "ccc".to_string()),
+            ("base.en".to_string(), "aaa".to_string()),
+            ("small".to_string(), "ddd".to_string()),
+        ];
+
+        assert_eq!(
+            available_updates(&manifest, &installed),
+            vec![ModelUpdate {
+                name: "base.en".to_string(),
+                current_checksum: "aaa".to_string(),
+                latest_checksum: "BBB".to_string(),
+                latest_version: Some("2".to_string()),
+                size: 100,
+            }]
+        );
+        // Checksums compare case-insensitively
+        let installed = vec![("base.en".to_string(), "bbb".to_string())];
+        assert!(available_updates(&manifest, &installed).is_empty());
+    }
+}
@@ -12,6 +12,38 @@ pub struct WindowManager {
     pill_window: Arc<Mutex<Option<WebviewWindow>>>,
 }
 
+/// Setting value that keeps the overlay on the primary monitor
+pub const OVERLAY_MONITOR_PRIMARY: &str = "primary";
+/// Setting value that moves the overlay to the monitor the user is working on
+pub const OVERLAY_MONITOR_FOLLOW_ACTIVE: &str = "follow_active";
+
+/// Which monitor the recording overlay appears on
+#[derive(Debug, Clone, PartialEq)]
+pub enum OverlayMonitor {
+    Primary,
+    FollowActive,
+    /// A specific monitor, by name; falls back to the primary when disconnected
+    Named(String),
+}
+
+impl OverlayMonitor {
+    pub fn from_setting(value: &str) -> Self {
+        match value {
+            "" | OVERLAY_MONITOR_PRIMARY => OverlayMonitor::Primary,
+            OVERLAY_MONITOR_FOLLOW_ACTIVE => OverlayMonitor::FollowActive,
+            name => OverlayMonitor::Named(name.to_string()),
+        }
+    }
+
+    pub fn as_setting(&self) -> &str {
+        match self {
+            OverlayMonitor::Primary => OVERLAY_MONITOR_PRIMARY,
+            OverlayMonitor::FollowActive => OVERLAY_MONITOR_FOLLOW_ACTIVE,
+            OverlayMonitor::Named(name) => name,
+        }
+    }
+}
+
 fn calculate_pill_position(
     position: &str,
     screen_width: f64,
@@ -610,13 +642,27 @@ impl WindowManager {
         }
     }
 
+    /// Get the monitor the overlay should appear on from settings
+    fn get_overlay_monitor_setting(&self) -> OverlayMonitor {
+        use tauri_plugin_store::StoreExt;
+        let value = self
+            .app_handle
+            .store("settings")
+            .ok()
+            .and_then(|store| store.get("overlay_monitor"))
+            .and_then(|v| v.as_str().map(|s| s.to_string()))
+            .unwrap_or_default();
+        OverlayMonitor::from_setting(&value)
+    }
+
     /// Calculate position for pill window based on position setting
     /// position: "top", "center", or "bottom"
     fn calculate_position_for(&self, position: &str) -> (f64, f64) {
-        // Get screen dimensions and offset
-        let (screen_width, screen_height) = self.get_screen_dimensions();
+        // Get the target monitor's area and offset
+        let (origin_x, origin_y, screen_width, screen_height) = self.get_overlay_area();
         let edge_offset = self.get_pill_offset_setting();
         let (x, y) = calculate_pill_position(position, screen_width, screen_height, edge_offset);
+        let (x, y) = (origin_x + x, origin_y + y);
 
         log::info!(
             "Calculated pill position: ({}, {}) for '{}' on {}x{} screen with offset {}",
@@ -630,27 +676,62 @@ impl WindowManager {
         (x, y)
     }
 
-    /// Get screen dimensions from available monitors
-    fn get_screen_dimensions(&self) -> (f64, f64) {
-        // Try to get monitor from main window
-        if let Some(main_window) = self.get_main_window() {
-            if let Ok(Some(monitor)) = main_window.current_monitor() {
-                let size = monitor.size();
-                let scale = monitor.scale_factor();
-                return (size.width as f64 / scale, size.height as f64 / scale);
+    /// Resolve the overlay monitor setting to a connected monitor
+    fn get_overlay_monitor(&self) -> Option<tauri::Monitor> {
+        let selected = match self.get_overlay_monitor_setting() {
+            OverlayMonitor::Primary => None,
+            // The frontmost app is almost always on the monitor under the cursor
+            OverlayMonitor::FollowActive => self
+                .app_handle
+                .cursor_position()
+                .ok()
+                .and_then(|p| self.app_handle.monitor_from_point(p.x, p.y).ok().flatten()),
+            OverlayMonitor::Named(name) => {
+                let found = self
+                    .app_handle
+                    .available_monitors()
+                    .ok()
+                    .and_then(|monitors| {
+                        monitors
+                            .into_iter()
+                            .find(|m| m.name().is_some_and(|n| *n == name))
+                    });
+                if found.is_none() {
+                    log::warn!("Overlay monitor '{}' not connected, using primary", name);
+                }
+                found
             }
+        };
+        if selected.is_some() {
+            return selected;
         }
 
-        // Fallback to primary monitor
         if let Ok(Some(monitor)) = self.app_handle.primary_monitor() {
+            return Some(monitor);
+        }
+
+        // Last resort: whichever monitor the main window is on
+        self.get_main_window()
+            .and_then(|window| window.current_monitor().ok().flatten())
+    }
+
+    /// Origin and size of the overlay monitor in logical pixels
+    fn get_overlay_area(&self) -> (f64, f64, f64, f64) {
+        if let Some(monitor) = self.get_overlay_monitor() {
+            let position = monitor.position();
             let size = monitor.size();
             let scale = monitor.scale_factor();
-            return (size.width as f64 / scale, size.height as f64 / scale);
+            return (
+                position.x as f64 / scale,
+                position.y as f64 / scale,
+                size.width as f64 / scale,
+                size.height as f64 / scale,
+            );
         }
 
         // Safe default for common screen sizes
         log::error!("Could not get any monitor info, using safe defaults");
-        (1920.0, 1080.0)
+        (0.0, 0.0, 1920.0, 1080.0)
     }
 
     /// Calculate center position for pill window using current settings
@@ -738,7 +819,7 @@ impl WindowManager {
 
 #[cfg(test)]
 mod tests {
-    use super::calculate_pill_position;
+    use super::{calculate_pill_position, OverlayMonitor};
 
     // Screen: 1920x1080, pill: 80x40, edge_offset: 10
     // x_left = 10, x_center = 920, x_right = 1830
@@ -800,4 +881,16 @@ mod tests {
         assert_eq!(x, 50.0);
         assert_eq!(y, 990.0); // 1080 - 40 - 50
     }
+
+    #[test]
+    fn overlay_monitor_setting_round_trips() {
+        assert_eq!(OverlayMonitor::from_setting(""), OverlayMonitor::Primary);
+        assert_eq!(
+            OverlayMonitor::from_setting("follow_active"),
+            OverlayMonitor::FollowActive
+        );
+        let named = OverlayMonitor::from_setting("DELL U2720Q");
+        assert_eq!(named, OverlayMonitor::Named("DELL U2720Q".to_string()));
+        assert_eq!(named.as_setting(), "DELL U2720Q");
+    }
 }
@@ -12,6 +12,7 @@ import { useEventCoordinator } from "@/hooks/useEventCoordinator";
 import { useModelManagementContext } from "@/contexts/ModelManagementContext";
 import { updateService } from "@/services/updateService";
 import { loadApiKeysToCache } from "@/utils/keyring";
+import type { PathStatus } from "@/types";
 
 // Type for error event payloads from backend
 interface ErrorEventPayload {
@@ -25,6 +26,89 @@ interface ErrorEventPayload {
   suggestion?: string;
 }
 
+// A recording salvaged after a crash (get_recovered_recordings)
+interface RecoveredRecording {
+  path: string;
+  duration_secs: number;
+  created_at: string;
+}
+
+// Ask what to do with each recording recovered after a crash. Toast ids are
+// the paths, so asking again doesn't stack duplicate prompts.
+async function promptRecoveredRecordings() {
+  const pending = await invoke<RecoveredRecording[]>("get_recovered_recordings");
+  for (const recording of pending) {
+    const seconds = Math.max(1, Math.round(recording.duration_secs));
+    toast.warning("Recovered an interrupted recording", {
+      id: recording.path,
+      description: `${seconds}s of audio was saved before VoiceTypr closed. Transcribe it now?`,
+      duration: Infinity,
+      action: {
+        label: "Transcribe",
+        onClick: async () => {
+          try {
+            await invoke("transcribe_recovered_recording", { path: recording.path });
+            toast.success("Recovered recording added to history");
+          } catch (error) {
+            console.error("Failed to transcribe recovered recording:", error);
+            toast.error(`Failed to transcribe recovered recording: ${error}`);
+          }
+        }
+      },
+      cancel: {
+        label: "Discard",
+        onClick: () => {
+          invoke("discard_recovered_recording", { path: recording.path }).catch((error) => {
+            console.error("Failed to discard recovered recording:", error);
+          });
+        }
+      }
+    });
+  }
+}
+
+// Outcome of a reset scheduled for this launch (get_last_scheduled_reset)
+interface ScheduledResetResult {
+  success: boolean;
+  errors: string[];
+  warnings: string[];
+  cleared_items: string[];
+}
+
+function reportScheduledReset(result: ScheduledResetResult | null) {
+  if (!result) {
+    return;
+  }
+  if (result.success) {
+    toast.success("Scheduled reset completed", {
+      id: "scheduled-reset",
+      description: result.warnings.length > 0 ? result.warnings.join("; ") : undefined
+    });
+  } else {
+    toast.error("Scheduled reset finished with errors", {
+      id: "scheduled-reset",
+      description: result.errors.join("; "),
+      duration: Infinity
+    });
+  }
+}
+
+// Warn once about app directories that can't be written to (read-only or
+// restricted locations), since saves there fail silently otherwise
+function warnUnwritablePaths(statuses: PathStatus[]) {
+  const unwritable = statuses.filter((status) => !status.writable);
+  if (unwritable.length === 0) {
+    return;
+  }
+  toast.error("Some VoiceTypr folders aren't writable", {
+    id: "unwritable-paths",
+    description: `${unwritable
+      .map((status) => `${status.name} (${status.path})`)
+      .join(", ")}. Models, recordings or history may fail to save until the folder permissions are fixed.`,
+    duration: Infinity
+  });
+}
+
 export function AppContainer() {
   const { registerEvent } = useEventCoordinator("main");
   const [activeSection, setActiveSection] = useState<string>("overview");
@@ -122,6 +206,28 @@ export function AppContainer() {
           }
         );
 
+        // Recordings recovered by recover_from_crash while the app is open
+        registerEvent("crash-recovered", () => {
+          promptRecoveredRecordings().catch((error) => {
+            console.error("Failed to load recovered recordings:", error);
+          });
+        });
+
+        // A reset scheduled for this launch, if this window was already listening
+        registerEvent("scheduled-reset-completed", () => {
+          invoke<ScheduledResetResult | null>("get_last_scheduled_reset")
+            .then(reportScheduledReset)
+            .catch((error) => {
+              console.error("Failed to load scheduled reset result:", error);
+            });
+        });
+
+        // Directories found unwritable by the startup checks
+        registerEvent<PathStatus[]>("unwritable-paths", (statuses) => {
+          console.warn("Unwritable app directories:", statuses);
+          warnUnwritablePaths(statuses);
+        });
+
         // Listen for no models error (when trying to record without any models)
         registerEvent<ErrorEventPayload>("no-models-error", (data) => {
           console.error("No models available:", data);
@@ -145,6 +251,24 @@ export function AppContainer() {
     init();
   }, [registerEvent, settings]);
 
+  // Recordings recovered at startup, before this window was listening
+  useEffect(() => {
+    promptRecoveredRecordings().catch((error) => {
+      console.error("Failed to load recovered recordings:", error);
+    });
+    // Same for a reset run at startup and the writability check
+    invoke<ScheduledResetResult | null>("get_last_scheduled_reset")
+      .then(reportScheduledReset)
+      .catch((error) => {
+        console.error("Failed to load scheduled reset result:", error);
+      });
+    invoke<PathStatus[]>("check_writable_paths")
+      .then(warnUnwritablePaths)
+      .catch((error) => {
+        console.error("Failed to check app directories:", error);
+      });
+  }, []);
+
   // Mark when onboarding is being shown
   useEffect(() => {
     if (showOnboarding) {
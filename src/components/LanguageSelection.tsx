@@ -14,6 +14,7 @@ import {
   PopoverTrigger,
 } from "@/components/ui/popover"
 import { cn } from "@/lib/utils"
+import { invoke } from "@tauri-apps/api/core"
 import { Check, ChevronsUpDown } from "lucide-react"
 import * as React from "react"
 
@@ -142,10 +143,34 @@ export function LanguageSelection({ value, onValueChange, className, engine = 'w
     'en','es','fr','de','it','pt','nl','ru','zh','ja','ko','ar','hi','tr','pl','sv','no','da','fi','el','cs','ro','hu','sk','uk','he','id','vi','th','ms','tl','fa','ur','bn','ta','te','gu','pa','bg','hr','sr','sl','lv','lt','et','is','ca','gl'
   ]), [])
 
+  // Authoritative list for the active backend/model; the static sets below are a fallback
+  const [backendCodes, setBackendCodes] = React.useState<Set<string> | null>(null)
+
+  React.useEffect(() => {
+    let cancelled = false
+    const load = async () => {
+      try {
+        const supported = await invoke<{ code: string; name: string }[]>('get_supported_languages')
+        if (!cancelled && Array.isArray(supported)) {
+          setBackendCodes(new Set(supported.map(l => l.code)))
+        }
+      } catch (error) {
+        console.warn('Failed to load supported languages:', error)
+      }
+    }
+    void load()
+    return () => {
+      cancelled = true
+    }
+  }, [engine, englishOnly])
+
   const displayed = React.useMemo(() => {
     if (englishOnly) {
       return languages.filter(l => l.value === 'en')
     }
+    if (backendCodes) {
+      return languages.filter(l => backendCodes.has(l.value))
+    }
     if (engine === 'parakeet') {
       return languages.filter(l => parakeetAllowed.has(l.value))
     }
@@ -153,7 +178,7 @@ export function LanguageSelection({ value, onValueChange, className, engine = 'w
       return languages.filter(l => sonioxAllowed.has(l.value))
     }
     return languages
-  }, [engine, parakeetAllowed, sonioxAllowed, englishOnly])
+  }, [engine, parakeetAllowed, sonioxAllowed, englishOnly, backendCodes])
   
   return (
     <Popover open={open} onOpenChange={setOpen}>
@@ -3,8 +3,9 @@ import { useSetting } from "@/contexts/SettingsContext";
 import { useRecording } from "@/hooks/useRecording";
 import { PillIndicatorMode } from "@/types";
 import { listen } from "@tauri-apps/api/event";
-import { useEffect, useState } from "react";
+import { useEffect, useRef, useState } from "react";
 import { motion } from "framer-motion";
+import { EyeOff } from "lucide-react";
 
 type PillState = "idle" | "listening" | "transcribing" | "formatting";
 
@@ -16,6 +17,37 @@ export function RecordingPill() {
   // Setting: pill indicator mode (default: "when_recording")
   const pillIndicatorMode: PillIndicatorMode = useSetting("pill_indicator_mode") ?? "when_recording";
 
+  // Incognito: transcriptions aren't saved, so make that visible while dictating
+  const incognitoSetting = useSetting("incognito_mode") ?? false;
+  const [incognito, setIncognito] = useState(incognitoSetting);
+  useEffect(() => setIncognito(incognitoSetting), [incognitoSetting]);
+  useEffect(() => {
+    const unlisten = listen<boolean>("incognito-changed", (event) => {
+      setIncognito(event.payload);
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // Keep the pill up for overlay_timeout_ms after a transcription completes
+  const overlayTimeoutMs: number = useSetting("overlay_timeout_ms") ?? 0;
+  const [lingering, setLingering] = useState(false);
+  const previousState = useRef(recording.state);
+  useEffect(() => {
+    const wasTranscribing = previousState.current === "transcribing";
+    previousState.current = recording.state;
+    if (recording.state !== "idle") {
+      setLingering(false);
+      return;
+    }
+    if (wasTranscribing && overlayTimeoutMs > 0) {
+      setLingering(true);
+      const timeoutId = setTimeout(() => setLingering(false), overlayTimeoutMs);
+      return () => clearTimeout(timeoutId);
+    }
+  }, [recording.state, overlayTimeoutMs]);
+
   // Determine pill state
   const getPillState = (): PillState => {
     if (isFormatting) return "formatting";
@@ -92,10 +124,10 @@ export function RecordingPill() {
   // Determine if pill should be hidden based on mode and state
   // "never" → always hide
   // "always" → never hide (always show)
-  // "when_recording" → hide when idle
+  // "when_recording" → hide when idle (after any post-completion linger)
   const shouldHide =
     pillIndicatorMode === "never" ||
-    (pillIndicatorMode === "when_recording" && pillState === "idle");
+    (pillIndicatorMode === "when_recording" && pillState === "idle" && !lingering);
 
   if (shouldHide) {
     return null;
@@ -118,6 +150,12 @@ export function RecordingPill() {
           ease: "easeOut",
         }}
       >
+        {incognito && (
+          <EyeOff
+            className="mr-1.5 h-3 w-3 text-white/70"
+            aria-label="Incognito: not saved to history"
+          />
+        )}
         <AudioDots state={pillState} audioLevel={audioLevel} />
       </motion.div>
     </div>
@@ -1,6 +1,7 @@
 import { PermissionErrorBoundary } from "@/components/PermissionErrorBoundary";
 import { Button } from "@/components/ui/button";
 import { ScrollArea } from "@/components/ui/scroll-area";
+import { Switch } from "@/components/ui/switch";
 import {
   Tooltip,
   TooltipContent,
@@ -10,6 +11,7 @@ import {
 import { useReadiness } from "@/contexts/ReadinessContext";
 import { useSettings } from "@/contexts/SettingsContext";
 import { isMacOS } from "@/lib/platform";
+import { getErrorCode, getErrorMessage } from "@/utils/error";
 import { invoke } from "@tauri-apps/api/core";
 import { ask } from "@tauri-apps/plugin-dialog";
 import { relaunch } from "@tauri-apps/plugin-process";
@@ -27,6 +29,7 @@ import { toast } from "sonner";
 export function AdvancedSection() {
   const { updateSettings } = useSettings();
   const [isResetting, setIsResetting] = useState(false);
+  const [keepPersonalization, setKeepPersonalization] = useState(true);
   const [isRequestingPermission, setIsRequestingPermission] = useState<string | null>(null);
   const [showAccessibility, setShowAccessibility] = useState(true);
   const {
@@ -237,6 +240,20 @@ export function AdvancedSection() {
                     <li>Clear all settings and preferences</li>
                     <li>Reset system permissions</li>
                   </ul>
+                  <div className="flex items-center justify-between mb-3">
+                    <label
+                      htmlFor="keep-personalization"
+                      className="text-xs text-muted-foreground"
+                    >
+                      Keep dictionary, snippets and AI prompt settings
+                    </label>
+                    <Switch
+                      id="keep-personalization"
+                      checked={keepPersonalization}
+                      onCheckedChange={setKeepPersonalization}
+                      disabled={isResetting}
+                    />
+                  </div>
                   <Button
                     variant="destructive"
                     size="sm"
@@ -255,14 +272,18 @@ export function AdvancedSection() {
                       if (confirmed) {
                         setIsResetting(true);
                         try {
-                          await invoke("reset_app_data");
+                          await invoke("reset_app_data", { keepPersonalization, writeReport: true });
                           toast.success("App data reset successfully. Restarting...");
                           setTimeout(() => {
                             relaunch();
                           }, 1000);
                         } catch (error) {
                           console.error("Failed to reset app data:", error);
-                          toast.error("Failed to reset app data");
+                          toast.error(
+                            getErrorCode(error) === "busy"
+                              ? getErrorMessage(error)
+                              : "Failed to reset app data"
+                          );
                           setIsResetting(false);
                         }
                       }
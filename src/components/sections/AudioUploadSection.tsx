@@ -21,6 +21,14 @@ import { useUploadStore } from "@/state/upload";
 
 // local result type not needed; handled by store
 
+function formatRemaining(seconds: number): string {
+  const rounded = Math.max(1, Math.round(seconds));
+  if (rounded < 60) return `~${rounded}s remaining`;
+  const minutes = Math.floor(rounded / 60);
+  const rest = rounded % 60;
+  return rest === 0 ? `~${minutes}m remaining` : `~${minutes}m ${rest}s remaining`;
+}
+
 export function AudioUploadSection() {
   const [copied, setCopied] = useState(false);
   const [isDragging, setIsDragging] = useState(false);
@@ -31,12 +39,15 @@ export function AudioUploadSection() {
     status,
     resultText,
     error: storeError,
+    estimatedSeconds,
+    startedAt,
     select,
     clearSelection,
     start,
     reset
   } = useUploadStore();
   const isProcessing = status === 'processing';
+  const [now, setNow] = useState(() => Date.now());
   const effectiveFileName = selectedFile?.name || null;
   const hasEffectiveSelection = !!selectedFile;
 
@@ -123,30 +134,23 @@ export function AudioUploadSection() {
   };
 
   // Handle file drop
-  const handleFileDrop = (filePath: string) => {
-    // Validate file extension
-    const supportedExtensions = ['wav', 'mp3', 'm4a', 'flac', 'ogg', 'mp4', 'webm'];
-    const fileExtension = filePath.split('.').pop()?.toLowerCase();
+  // Tick once a second so the ETA counts down while processing
+  useEffect(() => {
+    if (!isProcessing || estimatedSeconds === null) return;
+    const interval = setInterval(() => setNow(Date.now()), 1000);
+    return () => clearInterval(interval);
+  }, [isProcessing, estimatedSeconds]);
 
-    if (!fileExtension || !supportedExtensions.includes(fileExtension)) {
-      toast.error("Unsupported file format. Please drop an audio or video file.");
-      return;
-    }
-
-    select(filePath);
-  };
+  const remainingSeconds =
+    isProcessing && estimatedSeconds !== null && startedAt !== null
+      ? estimatedSeconds - (now - startedAt) / 1000
+      : null;
 
   // Setup drag and drop listeners
   useEffect(() => {
-    // Listen for file drop events
-    const unlisten = listen('tauri://drag-drop', (event) => {
+    // Dropped files are queued and transcribed by the backend
+    const unlisten = listen('tauri://drag-drop', () => {
       setIsDragging(false);
-
-      const payload = event.payload as { paths: string[]; position: { x: number; y: number } };
-      if (payload.paths && payload.paths.length > 0) {
-        // Only take the first file if multiple are dropped
-        handleFileDrop(payload.paths[0]);
-      }
     });
 
     // Listen for drag over events
@@ -223,7 +227,7 @@ export function AudioUploadSection() {
                             <div className="space-y-1">
                               <Upload className="h-7 w-7 mx-auto text-primary animate-bounce" />
                               <p className="text-sm font-medium text-primary">
-                                Drop your audio or video file here
+                                Drop audio or video files to transcribe them
                               </p>
                               <p className="text-xs text-muted-foreground">
                                 WAV, MP3, M4A, FLAC, OGG, MP4, WebM
@@ -234,7 +238,7 @@ export function AudioUploadSection() {
                               <div className="space-y-1">
                                 <Upload className="h-7 w-7 mx-auto text-muted-foreground" />
                                 <p className="text-sm font-medium">
-                                  Drag & drop your audio or video file here
+                                  Drag & drop audio or video files here
                                 </p>
                                 <p className="text-xs text-muted-foreground">
                                   or click to browse
@@ -273,6 +277,12 @@ export function AudioUploadSection() {
                           )}
                         </Button>
                       )}
+
+                      {remainingSeconds !== null && (
+                        <p className="text-xs text-center text-muted-foreground">
+                          {remainingSeconds > 0 ? formatRemaining(remainingSeconds) : "Almost done..."}
+                        </p>
+                      )}
                   </div>
                 )}
 
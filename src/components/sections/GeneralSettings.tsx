@@ -14,9 +14,9 @@ import { ToggleGroup, ToggleGroupItem } from "@/components/ui/toggle-group";
 import { useCanAutoInsert } from "@/contexts/ReadinessContext";
 import { useSettings } from "@/contexts/SettingsContext";
 import { isMacOS } from "@/lib/platform";
-import { PillIndicatorMode, PillIndicatorPosition } from "@/types";
+import { MonitorInfo, PillIndicatorMode, PillIndicatorPosition } from "@/types";
+import { getErrorMessage } from "@/utils/error";
 import { invoke } from "@tauri-apps/api/core";
-import { disable, enable, isEnabled } from "@tauri-apps/plugin-autostart";
 import {
   AlertCircle,
   Info,
@@ -33,6 +33,7 @@ export function GeneralSettings() {
   const { settings, updateSettings } = useSettings();
   const [autostartEnabled, setAutostartEnabled] = useState(false);
   const [autostartLoading, setAutostartLoading] = useState(false);
+  const [monitors, setMonitors] = useState<MonitorInfo[]>([]);
   const [showAccessibilityWarning, setShowAccessibilityWarning] =
     useState(true);
   const canAutoInsert = useCanAutoInsert();
@@ -41,14 +42,18 @@ export function GeneralSettings() {
     // Check if autostart is enabled on component mount
     const checkAutostart = async () => {
       try {
-        const enabled = await isEnabled();
-        setAutostartEnabled(enabled);
+        const enabled = await invoke<boolean>("get_launch_at_login");
+        setAutostartEnabled(enabled === true);
       } catch (error) {
         console.error("Failed to check autostart status:", error);
       }
     };
     checkAutostart();
 
+    invoke<MonitorInfo[]>("list_monitors")
+      .then((result) => setMonitors(result ?? []))
+      .catch((error) => console.error("Failed to list monitors:", error));
+
     // Check platform for accessibility warning
     setShowAccessibilityWarning(isMacOS);
   }, []);
@@ -58,18 +63,13 @@ export function GeneralSettings() {
   const handleAutostartToggle = async (checked: boolean) => {
     setAutostartLoading(true);
     try {
-      // Use the plugin API to enable/disable autostart
-      if (checked) {
-        await enable();
-      } else {
-        await disable();
-      }
+      // Backend applies the OS entry and persists the setting together
+      await invoke("set_launch_at_login", { enabled: checked });
       setAutostartEnabled(checked);
-
-      // Update settings to keep them in sync (backend is source of truth)
       await updateSettings({ launch_at_startup: checked });
     } catch (error) {
       console.error("Failed to toggle autostart:", error);
+      toast.error(getErrorMessage(error, "Failed to change launch at login"));
       // Revert the state if there was an error
       setAutostartEnabled(!checked);
     } finally {
@@ -77,6 +77,23 @@ export function GeneralSettings() {
     }
   };
 
+  const handleOverlayPositionChange = async (
+    monitorId: string,
+    anchor: PillIndicatorPosition,
+  ) => {
+    try {
+      // Backend owns these keys; save_settings doesn't write them
+      await invoke("set_overlay_position", { monitorId, anchor });
+      await updateSettings({
+        overlay_monitor: monitorId,
+        pill_indicator_position: anchor,
+      });
+    } catch (error) {
+      console.error("Failed to change indicator position:", error);
+      toast.error(getErrorMessage(error, "Failed to change indicator position"));
+    }
+  };
+
   return (
     <div className="h-full flex flex-col">
       {/* Header */}
@@ -441,11 +458,12 @@ export function GeneralSettings() {
                     </div>
                     <Select
                       value={settings.pill_indicator_position ?? "bottom-center"}
-                      onValueChange={async (value: PillIndicatorPosition) => {
-                        await updateSettings({
-                          pill_indicator_position: value,
-                        });
-                      }}
+                      onValueChange={(value: PillIndicatorPosition) =>
+                        handleOverlayPositionChange(
+                          settings.overlay_monitor ?? "primary",
+                          value,
+                        )
+                      }
                     >
                       <SelectTrigger className="w-[160px]">
                         <SelectValue />
@@ -461,6 +479,43 @@ export function GeneralSettings() {
                     </Select>
                   </div>
                   
+                  {/* Which monitor the indicator appears on */}
+                  <div className="flex items-center justify-between">
+                    <div className="space-y-0.5">
+                      <Label
+                        htmlFor="overlay-monitor"
+                        className="text-sm font-medium"
+                      >
+                        Indicator Monitor
+                      </Label>
+                      <p className="text-xs text-muted-foreground">
+                        Screen to show the indicator on; disconnected monitors fall back to primary
+                      </p>
+                    </div>
+                    <Select
+                      value={settings.overlay_monitor ?? "primary"}
+                      onValueChange={(value: string) =>
+                        handleOverlayPositionChange(
+                          value,
+                          settings.pill_indicator_position ?? "bottom-center",
+                        )
+                      }
+                    >
+                      <SelectTrigger className="w-[160px]">
+                        <SelectValue />
+                      </SelectTrigger>
+                      <SelectContent>
+                        <SelectItem value="primary">Primary</SelectItem>
+                        <SelectItem value="follow_active">Follow Active</SelectItem>
+                        {monitors.map((monitor) => (
+                          <SelectItem key={monitor.id} value={monitor.id}>
+                            {monitor.id}
+                          </SelectItem>
+                        ))}
+                      </SelectContent>
+                    </Select>
+                  </div>
+
                   {/* Indicator edge offset slider */}
                   <div className="flex items-center justify-between">
                     <div className="space-y-0.5">
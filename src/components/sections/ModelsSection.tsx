@@ -47,6 +47,7 @@ export function ModelsSection({
   const { settings, updateSettings } = useSettings();
   const [cloudModal, setCloudModal] = useState<CloudModalState | null>(null);
   const [cloudModalLoading, setCloudModalLoading] = useState(false);
+  const [suggestedModel, setSuggestedModel] = useState<string | null>(null);
 
   const { availableToUse, availableToSetup } = useMemo(() => {
     const useList: [string, ModelInfo][] = [];
@@ -112,6 +113,19 @@ export function ModelsSection({
     return entry[1].display_name || currentModel;
   }, [currentModel, models]);
 
+  // Learned from past confidence scores for audio like the latest recording
+  useEffect(() => {
+    invoke<string | null>("suggest_model_for_current_conditions")
+      .then((model) => setSuggestedModel(model ?? null))
+      .catch(() => setSuggestedModel(null));
+  }, [currentModel]);
+
+  const suggestedModelLabel = useMemo(() => {
+    if (!suggestedModel || suggestedModel === currentModel) return null;
+    const entry = models.find(([name]) => name === suggestedModel);
+    return entry?.[1].display_name || suggestedModel;
+  }, [suggestedModel, currentModel, models]);
+
   useEffect(() => {
     if (!settings) return;
     if (isEnglishOnlyModel && settings.language !== "en") {
@@ -296,6 +310,11 @@ export function ModelsSection({
                 <span className="text-amber-600 dark:text-amber-500">
                   {activeModelLabel}
                 </span>
+                {suggestedModelLabel && (
+                  <span className="ml-2 text-xs">
+                    (best for your recent audio: {suggestedModelLabel})
+                  </span>
+                )}
               </span>
             ) : (
               availableToUse.length > 0 && (
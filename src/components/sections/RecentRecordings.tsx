@@ -48,6 +48,7 @@ export function RecentRecordings({ history, hotkey = "Cmd+Shift+Space", onHistor
     const query = searchQuery.toLowerCase();
     return history.filter(item => 
       item.text.toLowerCase().includes(query) ||
+      (item.title && item.title.toLowerCase().includes(query)) ||
       (item.model && item.model.toLowerCase().includes(query))
     );
   }, [history, searchQuery]);
@@ -267,9 +268,20 @@ export function RecentRecordings({ history, hotkey = "Cmd+Shift+Space", onHistor
                       >
                         <div className="flex items-start justify-between gap-4">
                           <div className="flex-1 min-w-0">
-                            <p className="text-sm text-foreground leading-relaxed line-clamp-5">
-                              {item.text}
-                            </p>
+                            {item.title ? (
+                              <>
+                                <p className="text-sm font-medium text-foreground truncate">
+                                  {item.title}
+                                </p>
+                                <p className="text-xs text-muted-foreground leading-relaxed line-clamp-2 mt-1">
+                                  {item.text}
+                                </p>
+                              </>
+                            ) : (
+                              <p className="text-sm text-foreground leading-relaxed line-clamp-5">
+                                {item.text}
+                              </p>
+                            )}
                             {item.model && (
                               <div className="mt-2">
                                 <span className="text-xs text-muted-foreground">
@@ -20,7 +20,8 @@ export function RecordingsTab() {
         id: item.timestamp || Date.now().toString(),
         text: item.text,
         timestamp: new Date(item.timestamp),
-        model: item.model
+        model: item.model,
+        title: item.title ?? null
       }));
       setHistory(formattedHistory);
     } catch (error) {
@@ -11,7 +11,8 @@ import { SettingsTab } from "./SettingsTab";
 import { AudioUploadSection } from "../sections/AudioUploadSection";
 import { useEffect, useState, useCallback } from "react";
 import { invoke } from "@tauri-apps/api/core";
-import { TranscriptionHistory } from "@/types";
+import { toast } from "sonner";
+import { DroppedFileResult, TranscriptionHistory } from "@/types";
 import { useEventCoordinator } from "@/hooks/useEventCoordinator";
 
 interface TabContainerProps {
@@ -32,7 +33,8 @@ export function TabContainer({ activeSection }: TabContainerProps) {
         id: item.timestamp || Date.now().toString(),
         text: item.text,
         timestamp: new Date(item.timestamp),
-        model: item.model
+        model: item.model,
+        title: item.title ?? null
       }));
       setHistory(formattedHistory);
     } catch (error) {
@@ -67,6 +69,17 @@ export function TabContainer({ activeSection }: TabContainerProps) {
       console.log("[TabContainer] Full history reload (delete/clear operation)");
       await loadHistory();
     });
+
+    // Files dropped onto the window are transcribed in the background
+    registerEvent<DroppedFileResult>("dropped-file-transcribed", (result) => {
+      const name = result.path.split(/[\\/]/).pop() || "audio file";
+      if (result.error) {
+        toast.error(`${name}: ${result.error}`);
+      } else {
+        const more = result.remaining > 0 ? ` (${result.remaining} more queued)` : "";
+        toast.success(`Transcribed ${name}${more}`);
+      }
+    });
   }, [loadHistory, registerEvent]);
 
   const renderTabContent = () => {
@@ -0,0 +1,65 @@
+import { describe, it, expect, beforeEach } from 'vitest';
+import { renderHook, act, waitFor } from '@testing-library/react';
+import { ReactNode } from 'react';
+import { mockIPC, clearMocks } from '@tauri-apps/api/mocks';
+import { SettingsProvider, useSettings } from './SettingsContext';
+import { emitMockEvent } from '../test/setup';
+
+const wrapper = ({ children }: { children: ReactNode }) => (
+  <SettingsProvider>{children}</SettingsProvider>
+);
+
+describe('SettingsContext', () => {
+  let stored: Record<string, unknown>;
+  let saved: Record<string, unknown>[];
+
+  beforeEach(() => {
+    clearMocks();
+    stored = {
+      current_model: 'base.en',
+      current_model_engine: 'whisper',
+      theme: 'system',
+      onboarding_completed: true,
+    };
+    saved = [];
+    mockIPC((cmd, args) => {
+      if (cmd === 'get_settings') return { ...stored };
+      if (cmd === 'save_settings') {
+        const settings = (args as { settings: Record<string, unknown> }).settings;
+        saved.push(settings);
+        stored = { ...settings };
+        return null;
+      }
+      return null;
+    });
+  });
+
+  it('keeps a backend switch made by set_backend when saving other settings', async () => {
+    const { result } = renderHook(() => useSettings(), { wrapper });
+    await waitFor(() => expect(result.current.settings?.current_model).toBe('base.en'));
+
+    // set_backend writes the store directly, then emits model-changed
+    stored = {
+      ...stored,
+      current_model: 'parakeet-tdt-0.6b-v3',
+      current_model_engine: 'parakeet',
+    };
+    act(() => {
+      emitMockEvent('model-changed', {
+        model: 'parakeet-tdt-0.6b-v3',
+        engine: 'parakeet',
+      });
+    });
+    await waitFor(() =>
+      expect(result.current.settings?.current_model_engine).toBe('parakeet')
+    );
+
+    await act(async () => {
+      await result.current.updateSettings({ theme: 'dark' });
+    });
+
+    expect(saved).toHaveLength(1);
+    expect(saved[0].current_model).toBe('parakeet-tdt-0.6b-v3');
+    expect(saved[0].current_model_engine).toBe('parakeet');
+  });
+});
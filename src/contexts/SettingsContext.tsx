@@ -76,8 +76,13 @@ export function SettingsProvider({ children }: { children: ReactNode }) {
       loadSettings();
     });
 
+    // The settings file was edited outside the app and reloaded
+    const unlistenReloaded = listen('settings-reloaded', () => {
+      loadSettings();
+    });
+
     return () => {
-      Promise.all([unlistenModel, unlistenLanguage, unlistenAudioDevice]).then(unsubs => {
+      Promise.all([unlistenModel, unlistenLanguage, unlistenAudioDevice, unlistenReloaded]).then(unsubs => {
         unsubs.forEach(unsub => unsub());
       });
     };
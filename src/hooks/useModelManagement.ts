@@ -3,6 +3,7 @@ import { ask } from "@tauri-apps/plugin-dialog";
 import { useCallback, useEffect, useRef, useState } from "react";
 import { toast } from "sonner";
 import { ModelInfo, isCloudModel } from "../types";
+import { getErrorCode, getErrorMessage } from "../utils/error";
 import { useEventCoordinator } from "./useEventCoordinator";
 
 interface UseModelManagementOptions {
@@ -128,8 +129,9 @@ export function useModelManagement(options: UseModelManagementOptions = {}) {
       // Don't await - let it run async so progress events can update UI
       invoke("download_model", { modelName }).catch((error) => {
         console.error("[useModelManagement.downloadModel] Failed to download model:", error);
-        if (showToasts) {
-          toast.error(`Failed to download model: ${error}`);
+        // Cancellation has its own toast via the download-cancelled event
+        if (showToasts && getErrorCode(error) !== "cancelled") {
+          toast.error(`Failed to download model: ${getErrorMessage(error)}`);
         }
         // Remove from progress on error
         setDownloadProgress((prev) => {
@@ -143,7 +145,7 @@ export function useModelManagement(options: UseModelManagementOptions = {}) {
     } catch (error) {
       console.error("[useModelManagement.downloadModel] Failed to start download:", error);
       if (showToasts) {
-        toast.error(`Failed to start download: ${error}`);
+        toast.error(`Failed to start download: ${getErrorMessage(error)}`);
       }
       // Remove from active downloads
       activeDownloads.current.delete(modelName);
@@ -167,7 +169,7 @@ export function useModelManagement(options: UseModelManagementOptions = {}) {
     } catch (error) {
       console.error("Failed to cancel download:", error);
       if (showToasts) {
-        toast.error(`Failed to cancel download: ${error}`);
+        toast.error(`Failed to cancel download: ${getErrorMessage(error)}`);
       }
     }
   }, [showToasts]);
@@ -201,7 +203,7 @@ export function useModelManagement(options: UseModelManagementOptions = {}) {
     } catch (error) {
       console.error("Failed to delete model:", error);
       if (showToasts) {
-        toast.error(`Failed to delete model: ${error}`);
+        toast.error(`Failed to delete model: ${getErrorMessage(error)}`);
       }
     }
   }, [loadModels, models, showToasts]);
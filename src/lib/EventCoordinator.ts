@@ -146,6 +146,8 @@ export class EventCoordinator {
       // Transcription events
       "transcription-complete": "pill",  // Pill window handles paste/clipboard/save
       "history-updated": "main",         // Main window reloads history
+      "transcription-cancelled": "all",
+      "transcription-chunk-progress": "main",
       "audio-level": "pill",
       "recording-state-changed": "all",
       
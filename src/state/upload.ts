@@ -10,17 +10,34 @@ type UploadState = {
   status: UploadStatus
   resultText: string | null
   error: string | null
+  /** Predicted processing time for the current run, once known */
+  estimatedSeconds: number | null
+  startedAt: number | null
   select: (path: string) => void
   clearSelection: () => void
   start: (modelName: string, modelEngine: string) => Promise<void>
   reset: () => void
 }
 
+async function estimateTranscription(filePath: string, modelName: string): Promise<number | null> {
+  try {
+    const audioSeconds = await invoke<number>('get_audio_file_duration', { filePath })
+    if (typeof audioSeconds !== 'number') return null
+    const estimate = await invoke<number>('estimate_transcription_time', { audioSeconds, modelName })
+    return typeof estimate === 'number' ? estimate : null
+  } catch (e) {
+    console.warn('Failed to estimate transcription time:', e)
+    return null
+  }
+}
+
 export const useUploadStore = create<UploadState>((set, get) => ({
   selectedFile: null,
   status: 'idle',
   resultText: null,
   error: null,
+  estimatedSeconds: null,
+  startedAt: null,
 
   select: (path: string) => {
     const name = path.split('/').pop() || path.split('\\').pop() || 'audio file'
@@ -33,7 +50,15 @@ export const useUploadStore = create<UploadState>((set, get) => ({
     const { selectedFile, status } = get()
     if (!selectedFile) return
     if (status === 'processing') return
-    set({ status: 'processing', error: null, resultText: null })
+    set({ status: 'processing', error: null, resultText: null, estimatedSeconds: null, startedAt: Date.now() })
+
+    // ETA is best-effort and must never hold up or fail the transcription
+    void estimateTranscription(selectedFile.path, modelName).then((estimatedSeconds) => {
+      if (estimatedSeconds !== null && get().status === 'processing') {
+        set({ estimatedSeconds })
+      }
+    })
+
     try {
       const text = await invoke<string>('transcribe_audio_file', {
         filePath: selectedFile.path,
@@ -52,5 +77,6 @@ export const useUploadStore = create<UploadState>((set, get) => ({
     }
   },
 
-  reset: () => set({ selectedFile: null, status: 'idle', resultText: null, error: null })
+  reset: () =>
+    set({ selectedFile: null, status: 'idle', resultText: null, error: null, estimatedSeconds: null, startedAt: null })
 }))
@@ -23,6 +23,16 @@ export interface LocalModelInfo extends BaseModelInfo {
   sha256: string;
   speed_score: number;
   accuracy_score: number;
+  quantizations?: string[]; // Installed whisper.cpp variants, e.g. "f16", "q5_0"
+  active_quantization?: string; // Variant that will be loaded (set_active_quantization)
+}
+
+// Returned by get_loaded_models_memory; sizes in bytes, memory is estimated
+export interface ModelMemory {
+  name: string;
+  quantization: string;
+  file_size: number;
+  estimated_bytes: number;
 }
 
 export interface CloudModelInfo extends BaseModelInfo {
@@ -41,6 +51,25 @@ export type RecordingMode = 'toggle' | 'push_to_talk';
 export type PillIndicatorMode = 'never' | 'always' | 'when_recording';
 export type PillIndicatorPosition = 'top-left' | 'top-center' | 'top-right' | 'bottom-left' | 'bottom-center' | 'bottom-right';
 
+export interface MonitorInfo {
+  id: string;
+  is_primary: boolean;
+  width: number;
+  height: number;
+}
+
+export interface RecordingStateSnapshot {
+  state: 'idle' | 'starting' | 'recording' | 'paused' | 'stopping' | 'transcribing' | 'cancelling' | 'error';
+  elapsed_ms: number | null;
+  current_model: string | null;
+}
+
+export type OutputSink =
+  | { type: 'active_app_paste' }
+  | { type: 'clipboard' }
+  | { type: 'file'; path: string }
+  | { type: 'webhook_post'; url: string };
+
 export interface AppSettings {
   hotkey: string;
   current_model: string;
@@ -69,6 +98,120 @@ export interface AppSettings {
   pill_indicator_offset?: number;
   // Pause system media during recording
   pause_media_during_recording?: boolean;
+  // Extra delay in ms before/between paste steps (0-2000)
+  paste_delay_ms?: number;
+  // Delay in ms after each typed character in type mode (0-200)
+  typing_char_delay_ms?: number;
+  // Show interim text while recording (local Whisper only)
+  partial_transcripts_enabled?: boolean;
+  // Auto-stop recording after this many seconds (0 = no limit)
+  max_recording_seconds?: number;
+  // Notify when a transcription completes while the app isn't focused
+  notify_on_complete?: boolean;
+  // Quiet hours window for notifications ("HH:MM")
+  quiet_hours_start?: string | null;
+  // End of quiet hours window ("HH:MM")
+  quiet_hours_end?: string | null;
+  // Record anonymized latency metrics locally (no text)
+  telemetry_enabled?: boolean;
+  // Pre-roll window captured before the hotkey press (ms, 0 = off)
+  preroll_ms?: number;
+  // Suppress background noise before transcription
+  noise_suppression?: boolean;
+  // Noise suppression strength (0-1)
+  noise_suppression_strength?: number;
+  // Trigger phrase -> expansion text applied before pasting
+  snippets?: Record<string, string>;
+  // Peak memory (MB) during transcription before the model is unloaded (0 = no cap)
+  max_memory_mb?: number;
+  // Output casing applied before pasting
+  output_style?: 'verbatim' | 'sentences' | 'lowercase' | 'uppercase';
+  // Strip or add punctuation at the end of the output
+  trailing_punctuation?: 'keep' | 'strip' | 'add';
+  // Where finished transcriptions are delivered, in order
+  output_sinks?: OutputSink[];
+  // URL that receives each completed transcription as JSON
+  webhook_url?: string | null;
+  // Extra HTTP headers sent with the webhook
+  webhook_headers?: Record<string, string>;
+  // Webhook request timeout in seconds
+  webhook_timeout_secs?: number;
+  // Extra webhook attempts after a failure
+  webhook_retries?: number;
+  // Set via migrate_data_dir
+  data_dir_override?: string | null;
+  // Days of logs kept by auto-cleanup (0 = keep forever)
+  log_retention_days?: number;
+  // Days leftover recordings are kept (0 = keep forever)
+  recording_retention_days?: number;
+  // Hours between auto-cleanup runs
+  cleanup_interval_hours?: number;
+  // 0..1; transcriptions scoring below are flagged low_confidence
+  min_confidence?: number;
+  // Keep recordings (with a JSON sidecar) linked to their history entry
+  keep_recordings?: boolean;
+  // Input gain in dB (-20 to 20)
+  recording_gain_db?: number;
+  // Peak-normalize recordings before transcription
+  normalize_peak?: boolean;
+  // Target peak for normalization, 0-1 of full scale
+  normalize_target_peak?: number;
+  // Monitor for the recording overlay: "primary", "follow_active" or a monitor name
+  overlay_monitor?: string;
+  // Log file format; json writes one object per line
+  log_format?: 'text' | 'json';
+  // Chunk length in seconds for long file transcriptions; 0 disables chunking
+  chunk_seconds?: number;
+  // Overlap between chunks in seconds
+  overlap_seconds?: number;
+  // Overwrite recordings with zeros before deleting them
+  secure_delete_recordings?: boolean;
+  // Reload settings when the settings file is edited externally
+  watch_settings_file?: boolean;
+  // Cut leading/trailing silence before transcription
+  trim_silence?: boolean;
+  // Paste transcriptions without saving them to history
+  incognito_mode?: boolean;
+  // How text reaches the focused app
+  output_mode?: 'paste' | 'type' | 'type_animated';
+  // Characters per second for type_animated output
+  typing_speed_cps?: number;
+  // output_mode per app, keyed by app name or id as shown in history
+  output_mode_by_app?: Record<string, 'paste' | 'type' | 'type_animated'>;
+  // Keep the overlay visible until transcription finishes
+  overlay_during_transcription?: boolean;
+  // Milliseconds the overlay stays up after completion (0 = hide at once)
+  overlay_timeout_ms?: number;
+  // Tag segments with speaker labels; only accepted with a -tdrz Whisper model
+  diarization?: boolean;
+  // What to do when a recording contains no speech
+  on_empty_audio?: 'skip' | 'paste_nothing' | 'notify';
+  // Proxy for HTTP requests, e.g. http://proxy:8080; credentials are set with set_proxy_credentials
+  http_proxy?: string | null;
+  // Proxy for HTTPS requests
+  https_proxy?: string | null;
+  // Comma-separated hosts that bypass the proxy
+  no_proxy?: string | null;
+  // Recording hotkey pressed while a transcription is running
+  hotkey_while_busy?: 'ignore' | 'queue_new_recording' | 'cancel_and_restart';
+  // Type text into the active app while speaking (toggle mode only); the tail is corrected after the final pass
+  live_typing?: boolean;
+  // Check for newer model files at startup (model-updates-available event)
+  auto_check_model_updates?: boolean;
+  // Audio cues for recording start, stop and transcription complete
+  play_sound_cues?: boolean;
+  // 0.0-1.0
+  sound_cue_volume?: number;
+  // WAV file replacing the built-in start tone
+  sound_cue_start_file?: string | null;
+  // WAV file replacing the built-in stop tone
+  sound_cue_stop_file?: string | null;
+  // WAV file replacing the built-in complete tone
+  sound_cue_complete_file?: string | null;
+  // Stay silent in Do Not Disturb / Focus mode
+  sound_cues_respect_dnd?: boolean;
+  // Ignore toggle hotkey presses within this many ms of the last one
+  hotkey_cooldown_ms?: number;
 }
 
 export interface TranscriptionHistory {
@@ -76,6 +219,25 @@ export interface TranscriptionHistory {
   text: string;
   timestamp: Date;
   model: string;
+  title?: string | null; // Optional user-assigned label
+  peak_memory_mb?: number; // Peak process memory while transcribing
+  segments?: TranscriptionSegment[]; // Timed caption segments (seconds)
+  confidence?: number; // 0..1, from Whisper token probabilities
+  low_confidence?: boolean; // Below the min_confidence setting
+  recording_file?: string; // Kept recording in the recordings directory (keep_recordings)
+  recording_archive_dir?: string; // Set once recording_file was moved here by archive_recordings_before
+  app?: ActiveApp; // Frontmost app while dictating; name is "unknown" if undetected
+  original_text?: string; // Text before retranscribe_low_confidence replaced it
+  retranscribed_at?: string;
+  quantization?: string; // whisper.cpp variant used, e.g. "f16" or "q5_0"
+}
+
+export interface TranscriptionSegment {
+  text: string;
+  start: number;
+  end: number;
+  confidence?: number | null;
+  speaker?: string; // "Speaker 1", ... when diarization labeled the segment
 }
 
 export interface LicenseStatus {
@@ -85,3 +247,246 @@ export interface LicenseStatus {
   license_key?: string;
   expires_at?: string;
 }
+
+export type ReadinessIssueKind =
+  | 'onboarding_incomplete'
+  | 'busy'
+  | 'microphone_permission'
+  | 'no_input_device'
+  | 'selected_device_missing'
+  | 'no_model'
+  | 'model_not_downloaded'
+  | 'soniox_key_missing'
+  | 'accessibility_permission';
+
+export interface ReadinessReport {
+  ready: boolean;
+  input_device: string | null;
+  model: string | null;
+  issues: { kind: ReadinessIssueKind; message: string; blocking: boolean }[];
+}
+
+export type CommandErrorCode =
+  | 'not_found'
+  | 'permission_denied'
+  | 'disk_full'
+  | 'model_missing'
+  | 'invalid_input'
+  | 'busy'
+  | 'cancelled'
+  | 'license_required'
+  | 'insufficient_memory'
+  | 'verification_failed'
+  | 'io'
+  | 'internal';
+
+/** Structured error returned by commands that classify their failures */
+export interface CommandError {
+  code: CommandErrorCode;
+  message: string;
+}
+
+export interface SelfTestCheck {
+  name: string;
+  status: 'pass' | 'fail' | 'skipped';
+  message: string;
+}
+
+/** Per-check results of run_self_test */
+export interface SelfTestReport {
+  passed: boolean;
+  checks: SelfTestCheck[];
+}
+
+export type DownloadStatus = 'queued' | 'downloading' | 'paused' | 'done' | 'failed';
+
+/** One entry of the `download-queue-updated` payload */
+export interface DownloadQueueItem {
+  model: string;
+  engine: string;
+  status: DownloadStatus;
+  downloaded: number;
+  total: number;
+  progress: number;
+  error: string | null;
+}
+
+/** Result of check_secure_store */
+export interface SecureStoreHealth {
+  status: 'healthy' | 'corrupted' | 'missing';
+  key_count: number;
+  corrupted_keys: string[];
+}
+
+/** Frontmost application; `id` is the bundle id (macOS) or executable (Windows) */
+export interface ActiveApp {
+  name: string;
+  id: string | null;
+}
+
+/** Result of test_paste */
+export interface PasteTestResult {
+  app: ActiveApp | null;
+  marker: string;
+  received: string | null;
+  intact: boolean;
+}
+
+/** Result of measure_latency (also sent as `latency-measured`); stages are in ms, null if skipped */
+export interface LatencyBreakdown {
+  device_open_ms: number | null;
+  capture_ms: number | null;
+  preprocessing_ms: number | null;
+  inference_ms: number | null;
+  injection_ms: number | null;
+  total_ms: number;
+  bottleneck: string | null;
+  notes: string[];
+}
+
+/** Manifest of a history export job (start_history_export / get_export_progress) */
+export interface ExportManifest {
+  job_id: string;
+  format: 'markdown' | 'json';
+  dest: string;
+  status: 'running' | 'interrupted' | 'done' | 'failed';
+  total: number;
+  written: number;
+  bytes_written: number;
+  last_key: string | null;
+  /** SHA-256 of the finished file */
+  sha256: string | null;
+  error: string | null;
+}
+
+/** One step of a candidate pipeline for preview_filters_on_history */
+export type TextFilter =
+  | { type: 'corrections'; dictionary: string[]; max_distance?: number }
+  | { type: 'snippets'; snippets: Record<string, string> }
+  | { type: 'output_style'; style: 'verbatim' | 'sentences' | 'lowercase' | 'uppercase' }
+  | { type: 'trailing_punctuation'; mode: 'keep' | 'strip' | 'add' };
+
+/** A stored transcription before and after a candidate filter pipeline */
+export interface FilterPreview {
+  timestamp: string;
+  before: string;
+  after: string;
+  changed: boolean;
+}
+
+/** Result of check_time_consistency */
+export interface TimeReport {
+  system_time: string;
+  utc_offset_minutes: number;
+  log_files_checked: number;
+  log_date_mismatches: {
+    file: string;
+    name_date: string;
+    modified: string;
+    skew_days: number;
+  }[];
+  future_log_files: string[];
+  future_transcriptions: number;
+  newest_transcription: string | null;
+  issues: string[];
+}
+
+/** Regex redaction rule for export_transcription_redacted; replacement defaults to "[REDACTED]" */
+export interface RedactionRule {
+  pattern: string;
+  replacement?: string | null;
+}
+
+export interface ProxyStatus {
+  configured: boolean;
+  proxy: string | null; // Password masked
+  reachable: boolean;
+  status_code: number | null;
+  latency_ms: number | null;
+  error: string | null;
+}
+
+/** Payload of the "retranscribe-progress" event */
+export interface RetranscribeProgress {
+  done: number;
+  total: number;
+  updated: number; // Entries whose text was replaced
+  timestamp: string | null; // History key just processed
+  finished: boolean;
+  cancelled: boolean;
+}
+
+/** Payload of the "hotkey-while-busy" event */
+export interface HotkeyWhileBusyEvent {
+  action: 'ignored' | 'queued' | 'cancelled_and_restarting';
+  state: 'Stopping' | 'Transcribing';
+}
+
+// Returned by get_pending_crash_reports; secrets are scrubbed before writing
+export interface PendingCrashReport {
+  file_name: string; // Pass to dismiss_crash_report
+  path: string;
+  created_at: string;
+  app_version: string;
+  location: string;
+  message: string;
+  recording_state: string | null;
+  system: {
+    os: string;
+    os_version: string | null;
+    arch: string;
+    total_memory_mb: number;
+  };
+  log_tail: string[];
+}
+
+// Returned by check_model_updates and sent with "model-updates-available"
+export interface ModelUpdate {
+  name: string;
+  current_checksum: string;
+  latest_checksum: string;
+  latest_version: string | null;
+  size: number; // Bytes
+}
+
+// Result of migrate_models_layout
+export interface MigrationReport {
+  from_version: number; // 0 for an unversioned models directory
+  to_version: number;
+  moved: string[]; // "old -> new", relative to the models directory
+  skipped: string[];
+  errors: string[];
+}
+
+// Payload of the dropped-file-transcribed event
+export interface DroppedFileResult {
+  path: string;
+  text: string | null;
+  error: string | null;
+  remaining: number; // Files still queued after this one
+}
+
+// Whisper decoding parameters (get_whisper_params / set_whisper_params)
+export interface WhisperParams {
+  temperature: number; // 0.0-1.0
+  beam_size: number; // 1-10; 1 uses greedy decoding
+  best_of: number; // 1-10; greedy decoding only
+  no_speech_threshold: number; // 0.0-1.0
+}
+
+// One row of compare_params output
+export interface ParamResult {
+  params: WhisperParams; // As applied, after clamping
+  text: string;
+  confidence: number | null;
+  elapsed_ms: number;
+  error: string | null;
+}
+
+// One directory in check_writable_paths / the unwritable-paths event
+export interface PathStatus {
+  name: 'models' | 'recordings' | 'stores' | 'history' | 'logs';
+  path: string;
+  writable: boolean;
+  error: string | null;
+}
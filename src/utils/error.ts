@@ -1,3 +1,19 @@
+import type { CommandError, CommandErrorCode } from "@/types";
+
+export function isCommandError(error: unknown): error is CommandError {
+  return (
+    typeof error === "object" &&
+    error !== null &&
+    typeof (error as CommandError).code === "string" &&
+    typeof (error as CommandError).message === "string"
+  );
+}
+
+/** Machine-readable code of a structured command error, if it is one */
+export function getErrorCode(error: unknown): CommandErrorCode | undefined {
+  return isCommandError(error) ? error.code : undefined;
+}
+
 export function getErrorMessage(error: unknown, fallback?: string): string {
   if (typeof error === "string") {
     return error || fallback || "An unexpected error occurred";
@@ -7,6 +23,10 @@ export function getErrorMessage(error: unknown, fallback?: string): string {
     return error.message || fallback || "An unexpected error occurred";
   }
 
+  if (isCommandError(error)) {
+    return error.message || fallback || "An unexpected error occurred";
+  }
+
   try {
     const serialized = JSON.stringify(error);
     if (serialized && serialized !== "{}") {
@@ -18,4 +38,3 @@ export function getErrorMessage(error: unknown, fallback?: string): string {
 
   return fallback || "An unexpected error occurred";
 }
-